@@ -0,0 +1,5 @@
+//! Session server that relays input between devices in the same session.
+
+pub mod model;
+pub mod play;
+pub mod system;
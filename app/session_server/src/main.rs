@@ -4,6 +4,7 @@ use std::{
     io::BufReader,
     net::{IpAddr, TcpListener},
     path::PathBuf,
+    time::Duration,
 };
 
 use amethyst::{
@@ -11,22 +12,29 @@ use amethyst::{
     Application, Error, GameDataBuilder, LoggerConfig, SimpleState,
 };
 use frame_rate::strategy::frame_rate_limit_config;
-use net_play::{NetListenerSystem, NetListenerSystemDesc};
+use net_play::{
+    GameInputReceiveSystem, GameInputRetransmitSystem, NetListenerSystem, NetListenerSystemDesc,
+};
+use session_reconnect_model::config::SessionReconnectConfig;
 use structopt::StructOpt;
 
-use crate::system::{
-    NetworkInputResponderSystem, NetworkInputResponderSystemDesc,
-    SessionDeviceDisconnectResponderSystem, SessionDeviceDisconnectResponderSystemDesc,
-    SessionHostResponderSystem, SessionHostResponderSystemDesc, SessionJoinResponderSystem,
-    SessionJoinResponderSystemDesc, SessionLobbyResponderSystem, SessionLobbyResponderSystemDesc,
-    SessionMessageResponderSystem, SessionMessageResponderSystemDesc,
+use session_server::{
+    model::{AdminListener, SessionLimits},
+    system::{
+        GamePlayPauseResponderSystem, GamePlayPauseResponderSystemDesc,
+        GamePlayRestartResponderSystem, GamePlayRestartResponderSystemDesc,
+        NetworkInputResponderSystem, NetworkInputResponderSystemDesc, ServerMetricsSystem,
+        ServerMetricsSystemDesc, SessionAdminSystem, SessionAdminSystemDesc,
+        SessionDeviceDisconnectResponderSystem, SessionDeviceDisconnectResponderSystemDesc,
+        SessionExpirySystem, SessionHostResponderSystem, SessionHostResponderSystemDesc,
+        SessionJoinResponderSystem, SessionJoinResponderSystemDesc, SessionLobbyResponderSystem,
+        SessionLobbyResponderSystemDesc, SessionMessageResponderSystem,
+        SessionMessageResponderSystemDesc, SessionReconnectResponderSystem,
+        SessionReconnectResponderSystemDesc, StateChecksumResponderSystem,
+        StateChecksumResponderSystemDesc,
+    },
 };
 
-pub mod model;
-pub mod play;
-
-mod system;
-
 /// Default file for logger configuration.
 const LOGGER_CONFIG: &str = "logger.yaml";
 
@@ -51,6 +59,31 @@ pub struct Opt {
     /// Port that the session server is listening on.
     #[structopt(long, default_value = "1234")]
     port: u16,
+    /// Number of seconds a disconnected device's place in a session is held for.
+    #[structopt(long, default_value = "30")]
+    reconnect_grace_period: u64,
+    /// Number of seconds a session lobby may be idle before its session code expires.
+    #[structopt(long, default_value = "600")]
+    session_code_expiry: u64,
+    /// Maximum number of devices in a session, including spectators.
+    #[structopt(long, default_value = "8")]
+    session_devices_max: usize,
+    /// Maximum number of host / join requests from an IP address within the request window.
+    #[structopt(long, default_value = "10")]
+    request_limit: u32,
+    /// Number of seconds over which host / join requests from an IP address are counted.
+    #[structopt(long, default_value = "10")]
+    request_window: u64,
+    /// Number of seconds an IP address is banned for after exceeding the request limit.
+    #[structopt(long, default_value = "60")]
+    ban_duration: u64,
+
+    /// Address to bind the admin interface to.
+    #[structopt(long, default_value = "127.0.0.1")]
+    admin_address: IpAddr,
+    /// Port for the admin interface. The admin interface is disabled when not specified.
+    #[structopt(long)]
+    admin_port: Option<u16>,
 }
 
 fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
@@ -97,6 +130,14 @@ fn main() -> Result<(), Error> {
     let tcp_listener = TcpListener::bind((opt.address, opt.port))?;
     tcp_listener.set_nonblocking(true)?;
 
+    let admin_tcp_listener = if let Some(admin_port) = opt.admin_port {
+        let admin_tcp_listener = TcpListener::bind((opt.admin_address, admin_port))?;
+        admin_tcp_listener.set_nonblocking(true)?;
+        Some(admin_tcp_listener)
+    } else {
+        None
+    };
+
     let assets_dir = application_root_dir()?.join("./");
 
     let game_data = GameDataBuilder::default()
@@ -106,6 +147,11 @@ fn main() -> Result<(), Error> {
             any::type_name::<NetListenerSystem>(),
             &["network_recv"],
         )
+        .with_system_desc(
+            ServerMetricsSystemDesc::default(),
+            any::type_name::<ServerMetricsSystem>(),
+            &["network_recv"],
+        )
         .with_system_desc(
             SessionHostResponderSystemDesc::default(),
             any::type_name::<SessionHostResponderSystem>(),
@@ -116,15 +162,30 @@ fn main() -> Result<(), Error> {
             any::type_name::<SessionJoinResponderSystem>(),
             &[any::type_name::<NetListenerSystem>()],
         )
+        .with_system_desc(
+            SessionReconnectResponderSystemDesc::default(),
+            any::type_name::<SessionReconnectResponderSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
         .with_system_desc(
             SessionLobbyResponderSystemDesc::default(),
             any::type_name::<SessionLobbyResponderSystem>(),
             &[any::type_name::<NetListenerSystem>()],
         )
+        .with(
+            GameInputReceiveSystem::new(),
+            any::type_name::<GameInputReceiveSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
         .with_system_desc(
             NetworkInputResponderSystemDesc::default(),
             any::type_name::<NetworkInputResponderSystem>(),
-            &[any::type_name::<NetListenerSystem>()],
+            &[any::type_name::<GameInputReceiveSystem>()],
+        )
+        .with(
+            GameInputRetransmitSystem::new(),
+            any::type_name::<GameInputRetransmitSystem>(),
+            &[any::type_name::<NetworkInputResponderSystem>()],
         )
         .with_system_desc(
             SessionMessageResponderSystemDesc::default(),
@@ -134,14 +195,73 @@ fn main() -> Result<(), Error> {
                 any::type_name::<NetworkInputResponderSystem>(),
             ],
         )
+        .with_system_desc(
+            StateChecksumResponderSystemDesc::default(),
+            any::type_name::<StateChecksumResponderSystem>(),
+            &[any::type_name::<NetListenerSystem>()],
+        )
+        .with_system_desc(
+            GamePlayRestartResponderSystemDesc::default(),
+            any::type_name::<GamePlayRestartResponderSystem>(),
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<NetworkInputResponderSystem>(),
+                any::type_name::<StateChecksumResponderSystem>(),
+            ],
+        )
+        .with_system_desc(
+            GamePlayPauseResponderSystemDesc::default(),
+            any::type_name::<GamePlayPauseResponderSystem>(),
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<NetworkInputResponderSystem>(),
+            ],
+        )
         .with_system_desc(
             SessionDeviceDisconnectResponderSystemDesc::default(),
             any::type_name::<SessionDeviceDisconnectResponderSystem>(),
-            &[any::type_name::<NetListenerSystem>()],
+            &[
+                any::type_name::<NetListenerSystem>(),
+                any::type_name::<SessionReconnectResponderSystem>(),
+            ],
+        )
+        .with(
+            SessionExpirySystem::new(),
+            any::type_name::<SessionExpirySystem>(),
+            &[
+                any::type_name::<SessionHostResponderSystem>(),
+                any::type_name::<SessionJoinResponderSystem>(),
+                any::type_name::<SessionLobbyResponderSystem>(),
+                any::type_name::<SessionMessageResponderSystem>(),
+            ],
+        )
+        .with_system_desc(
+            SessionAdminSystemDesc::default(),
+            any::type_name::<SessionAdminSystem>(),
+            &[
+                any::type_name::<ServerMetricsSystem>(),
+                any::type_name::<SessionMessageResponderSystem>(),
+                any::type_name::<StateChecksumResponderSystem>(),
+                any::type_name::<SessionDeviceDisconnectResponderSystem>(),
+                any::type_name::<SessionExpirySystem>(),
+            ],
         );
 
+    let session_reconnect_config =
+        SessionReconnectConfig::new(Duration::from_secs(opt.reconnect_grace_period));
+    let session_limits = SessionLimits::new(
+        Duration::from_secs(opt.session_code_expiry),
+        opt.session_devices_max,
+        opt.request_limit,
+        Duration::from_secs(opt.request_window),
+        Duration::from_secs(opt.ban_duration),
+    );
+
     let mut game = Application::build(assets_dir, RunState)?
         .with_frame_limit_config(frame_rate_limit_config(opt.frame_rate))
+        .with_resource(session_reconnect_config)
+        .with_resource(session_limits)
+        .with_resource(AdminListener::new(admin_tcp_listener))
         .build(game_data)?;
     game.run();
 
@@ -1,21 +1,57 @@
 //! Data types used at runtime.
 
 pub use self::{
+    admin_command::AdminCommand,
+    admin_listener::AdminListener,
+    disconnected_device::DisconnectedDevice,
+    disconnected_devices::DisconnectedDevices,
     game_input_tick_status::GameInputTickStatus,
+    ip_request_tracker::IpRequestTracker,
+    ip_request_trackers::IpRequestTrackers,
+    server_metrics::ServerMetrics,
     session_code_id::SessionCodeId,
     session_code_to_id::SessionCodeToId,
+    session_codes_expired::SessionCodesExpired,
     session_device_mappings::{SessionDeviceMappings, SessionDeviceMappingsRead},
+    session_device_reconnect_tokens::SessionDeviceReconnectTokens,
+    session_device_state_checksums::SessionDeviceStateChecksums,
     session_device_tick_statuses::SessionDeviceTickStatuses,
+    session_game_ticks::SessionGameTicks,
+    session_handshakes::SessionHandshakes,
     session_id_to_device_mappings::SessionIdToDeviceMappings,
+    session_last_active::SessionLastActive,
+    session_limits::SessionLimits,
+    session_reconnect_tokens::SessionReconnectTokens,
+    session_state_checksums::SessionStateChecksums,
     session_tick_statuses::SessionTickStatuses,
     socket_to_device_id::SocketToDeviceId,
+    socket_to_device_type::SocketToDeviceType,
+    socket_to_last_seen::SocketToLastSeen,
 };
 
+mod admin_command;
+mod admin_listener;
+mod disconnected_device;
+mod disconnected_devices;
 mod game_input_tick_status;
+mod ip_request_tracker;
+mod ip_request_trackers;
+mod server_metrics;
 mod session_code_id;
 mod session_code_to_id;
+mod session_codes_expired;
 mod session_device_mappings;
+mod session_device_reconnect_tokens;
+mod session_device_state_checksums;
 mod session_device_tick_statuses;
+mod session_game_ticks;
+mod session_handshakes;
 mod session_id_to_device_mappings;
+mod session_last_active;
+mod session_limits;
+mod session_reconnect_tokens;
+mod session_state_checksums;
 mod session_tick_statuses;
 mod socket_to_device_id;
+mod socket_to_device_type;
+mod socket_to_last_seen;
@@ -0,0 +1,104 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use network_session_model::play::{SessionCode, SessionDeviceId};
+
+/// Commands accepted by the session server admin interface.
+///
+/// Each command is sent as a single line of text.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AdminCommand {
+    /// Lists the available commands.
+    Help,
+    /// Lists active sessions and their devices.
+    Sessions,
+    /// Shows message counters and tick wait latency.
+    Metrics,
+    /// Removes a device from a session.
+    Kick {
+        /// Code of the session the device is in.
+        session_code: SessionCode,
+        /// ID of the device to remove.
+        session_device_id: SessionDeviceId,
+    },
+    /// Removes a session and all of its devices.
+    Close {
+        /// Code of the session to remove.
+        session_code: SessionCode,
+    },
+    /// Closes the admin connection.
+    Quit,
+}
+
+impl AdminCommand {
+    /// Usage text for all commands.
+    pub const USAGE: &'static str = "\
+help                           Lists the available commands.
+sessions                       Lists active sessions and their devices.
+metrics                        Shows message counters and tick wait latency.
+kick <session_code> <id>       Removes a device from a session.
+close <session_code>           Removes a session and all of its devices.
+quit                           Closes the admin connection.";
+}
+
+impl FromStr for AdminCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        let mut tokens = s.split_whitespace();
+        let command = tokens.next().unwrap_or("");
+        let admin_command = match command {
+            "help" => AdminCommand::Help,
+            "sessions" => AdminCommand::Sessions,
+            "metrics" => AdminCommand::Metrics,
+            "kick" => {
+                let session_code = tokens.next().map(|s| SessionCode::new(String::from(s)));
+                let session_device_id = tokens.next().map(SessionDeviceId::from_str);
+                match (session_code, session_device_id) {
+                    (Some(session_code), Some(Ok(session_device_id))) => AdminCommand::Kick {
+                        session_code,
+                        session_device_id,
+                    },
+                    _ => return Err(String::from("Usage: kick <session_code> <id>")),
+                }
+            }
+            "close" => match tokens.next() {
+                Some(session_code) => AdminCommand::Close {
+                    session_code: SessionCode::new(String::from(session_code)),
+                },
+                None => return Err(String::from("Usage: close <session_code>")),
+            },
+            "quit" => AdminCommand::Quit,
+            _ => {
+                return Err(format!(
+                    "Unknown command: `{}`. Enter `help` for a list of commands.",
+                    command
+                ))
+            }
+        };
+
+        if tokens.next().is_some() {
+            Err(format!("Too many arguments for `{}`.", command))
+        } else {
+            Ok(admin_command)
+        }
+    }
+}
+
+impl Display for AdminCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdminCommand::Help => write!(f, "help"),
+            AdminCommand::Sessions => write!(f, "sessions"),
+            AdminCommand::Metrics => write!(f, "metrics"),
+            AdminCommand::Kick {
+                session_code,
+                session_device_id,
+            } => write!(f, "kick {} {}", session_code, session_device_id),
+            AdminCommand::Close { session_code } => write!(f, "close {}", session_code),
+            AdminCommand::Quit => write!(f, "quit"),
+        }
+    }
+}
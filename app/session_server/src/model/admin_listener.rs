@@ -0,0 +1,12 @@
+use std::net::TcpListener;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Listener for admin interface connections.
+///
+/// This is `None` when the admin interface is disabled.
+///
+/// `Option<TcpListener>` newtype.
+#[derive(Debug, Default, Deref, DerefMut, new)]
+pub struct AdminListener(pub Option<TcpListener>);
@@ -0,0 +1,17 @@
+use std::time::Instant;
+
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+
+use crate::model::SessionCodeId;
+
+/// Device whose connection dropped, and whose place in its session is being held.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct DisconnectedDevice {
+    /// ID of the session the device belongs to.
+    pub session_code_id: SessionCodeId,
+    /// ID of the device in the session.
+    pub session_device_id: SessionDeviceId,
+    /// When the connection dropped.
+    pub disconnected_at: Instant,
+}
@@ -0,0 +1,12 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::DisconnectedDevice;
+
+/// Tracks devices whose connection dropped, by their previous `SocketAddr`.
+///
+/// `HashMap<SocketAddr, DisconnectedDevice>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct DisconnectedDevices(pub HashMap<SocketAddr, DisconnectedDevice>);
@@ -0,0 +1,16 @@
+use std::time::Instant;
+
+use derive_new::new;
+
+/// Tracks host / join requests from an IP address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, new)]
+pub struct IpRequestTracker {
+    /// When the current request window started.
+    pub window_start: Instant,
+    /// Number of requests received in the current window.
+    #[new(default)]
+    pub request_count: u32,
+    /// When the ban on the IP address is lifted, if it is banned.
+    #[new(default)]
+    pub banned_until: Option<Instant>,
+}
@@ -0,0 +1,68 @@
+use std::{collections::HashMap, net::IpAddr, time::Instant};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::{IpRequestTracker, SessionLimits};
+
+/// Tracks host / join requests from each IP address, used to rate limit requests.
+///
+/// `HashMap<IpAddr, IpRequestTracker>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct IpRequestTrackers(pub HashMap<IpAddr, IpRequestTracker>);
+
+impl IpRequestTrackers {
+    /// Records a request from the IP address, returning whether it is allowed.
+    ///
+    /// When an IP address exceeds the request limit, it is banned for the `SessionLimits` ban
+    /// duration.
+    ///
+    /// # Parameters
+    ///
+    /// * `ip_addr`: IP address the request came from.
+    /// * `session_limits`: Request limits.
+    /// * `now`: Time the request was received.
+    pub fn request_allowed(
+        &mut self,
+        ip_addr: IpAddr,
+        session_limits: &SessionLimits,
+        now: Instant,
+    ) -> bool {
+        let ip_request_tracker = self
+            .entry(ip_addr)
+            .or_insert_with(|| IpRequestTracker::new(now));
+
+        if let Some(banned_until) = ip_request_tracker.banned_until {
+            if now < banned_until {
+                return false;
+            }
+            *ip_request_tracker = IpRequestTracker::new(now);
+        }
+
+        if now.duration_since(ip_request_tracker.window_start) >= session_limits.request_window {
+            *ip_request_tracker = IpRequestTracker::new(now);
+        }
+
+        ip_request_tracker.request_count += 1;
+        if ip_request_tracker.request_count > session_limits.request_limit {
+            ip_request_tracker.banned_until = Some(now + session_limits.ban_duration);
+            false
+        } else {
+            true
+        }
+    }
+
+    /// Removes trackers whose window has elapsed and are not banned.
+    pub fn prune(&mut self, session_limits: &SessionLimits, now: Instant) {
+        self.retain(|_, ip_request_tracker| {
+            let banned = ip_request_tracker
+                .banned_until
+                .map(|banned_until| now < banned_until)
+                .unwrap_or(false);
+            let window_active =
+                now.duration_since(ip_request_tracker.window_start) < session_limits.request_window;
+
+            banned || window_active
+        });
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use derive_new::new;
+
+/// Counters for messages handled by the session server.
+///
+/// These are reported through the admin interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, new)]
+pub struct ServerMetrics {
+    /// Number of messages received from devices.
+    pub messages_in: u64,
+    /// Number of messages sent to devices.
+    pub messages_out: u64,
+    /// Number of messages that were rejected or ignored.
+    pub messages_rejected: u64,
+    /// Number of ticks where the server waited for all devices to send `GameInputTick`.
+    pub tick_wait_count: u64,
+    /// Total time waited for all devices to send `GameInputTick`.
+    pub tick_wait_total: Duration,
+    /// Longest time waited for all devices to send `GameInputTick`.
+    pub tick_wait_max: Duration,
+}
+
+impl ServerMetrics {
+    /// Records the time waited for all devices in a session to send `GameInputTick`.
+    pub fn tick_wait_record(&mut self, tick_wait: Duration) {
+        self.tick_wait_count += 1;
+        self.tick_wait_total += tick_wait;
+        if self.tick_wait_max < tick_wait {
+            self.tick_wait_max = tick_wait;
+        }
+    }
+
+    /// Returns the mean time waited for all devices to send `GameInputTick`.
+    pub fn tick_wait_mean(&self) -> Duration {
+        if self.tick_wait_count == 0 {
+            Duration::default()
+        } else {
+            self.tick_wait_total / self.tick_wait_count as u32
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use std::{collections::HashMap, time::Instant};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionCode;
+
+/// Session codes that expired, and when they expired.
+///
+/// These are remembered so that join requests for an expired code can be told so.
+///
+/// `HashMap<SessionCode, Instant>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionCodesExpired(pub HashMap<SessionCode, Instant>);
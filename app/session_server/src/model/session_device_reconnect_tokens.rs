@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::{SessionDeviceId, SessionReconnectToken};
+
+/// Tracks the `SessionReconnectToken` issued to each `SessionDeviceId`.
+///
+/// `HashMap<SessionDeviceId, SessionReconnectToken>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionDeviceReconnectTokens(pub HashMap<SessionDeviceId, SessionReconnectToken>);
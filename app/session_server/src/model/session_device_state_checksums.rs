@@ -0,0 +1,11 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::{SessionDeviceId, StateChecksum};
+
+/// Tracks the `StateChecksum` that each `SessionDeviceId` has sent for a tick.
+///
+/// `HashMap<SessionDeviceId, StateChecksum>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionDeviceStateChecksums(pub HashMap<SessionDeviceId, StateChecksum>);
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::play::GameTick;
+
+use crate::model::SessionCodeId;
+
+/// Tracks the latest `GameTick` of input relayed for each `Session`.
+///
+/// `HashMap<SessionCodeId, GameTick>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionGameTicks(pub HashMap<SessionCodeId, GameTick>);
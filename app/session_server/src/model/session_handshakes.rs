@@ -0,0 +1,15 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionHandshake;
+
+use crate::model::SessionCodeId;
+
+/// Handshake of the session host for each session.
+///
+/// Devices joining a session must have the same assets and session sync mode as the host.
+///
+/// `HashMap<SessionCodeId, SessionHandshake>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionHandshakes(pub HashMap<SessionCodeId, SessionHandshake>);
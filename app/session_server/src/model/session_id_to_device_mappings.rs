@@ -2,6 +2,7 @@ use std::{collections::HashMap, net::SocketAddr};
 
 use derive_new::new;
 use net_model::play::{NetSessionDevice, NetSessionDevices};
+use network_session_model::play::SessionDeviceId;
 
 use crate::model::SessionCodeId;
 
@@ -222,6 +223,41 @@ impl SessionIdToDeviceMappings {
         }
     }
 
+    /// Replaces the `SocketAddr` of a device in a session, returning the previous `SocketAddr`.
+    ///
+    /// This is used when a device reconnects to its session from a different socket.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_code_id`: ID of the session.
+    /// * `session_device_id`: ID of the device in the session.
+    /// * `socket_addr`: New `SocketAddr` of the device.
+    pub fn socket_addr_replace(
+        &mut self,
+        session_code_id: SessionCodeId,
+        session_device_id: SessionDeviceId,
+        socket_addr: SocketAddr,
+    ) -> Option<SocketAddr> {
+        let net_session_device = self
+            .session_code_id_to_devices
+            .get_mut(&session_code_id)
+            .and_then(|net_session_devices| {
+                net_session_devices
+                    .iter_mut()
+                    .find(|net_session_device| net_session_device.data.id == session_device_id)
+            })?;
+
+        let socket_addr_previous =
+            std::mem::replace(&mut net_session_device.socket_addr, socket_addr);
+
+        self.socket_addr_to_session_code_id
+            .remove(&socket_addr_previous);
+        self.socket_addr_to_session_code_id
+            .insert(socket_addr, session_code_id);
+
+        Some(socket_addr_previous)
+    }
+
     /// Reserves capacity for at least `additional` more mappings to be inserted.
     ///
     /// This may reserve more space to avosession_code frequent reallocations.
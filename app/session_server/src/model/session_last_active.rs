@@ -0,0 +1,14 @@
+use std::{collections::HashMap, time::Instant};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::SessionCodeId;
+
+/// When each session lobby was last active.
+///
+/// Sessions are removed from this map when they start, so that sessions in play do not expire.
+///
+/// `HashMap<SessionCodeId, Instant>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionLastActive(pub HashMap<SessionCodeId, Instant>);
@@ -0,0 +1,30 @@
+use std::time::Duration;
+
+use derive_new::new;
+
+/// Limits on sessions and requests enforced by the session server.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct SessionLimits {
+    /// Duration a session lobby may be idle before its session code expires.
+    pub session_code_expiry: Duration,
+    /// Maximum number of devices in a session, including spectators.
+    pub session_devices_max: usize,
+    /// Maximum number of host / join requests from an IP address within the `request_window`.
+    pub request_limit: u32,
+    /// Duration over which requests from an IP address are counted.
+    pub request_window: Duration,
+    /// Duration an IP address is banned for after exceeding the `request_limit`.
+    pub ban_duration: Duration,
+}
+
+impl Default for SessionLimits {
+    fn default() -> Self {
+        SessionLimits {
+            session_code_expiry: Duration::from_secs(600),
+            session_devices_max: 8,
+            request_limit: 10,
+            request_window: Duration::from_secs(10),
+            ban_duration: Duration::from_secs(60),
+        }
+    }
+}
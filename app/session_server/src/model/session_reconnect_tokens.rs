@@ -0,0 +1,12 @@
+use std::collections::HashMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::model::{SessionCodeId, SessionDeviceReconnectTokens};
+
+/// Tracks the `SessionDeviceReconnectTokens` for each `Session`.
+///
+/// `HashMap<SessionCodeId, SessionDeviceReconnectTokens>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionReconnectTokens(pub HashMap<SessionCodeId, SessionDeviceReconnectTokens>);
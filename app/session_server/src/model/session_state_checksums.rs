@@ -0,0 +1,15 @@
+use std::collections::{BTreeMap, HashMap};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::play::GameTick;
+
+use crate::model::{SessionCodeId, SessionDeviceStateChecksums};
+
+/// Tracks the `SessionDeviceStateChecksums` for each tick of each `Session`.
+///
+/// `HashMap<SessionCodeId, BTreeMap<GameTick, SessionDeviceStateChecksums>>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionStateChecksums(
+    pub HashMap<SessionCodeId, BTreeMap<GameTick, SessionDeviceStateChecksums>>,
+);
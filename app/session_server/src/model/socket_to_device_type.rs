@@ -0,0 +1,18 @@
+use std::{collections::HashMap, net::SocketAddr};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use network_session_model::play::SessionDeviceType;
+
+/// Tracks whether each session device is a player or a spectator.
+///
+/// `HashMap<SocketAddr, SessionDeviceType>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SocketToDeviceType(pub HashMap<SocketAddr, SessionDeviceType>);
+
+impl SocketToDeviceType {
+    /// Returns whether the device at the given `SocketAddr` is a spectator.
+    pub fn is_spectator(&self, socket_addr: &SocketAddr) -> bool {
+        self.get(socket_addr) == Some(&SessionDeviceType::Spectator)
+    }
+}
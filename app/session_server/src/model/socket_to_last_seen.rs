@@ -0,0 +1,10 @@
+use std::{collections::HashMap, net::SocketAddr, time::Instant};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Tracks when a message was last received from each socket.
+///
+/// `HashMap<SocketAddr, Instant>` newtype.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SocketToLastSeen(pub HashMap<SocketAddr, Instant>);
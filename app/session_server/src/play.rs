@@ -1,5 +1,6 @@
 //! Runtime logic for session tracking.
 
-pub use self::session_tracker::SessionTracker;
+pub use self::{admin_connection::AdminConnection, session_tracker::SessionTracker};
 
+mod admin_connection;
 mod session_tracker;
@@ -0,0 +1,103 @@
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    net::{SocketAddr, TcpStream},
+};
+
+use derive_new::new;
+use log::debug;
+
+/// Maximum number of bytes buffered for a line before the connection is closed.
+const LINE_LENGTH_MAX: usize = 4096;
+
+/// Connection to the admin interface.
+///
+/// The stream is non-blocking, so reads and writes are buffered until they can complete.
+#[derive(Debug, new)]
+pub struct AdminConnection {
+    /// `SocketAddr` of the admin client.
+    pub socket_addr: SocketAddr,
+    /// Non-blocking stream to the admin client.
+    tcp_stream: TcpStream,
+    /// Bytes received that do not yet form a complete line.
+    #[new(default)]
+    read_buffer: Vec<u8>,
+    /// Bytes waiting to be sent.
+    #[new(default)]
+    write_buffer: Vec<u8>,
+    /// Whether the connection is closed or should be closed.
+    #[new(default)]
+    pub closed: bool,
+}
+
+impl AdminConnection {
+    /// Returns the complete lines received since the last call.
+    pub fn lines_read(&mut self) -> Vec<String> {
+        let mut chunk = [0u8; 1024];
+        loop {
+            match self.tcp_stream.read(&mut chunk) {
+                Ok(0) => {
+                    self.closed = true;
+                    break;
+                }
+                Ok(n) => self.read_buffer.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.close_on_error(e);
+                    break;
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+        while let Some(index) = self.read_buffer.iter().position(|byte| *byte == b'\n') {
+            let line = self.read_buffer.drain(..=index).collect::<Vec<u8>>();
+            lines.push(String::from_utf8_lossy(&line).trim().to_string());
+        }
+
+        if self.read_buffer.len() > LINE_LENGTH_MAX {
+            debug!(
+                "Admin connection `{}` sent a line longer than {} bytes, closing.",
+                self.socket_addr, LINE_LENGTH_MAX
+            );
+            self.closed = true;
+        }
+
+        lines
+    }
+
+    /// Queues text to be sent to the admin client, followed by a new line.
+    pub fn write_line(&mut self, text: &str) {
+        self.write_buffer.extend_from_slice(text.as_bytes());
+        self.write_buffer.push(b'\n');
+    }
+
+    /// Sends as much of the queued text as the stream accepts.
+    pub fn flush(&mut self) {
+        while !self.write_buffer.is_empty() {
+            match self.tcp_stream.write(&self.write_buffer) {
+                Ok(0) => {
+                    self.closed = true;
+                    break;
+                }
+                Ok(n) => {
+                    self.write_buffer.drain(..n);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.close_on_error(e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn close_on_error(&mut self, e: io::Error) {
+        debug!(
+            "Admin connection `{}` errored, closing. Error: `{}`.",
+            self.socket_addr, e
+        );
+        self.closed = true;
+    }
+}
@@ -7,7 +7,8 @@ use game_input_model::{
 use log::debug;
 use net_model::play::{NetSessionDevice, NetSessionDevices};
 use network_session_model::play::{
-    Session, SessionCode, SessionDevice, SessionDeviceId, SessionDevices, Sessions,
+    Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceType, SessionDevices,
+    Sessions,
 };
 use network_session_play::SessionCodeGenerator;
 use session_host_model::play::SessionHostRequestParams;
@@ -41,6 +42,7 @@ impl<'s> SessionTracker<'s> {
         let SessionHostRequestParams {
             session_device_name,
             player_controllers,
+            ..
         } = session_host_request_params;
 
         let session_code = self.generate_session_code(session_code_generator);
@@ -67,10 +69,21 @@ impl<'s> SessionTracker<'s> {
         (session, session_device_id, player_controllers.clone())
     }
 
+    /// Adds a device to an existing session.
+    ///
+    /// Spectators are tracked in the session device mappings so that they receive the session's
+    /// input, but are not added to the `Session`'s devices, and do not have `PlayerControllers`.
+    ///
+    /// # Parameters
+    ///
+    /// * `socket_addr`: `SocketAddr` of the session device.
+    /// * `session_join_request_params`: Parameters from the session join request.
+    /// * `session_devices_max`: Maximum number of devices in a session, including spectators.
     pub fn append_device(
         &mut self,
         socket_addr: SocketAddr,
         session_join_request_params: &SessionJoinRequestParams,
+        session_devices_max: usize,
     ) -> Result<
         (
             Session,
@@ -84,16 +97,30 @@ impl<'s> SessionTracker<'s> {
             session_code,
             session_device_name,
             player_controllers,
+            session_device_type,
+            ..
         } = session_join_request_params;
 
+        // Spectators are also in the mappings, so we use them to avoid ID collisions.
+        let session_device_mappings_read = self.session_device_mappings.as_read();
+        let net_session_devices = session_device_mappings_read.net_session_devices(session_code);
+        let session_device_count = net_session_devices
+            .map(|net_session_devices| net_session_devices.len())
+            .unwrap_or(0);
+        let session_device_id = net_session_devices
+            .and_then(|net_session_devices| {
+                net_session_devices
+                    .iter()
+                    .map(|net_session_device| net_session_device.data.id)
+                    .max()
+            })
+            .map(|session_device_id| SessionDeviceId::new(*session_device_id + 1))
+            .unwrap_or_else(|| SessionDeviceId::new(0));
+
         if let Some(session) = self.sessions.get_mut(session_code) {
-            let session_device_id = session
-                .session_devices
-                .iter()
-                .map(|session_device| session_device.id)
-                .max()
-                .map(|session_device_id| SessionDeviceId::new(*session_device_id + 1))
-                .unwrap_or_else(|| SessionDeviceId::new(0));
+            if session_device_count >= session_devices_max {
+                return Err(SessionJoinError::SessionFull);
+            }
 
             // Mutate the `ControllerId`s on the `player_controllers`.
             let controller_id_offset: ControllerIdOffset = ControllerIdOffset::new(
@@ -103,10 +130,16 @@ impl<'s> SessionTracker<'s> {
                     .map(|session_device| session_device.player_controllers.len())
                     .sum(),
             );
-            let mut player_controllers = player_controllers.clone();
-            player_controllers.iter_mut().for_each(|player_controller| {
-                player_controller.controller_id += controller_id_offset.0
-            });
+            let player_controllers = match session_device_type {
+                SessionDeviceType::Player => {
+                    let mut player_controllers = player_controllers.clone();
+                    player_controllers.iter_mut().for_each(|player_controller| {
+                        player_controller.controller_id += controller_id_offset.0
+                    });
+                    player_controllers
+                }
+                SessionDeviceType::Spectator => PlayerControllers::default(),
+            };
 
             // Add the new device to the session before adding it to the response.
             let session_device = SessionDevice::new(
@@ -114,15 +147,17 @@ impl<'s> SessionTracker<'s> {
                 session_device_name.clone(),
                 player_controllers,
             );
-            session.session_devices.push(session_device.clone());
+            if *session_device_type == SessionDeviceType::Player {
+                session.session_devices.push(session_device.clone());
+            }
 
             let net_session_device = NetSessionDevice::new(socket_addr, session_device.clone());
             self.session_device_mappings
                 .append(session_code, net_session_device);
 
             debug!(
-                "Session `{}` joined by `{}` with id: `{}`.",
-                session_code, session_device.name, session_device.id
+                "Session `{}` joined by {} `{}` with id: `{}`.",
+                session_code, session_device_type, session_device.name, session_device.id
             );
 
             // Compute combined player controllers
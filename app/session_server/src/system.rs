@@ -6,13 +6,22 @@
 //! * Crates under `crate` are configured to use a consistent set of `amethyst` features.
 
 pub use self::{
+    game_play_pause_responder_system::{
+        GamePlayPauseResponderSystem, GamePlayPauseResponderSystemDesc,
+    },
+    game_play_restart_responder_system::{
+        GamePlayRestartResponderSystem, GamePlayRestartResponderSystemDesc,
+    },
     network_input_responder_system::{
         NetworkInputResponderSystem, NetworkInputResponderSystemDesc,
     },
+    server_metrics_system::{ServerMetricsSystem, ServerMetricsSystemDesc},
+    session_admin_system::{SessionAdminSystem, SessionAdminSystemDesc},
     session_cleaner::SessionCleaner,
     session_device_disconnect_responder_system::{
         SessionDeviceDisconnectResponderSystem, SessionDeviceDisconnectResponderSystemDesc,
     },
+    session_expiry_system::SessionExpirySystem,
     session_host_responder_system::{SessionHostResponderSystem, SessionHostResponderSystemDesc},
     session_join_responder_system::{SessionJoinResponderSystem, SessionJoinResponderSystemDesc},
     session_lobby_responder_system::{
@@ -21,12 +30,25 @@ pub use self::{
     session_message_responder_system::{
         SessionMessageResponderSystem, SessionMessageResponderSystemDesc,
     },
+    session_reconnect_responder_system::{
+        SessionReconnectResponderSystem, SessionReconnectResponderSystemDesc,
+    },
+    state_checksum_responder_system::{
+        StateChecksumResponderSystem, StateChecksumResponderSystemDesc,
+    },
 };
 
+mod game_play_pause_responder_system;
+mod game_play_restart_responder_system;
 mod network_input_responder_system;
+mod server_metrics_system;
+mod session_admin_system;
 mod session_cleaner;
 mod session_device_disconnect_responder_system;
+mod session_expiry_system;
 mod session_host_responder_system;
 mod session_join_responder_system;
 mod session_lobby_responder_system;
 mod session_message_responder_system;
+mod session_reconnect_responder_system;
+mod state_checksum_responder_system;
@@ -0,0 +1,161 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use network_session_model::SessionMessageEvent;
+
+use crate::model::{
+    ServerMetrics, SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings,
+    SocketToDeviceType,
+};
+
+/// Notifies all devices in a session to pause or resume the round when a device requests it.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamePlayPauseResponderSystemDesc))]
+pub struct GamePlayPauseResponderSystem {
+    /// Reader ID for the `SessionMessageEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_message_event_rid: ReaderId<NetData<SessionMessageEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayPauseResponderSystemData<'s> {
+    /// `SessionMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_message_nec: Read<'s, NetEventChannel<SessionMessageEvent>>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Read<'s, SessionCodeToId>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Read<'s, SocketToDeviceType>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+}
+
+impl GamePlayPauseResponderSystem {
+    fn send_notify(
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+        session_message_event: SessionMessageEvent,
+    ) {
+        let net_message_event = NetMessageEvent::from(session_message_event);
+
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                socket_addrs.for_each(|socket_addr| {
+                    transport_resource.send_with_requirements(
+                        socket_addr,
+                        &payload,
+                        DeliveryRequirement::ReliableOrdered(None),
+                        UrgencyRequirement::OnTick,
+                    );
+                    server_metrics.messages_out += 1;
+                });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionMessageEvent`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for GamePlayPauseResponderSystem {
+    type SystemData = GamePlayPauseResponderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayPauseResponderSystemData {
+            session_message_nec,
+            session_code_to_id,
+            session_id_to_device_mappings,
+            socket_to_device_type,
+            mut transport_resource,
+            mut server_metrics,
+        }: Self::SystemData,
+    ) {
+        let session_code_to_id = &*session_code_to_id;
+        let session_id_to_device_mappings = &*session_id_to_device_mappings;
+        let session_device_mappings_read = SessionDeviceMappingsRead {
+            session_code_to_id,
+            session_id_to_device_mappings,
+        };
+
+        session_message_nec
+            .read(&mut self.session_message_event_rid)
+            .filter_map(|net_session_message_event| {
+                let NetData { socket_addr, data } = net_session_message_event;
+                match data {
+                    SessionMessageEvent::GamePlayPauseRequest => {
+                        Some((*socket_addr, SessionMessageEvent::GamePlayPauseNotify))
+                    }
+                    SessionMessageEvent::GamePlayResumeRequest => {
+                        Some((*socket_addr, SessionMessageEvent::GamePlayResumeNotify))
+                    }
+                    _ => None,
+                }
+            })
+            .for_each(|(socket_addr, session_message_event)| {
+                // Spectators may not affect the game.
+                if socket_to_device_type.is_spectator(&socket_addr) {
+                    debug!(
+                        "Received pause or resume request from spectator {:?}, ignoring.",
+                        socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+                    return;
+                }
+
+                let session_code = session_device_mappings_read.session_code(&socket_addr);
+                let net_session_devices = session_code.and_then(|session_code| {
+                    session_device_mappings_read.net_session_devices(session_code)
+                });
+                if let (Some(session_code), Some(net_session_devices)) =
+                    (session_code, net_session_devices)
+                {
+                    debug!(
+                        "Sending `{:?}` for session: `{}`.",
+                        session_message_event, session_code
+                    );
+
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    Self::send_notify(
+                        &mut transport_resource,
+                        &mut server_metrics,
+                        socket_addrs,
+                        session_message_event,
+                    );
+                } else {
+                    debug!(
+                        "Received pause or resume request from {:?}, but no session code tracked \
+                         for that socket.",
+                        socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+                }
+            });
+    }
+}
@@ -0,0 +1,174 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use network_session_model::SessionMessageEvent;
+
+use crate::model::{
+    ServerMetrics, SessionCodeToId, SessionDeviceMappingsRead, SessionGameTicks,
+    SessionIdToDeviceMappings, SessionStateChecksums, SocketToDeviceType,
+};
+
+/// Notifies all devices in a session to restart the round when a device requests it.
+///
+/// The session's tracked game tick and state checksums are cleared, as the round begins from the
+/// first tick again.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamePlayRestartResponderSystemDesc))]
+pub struct GamePlayRestartResponderSystem {
+    /// Reader ID for the `SessionMessageEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_message_event_rid: ReaderId<NetData<SessionMessageEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayRestartResponderSystemData<'s> {
+    /// `SessionMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_message_nec: Read<'s, NetEventChannel<SessionMessageEvent>>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Read<'s, SessionCodeToId>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Read<'s, SocketToDeviceType>,
+    /// `SessionGameTicks` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_game_ticks: Write<'s, SessionGameTicks>,
+    /// `SessionStateChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_state_checksums: Write<'s, SessionStateChecksums>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+}
+
+impl GamePlayRestartResponderSystem {
+    fn send_game_play_restart_notify(
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+    ) {
+        let net_message_event = NetMessageEvent::from(SessionMessageEvent::GamePlayRestartNotify);
+
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                socket_addrs.for_each(|socket_addr| {
+                    transport_resource.send_with_requirements(
+                        socket_addr,
+                        &payload,
+                        DeliveryRequirement::ReliableOrdered(None),
+                        UrgencyRequirement::OnTick,
+                    );
+                    server_metrics.messages_out += 1;
+                });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionMessageEvent`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for GamePlayRestartResponderSystem {
+    type SystemData = GamePlayRestartResponderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayRestartResponderSystemData {
+            session_message_nec,
+            session_code_to_id,
+            session_id_to_device_mappings,
+            socket_to_device_type,
+            mut session_game_ticks,
+            mut session_state_checksums,
+            mut transport_resource,
+            mut server_metrics,
+        }: Self::SystemData,
+    ) {
+        let session_code_to_id = &*session_code_to_id;
+        let session_id_to_device_mappings = &*session_id_to_device_mappings;
+        let session_device_mappings_read = SessionDeviceMappingsRead {
+            session_code_to_id,
+            session_id_to_device_mappings,
+        };
+
+        session_message_nec
+            .read(&mut self.session_message_event_rid)
+            .filter_map(|net_session_message_event| {
+                if let NetData {
+                    socket_addr,
+                    data: SessionMessageEvent::GamePlayRestartRequest,
+                } = net_session_message_event
+                {
+                    Some(*socket_addr)
+                } else {
+                    None
+                }
+            })
+            .for_each(|socket_addr| {
+                // Spectators may not affect the game.
+                if socket_to_device_type.is_spectator(&socket_addr) {
+                    debug!(
+                        "Received `GamePlayRestartRequest` from spectator {:?}, ignoring.",
+                        socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+                    return;
+                }
+
+                let session_code = session_device_mappings_read.session_code(&socket_addr);
+                let net_session_devices = session_code.and_then(|session_code| {
+                    session_device_mappings_read.net_session_devices(session_code)
+                });
+                if let (Some(session_code), Some(net_session_devices)) =
+                    (session_code, net_session_devices)
+                {
+                    debug!(
+                        "Sending `GamePlayRestartNotify` for session: `{}`.",
+                        session_code
+                    );
+
+                    if let Some(session_code_id) = session_code_to_id.id(session_code) {
+                        session_game_ticks.remove(&session_code_id);
+                        session_state_checksums.remove(&session_code_id);
+                    }
+
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    Self::send_game_play_restart_notify(
+                        &mut transport_resource,
+                        &mut server_metrics,
+                        socket_addrs,
+                    );
+                } else {
+                    debug!(
+                        "Received `GamePlayRestartRequest` from {:?}, but no session code tracked \
+                         for that socket.",
+                        socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+                }
+            });
+    }
+}
@@ -9,49 +9,74 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::GameInputEvent;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{
+    GameInputEnvelope, GameInputSendBuffer, NetData, NetEventChannel, NetMessageEvent,
+};
+use network_session_model::SessionMessageEvent;
 
-use crate::model::{SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings};
+use crate::model::{
+    ServerMetrics, SessionCodeToId, SessionDeviceMappingsRead, SessionGameTicks,
+    SessionIdToDeviceMappings, SocketToDeviceType,
+};
 
 /// Broadcasts `InputEvent`s to connected devices within the same session.
+///
+/// The `GameInputEnvelope`s are expected to have been ordered by the `GameInputReceiveSystem`, and
+/// each relayed envelope is tracked in the `GameInputSendBuffer` until it is acknowledged.
+///
+/// Devices that send input without being in a session are sent `SessionNotFound`.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputResponderSystemDesc))]
 pub struct NetworkInputResponderSystem {
-    /// Reader ID for the `GameInputEvent` channel.
+    /// Reader ID for the `GameInputEnvelope` channel.
     #[system_desc(event_channel_reader)]
-    game_input_event_rid: ReaderId<NetData<GameInputEvent>>,
+    game_input_envelope_rid: ReaderId<NetData<GameInputEnvelope>>,
 }
 
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct NetworkInputResponderSystemData<'s> {
-    /// `InputEvent` channel.
+    /// `GameInputEnvelope` channel.
     #[derivative(Debug = "ignore")]
-    pub network_input_nec: Read<'s, NetEventChannel<GameInputEvent>>,
+    pub network_input_nec: Read<'s, NetEventChannel<GameInputEnvelope>>,
     /// `SessionCodeToId` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_to_id: Read<'s, SessionCodeToId>,
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Read<'s, SocketToDeviceType>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `SessionGameTicks` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_game_ticks: Write<'s, SessionGameTicks>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
 }
 
 impl NetworkInputResponderSystem {
     fn send_game_input_event(
         transport_resource: &mut TransportResource,
+        game_input_send_buffer: &mut GameInputSendBuffer,
+        server_metrics: &mut ServerMetrics,
         socket_addrs: impl Iterator<Item = SocketAddr>,
-        game_input_event: GameInputEvent,
+        game_input_envelope: GameInputEnvelope,
     ) {
-        let net_message_event = NetMessageEvent::from(game_input_event);
+        let net_message_event = NetMessageEvent::from(game_input_envelope);
 
         match bincode::serialize(&net_message_event) {
             Ok(payload) => {
                 socket_addrs.for_each(|socket_addr| {
+                    game_input_send_buffer.push(socket_addr, game_input_envelope);
                     transport_resource.send_with_requirements(
                         socket_addr,
                         &payload,
@@ -62,6 +87,7 @@ impl NetworkInputResponderSystem {
                         DeliveryRequirement::ReliableOrdered(None),
                         UrgencyRequirement::OnTick,
                     );
+                    server_metrics.messages_out += 1;
                 });
             }
             Err(e) => {
@@ -72,6 +98,32 @@ impl NetworkInputResponderSystem {
             }
         }
     }
+
+    fn send_session_not_found(
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        socket_addr: SocketAddr,
+    ) {
+        let net_message_event = NetMessageEvent::from(SessionMessageEvent::SessionNotFound);
+
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                transport_resource.send_with_requirements(
+                    socket_addr,
+                    &payload,
+                    DeliveryRequirement::ReliableOrdered(None),
+                    UrgencyRequirement::OnTick,
+                );
+                server_metrics.messages_out += 1;
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `SessionMessageEvent::SessionNotFound`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
 }
 
 impl<'s> System<'s> for NetworkInputResponderSystem {
@@ -83,7 +135,11 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
             network_input_nec,
             session_code_to_id,
             session_id_to_device_mappings,
+            socket_to_device_type,
             mut transport_resource,
+            mut game_input_send_buffer,
+            mut session_game_ticks,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
         let session_code_to_id = &*session_code_to_id;
@@ -94,13 +150,23 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
         };
 
         network_input_nec
-            .read(&mut self.game_input_event_rid)
+            .read(&mut self.game_input_envelope_rid)
             .for_each(|net_game_input_event| {
                 let NetData {
                     socket_addr,
-                    data: game_input_event,
+                    data: game_input_envelope,
                 } = net_game_input_event;
 
+                // Spectators may not affect the game.
+                if socket_to_device_type.is_spectator(socket_addr) {
+                    debug!(
+                        "Received `{:?}` from spectator {:?}, ignoring.",
+                        game_input_envelope, socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+                    return;
+                }
+
                 if let Some(session_code) = session_device_mappings_read.session_code(&socket_addr)
                 {
                     if let Some(net_session_devices) =
@@ -108,21 +174,51 @@ impl<'s> System<'s> for NetworkInputResponderSystem {
                     {
                         debug!("Sending `GameInputEvent` for session: `{}`.", session_code);
 
+                        // Use the server's record of the sender, rather than trusting the client.
+                        let mut game_input_envelope = *game_input_envelope;
+                        if let Some(net_session_device) =
+                            net_session_devices.iter().find(|net_session_device| {
+                                net_session_device.socket_addr == *socket_addr
+                            })
+                        {
+                            game_input_envelope.session_device_id = net_session_device.data.id;
+                        }
+
+                        // Track the session's progress, so that reconnecting devices can catch up.
+                        if let Some(session_code_id) =
+                            session_id_to_device_mappings.session_code_id(&socket_addr)
+                        {
+                            let tick = game_input_envelope.ticked_game_input_event.tick;
+                            let session_game_tick =
+                                session_game_ticks.entry(session_code_id).or_default();
+                            if *session_game_tick < tick {
+                                *session_game_tick = tick;
+                            }
+                        }
+
                         let socket_addrs = net_session_devices
                             .iter()
                             .map(|net_session_device| net_session_device.socket_addr);
                         Self::send_game_input_event(
                             &mut transport_resource,
+                            &mut game_input_send_buffer,
+                            &mut server_metrics,
                             socket_addrs,
-                            *game_input_event,
+                            game_input_envelope,
                         );
                     }
                 } else {
                     debug!(
                         "Received `{:?}` from {:?}, but no session code tracked for that socket.",
-                        game_input_event, socket_addr
+                        game_input_envelope, socket_addr
+                    );
+                    server_metrics.messages_rejected += 1;
+
+                    Self::send_session_not_found(
+                        &mut transport_resource,
+                        &mut server_metrics,
+                        *socket_addr,
                     );
-                    // TODO: reject
                 }
             });
     }
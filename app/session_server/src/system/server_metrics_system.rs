@@ -0,0 +1,74 @@
+use std::time::Instant;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::NetworkSimulationEvent,
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+
+use crate::model::{ServerMetrics, SessionIdToDeviceMappings, SocketToLastSeen};
+
+/// Counts messages received from devices, and records when each device was last seen.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ServerMetricsSystemDesc))]
+pub struct ServerMetricsSystem {
+    /// Reader ID for the `NetworkSimulationEvent` channel.
+    #[system_desc(event_channel_reader)]
+    network_simulation_event_rid: ReaderId<NetworkSimulationEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ServerMetricsSystemData<'s> {
+    /// `NetworkSimulationEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+    /// `SocketToLastSeen` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_last_seen: Write<'s, SocketToLastSeen>,
+}
+
+impl<'s> System<'s> for ServerMetricsSystem {
+    type SystemData = ServerMetricsSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ServerMetricsSystemData {
+            network_simulation_ec,
+            session_id_to_device_mappings,
+            mut server_metrics,
+            mut socket_to_last_seen,
+        }: Self::SystemData,
+    ) {
+        network_simulation_ec
+            .read(&mut self.network_simulation_event_rid)
+            .for_each(|ev| match ev {
+                NetworkSimulationEvent::Connect(socket_addr) => {
+                    socket_to_last_seen.insert(*socket_addr, Instant::now());
+                }
+                NetworkSimulationEvent::Message(socket_addr, _) => {
+                    server_metrics.messages_in += 1;
+                    socket_to_last_seen.insert(*socket_addr, Instant::now());
+                }
+                _ => {}
+            });
+
+        // Disconnected devices are kept while they are in a session, so that the time they were
+        // last seen can be reported.
+        socket_to_last_seen.retain(|socket_addr, _| {
+            session_id_to_device_mappings
+                .session_code_id(socket_addr)
+                .is_some()
+        });
+    }
+}
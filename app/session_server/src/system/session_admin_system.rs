@@ -0,0 +1,458 @@
+use std::{fmt::Write as _, io::ErrorKind, str::FromStr, time::Instant};
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{error, info, warn};
+use net_model::play::{GameInputReceiveBuffer, GameInputSendBuffer, NetMessageEvent};
+use network_session_model::{
+    play::{SessionCode, SessionDeviceId, Sessions},
+    SessionMessageEvent,
+};
+
+use crate::{
+    model::{
+        AdminCommand, AdminListener, DisconnectedDevices, GameInputTickStatus, ServerMetrics,
+        SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SessionTickStatuses,
+        SocketToDeviceId, SocketToDeviceType, SocketToLastSeen,
+    },
+    play::{AdminConnection, SessionTracker},
+    system::SessionCleaner,
+};
+
+/// Serves the admin interface, used to inspect and manage sessions.
+///
+/// Admin clients send one `AdminCommand` per line, and each response is terminated by an empty
+/// line.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionAdminSystemDesc))]
+pub struct SessionAdminSystem {
+    /// Connected admin clients.
+    #[system_desc(skip)]
+    #[new(default)]
+    admin_connections: Vec<AdminConnection>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionAdminSystemData<'s> {
+    /// `AdminListener` resource.
+    #[derivative(Debug = "ignore")]
+    pub admin_listener: Read<'s, AdminListener>,
+    /// `SocketToLastSeen` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_last_seen: Read<'s, SocketToLastSeen>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Write<'s, Sessions>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Write<'s, SessionCodeToId>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Write<'s, SocketToDeviceType>,
+    /// `SessionTickStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_statuses: Write<'s, SessionTickStatuses>,
+    /// `DisconnectedDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub disconnected_devices: Write<'s, DisconnectedDevices>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+}
+
+impl SessionAdminSystem {
+    /// Accepts pending connections to the admin interface.
+    fn admin_connections_accept(&mut self, admin_listener: &AdminListener) {
+        let tcp_listener = if let Some(tcp_listener) = admin_listener.as_ref() {
+            tcp_listener
+        } else {
+            return;
+        };
+
+        loop {
+            match tcp_listener.accept() {
+                Ok((tcp_stream, socket_addr)) => {
+                    if let Err(e) = tcp_stream.set_nonblocking(true) {
+                        error!(
+                            "Failed to set admin connection `{}` to non-blocking. Error: `{}`.",
+                            socket_addr, e
+                        );
+                        continue;
+                    }
+
+                    info!("Admin connection opened from `{}`.", socket_addr);
+                    let mut admin_connection = AdminConnection::new(socket_addr, tcp_stream);
+                    admin_connection.write_line(AdminCommand::USAGE);
+                    admin_connection.write_line("");
+                    self.admin_connections.push(admin_connection);
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => {
+                    error!("Failed to accept admin connection. Error: `{}`.", e);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Runs an `AdminCommand`, returning the response text.
+    fn admin_command_handle(
+        session_admin_system_data: &mut SessionAdminSystemData<'_>,
+        admin_connection: &mut AdminConnection,
+        admin_command: AdminCommand,
+    ) -> String {
+        match admin_command {
+            AdminCommand::Help => String::from(AdminCommand::USAGE),
+            AdminCommand::Sessions => Self::sessions_describe(session_admin_system_data),
+            AdminCommand::Metrics => {
+                Self::metrics_describe(&session_admin_system_data.server_metrics)
+            }
+            AdminCommand::Kick {
+                session_code,
+                session_device_id,
+            } => Self::device_kick(session_admin_system_data, &session_code, session_device_id),
+            AdminCommand::Close { session_code } => {
+                Self::session_close(session_admin_system_data, &session_code)
+            }
+            AdminCommand::Quit => {
+                admin_connection.closed = true;
+                String::from("Bye.")
+            }
+        }
+    }
+
+    fn sessions_describe(session_admin_system_data: &SessionAdminSystemData<'_>) -> String {
+        let SessionAdminSystemData {
+            socket_to_last_seen,
+            session_code_to_id,
+            session_id_to_device_mappings,
+            socket_to_device_type,
+            session_tick_statuses,
+            disconnected_devices,
+            ..
+        } = session_admin_system_data;
+
+        if session_id_to_device_mappings.is_empty() {
+            return String::from("No active sessions.");
+        }
+
+        let now = Instant::now();
+        let mut description = String::new();
+        session_id_to_device_mappings
+            .iter()
+            .for_each(|(session_code_id, net_session_devices)| {
+                let session_code = session_code_to_id
+                    .code(*session_code_id)
+                    .map(SessionCode::to_string)
+                    .unwrap_or_else(|| String::from("?"));
+                let _ = writeln!(
+                    description,
+                    "Session `{}` (id: {}), {} device(s):",
+                    session_code,
+                    session_code_id,
+                    net_session_devices.len()
+                );
+
+                let device_tick_statuses = session_tick_statuses.get(session_code_id);
+                net_session_devices.iter().for_each(|net_session_device| {
+                    let socket_addr = net_session_device.socket_addr;
+                    let session_device = &net_session_device.data;
+                    let session_device_type = socket_to_device_type
+                        .get(&socket_addr)
+                        .copied()
+                        .unwrap_or_default();
+                    let tick_status = device_tick_statuses
+                        .and_then(|device_tick_statuses| {
+                            device_tick_statuses.get(&session_device.id)
+                        })
+                        .map(|game_input_tick_status| match game_input_tick_status {
+                            GameInputTickStatus::Pending => "pending",
+                            GameInputTickStatus::Received => "received",
+                        })
+                        .unwrap_or("-");
+                    let last_seen = socket_to_last_seen
+                        .get(&socket_addr)
+                        .map(|last_seen| {
+                            format!("{:.1}s ago", now.duration_since(*last_seen).as_secs_f32())
+                        })
+                        .unwrap_or_else(|| String::from("never"));
+
+                    let _ = write!(
+                        description,
+                        "  [{}] `{}` {} {}, tick: {}, last seen: {}",
+                        session_device.id,
+                        session_device.name,
+                        session_device_type,
+                        socket_addr,
+                        tick_status,
+                        last_seen
+                    );
+                    if let Some(disconnected_device) = disconnected_devices.get(&socket_addr) {
+                        let _ = write!(
+                            description,
+                            ", disconnected: {:.1}s ago",
+                            now.duration_since(disconnected_device.disconnected_at)
+                                .as_secs_f32()
+                        );
+                    }
+                    description.push('\n');
+                });
+            });
+
+        description.trim_end().to_string()
+    }
+
+    fn metrics_describe(server_metrics: &ServerMetrics) -> String {
+        format!(
+            "messages_in: {}\n\
+             messages_out: {}\n\
+             messages_rejected: {}\n\
+             tick_wait_count: {}\n\
+             tick_wait_mean: {:.3}ms\n\
+             tick_wait_max: {:.3}ms",
+            server_metrics.messages_in,
+            server_metrics.messages_out,
+            server_metrics.messages_rejected,
+            server_metrics.tick_wait_count,
+            server_metrics.tick_wait_mean().as_secs_f64() * 1000.,
+            server_metrics.tick_wait_max.as_secs_f64() * 1000.,
+        )
+    }
+
+    /// Removes a device from its session, and notifies the remaining devices.
+    fn device_kick(
+        session_admin_system_data: &mut SessionAdminSystemData<'_>,
+        session_code: &SessionCode,
+        session_device_id: SessionDeviceId,
+    ) -> String {
+        let SessionAdminSystemData {
+            sessions,
+            session_code_to_id,
+            session_id_to_device_mappings,
+            socket_to_device_id,
+            socket_to_device_type,
+            session_tick_statuses,
+            disconnected_devices,
+            game_input_send_buffer,
+            game_input_receive_buffer,
+            server_metrics,
+            transport_resource,
+            ..
+        } = session_admin_system_data;
+
+        let session_code_id = if let Some(session_code_id) = session_code_to_id.id(session_code) {
+            session_code_id
+        } else {
+            return format!("Session `{}` not found.", session_code);
+        };
+        let socket_addr = session_id_to_device_mappings
+            .net_session_devices(session_code_id)
+            .and_then(|net_session_devices| {
+                net_session_devices
+                    .iter()
+                    .find(|net_session_device| net_session_device.data.id == session_device_id)
+            })
+            .map(|net_session_device| net_session_device.socket_addr);
+        let socket_addr = if let Some(socket_addr) = socket_addr {
+            socket_addr
+        } else {
+            return format!(
+                "Device [{}] not found in session `{}`.",
+                session_device_id, session_code
+            );
+        };
+
+        {
+            let mut session_device_mappings = SessionDeviceMappings::new(
+                &mut **session_code_to_id,
+                &mut **session_id_to_device_mappings,
+            );
+            let mut session_tracker = SessionTracker {
+                sessions: &mut **sessions,
+                session_device_mappings: &mut session_device_mappings,
+            };
+            session_tracker.remove_device_from_existing_session(socket_addr);
+        }
+        socket_to_device_id.remove(&socket_addr);
+        socket_to_device_type.remove(&socket_addr);
+        disconnected_devices.remove(&socket_addr);
+        game_input_send_buffer.remove_socket(socket_addr);
+        game_input_receive_buffer.remove_socket(socket_addr);
+        if let Some(device_tick_statuses) = session_tick_statuses.get_mut(&session_code_id) {
+            device_tick_statuses.remove(&session_device_id);
+        }
+
+        info!(
+            "Admin kicked device [{}] ({}) from session `{}`.",
+            session_device_id, socket_addr, session_code
+        );
+
+        // Let the other devices know the device is gone.
+        let net_message_event = NetMessageEvent::from(
+            SessionMessageEvent::SessionDeviceDisconnect(session_device_id),
+        );
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                session_id_to_device_mappings
+                    .net_session_devices(session_code_id)
+                    .into_iter()
+                    .flat_map(|net_session_devices| net_session_devices.iter())
+                    .map(|net_session_device| net_session_device.socket_addr)
+                    .filter(|socket_addr| !disconnected_devices.contains_key(socket_addr))
+                    .for_each(|socket_addr| {
+                        transport_resource.send_with_requirements(
+                            socket_addr,
+                            &payload,
+                            // None means it uses a default multiplexed stream.
+                            //
+                            // Suspect if we give it a value, the value will be a "channel" over the same
+                            // socket connection.
+                            DeliveryRequirement::ReliableOrdered(None),
+                            UrgencyRequirement::OnTick,
+                        );
+                        server_metrics.messages_out += 1;
+                    });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionMessageEvent`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+
+        format!(
+            "Kicked device [{}] from session `{}`.",
+            session_device_id, session_code
+        )
+    }
+
+    /// Removes a session and all of its devices.
+    ///
+    /// Remaining session resources are cleaned up by the `SessionDeviceDisconnectResponderSystem`.
+    fn session_close(
+        session_admin_system_data: &mut SessionAdminSystemData<'_>,
+        session_code: &SessionCode,
+    ) -> String {
+        let SessionAdminSystemData {
+            sessions,
+            session_code_to_id,
+            session_id_to_device_mappings,
+            socket_to_device_id,
+            session_tick_statuses,
+            game_input_send_buffer,
+            game_input_receive_buffer,
+            ..
+        } = session_admin_system_data;
+
+        let session_code_id = if let Some(session_code_id) = session_code_to_id.id(session_code) {
+            session_code_id
+        } else {
+            return format!("Session `{}` not found.", session_code);
+        };
+        let socket_addr = session_id_to_device_mappings
+            .net_session_devices(session_code_id)
+            .and_then(|net_session_devices| net_session_devices.iter().next())
+            .map(|net_session_device| net_session_device.socket_addr);
+
+        let session_code_and_devices = socket_addr.and_then(|socket_addr| {
+            let mut session_device_mappings = SessionDeviceMappings::new(
+                &mut **session_code_to_id,
+                &mut **session_id_to_device_mappings,
+            );
+            SessionCleaner::session_forget(
+                sessions,
+                &mut session_device_mappings,
+                socket_to_device_id,
+                socket_addr,
+            )
+        });
+        session_tick_statuses.remove(&session_code_id);
+
+        if let Some((_session_code, net_session_devices)) = session_code_and_devices {
+            net_session_devices.iter().for_each(|net_session_device| {
+                game_input_send_buffer.remove_socket(net_session_device.socket_addr);
+                game_input_receive_buffer.remove_socket(net_session_device.socket_addr);
+            });
+
+            info!("Admin closed session `{}`.", session_code);
+            format!(
+                "Closed session `{}`, removed {} device(s).",
+                session_code,
+                net_session_devices.len()
+            )
+        } else {
+            sessions.remove(session_code);
+            format!("Closed session `{}`.", session_code)
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionAdminSystem {
+    type SystemData = SessionAdminSystemData<'s>;
+
+    fn run(&mut self, mut session_admin_system_data: Self::SystemData) {
+        self.admin_connections_accept(&session_admin_system_data.admin_listener);
+
+        self.admin_connections
+            .iter_mut()
+            .for_each(|admin_connection| {
+                admin_connection
+                    .lines_read()
+                    .into_iter()
+                    .filter(|line| !line.is_empty())
+                    .for_each(|line| {
+                        let response = match AdminCommand::from_str(&line) {
+                            Ok(admin_command) => Self::admin_command_handle(
+                                &mut session_admin_system_data,
+                                admin_connection,
+                                admin_command,
+                            ),
+                            Err(e) => {
+                                warn!(
+                                    "Invalid admin command from `{}`: `{}`.",
+                                    admin_connection.socket_addr, line
+                                );
+                                e
+                            }
+                        };
+                        admin_connection.write_line(&response);
+                        admin_connection.write_line("");
+                    });
+
+                admin_connection.flush();
+            });
+
+        self.admin_connections.retain(|admin_connection| {
+            if admin_connection.closed {
+                info!(
+                    "Admin connection closed from `{}`.",
+                    admin_connection.socket_addr
+                );
+            }
+            !admin_connection.closed
+        });
+    }
+}
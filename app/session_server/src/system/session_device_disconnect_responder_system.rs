@@ -1,20 +1,35 @@
+use std::{net::SocketAddr, time::Instant};
+
 use amethyst::{
     derive::SystemDesc,
     ecs::{Read, System, World, Write},
-    network::simulation::NetworkSimulationEvent,
+    network::simulation::{
+        DeliveryRequirement, NetworkSimulationEvent, TransportResource, UrgencyRequirement,
+    },
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
 use derivative::Derivative;
 use derive_new::new;
-use network_session_model::play::Sessions;
+use log::{debug, error};
+use net_model::play::{GameInputReceiveBuffer, GameInputSendBuffer, NetMessageEvent};
+use network_session_model::{play::Sessions, SessionMessageEvent};
+use session_reconnect_model::config::SessionReconnectConfig;
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
+    model::{
+        DisconnectedDevice, DisconnectedDevices, ServerMetrics, SessionCodeId, SessionCodeToId,
+        SessionDeviceMappings, SessionGameTicks, SessionHandshakes, SessionIdToDeviceMappings,
+        SessionLastActive, SessionReconnectTokens, SessionStateChecksums, SocketToDeviceId,
+        SocketToDeviceType,
+    },
     system::SessionCleaner,
 };
 
 /// Listens for client disconnects, and removes them from the sessions.
+///
+/// A disconnected device's place in its session is held for the `SessionReconnectConfig` grace
+/// period, so that it may reconnect. When the grace period elapses, the session is removed.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionDeviceDisconnectResponderSystemDesc))]
 pub struct SessionDeviceDisconnectResponderSystem {
@@ -29,6 +44,9 @@ pub struct SessionDeviceDisconnectResponderSystemData<'s> {
     /// `NetworkSimulationEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// `SessionReconnectConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_config: Read<'s, SessionReconnectConfig>,
     /// `Sessions` resource.
     #[derivative(Debug = "ignore")]
     pub sessions: Write<'s, Sessions>,
@@ -38,9 +56,88 @@ pub struct SessionDeviceDisconnectResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Write<'s, SocketToDeviceType>,
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `DisconnectedDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub disconnected_devices: Write<'s, DisconnectedDevices>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// `SessionStateChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_state_checksums: Write<'s, SessionStateChecksums>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `SessionGameTicks` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_game_ticks: Write<'s, SessionGameTicks>,
+    /// `SessionLastActive` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_last_active: Write<'s, SessionLastActive>,
+    /// `SessionHandshakes` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_handshakes: Write<'s, SessionHandshakes>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+}
+
+impl SessionDeviceDisconnectResponderSystem {
+    /// Sends a `SessionMessageEvent` to all connected devices in the session.
+    fn send_session_message_event(
+        session_id_to_device_mappings: &SessionIdToDeviceMappings,
+        disconnected_devices: &DisconnectedDevices,
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        session_code_id: SessionCodeId,
+        session_message_event: SessionMessageEvent,
+    ) {
+        let net_message_event = NetMessageEvent::from(session_message_event);
+
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                if let Some(net_session_devices) =
+                    session_id_to_device_mappings.net_session_devices(session_code_id)
+                {
+                    net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr)
+                        .filter(|socket_addr| !disconnected_devices.contains_key(socket_addr))
+                        .for_each(|socket_addr| {
+                            transport_resource.send_with_requirements(
+                                socket_addr,
+                                &payload,
+                                // None means it uses a default multiplexed stream.
+                                //
+                                // Suspect if we give it a value, the value will be a "channel" over the same
+                                // socket connection.
+                                DeliveryRequirement::ReliableOrdered(None),
+                                UrgencyRequirement::OnTick,
+                            );
+                            server_metrics.messages_out += 1;
+                        });
+                }
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionMessageEvent`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
 }
 
 impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
@@ -50,32 +147,139 @@ impl<'s> System<'s> for SessionDeviceDisconnectResponderSystem {
         &mut self,
         SessionDeviceDisconnectResponderSystemData {
             network_simulation_ec,
+            session_reconnect_config,
             mut sessions,
             mut session_code_to_id,
             mut socket_to_device_id,
+            mut socket_to_device_type,
             mut session_id_to_device_mappings,
+            mut disconnected_devices,
+            mut game_input_send_buffer,
+            mut game_input_receive_buffer,
+            mut session_state_checksums,
+            mut session_reconnect_tokens,
+            mut session_game_ticks,
+            mut session_last_active,
+            mut session_handshakes,
+            mut transport_resource,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
-        let session_code_to_id = &mut *session_code_to_id;
-        let session_id_to_device_mappings = &mut *session_id_to_device_mappings;
-        let mut session_device_mappings =
-            SessionDeviceMappings::new(session_code_to_id, session_id_to_device_mappings);
+        // Hold the place of disconnected devices, so that they may reconnect.
+        //
+        // The device stays in the session mappings, so input relayed to it is buffered in the
+        // `GameInputSendBuffer` until it reconnects.
         network_simulation_ec
             .read(&mut self.network_simulation_event_rid)
-            .for_each(|ev| {
+            .filter_map(|ev| {
                 if let NetworkSimulationEvent::Disconnect(socket_addr) = ev {
-                    // Forget all clients in the session.
-                    let session_code_and_devices = SessionCleaner::session_forget(
-                        &mut sessions,
-                        &mut session_device_mappings,
-                        &mut socket_to_device_id,
-                        *socket_addr,
+                    Some(*socket_addr)
+                } else {
+                    None
+                }
+            })
+            .for_each(|socket_addr| {
+                // Spectators do not hold up the session, so they are removed immediately.
+                if socket_to_device_type.is_spectator(&socket_addr) {
+                    if let Some((_session_code_id, net_session_device)) =
+                        session_id_to_device_mappings.remove_device(&socket_addr)
+                    {
+                        debug!(
+                            "Spectator `{}` [{}] ({}) disconnected.",
+                            net_session_device.data.name, net_session_device.data.id, socket_addr
+                        );
+                    }
+                    socket_to_device_id.remove(&socket_addr);
+                    socket_to_device_type.remove(&socket_addr);
+                    game_input_send_buffer.remove_socket(socket_addr);
+                    return;
+                }
+
+                let session_code_id = session_id_to_device_mappings.session_code_id(&socket_addr);
+                let session_device_id = socket_to_device_id.get(&socket_addr).copied();
+
+                if let (Some(session_code_id), Some(session_device_id)) =
+                    (session_code_id, session_device_id)
+                {
+                    debug!(
+                        "Device [{}] ({}) disconnected, holding place in session.",
+                        session_device_id, socket_addr
                     );
 
-                    if let Some((_session_code, _net_session_devices)) = session_code_and_devices {
-                        // TODO: Send disconnect message to all clients except the one that disconnected.
-                    }
+                    disconnected_devices.insert(
+                        socket_addr,
+                        DisconnectedDevice::new(session_code_id, session_device_id, Instant::now()),
+                    );
+
+                    Self::send_session_message_event(
+                        &session_id_to_device_mappings,
+                        &disconnected_devices,
+                        &mut transport_resource,
+                        &mut server_metrics,
+                        session_code_id,
+                        SessionMessageEvent::SessionDeviceDisconnect(session_device_id),
+                    );
+                }
+            });
+
+        // Forget sessions whose disconnected devices did not reconnect in time.
+        let grace_period = session_reconnect_config.grace_period;
+        let socket_addrs_expired = disconnected_devices
+            .iter()
+            .filter_map(|(socket_addr, disconnected_device)| {
+                if disconnected_device.disconnected_at.elapsed() >= grace_period {
+                    Some(*socket_addr)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<SocketAddr>>();
+
+        let session_code_to_id = &mut *session_code_to_id;
+        let session_id_to_device_mappings = &mut *session_id_to_device_mappings;
+        {
+            let mut session_device_mappings =
+                SessionDeviceMappings::new(session_code_to_id, session_id_to_device_mappings);
+            socket_addrs_expired.into_iter().for_each(|socket_addr| {
+                disconnected_devices.remove(&socket_addr);
+
+                // Forget all clients in the session.
+                let session_code_and_devices = SessionCleaner::session_forget(
+                    &mut sessions,
+                    &mut session_device_mappings,
+                    &mut socket_to_device_id,
+                    socket_addr,
+                );
+
+                if let Some((_session_code, net_session_devices)) = session_code_and_devices {
+                    // Stop tracking input for the devices in the session.
+                    net_session_devices.iter().for_each(|net_session_device| {
+                        game_input_send_buffer.remove_socket(net_session_device.socket_addr);
+                        game_input_receive_buffer.remove_socket(net_session_device.socket_addr);
+                    });
+
+                    // TODO: Send disconnect message to all clients except the one that disconnected.
                 }
             });
+        }
+
+        // Stop tracking sessions that have been removed.
+        let session_exists = |session_code_id: &SessionCodeId| {
+            session_id_to_device_mappings
+                .net_session_devices(*session_code_id)
+                .is_some()
+        };
+        disconnected_devices
+            .retain(|_, disconnected_device| session_exists(&disconnected_device.session_code_id));
+        session_state_checksums.retain(|session_code_id, _| session_exists(session_code_id));
+        session_reconnect_tokens.retain(|session_code_id, _| session_exists(session_code_id));
+        session_game_ticks.retain(|session_code_id, _| session_exists(session_code_id));
+        session_last_active.retain(|session_code_id, _| session_exists(session_code_id));
+        session_handshakes.retain(|session_code_id, _| session_exists(session_code_id));
+        socket_to_device_type.retain(|socket_addr, _| {
+            session_id_to_device_mappings
+                .session_code_id(socket_addr)
+                .is_some()
+        });
     }
 }
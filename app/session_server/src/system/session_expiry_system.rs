@@ -0,0 +1,147 @@
+use std::time::Instant;
+
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::info;
+use net_model::play::{GameInputReceiveBuffer, GameInputSendBuffer};
+use network_session_model::play::Sessions;
+
+use crate::{
+    model::{
+        IpRequestTrackers, SessionCodeId, SessionCodeToId, SessionCodesExpired,
+        SessionDeviceMappings, SessionIdToDeviceMappings, SessionLastActive, SessionLimits,
+        SessionTickStatuses, SocketToDeviceId,
+    },
+    system::SessionCleaner,
+};
+
+/// Removes sessions whose lobby has been idle for longer than the session code expiry.
+///
+/// Expired session codes are remembered for the same duration, so that join requests for them are
+/// rejected with `SessionJoinError::SessionCodeExpired`.
+#[derive(Debug, new)]
+pub struct SessionExpirySystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionExpirySystemData<'s> {
+    /// `SessionLimits` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_limits: Read<'s, SessionLimits>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Write<'s, Sessions>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Write<'s, SessionCodeToId>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SessionTickStatuses` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_tick_statuses: Write<'s, SessionTickStatuses>,
+    /// `SessionLastActive` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_last_active: Write<'s, SessionLastActive>,
+    /// `SessionCodesExpired` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_codes_expired: Write<'s, SessionCodesExpired>,
+    /// `IpRequestTrackers` resource.
+    #[derivative(Debug = "ignore")]
+    pub ip_request_trackers: Write<'s, IpRequestTrackers>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+}
+
+impl<'s> System<'s> for SessionExpirySystem {
+    type SystemData = SessionExpirySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionExpirySystemData {
+            session_limits,
+            mut sessions,
+            mut session_code_to_id,
+            mut session_id_to_device_mappings,
+            mut socket_to_device_id,
+            mut session_tick_statuses,
+            mut session_last_active,
+            mut session_codes_expired,
+            mut ip_request_trackers,
+            mut game_input_send_buffer,
+            mut game_input_receive_buffer,
+        }: Self::SystemData,
+    ) {
+        let now = Instant::now();
+        let session_code_expiry = session_limits.session_code_expiry;
+
+        let session_code_ids_expired = session_last_active
+            .iter()
+            .filter_map(|(session_code_id, last_active)| {
+                if now.duration_since(*last_active) >= session_code_expiry {
+                    Some(*session_code_id)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<SessionCodeId>>();
+
+        {
+            let mut session_device_mappings = SessionDeviceMappings::new(
+                &mut *session_code_to_id,
+                &mut *session_id_to_device_mappings,
+            );
+            session_code_ids_expired
+                .into_iter()
+                .for_each(|session_code_id| {
+                    session_last_active.remove(&session_code_id);
+                    session_tick_statuses.remove(&session_code_id);
+
+                    let socket_addr = session_device_mappings
+                        .as_read()
+                        .session_id_to_device_mappings
+                        .net_session_devices(session_code_id)
+                        .and_then(|net_session_devices| net_session_devices.iter().next())
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    let session_code_and_devices = socket_addr.and_then(|socket_addr| {
+                        SessionCleaner::session_forget(
+                            &mut sessions,
+                            &mut session_device_mappings,
+                            &mut socket_to_device_id,
+                            socket_addr,
+                        )
+                    });
+
+                    if let Some((session_code, net_session_devices)) = session_code_and_devices {
+                        info!(
+                            "Session `{}` expired after its lobby was idle for {} seconds.",
+                            session_code,
+                            session_code_expiry.as_secs()
+                        );
+
+                        net_session_devices.iter().for_each(|net_session_device| {
+                            game_input_send_buffer.remove_socket(net_session_device.socket_addr);
+                            game_input_receive_buffer.remove_socket(net_session_device.socket_addr);
+                        });
+
+                        session_codes_expired.insert(session_code, now);
+                    }
+                });
+        }
+
+        session_codes_expired
+            .retain(|_, expired_at| now.duration_since(*expired_at) < session_code_expiry);
+        ip_request_trackers.prune(&session_limits, now);
+    }
+}
@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Instant};
 
 use amethyst::{
     derive::SystemDesc,
@@ -9,17 +9,25 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use log::{error, warn};
+use log::{debug, error, warn};
 use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
-use network_session_model::play::Sessions;
+use network_session_model::play::{
+    ProtocolVersion, SessionDeviceType, SessionReconnectToken, Sessions,
+};
 use network_session_play::SessionCodeGenerator;
 use session_host_model::{
-    play::{SessionAcceptResponse, SessionHostRequestParams, SessionRejectResponse},
+    play::{
+        SessionAcceptResponse, SessionHostError, SessionHostRequestParams, SessionRejectResponse,
+    },
     SessionHostEvent,
 };
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
+    model::{
+        IpRequestTrackers, ServerMetrics, SessionCodeToId, SessionCodesExpired,
+        SessionDeviceMappings, SessionHandshakes, SessionIdToDeviceMappings, SessionLastActive,
+        SessionLimits, SessionReconnectTokens, SocketToDeviceId, SocketToDeviceType,
+    },
     play::SessionTracker,
     system::SessionCleaner,
 };
@@ -42,6 +50,9 @@ pub struct SessionHostResponderSystemData<'s> {
     /// `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_host_nec: Read<'s, NetEventChannel<SessionHostEvent>>,
+    /// `SessionLimits` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_limits: Read<'s, SessionLimits>,
     /// `SessionCodeGenerator` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_generator: Write<'s, SessionCodeGenerator>,
@@ -57,21 +68,78 @@ pub struct SessionHostResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Write<'s, SocketToDeviceType>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `SessionLastActive` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_last_active: Write<'s, SessionLastActive>,
+    /// `SessionCodesExpired` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_codes_expired: Write<'s, SessionCodesExpired>,
+    /// `SessionHandshakes` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_handshakes: Write<'s, SessionHandshakes>,
+    /// `IpRequestTrackers` resource.
+    #[derivative(Debug = "ignore")]
+    pub ip_request_trackers: Write<'s, IpRequestTrackers>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
 }
 
 impl SessionHostResponderSystem {
+    #[allow(clippy::too_many_arguments)]
     fn handle_session_request(
         session_tracker: &mut SessionTracker<'_>,
         session_code_generator: &mut SessionCodeGenerator,
+        session_limits: &SessionLimits,
         socket_to_device_id: &mut SocketToDeviceId,
+        socket_to_device_type: &mut SocketToDeviceType,
+        session_reconnect_tokens: &mut SessionReconnectTokens,
+        session_last_active: &mut SessionLastActive,
+        session_codes_expired: &mut SessionCodesExpired,
+        session_handshakes: &mut SessionHandshakes,
+        ip_request_trackers: &mut IpRequestTrackers,
         socket_addr: SocketAddr,
         session_host_request_params: &SessionHostRequestParams,
     ) -> SessionHostEvent {
         let session_device_name = &session_host_request_params.session_device_name;
 
+        let now = Instant::now();
+        if !ip_request_trackers.request_allowed(socket_addr.ip(), session_limits, now) {
+            debug!(
+                "Rejecting request to host session from `{}` ({}), too many requests.",
+                session_device_name, socket_addr
+            );
+
+            return SessionHostEvent::SessionReject(SessionRejectResponse::new(
+                SessionHostError::Banned,
+            ));
+        }
+
+        let session_handshake = session_host_request_params.session_handshake;
+        if session_handshake.protocol_version != ProtocolVersion::CURRENT {
+            debug!(
+                "Rejecting request to host session from `{}` ({}), protocol version `{}` does not \
+                match server protocol version `{}`.",
+                session_device_name,
+                socket_addr,
+                session_handshake.protocol_version,
+                ProtocolVersion::CURRENT
+            );
+
+            return SessionHostEvent::SessionReject(SessionRejectResponse::new(
+                SessionHostError::VersionMismatch,
+            ));
+        }
+
         SessionCleaner::client_forget(
             session_tracker,
             socket_to_device_id,
@@ -87,9 +155,32 @@ impl SessionHostResponderSystem {
             );
 
             socket_to_device_id.insert(socket_addr, session_device_id);
+            socket_to_device_type.insert(socket_addr, SessionDeviceType::Player);
 
-            let session_accept_response =
-                SessionAcceptResponse::new(session, session_device_id, player_controllers);
+            // The code may be reused once it has expired.
+            session_codes_expired.remove(&session.session_code);
+
+            let session_reconnect_token = SessionReconnectToken::new(rand::random());
+            if let Some(session_code_id) = session_tracker
+                .session_device_mappings
+                .as_read()
+                .session_id_to_device_mappings
+                .session_code_id(&socket_addr)
+            {
+                session_reconnect_tokens
+                    .entry(session_code_id)
+                    .or_default()
+                    .insert(session_device_id, session_reconnect_token);
+                session_last_active.insert(session_code_id, now);
+                session_handshakes.insert(session_code_id, session_handshake);
+            }
+
+            let session_accept_response = SessionAcceptResponse::new(
+                session,
+                session_device_id,
+                session_reconnect_token,
+                player_controllers,
+            );
 
             SessionHostEvent::SessionAccept(session_accept_response)
         } else {
@@ -99,7 +190,9 @@ impl SessionHostResponderSystem {
                 session_device_name
             );
 
-            SessionHostEvent::SessionReject(SessionRejectResponse::new())
+            SessionHostEvent::SessionReject(SessionRejectResponse::new(
+                SessionHostError::ServerFull,
+            ))
         }
     }
 }
@@ -111,12 +204,20 @@ impl<'s> System<'s> for SessionHostResponderSystem {
         &mut self,
         SessionHostResponderSystemData {
             session_host_nec,
+            session_limits,
             mut session_code_generator,
             mut sessions,
             mut session_code_to_id,
             mut session_id_to_device_mappings,
             mut socket_to_device_id,
+            mut socket_to_device_type,
+            mut session_reconnect_tokens,
+            mut session_last_active,
+            mut session_codes_expired,
+            mut session_handshakes,
+            mut ip_request_trackers,
             mut transport_resource,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
         let session_code_to_id = &mut *session_code_to_id;
@@ -145,7 +246,14 @@ impl<'s> System<'s> for SessionHostResponderSystem {
                 let session_host_event = Self::handle_session_request(
                     &mut session_tracker,
                     &mut session_code_generator,
+                    &session_limits,
                     &mut socket_to_device_id,
+                    &mut socket_to_device_type,
+                    &mut session_reconnect_tokens,
+                    &mut session_last_active,
+                    &mut session_codes_expired,
+                    &mut session_handshakes,
+                    &mut ip_request_trackers,
                     socket_addr,
                     session_host_request_params,
                 );
@@ -153,6 +261,12 @@ impl<'s> System<'s> for SessionHostResponderSystem {
                 (socket_addr, NetMessageEvent::from(session_host_event))
             })
             .for_each(|(socket_addr, net_message_event)| {
+                if let NetMessageEvent::SessionHostEvent(SessionHostEvent::SessionReject(_)) =
+                    &net_message_event
+                {
+                    server_metrics.messages_rejected += 1;
+                }
+
                 match bincode::serialize(&net_message_event) {
                     Ok(payload) => {
                         transport_resource.send_with_requirements(
@@ -165,6 +279,7 @@ impl<'s> System<'s> for SessionHostResponderSystem {
                             DeliveryRequirement::ReliableOrdered(None),
                             UrgencyRequirement::OnTick,
                         );
+                        server_metrics.messages_out += 1;
                     }
                     Err(e) => {
                         error!(
@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{net::SocketAddr, time::Instant};
 
 use amethyst::{
     derive::SystemDesc,
@@ -12,16 +12,24 @@ use derive_new::new;
 use log::{debug, error};
 use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
 use network_session_model::{
-    play::{SessionDeviceJoin, Sessions},
+    play::{
+        ProtocolVersion, SessionDeviceJoin, SessionDeviceType, SessionReconnectToken, Sessions,
+    },
     SessionMessageEvent,
 };
 use session_join_model::{
-    play::{SessionAcceptResponse, SessionJoinRequestParams, SessionRejectResponse},
+    play::{
+        SessionAcceptResponse, SessionJoinError, SessionJoinRequestParams, SessionRejectResponse,
+    },
     SessionJoinEvent,
 };
 
 use crate::{
-    model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings, SocketToDeviceId},
+    model::{
+        IpRequestTrackers, ServerMetrics, SessionCodeToId, SessionCodesExpired,
+        SessionDeviceMappings, SessionHandshakes, SessionIdToDeviceMappings, SessionLastActive,
+        SessionLimits, SessionReconnectTokens, SocketToDeviceId, SocketToDeviceType,
+    },
     play::SessionTracker,
     system::SessionCleaner,
 };
@@ -41,6 +49,15 @@ pub struct SessionJoinResponderSystemData<'s> {
     /// `SessionJoinEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_join_nec: Read<'s, NetEventChannel<SessionJoinEvent>>,
+    /// `SessionLimits` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_limits: Read<'s, SessionLimits>,
+    /// `SessionCodesExpired` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_codes_expired: Read<'s, SessionCodesExpired>,
+    /// `SessionHandshakes` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_handshakes: Read<'s, SessionHandshakes>,
     /// `Sessions` resource.
     #[derivative(Debug = "ignore")]
     pub sessions: Write<'s, Sessions>,
@@ -53,54 +70,150 @@ pub struct SessionJoinResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Write<'s, SocketToDeviceType>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Write<'s, SessionReconnectTokens>,
+    /// `SessionLastActive` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_last_active: Write<'s, SessionLastActive>,
+    /// `IpRequestTrackers` resource.
+    #[derivative(Debug = "ignore")]
+    pub ip_request_trackers: Write<'s, IpRequestTrackers>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
 }
 
 impl SessionJoinResponderSystem {
+    #[allow(clippy::too_many_arguments)]
     fn handle_session_request(
         session_tracker: &mut SessionTracker,
+        session_limits: &SessionLimits,
+        session_codes_expired: &SessionCodesExpired,
+        session_handshakes: &SessionHandshakes,
         socket_to_device_id: &mut SocketToDeviceId,
+        socket_to_device_type: &mut SocketToDeviceType,
+        session_reconnect_tokens: &mut SessionReconnectTokens,
+        session_last_active: &mut SessionLastActive,
+        ip_request_trackers: &mut IpRequestTrackers,
         socket_addr: SocketAddr,
         session_join_request_params: &SessionJoinRequestParams,
     ) -> (SessionJoinEvent, Option<SessionMessageEvent>) {
         let SessionJoinRequestParams {
             session_device_name,
             session_code,
+            session_device_type,
+            session_handshake,
             ..
         } = session_join_request_params;
 
-        SessionCleaner::client_forget(
-            session_tracker,
-            socket_to_device_id,
-            socket_addr,
-            session_device_name,
-        );
+        // Devices must have the same assets and session sync mode as the session host to stay in
+        // sync.
+        let session_handshake_host = session_tracker
+            .session_device_mappings
+            .as_read()
+            .session_code_to_id
+            .id(session_code)
+            .and_then(|session_code_id| session_handshakes.get(&session_code_id))
+            .copied();
+
+        let now = Instant::now();
+        let result = if !ip_request_trackers.request_allowed(socket_addr.ip(), session_limits, now)
+        {
+            Err(SessionJoinError::Banned)
+        } else if session_handshake.protocol_version != ProtocolVersion::CURRENT {
+            Err(SessionJoinError::VersionMismatch)
+        } else if session_handshake_host
+            .map(|session_handshake_host| {
+                session_handshake_host.asset_fingerprint != session_handshake.asset_fingerprint
+            })
+            .unwrap_or(false)
+        {
+            Err(SessionJoinError::AssetMismatch)
+        } else if session_handshake_host
+            .map(|session_handshake_host| {
+                session_handshake_host.session_sync_mode != session_handshake.session_sync_mode
+            })
+            .unwrap_or(false)
+        {
+            Err(SessionJoinError::SyncModeMismatch)
+        } else {
+            SessionCleaner::client_forget(
+                session_tracker,
+                socket_to_device_id,
+                socket_addr,
+                session_device_name,
+            );
 
-        match session_tracker.append_device(socket_addr, session_join_request_params) {
+            session_tracker
+                .append_device(
+                    socket_addr,
+                    session_join_request_params,
+                    session_limits.session_devices_max,
+                )
+                .map_err(|session_join_error| match session_join_error {
+                    SessionJoinError::SessionCodeNotFound
+                        if session_codes_expired.contains_key(session_code) =>
+                    {
+                        SessionJoinError::SessionCodeExpired
+                    }
+                    _ => session_join_error,
+                })
+        };
+
+        match result {
             Ok((session, session_device, player_controllers, controller_id_offset)) => {
                 socket_to_device_id.insert(socket_addr, session_device.id);
+                socket_to_device_type.insert(socket_addr, *session_device_type);
+
+                let session_reconnect_token = SessionReconnectToken::new(rand::random());
+                if let Some(session_code_id) = session_tracker
+                    .session_device_mappings
+                    .as_read()
+                    .session_id_to_device_mappings
+                    .session_code_id(&socket_addr)
+                {
+                    session_reconnect_tokens
+                        .entry(session_code_id)
+                        .or_default()
+                        .insert(session_device.id, session_reconnect_token);
+
+                    // Joining keeps the session lobby active.
+                    if let Some(last_active) = session_last_active.get_mut(&session_code_id) {
+                        *last_active = now;
+                    }
+                }
 
                 let session_accept_response = SessionAcceptResponse::new(
                     session,
                     session_device.id,
+                    session_reconnect_token,
                     player_controllers.clone(),
                     controller_id_offset,
                 );
                 let session_join_event = SessionJoinEvent::SessionAccept(session_accept_response);
 
-                let session_message_event = {
-                    let session_device_join =
-                        SessionDeviceJoin::new(session_device, player_controllers);
-                    SessionMessageEvent::SessionDeviceJoin(session_device_join)
+                // Spectators are not shown to the other devices.
+                let session_message_event = match session_device_type {
+                    SessionDeviceType::Player => {
+                        let session_device_join =
+                            SessionDeviceJoin::new(session_device, player_controllers);
+                        Some(SessionMessageEvent::SessionDeviceJoin(session_device_join))
+                    }
+                    SessionDeviceType::Spectator => None,
                 };
 
-                (session_join_event, Some(session_message_event))
+                (session_join_event, session_message_event)
             }
             Err(e) => {
                 debug!(
-                    "Rejecting request to join session `{}` joined from `{}`. Error: `{:?}`",
+                    "Rejecting request to join session `{}` from `{}`. Error: `{:?}`",
                     session_code, session_device_name, e
                 );
 
@@ -115,6 +228,7 @@ impl SessionJoinResponderSystem {
 
     fn send_session_join_event(
         transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
         socket_addr: SocketAddr,
         session_join_event: SessionJoinEvent,
     ) {
@@ -132,6 +246,7 @@ impl SessionJoinResponderSystem {
                     DeliveryRequirement::ReliableOrdered(None),
                     UrgencyRequirement::OnTick,
                 );
+                server_metrics.messages_out += 1;
             }
             Err(e) => {
                 error!(
@@ -145,6 +260,7 @@ impl SessionJoinResponderSystem {
     fn send_session_message_event(
         session_device_mappings: &SessionDeviceMappings,
         transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
         socket_addr_exclude: SocketAddr,
         session_message_event: SessionMessageEvent,
     ) {
@@ -179,6 +295,7 @@ impl SessionJoinResponderSystem {
                                 DeliveryRequirement::ReliableOrdered(None),
                                 UrgencyRequirement::OnTick,
                             );
+                            server_metrics.messages_out += 1;
                         });
                 }
             }
@@ -199,11 +316,19 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
         &mut self,
         SessionJoinResponderSystemData {
             session_join_nec,
+            session_limits,
+            session_codes_expired,
+            session_handshakes,
             mut sessions,
             mut session_code_to_id,
             mut session_id_to_device_mappings,
             mut socket_to_device_id,
+            mut socket_to_device_type,
+            mut session_reconnect_tokens,
+            mut session_last_active,
+            mut ip_request_trackers,
             mut transport_resource,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
         let session_code_to_id = &mut *session_code_to_id;
@@ -231,7 +356,14 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
             .map(|(socket_addr, session_join_request_params)| {
                 let session_join_and_message_events = Self::handle_session_request(
                     &mut session_tracker,
+                    &session_limits,
+                    &session_codes_expired,
+                    &session_handshakes,
                     &mut socket_to_device_id,
+                    &mut socket_to_device_type,
+                    &mut session_reconnect_tokens,
+                    &mut session_last_active,
+                    &mut ip_request_trackers,
                     socket_addr,
                     session_join_request_params,
                 );
@@ -242,8 +374,13 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
             .into_iter()
             .for_each(
                 |(socket_addr, (session_join_event, session_message_event))| {
+                    if let SessionJoinEvent::SessionReject(_) = &session_join_event {
+                        server_metrics.messages_rejected += 1;
+                    }
+
                     Self::send_session_join_event(
                         &mut transport_resource,
+                        &mut server_metrics,
                         socket_addr,
                         session_join_event,
                     );
@@ -252,6 +389,7 @@ impl<'s> System<'s> for SessionJoinResponderSystem {
                         Self::send_session_message_event(
                             &session_tracker.session_device_mappings,
                             &mut transport_resource,
+                            &mut server_metrics,
                             socket_addr,
                             session_message_event,
                         );
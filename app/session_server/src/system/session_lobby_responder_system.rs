@@ -13,7 +13,10 @@ use log::{debug, error};
 use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
 use session_lobby_model::{play::SessionStartRequestParams, SessionLobbyEvent};
 
-use crate::model::{SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings};
+use crate::model::{
+    ServerMetrics, SessionCodeToId, SessionDeviceMappingsRead, SessionIdToDeviceMappings,
+    SessionLastActive,
+};
 
 /// Accepts or rejects session start requests, and notifies all connected devices.
 #[derive(Debug, SystemDesc, new)]
@@ -36,14 +39,21 @@ pub struct SessionLobbyResponderSystemData<'s> {
     /// `SessionIdToDeviceMappings` resource.
     #[derivative(Debug = "ignore")]
     pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SessionLastActive` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_last_active: Write<'s, SessionLastActive>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
 }
 
 impl SessionLobbyResponderSystem {
     fn send_session_lobby_event(
         transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
         socket_addrs: impl Iterator<Item = SocketAddr>,
         session_lobby_event: SessionLobbyEvent,
     ) {
@@ -62,6 +72,7 @@ impl SessionLobbyResponderSystem {
                         DeliveryRequirement::ReliableOrdered(None),
                         UrgencyRequirement::OnTick,
                     );
+                    server_metrics.messages_out += 1;
                 });
             }
             Err(e) => {
@@ -83,7 +94,9 @@ impl<'s> System<'s> for SessionLobbyResponderSystem {
             session_lobby_nec,
             session_code_to_id,
             session_id_to_device_mappings,
+            mut session_last_active,
             mut transport_resource,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
         let session_code_to_id = &*session_code_to_id;
@@ -126,15 +139,22 @@ impl<'s> System<'s> for SessionLobbyResponderSystem {
                                 .map(|net_session_device| net_session_device.socket_addr);
                             Self::send_session_lobby_event(
                                 &mut transport_resource,
+                                &mut server_metrics,
                                 socket_addrs,
                                 SessionLobbyEvent::SessionStartNotify,
                             );
+
+                            // Sessions in play do not expire.
+                            if let Some(session_code_id) = session_code_to_id.id(session_code) {
+                                session_last_active.remove(&session_code_id);
+                            }
                         }
                     } else {
                         debug!(
                             "Received `{:?}` from {:?}, but session code tracked is `{}`.",
                             session_start_request_params, socket_addr, session_code_tracked,
                         );
+                        server_metrics.messages_rejected += 1;
                         // TODO: reject because the session code doesn't match
                     }
                 } else {
@@ -142,6 +162,7 @@ impl<'s> System<'s> for SessionLobbyResponderSystem {
                         "Received `{:?}` from {:?}, but no session code tracked for that socket.",
                         session_start_request_params, socket_addr
                     );
+                    server_metrics.messages_rejected += 1;
                     // TODO: reject
                 }
             });
@@ -1,4 +1,4 @@
-use std::net::SocketAddr;
+use std::{collections::HashMap, net::SocketAddr, time::Instant};
 
 use amethyst::{
     derive::SystemDesc,
@@ -17,8 +17,8 @@ use network_session_model::{
 };
 
 use crate::model::{
-    GameInputTickStatus, SessionCodeId, SessionCodeToId, SessionDeviceTickStatuses,
-    SessionIdToDeviceMappings, SessionTickStatuses, SocketToDeviceId,
+    GameInputTickStatus, ServerMetrics, SessionCodeId, SessionCodeToId, SessionDeviceTickStatuses,
+    SessionIdToDeviceMappings, SessionTickStatuses, SocketToDeviceId, SocketToDeviceType,
 };
 
 /// Notifies game clients when all `GameInputEvent`s have been sent for the current tick.
@@ -26,12 +26,18 @@ use crate::model::{
 /// When `SessionMessageEvent::GameInputTick` has been received from each client, the session server then sends its own
 /// `SessionMessageEvent::GameInputTick` messages to all clients, notifying them that all `GameInputEvent`s have been
 /// sent to them.
+///
+/// Spectators are sent `GameInputTick` messages, but the session does not wait for them.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionMessageResponderSystemDesc))]
 pub struct SessionMessageResponderSystem {
     /// Reader ID for the `SessionMessageEvent` channel.
     #[system_desc(event_channel_reader)]
     session_message_event_rid: ReaderId<NetData<SessionMessageEvent>>,
+    /// When the first `GameInputTick` was received for each session's current tick.
+    #[system_desc(skip)]
+    #[new(default)]
+    tick_wait_starts: HashMap<SessionCodeId, Instant>,
 }
 
 #[derive(Derivative, SystemData)]
@@ -46,6 +52,9 @@ pub struct SessionMessageResponderSystemData<'s> {
     /// `SocketToDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub socket_to_device_id: Read<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Read<'s, SocketToDeviceType>,
     /// `SessionCodeToId` resource.
     #[derivative(Debug = "ignore")]
     pub session_code_to_id: Read<'s, SessionCodeToId>,
@@ -55,6 +64,9 @@ pub struct SessionMessageResponderSystemData<'s> {
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
 }
 
 impl SessionMessageResponderSystem {
@@ -172,6 +184,7 @@ impl SessionMessageResponderSystem {
         session_id_to_device_mappings: &SessionIdToDeviceMappings,
         session_code_to_id: &SessionCodeToId,
         transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
         session_code_id: SessionCodeId,
     ) {
         let session_code = session_code_to_id.code(session_code_id);
@@ -194,7 +207,7 @@ impl SessionMessageResponderSystem {
                 .iter()
                 .map(|net_session_device| net_session_device.socket_addr);
 
-            Self::send_game_input_tick(transport_resource, socket_addrs);
+            Self::send_game_input_tick(transport_resource, server_metrics, socket_addrs);
         } else if let Some(session_code) = session_code {
             error!(
                 "`NetSessionDevices` not found for session: `{}`",
@@ -210,6 +223,7 @@ impl SessionMessageResponderSystem {
 
     fn send_game_input_tick(
         transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
         socket_addrs: impl Iterator<Item = SocketAddr>,
     ) {
         let net_message_event = NetMessageEvent::from(SessionMessageEvent::GameInputTick);
@@ -227,6 +241,7 @@ impl SessionMessageResponderSystem {
                         DeliveryRequirement::ReliableOrdered(None),
                         UrgencyRequirement::OnTick,
                     );
+                    server_metrics.messages_out += 1;
                 });
             }
             Err(e) => {
@@ -248,9 +263,11 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
             session_message_nec,
             session_id_to_device_mappings,
             socket_to_device_id,
+            socket_to_device_type,
             session_code_to_id,
             mut session_tick_statuses,
             mut transport_resource,
+            mut server_metrics,
         }: Self::SystemData,
     ) {
         // 1. When a client sends `SessionMessageEvent::GameInputTick`, record it.
@@ -260,13 +277,16 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
         //
         // * If the client has sent `GameInputTick` more than once, ignore it and log a warning.
         // * If the client isn't registered to a session, ignore it and log a warning.
+        // * If the client is a spectator, ignore it.
 
         let session_id_to_device_mappings = &session_id_to_device_mappings;
         let session_tick_statuses = &mut session_tick_statuses;
+        let tick_wait_starts = &mut self.tick_wait_starts;
         session_message_nec
             .read(&mut self.session_message_event_rid)
             .filter(|net_session_message_event| {
                 net_session_message_event.data == SessionMessageEvent::GameInputTick
+                    && !socket_to_device_type.is_spectator(&net_session_message_event.socket_addr)
             })
             .for_each(|net_session_message_event| {
                 let session_information = Self::select_session_information(
@@ -280,6 +300,10 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
 
                 match session_information {
                     Ok((session_device_tick_statuses, session_code_id, session_device_id)) => {
+                        tick_wait_starts
+                            .entry(session_code_id)
+                            .or_insert_with(Instant::now);
+
                         // Record `GameInputTick` for this client.
                         Self::tick_device(
                             session_id_to_device_mappings,
@@ -297,9 +321,15 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
                                 session_id_to_device_mappings,
                                 &session_code_to_id,
                                 &mut transport_resource,
+                                &mut server_metrics,
                                 session_code_id,
                             );
 
+                            if let Some(tick_wait_start) = tick_wait_starts.remove(&session_code_id)
+                            {
+                                server_metrics.tick_wait_record(tick_wait_start.elapsed());
+                            }
+
                             // Reset session device tick statuses.
                             session_device_tick_statuses
                                 .values_mut()
@@ -308,7 +338,10 @@ impl<'s> System<'s> for SessionMessageResponderSystem {
                                 });
                         }
                     }
-                    Err(e) => warn!("{}", e),
+                    Err(e) => {
+                        warn!("{}", e);
+                        server_metrics.messages_rejected += 1;
+                    }
                 }
             });
     }
@@ -0,0 +1,302 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::loaded::{PlayerController, PlayerControllers};
+use log::{debug, error};
+use net_model::play::{
+    GameInputReceiveBuffer, GameInputSendBuffer, NetData, NetEventChannel, NetMessageEvent,
+};
+use network_session_model::{play::Sessions, SessionMessageEvent};
+use session_reconnect_model::{
+    play::{
+        SessionReconnectAcceptResponse, SessionReconnectError, SessionReconnectRejectResponse,
+        SessionReconnectRequestParams,
+    },
+    SessionReconnectEvent,
+};
+
+use crate::model::{
+    DisconnectedDevices, ServerMetrics, SessionCodeToId, SessionGameTicks,
+    SessionIdToDeviceMappings, SessionReconnectTokens, SocketToDeviceId, SocketToDeviceType,
+};
+
+/// Accepts or rejects session reconnect requests, and sends the response to the requester.
+///
+/// When accepted, the device's previous `SocketAddr` is replaced with the one it reconnected from,
+/// and input buffered for the device is resent.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionReconnectResponderSystemDesc))]
+pub struct SessionReconnectResponderSystem {
+    /// Reader ID for the `SessionReconnectEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_reconnect_event_rid: ReaderId<NetData<SessionReconnectEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionReconnectResponderSystemData<'s> {
+    /// `SessionReconnectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_nec: Read<'s, NetEventChannel<SessionReconnectEvent>>,
+    /// `Sessions` resource.
+    #[derivative(Debug = "ignore")]
+    pub sessions: Read<'s, Sessions>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Read<'s, SessionCodeToId>,
+    /// `SessionReconnectTokens` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_tokens: Read<'s, SessionReconnectTokens>,
+    /// `SessionGameTicks` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_game_ticks: Read<'s, SessionGameTicks>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Write<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Write<'s, SocketToDeviceId>,
+    /// `SocketToDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_type: Write<'s, SocketToDeviceType>,
+    /// `DisconnectedDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub disconnected_devices: Write<'s, DisconnectedDevices>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+}
+
+impl SessionReconnectResponderSystem {
+    fn handle_session_reconnect_request(
+        SessionReconnectResponderSystemData {
+            sessions,
+            session_code_to_id,
+            session_reconnect_tokens,
+            session_game_ticks,
+            session_id_to_device_mappings,
+            socket_to_device_id,
+            socket_to_device_type,
+            disconnected_devices,
+            game_input_send_buffer,
+            game_input_receive_buffer,
+            ..
+        }: &mut SessionReconnectResponderSystemData<'_>,
+        socket_addr: SocketAddr,
+        session_reconnect_request_params: &SessionReconnectRequestParams,
+    ) -> Result<SessionReconnectAcceptResponse, SessionReconnectError> {
+        let SessionReconnectRequestParams {
+            session_code,
+            session_device_id,
+            session_reconnect_token,
+        } = session_reconnect_request_params;
+        let session_device_id = *session_device_id;
+
+        let (session_code_id, session) = session_code_to_id
+            .get_by_left(session_code)
+            .copied()
+            .and_then(|session_code_id| {
+                sessions
+                    .get(session_code)
+                    .map(|session| (session_code_id, session))
+            })
+            .ok_or(SessionReconnectError::SessionCodeNotFound)?;
+
+        let session_reconnect_token_expected = session_reconnect_tokens
+            .get(&session_code_id)
+            .and_then(|session_device_reconnect_tokens| {
+                session_device_reconnect_tokens.get(&session_device_id)
+            })
+            .ok_or(SessionReconnectError::SessionDeviceNotFound)?;
+        if session_reconnect_token_expected != session_reconnect_token {
+            return Err(SessionReconnectError::SessionReconnectTokenInvalid);
+        }
+
+        // This also handles the case where the previous connection has not been detected as
+        // disconnected, and the device reconnects from a new socket.
+        let socket_addr_previous = session_id_to_device_mappings
+            .socket_addr_replace(session_code_id, session_device_id, socket_addr)
+            .ok_or(SessionReconnectError::SessionDeviceNotFound)?;
+
+        if socket_addr_previous != socket_addr {
+            socket_to_device_id.remove(&socket_addr_previous);
+            if let Some(session_device_type) = socket_to_device_type.remove(&socket_addr_previous) {
+                socket_to_device_type.insert(socket_addr, session_device_type);
+            }
+            game_input_send_buffer.socket_replace(socket_addr_previous, socket_addr);
+            game_input_receive_buffer.socket_replace(socket_addr_previous, socket_addr);
+        }
+        socket_to_device_id.insert(socket_addr, session_device_id);
+        disconnected_devices.remove(&socket_addr_previous);
+
+        let player_controllers = session
+            .session_devices
+            .iter()
+            .flat_map(|session_device| session_device.player_controllers.iter().cloned())
+            .collect::<Vec<PlayerController>>();
+        let player_controllers = PlayerControllers::new(player_controllers);
+        let game_tick = session_game_ticks
+            .get(&session_code_id)
+            .copied()
+            .unwrap_or_default();
+
+        debug!(
+            "Device [{}] reconnected to session `{}` from `{}`.",
+            session_device_id, session_code, socket_addr
+        );
+
+        Ok(SessionReconnectAcceptResponse::new(
+            session.clone(),
+            session_device_id,
+            player_controllers,
+            game_tick,
+        ))
+    }
+
+    fn send_net_message_event(
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+        net_message_event: NetMessageEvent,
+    ) {
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                socket_addrs.for_each(|socket_addr| {
+                    transport_resource.send_with_requirements(
+                        socket_addr,
+                        &payload,
+                        // None means it uses a default multiplexed stream.
+                        //
+                        // Suspect if we give it a value, the value will be a "channel" over the same
+                        // socket connection.
+                        DeliveryRequirement::ReliableOrdered(None),
+                        UrgencyRequirement::OnTick,
+                    );
+                    server_metrics.messages_out += 1;
+                });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `{:?}`. Error: `{}`.",
+                    net_message_event, e
+                );
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionReconnectResponderSystem {
+    type SystemData = SessionReconnectResponderSystemData<'s>;
+
+    fn run(&mut self, mut session_reconnect_responder_system_data: Self::SystemData) {
+        let session_reconnect_requests = session_reconnect_responder_system_data
+            .session_reconnect_nec
+            .read(&mut self.session_reconnect_event_rid)
+            .filter_map(|session_reconnect_event| {
+                if let NetData {
+                    socket_addr,
+                    data: SessionReconnectEvent::SessionReconnectRequest(params),
+                } = session_reconnect_event
+                {
+                    Some((*socket_addr, params.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+
+        session_reconnect_requests.into_iter().for_each(
+            |(socket_addr, session_reconnect_request_params)| {
+                let result = Self::handle_session_reconnect_request(
+                    &mut session_reconnect_responder_system_data,
+                    socket_addr,
+                    &session_reconnect_request_params,
+                );
+
+                let SessionReconnectResponderSystemData {
+                    session_id_to_device_mappings,
+                    disconnected_devices,
+                    transport_resource,
+                    server_metrics,
+                    ..
+                } = &mut session_reconnect_responder_system_data;
+
+                match result {
+                    Ok(session_reconnect_accept_response) => {
+                        let session_device_id = session_reconnect_accept_response.session_device_id;
+                        Self::send_net_message_event(
+                            transport_resource,
+                            server_metrics,
+                            std::iter::once(socket_addr),
+                            NetMessageEvent::from(SessionReconnectEvent::SessionReconnectAccept(
+                                session_reconnect_accept_response,
+                            )),
+                        );
+
+                        // Let the other devices know the device is back.
+                        let socket_addrs = session_id_to_device_mappings
+                            .session_code_id(&socket_addr)
+                            .and_then(|session_code_id| {
+                                session_id_to_device_mappings.net_session_devices(session_code_id)
+                            })
+                            .into_iter()
+                            .flat_map(|net_session_devices| net_session_devices.iter())
+                            .map(|net_session_device| net_session_device.socket_addr)
+                            .filter(|socket_addr_other| {
+                                *socket_addr_other != socket_addr
+                                    && !disconnected_devices.contains_key(socket_addr_other)
+                            });
+                        Self::send_net_message_event(
+                            transport_resource,
+                            server_metrics,
+                            socket_addrs,
+                            NetMessageEvent::from(SessionMessageEvent::SessionDeviceReconnect(
+                                session_device_id,
+                            )),
+                        );
+                    }
+                    Err(session_reconnect_error) => {
+                        debug!(
+                            "Rejecting request to reconnect to session `{}` from `{}`. Error: `{}`",
+                            session_reconnect_request_params.session_code,
+                            socket_addr,
+                            session_reconnect_error
+                        );
+
+                        server_metrics.messages_rejected += 1;
+
+                        let session_reconnect_reject_response = SessionReconnectRejectResponse::new(
+                            session_reconnect_request_params.session_code,
+                            session_reconnect_error,
+                        );
+                        Self::send_net_message_event(
+                            transport_resource,
+                            server_metrics,
+                            std::iter::once(socket_addr),
+                            NetMessageEvent::from(SessionReconnectEvent::SessionReconnectReject(
+                                session_reconnect_reject_response,
+                            )),
+                        );
+                    }
+                }
+            },
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error, warn};
+use net_model::play::{NetData, NetEventChannel, NetMessageEvent, NetSessionDevices};
+use network_session_model::{
+    play::{SessionDesync, StateChecksum},
+    SessionMessageEvent,
+};
+
+use crate::model::{
+    ServerMetrics, SessionCodeId, SessionCodeToId, SessionDeviceStateChecksums,
+    SessionIdToDeviceMappings, SessionStateChecksums, SocketToDeviceId,
+};
+
+/// Compares the `StateChecksum`s sent by each device, and notifies the session when they differ.
+///
+/// When a `StateChecksum` has been received from every device in the session for a tick, the
+/// checksum held by the most devices is treated as the expected state. A
+/// `SessionMessageEvent::SessionDesync` is sent to all devices in the session for each device whose
+/// checksum differs.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(StateChecksumResponderSystemDesc))]
+pub struct StateChecksumResponderSystem {
+    /// Reader ID for the `SessionMessageEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_message_event_rid: ReaderId<NetData<SessionMessageEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StateChecksumResponderSystemData<'s> {
+    /// `SessionMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_message_nec: Read<'s, NetEventChannel<SessionMessageEvent>>,
+    /// `SessionIdToDeviceMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_id_to_device_mappings: Read<'s, SessionIdToDeviceMappings>,
+    /// `SocketToDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub socket_to_device_id: Read<'s, SocketToDeviceId>,
+    /// `SessionCodeToId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code_to_id: Read<'s, SessionCodeToId>,
+    /// `SessionStateChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_state_checksums: Write<'s, SessionStateChecksums>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+    /// `ServerMetrics` resource.
+    #[derivative(Debug = "ignore")]
+    pub server_metrics: Write<'s, ServerMetrics>,
+}
+
+impl StateChecksumResponderSystem {
+    /// Returns the checksum held by the most devices in the session.
+    ///
+    /// When multiple checksums are held by the same number of devices, the checksum of the device
+    /// that joined the session earliest is used.
+    fn expected_state_checksum<'f>(
+        net_session_devices: &NetSessionDevices,
+        session_device_state_checksums: &'f SessionDeviceStateChecksums,
+    ) -> Option<&'f StateChecksum> {
+        net_session_devices
+            .iter()
+            .filter_map(|net_session_device| {
+                session_device_state_checksums.get(&net_session_device.data.id)
+            })
+            .fold(
+                None,
+                |expected: Option<(&StateChecksum, usize)>, state_checksum| {
+                    let count = session_device_state_checksums
+                        .values()
+                        .filter(|other| other.checksum == state_checksum.checksum)
+                        .count();
+                    match expected {
+                        Some((_, count_expected)) if count_expected >= count => expected,
+                        _ => Some((state_checksum, count)),
+                    }
+                },
+            )
+            .map(|(state_checksum, _)| state_checksum)
+    }
+
+    /// Returns a `SessionDesync` for each device whose checksum differs from the expected checksum.
+    fn session_desyncs(
+        net_session_devices: &NetSessionDevices,
+        session_device_state_checksums: &SessionDeviceStateChecksums,
+    ) -> Vec<SessionDesync> {
+        let expected_state_checksum =
+            Self::expected_state_checksum(net_session_devices, session_device_state_checksums);
+
+        if let Some(expected_state_checksum) = expected_state_checksum {
+            net_session_devices
+                .iter()
+                .filter_map(|net_session_device| {
+                    let session_device = &net_session_device.data;
+                    session_device_state_checksums
+                        .get(&session_device.id)
+                        .filter(|state_checksum| {
+                            state_checksum.checksum != expected_state_checksum.checksum
+                        })
+                        .map(|state_checksum| {
+                            SessionDesync::new(
+                                state_checksum.tick,
+                                session_device.id,
+                                session_device.name.clone(),
+                                state_checksum.checksum,
+                                expected_state_checksum.checksum,
+                                state_checksum.state_dump.clone(),
+                                expected_state_checksum.state_dump.clone(),
+                            )
+                        })
+                })
+                .collect::<Vec<SessionDesync>>()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the session code for display, falling back to the `SessionCodeId`.
+    fn session_code_display(
+        session_code_to_id: &SessionCodeToId,
+        session_code_id: SessionCodeId,
+    ) -> String {
+        session_code_to_id
+            .code(session_code_id)
+            .map(ToString::to_string)
+            .unwrap_or_else(|| session_code_id.to_string())
+    }
+
+    fn send_session_desync(
+        transport_resource: &mut TransportResource,
+        server_metrics: &mut ServerMetrics,
+        socket_addrs: impl Iterator<Item = SocketAddr>,
+        session_desync: SessionDesync,
+    ) {
+        let net_message_event =
+            NetMessageEvent::from(SessionMessageEvent::SessionDesync(session_desync));
+
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                socket_addrs.for_each(|socket_addr| {
+                    transport_resource.send_with_requirements(
+                        socket_addr,
+                        &payload,
+                        DeliveryRequirement::ReliableOrdered(None),
+                        UrgencyRequirement::OnTick,
+                    );
+                    server_metrics.messages_out += 1;
+                });
+            }
+            Err(e) => {
+                error!(
+                    "Failed to serialize `NetMessageEvent::SessionDesync`. Error: `{}`.",
+                    e
+                );
+            }
+        }
+    }
+}
+
+impl<'s> System<'s> for StateChecksumResponderSystem {
+    type SystemData = StateChecksumResponderSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StateChecksumResponderSystemData {
+            session_message_nec,
+            session_id_to_device_mappings,
+            socket_to_device_id,
+            session_code_to_id,
+            mut session_state_checksums,
+            mut transport_resource,
+            mut server_metrics,
+        }: Self::SystemData,
+    ) {
+        session_message_nec
+            .read(&mut self.session_message_event_rid)
+            .for_each(|net_session_message_event| {
+                let NetData {
+                    socket_addr,
+                    data: session_message_event,
+                } = net_session_message_event;
+
+                let state_checksum = if let SessionMessageEvent::StateChecksum(state_checksum) =
+                    session_message_event
+                {
+                    state_checksum
+                } else {
+                    return;
+                };
+
+                let session_code_id = session_id_to_device_mappings.session_code_id(socket_addr);
+                let session_device_id = socket_to_device_id.get(socket_addr).copied();
+                let net_session_devices = session_code_id.and_then(|session_code_id| {
+                    session_id_to_device_mappings.net_session_devices(session_code_id)
+                });
+                let (session_code_id, session_device_id, net_session_devices) =
+                    match (session_code_id, session_device_id, net_session_devices) {
+                        (
+                            Some(session_code_id),
+                            Some(session_device_id),
+                            Some(net_session_devices),
+                        ) => (session_code_id, session_device_id, net_session_devices),
+                        _ => {
+                            warn!(
+                                "Received `StateChecksum` from {}, but it is not in a session.",
+                                socket_addr
+                            );
+                            server_metrics.messages_rejected += 1;
+                            return;
+                        }
+                    };
+
+                let tick = state_checksum.tick;
+                let tick_state_checksums = session_state_checksums
+                    .entry(session_code_id)
+                    .or_insert_with(Default::default);
+                let session_device_state_checksums = tick_state_checksums
+                    .entry(tick)
+                    .or_insert_with(SessionDeviceStateChecksums::default);
+                session_device_state_checksums.insert(session_device_id, state_checksum.clone());
+
+                // Wait until all devices have sent their checksum for this tick.
+                if session_device_state_checksums.len() < net_session_devices.len() {
+                    return;
+                }
+
+                let session_desyncs =
+                    Self::session_desyncs(net_session_devices, session_device_state_checksums);
+
+                // Messages are delivered in order, so checksums for earlier ticks that are still
+                // incomplete will never be completed.
+                *tick_state_checksums = tick_state_checksums.split_off(&tick);
+                tick_state_checksums.remove(&tick);
+
+                if session_desyncs.is_empty() {
+                    debug!(
+                        "State checksums match for session `{}` at tick `{}`.",
+                        Self::session_code_display(&session_code_to_id, session_code_id),
+                        tick
+                    );
+                }
+                session_desyncs.into_iter().for_each(|session_desync| {
+                    warn!(
+                        "Session `{}` desynced at tick `{}`: `{}` [{}] has checksum `{}`, \
+                         expected `{}`.",
+                        Self::session_code_display(&session_code_to_id, session_code_id),
+                        session_desync.tick,
+                        session_desync.session_device_name,
+                        session_desync.session_device_id,
+                        session_desync.checksum,
+                        session_desync.expected_checksum,
+                    );
+
+                    let socket_addrs = net_session_devices
+                        .iter()
+                        .map(|net_session_device| net_session_device.socket_addr);
+                    Self::send_session_desync(
+                        &mut transport_resource,
+                        &mut server_metrics,
+                        socket_addrs,
+                        session_desync,
+                    );
+                });
+            });
+    }
+}
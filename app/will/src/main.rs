@@ -3,12 +3,14 @@
 use std::{
     any,
     convert::TryFrom,
-    net::{IpAddr, Ipv4Addr},
+    fs,
+    net::Ipv4Addr,
     path::{Path, PathBuf},
 };
 #[cfg(not(target_arch = "wasm32"))]
 use std::{fs::File, io::BufReader};
 
+use ai_model::config::AiBehaviours;
 use amethyst::{
     assets::{HotReloadBundle, HotReloadStrategy},
     audio::AudioBundle,
@@ -48,8 +50,11 @@ use audio_loading::AudioLoadingBundle;
 use background_loading::BackgroundLoadingBundle;
 use camera_play::CameraPlayBundle;
 use character_loading::CharacterLoadingBundle;
+use chase_loading::ChaseLoadingBundle;
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
+use desync_model::config::DesyncConfig;
+use desync_play::StateChecksumSystem;
 use energy_loading::EnergyLoadingBundle;
 use frame_rate::strategy::frame_rate_limit_config;
 use game_input::{
@@ -61,21 +66,31 @@ use game_input_model::{
     loaded::PlayerControllers,
 };
 use game_input_stdio::ControlInputEventStdinMapper;
+use game_loading::GameLoadingState;
 use game_mode_selection::{GameModeSelectionStateBuilder, GameModeSelectionStateDelegate};
 #[cfg(not(target_arch = "wasm32"))]
 use game_mode_selection_stdio::GameModeSelectionStdioBundle;
 use game_mode_selection_ui::GameModeSelectionSfxSystem;
-use game_play::GamePlayBundle;
+use game_model::play::{GameRng, RngSeed};
+use game_play::{GamePlayBundle, GamePlayState, GameTickUpdateSystem};
+#[cfg(not(target_arch = "wasm32"))]
+use game_play_stats_stdio::GamePlayStatsStdioBundle;
+use game_play_stats_ui_play::{PlayerStatsEntityCreateDeleteSystem, PlayerStatsWidgetUpdateSystem};
 #[cfg(not(target_arch = "wasm32"))]
 use game_play_stdio::GamePlayStdioBundle;
+#[cfg(not(target_arch = "wasm32"))]
+use game_rules_selection_stdio::GameRulesSelectionStdioBundle;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 #[cfg(not(target_arch = "wasm32"))]
 use log::debug;
 use map_loading::MapLoadingBundle;
 use net_play::{
-    NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
+    GameInputBuffersResetSystem, GameInputBuffersResetSystemDesc, GameInputReceiveSystem,
+    GameInputRetransmitSystem, NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem,
+    NetMessageRequestSystemDesc,
 };
 use network_input_play::{
     GameInputTickRequestSystem, NetworkInputRequestSystem, NetworkInputRequestSystemDesc,
@@ -85,21 +100,31 @@ use network_input_play::{
 use network_mode_selection_stdio::NetworkModeSelectionStdioBundle;
 use network_session_model::config::SessionServerConfig;
 use network_session_play::{
-    SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
-    SessionMessageResponseSystem, SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
+    GamePlayPauseRequestSystem, GamePlayPauseRequestSystemDesc, GamePlayRestartRequestSystem,
+    GamePlayRestartRequestSystemDesc, SessionInputResourcesSyncSystem,
+    SessionInputResourcesSyncSystemDesc, SessionMessageResponseSystem,
+    SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
 };
 use parent_play::ChildEntityDeleteSystem;
+use replay_model::{config::Replay, play::ReplayMode};
+use replay_play::{
+    ReplayPlaybackState, ReplayPlaybackSystem, ReplayRecordSystem, ReplayRecordSystemDesc,
+};
+use rollback_model::config::{RollbackConfig, SessionSyncMode};
+use rollback_play::{RollbackPlayBundle, RollbackStatusUpdateSystem};
 use sequence_loading::SequenceLoadingBundle;
 use serde::{Deserialize, Serialize};
 use session_host_play::{
     SessionHostRequestSystem, SessionHostRequestSystemDesc, SessionHostResponseSystem,
-    SessionHostResponseSystemDesc,
+    SessionHostResponseSystemDesc, SessionHostStatusDisplaySystem,
+    SessionHostStatusDisplaySystemDesc,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use session_host_stdio::SessionHostStdioBundle;
 use session_join_play::{
     SessionJoinRequestSystem, SessionJoinRequestSystemDesc, SessionJoinResponseSystem,
-    SessionJoinResponseSystemDesc,
+    SessionJoinResponseSystemDesc, SessionJoinStatusDisplaySystem,
+    SessionJoinStatusDisplaySystemDesc,
 };
 #[cfg(not(target_arch = "wasm32"))]
 use session_join_stdio::SessionJoinStdioBundle;
@@ -111,6 +136,10 @@ use session_lobby_ui_play::{
     SessionCodeLabelUpdateSystem, SessionDeviceEntityCreateDeleteSystem,
     SessionDeviceWidgetUpdateSystem,
 };
+use session_reconnect_play::{
+    SessionReconnectRequestSystem, SessionReconnectRequestSystemDesc,
+    SessionReconnectResponseSystem, SessionReconnectResponseSystemDesc,
+};
 use spawn_loading::SpawnLoadingBundle;
 use sprite_loading::SpriteLoadingBundle;
 use state_play::{
@@ -164,24 +193,68 @@ pub struct WillConfig {
     logger_config: Option<PathBuf>,
     /// Address of the session server.
     ///
-    /// Currently must be an `IpAddr`, in the future we may accept hostnames.
+    /// This may be a hostname, an IPv4 address, or an IPv6 address, optionally with a port, such as
+    /// `example.com:1234` or `[::1]:1234`.
     #[serde(default = "WillConfig::session_server_address_default")]
     #[structopt(long, default_value = "127.0.0.1")]
-    session_server_address: IpAddr,
+    session_server_address: String,
     /// Port that the session server is listening on.
     #[serde(default = "WillConfig::session_server_port_default")]
     #[structopt(long, default_value = "1234")]
     session_server_port: u16,
+    /// How game play is kept in sync in network sessions: `lockstep` or `rollback`.
+    #[serde(default)]
+    #[structopt(long, default_value = "lockstep")]
+    session_sync_mode: SessionSyncMode,
+    /// Number of ticks to delay local input by in rollback mode.
+    #[serde(default = "WillConfig::rollback_input_delay_default")]
+    #[structopt(long, default_value = "2")]
+    rollback_input_delay: u64,
+    /// Maximum number of ticks that game state may be rolled back by.
+    #[serde(default = "WillConfig::rollback_window_default")]
+    #[structopt(long, default_value = "8")]
+    rollback_window: u64,
+    /// Number of ticks between each game play state checksum in network sessions.
+    #[serde(default = "WillConfig::state_checksum_interval_default")]
+    #[structopt(long, default_value = "60")]
+    state_checksum_interval: u64,
+    /// Whether to send a dump of the game play state with each checksum, for debugging desyncs.
+    #[serde(default)]
+    #[structopt(long)]
+    state_dump: bool,
+    /// Seed for random number generation. A random seed is used when not specified.
+    #[structopt(long)]
+    rng_seed: Option<u64>,
+    /// Replay file to record matches to.
+    #[structopt(long)]
+    replay_record: Option<PathBuf>,
+    /// Replay file to play back, instead of reading live input.
+    ///
+    /// When this is specified, `replay_record` is ignored.
+    #[structopt(long)]
+    replay_playback: Option<PathBuf>,
 }
 
 impl WillConfig {
-    fn session_server_address_default() -> IpAddr {
-        IpAddr::V4(Ipv4Addr::LOCALHOST)
+    fn session_server_address_default() -> String {
+        Ipv4Addr::LOCALHOST.to_string()
     }
 
     fn session_server_port_default() -> u16 {
         1234
     }
+
+    fn rollback_input_delay_default() -> u64 {
+        RollbackConfig::INPUT_DELAY_DEFAULT
+    }
+
+    fn rollback_window_default() -> u64 {
+        RollbackConfig::ROLLBACK_WINDOW_DEFAULT
+    }
+
+    fn state_checksum_interval_default() -> u64 {
+        DesyncConfig::CHECKSUM_INTERVAL_DEFAULT
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -223,11 +296,56 @@ fn logger_setup(logger_config_path: Option<PathBuf>) -> Result<(), Error> {
 
 fn session_server_config(will_config: &WillConfig) -> SessionServerConfig {
     SessionServerConfig {
-        address: will_config.session_server_address,
+        address: will_config.session_server_address.clone(),
         port: will_config.session_server_port,
     }
 }
 
+fn rollback_config(will_config: &WillConfig) -> RollbackConfig {
+    RollbackConfig {
+        input_delay: will_config.rollback_input_delay,
+        rollback_window: will_config.rollback_window,
+    }
+}
+
+fn desync_config(will_config: &WillConfig) -> DesyncConfig {
+    DesyncConfig {
+        checksum_interval: will_config.state_checksum_interval,
+        state_dump: will_config.state_dump,
+    }
+}
+
+fn replay_mode(will_config: &WillConfig) -> ReplayMode {
+    if will_config.replay_playback.is_some() {
+        ReplayMode::Playback
+    } else if let Some(path) = will_config.replay_record.clone() {
+        ReplayMode::Record { path }
+    } else {
+        ReplayMode::None
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn ai_behaviours_load() -> AiBehaviours {
+    AppFile::load_in::<AiBehaviours, _>(AppDir::RESOURCES, "ai_behaviours.yaml", Format::Yaml)
+        .unwrap_or_else(|e| {
+            debug!("Using built in `AiBehaviours`: {}", e);
+            AiBehaviours::default()
+        })
+}
+
+#[cfg(target_arch = "wasm32")]
+fn ai_behaviours_load() -> AiBehaviours {
+    AiBehaviours::default()
+}
+
+fn replay_load(replay_path: &Path) -> Result<Replay, Error> {
+    let replay_yaml = fs::read(replay_path)?;
+    let replay = serde_yaml::from_slice::<Replay>(&replay_yaml)?;
+
+    Ok(replay)
+}
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::*;
 
@@ -454,11 +572,27 @@ where
         fn_setup(&app_root, &event_loop)?;
 
     let session_server_config = session_server_config(&will_config);
-
-    let game_mode_selection_state =
-        GameModeSelectionStateBuilder::new(GameModeSelectionStateDelegate::new()).build();
-    let loading_state = LoadingState::<_>::new(game_mode_selection_state);
-    let state = RobotState::new(Box::new(loading_state));
+    let session_sync_mode = will_config.session_sync_mode;
+    let rollback_config = rollback_config(&will_config);
+    let desync_config = desync_config(&will_config);
+    let replay_mode = replay_mode(&will_config);
+    let rng_seed = RngSeed::new(will_config.rng_seed.unwrap_or_else(rand::random));
+    let ai_behaviours = ai_behaviours_load();
+
+    let state = if let Some(replay_path) = will_config.replay_playback.as_ref() {
+        let replay = replay_load(replay_path)?;
+
+        let game_play_fn = || Box::new(GamePlayState::new());
+        let game_loading_fn = move || Box::new(GameLoadingState::new(game_play_fn));
+        let replay_playback_state = ReplayPlaybackState::new(replay, game_loading_fn);
+        let loading_state = LoadingState::<_>::new(replay_playback_state);
+        RobotState::new(Box::new(loading_state))
+    } else {
+        let game_mode_selection_state =
+            GameModeSelectionStateBuilder::new(GameModeSelectionStateDelegate::new()).build();
+        let loading_state = LoadingState::<_>::new(game_mode_selection_state);
+        RobotState::new(Box::new(loading_state))
+    };
 
     let player_controllers = PlayerControllers::from(&player_input_configs);
 
@@ -487,16 +621,42 @@ where
             .with_bundle(SequenceLoadingBundle::new())?
             .with_bundle(AudioLoadingBundle::new())?
             .with_bundle(KinematicLoadingBundle::new())?
+            .with_bundle(ChaseLoadingBundle::new())?
             .with_bundle(LoadingBundle::new(assets_dir.clone()))?
+            // Local input must be stamped with the game tick before a rollback changes it.
+            .with_system_desc(
+                NetworkInputRequestSystemDesc::default(),
+                any::type_name::<NetworkInputRequestSystem>(),
+                &["input_system"],
+            )
+            .with_bundle(
+                RollbackPlayBundle::new()
+                    .with_input_dependencies(&[any::type_name::<NetworkInputRequestSystem>()]),
+            )?
+            // Game state must be restored before input is applied when rolling back.
+            .with_barrier()
             .with_system_desc(
                 InputToGameInputSystemDesc::default(),
                 any::type_name::<InputToGameInputSystem>(),
                 &["input_system"],
             )
+            .with(
+                ReplayPlaybackSystem::new(),
+                any::type_name::<ReplayPlaybackSystem>(),
+                &[any::type_name::<InputToGameInputSystem>()],
+            )
             .with_system_desc(
                 GameInputToControlInputSystemDesc::default(),
                 any::type_name::<GameInputToControlInputSystem>(),
-                &[any::type_name::<InputToGameInputSystem>()],
+                &[
+                    any::type_name::<InputToGameInputSystem>(),
+                    any::type_name::<ReplayPlaybackSystem>(),
+                ],
+            )
+            .with_system_desc(
+                ReplayRecordSystemDesc::default(),
+                any::type_name::<ReplayRecordSystem>(),
+                &[any::type_name::<GameInputToControlInputSystem>()],
             )
             .with(
                 MapperSystem::<ControlInputEventStdinMapper>::new(AppEventVariant::ControlInput),
@@ -522,6 +682,8 @@ where
                 .with_bundle(StdioCommandStdioBundle::new())?
                 .with_bundle(AssetSelectionStdioBundle::new())?
                 .with_bundle(GamePlayStdioBundle::new())?
+                .with_bundle(GamePlayStatsStdioBundle::new())?
+                .with_bundle(GameRulesSelectionStdioBundle::new())?
                 .with_bundle(GameModeSelectionStdioBundle::new())?
                 .with_bundle(NetworkModeSelectionStdioBundle::new())?
                 .with_bundle(SessionHostStdioBundle::new())?
@@ -541,6 +703,7 @@ where
             .with_bundle(MapLoadingBundle::new())?
             .with_bundle(CharacterLoadingBundle::new())?
             .with_bundle(EnergyLoadingBundle::new())?
+            .with_bundle(ItemLoadingBundle::new())?
             .with_bundle(InputReactionLoadingBundle::new())?
             .with_bundle(CollisionAudioLoadingBundle::new(assets_dir.clone()))?
             .with_bundle(UiAudioLoadingBundle::new(assets_dir.clone()))?
@@ -607,14 +770,24 @@ where
                 &[],
             )
             .with_system_desc(
-                NetworkInputRequestSystemDesc::default(),
-                any::type_name::<NetworkInputRequestSystem>(),
-                &["input_system"],
+                GamePlayRestartRequestSystemDesc::default(),
+                any::type_name::<GamePlayRestartRequestSystem>(),
+                &[],
+            )
+            .with_system_desc(
+                GamePlayPauseRequestSystemDesc::default(),
+                any::type_name::<GamePlayPauseRequestSystem>(),
+                &[],
+            )
+            .with_system_desc(
+                SessionReconnectRequestSystemDesc::default(),
+                any::type_name::<SessionReconnectRequestSystem>(),
+                &[],
             )
             .with(
                 GameInputTickRequestSystem::new(),
                 any::type_name::<GameInputTickRequestSystem>(),
-                &[any::type_name::<NetworkInputRequestSystem>()],
+                &[],
             )
             .with_system_desc(
                 NetMessageRequestSystemDesc::default(),
@@ -623,6 +796,9 @@ where
                     any::type_name::<SessionHostRequestSystem>(),
                     any::type_name::<SessionJoinRequestSystem>(),
                     any::type_name::<SessionLobbyRequestSystem>(),
+                    any::type_name::<GamePlayRestartRequestSystem>(),
+                    any::type_name::<GamePlayPauseRequestSystem>(),
+                    any::type_name::<SessionReconnectRequestSystem>(),
                     any::type_name::<NetworkInputRequestSystem>(),
                     any::type_name::<GameInputTickRequestSystem>(),
                 ],
@@ -632,21 +808,55 @@ where
                 any::type_name::<NetListenerSystem>(),
                 &[],
             )
+            .with(
+                GameInputReceiveSystem::new(),
+                any::type_name::<GameInputReceiveSystem>(),
+                &[any::type_name::<NetListenerSystem>()],
+            )
+            .with(
+                GameInputRetransmitSystem::new(),
+                any::type_name::<GameInputRetransmitSystem>(),
+                &[any::type_name::<NetMessageRequestSystem>()],
+            )
             .with_system_desc(
                 SessionHostResponseSystemDesc::default(),
                 any::type_name::<SessionHostResponseSystem>(),
                 &[any::type_name::<NetListenerSystem>()],
             )
+            .with_system_desc(
+                SessionHostStatusDisplaySystemDesc::default(),
+                any::type_name::<SessionHostStatusDisplaySystem>(),
+                &[
+                    any::type_name::<SessionHostRequestSystem>(),
+                    any::type_name::<SessionHostResponseSystem>(),
+                ],
+            )
             .with_system_desc(
                 SessionJoinResponseSystemDesc::default(),
                 any::type_name::<SessionJoinResponseSystem>(),
                 &[any::type_name::<NetListenerSystem>()],
             )
+            .with_system_desc(
+                SessionJoinStatusDisplaySystemDesc::default(),
+                any::type_name::<SessionJoinStatusDisplaySystem>(),
+                &[
+                    any::type_name::<SessionJoinRequestSystem>(),
+                    any::type_name::<SessionJoinResponseSystem>(),
+                ],
+            )
             .with_system_desc(
                 SessionLobbyResponseSystemDesc::default(),
                 any::type_name::<SessionLobbyResponseSystem>(),
                 &[any::type_name::<NetListenerSystem>()],
             )
+            .with_system_desc(
+                SessionReconnectResponseSystemDesc::default(),
+                any::type_name::<SessionReconnectResponseSystem>(),
+                &[
+                    any::type_name::<NetListenerSystem>(),
+                    any::type_name::<SessionReconnectRequestSystem>(),
+                ],
+            )
             .with_system_desc(
                 SessionMessageResponseSystemDesc::default(),
                 any::type_name::<SessionMessageResponseSystem>(),
@@ -659,10 +869,15 @@ where
                 NetworkInputResponseSystemDesc::default(),
                 any::type_name::<NetworkInputResponseSystem>(),
                 &[
-                    any::type_name::<NetListenerSystem>(),
+                    any::type_name::<GameInputReceiveSystem>(),
                     any::type_name::<SessionMessageResponseSystem>(),
                 ],
             )
+            .with_system_desc(
+                GameInputBuffersResetSystemDesc::default(),
+                any::type_name::<GameInputBuffersResetSystem>(),
+                &[any::type_name::<NetworkInputResponseSystem>()],
+            )
             .with(
                 SessionCodeLabelUpdateSystem::new(),
                 any::type_name::<SessionCodeLabelUpdateSystem>(),
@@ -686,6 +901,16 @@ where
                 any::type_name::<SessionDeviceWidgetUpdateSystem>(),
                 &[any::type_name::<SessionDeviceEntityCreateDeleteSystem>()],
             )
+            .with(
+                PlayerStatsEntityCreateDeleteSystem::new(),
+                any::type_name::<PlayerStatsEntityCreateDeleteSystem>(),
+                &[],
+            )
+            .with(
+                PlayerStatsWidgetUpdateSystem::new(),
+                any::type_name::<PlayerStatsWidgetUpdateSystem>(),
+                &[any::type_name::<PlayerStatsEntityCreateDeleteSystem>()],
+            )
             .with_system_desc(
                 StateItemUiInputAugmentSystemDesc::default(),
                 any::type_name::<StateItemUiInputAugmentSystem>(),
@@ -698,6 +923,16 @@ where
             )
             .with_barrier()
             .with_bundle(GamePlayBundle::new())?
+            .with(
+                RollbackStatusUpdateSystem::new(),
+                any::type_name::<RollbackStatusUpdateSystem>(),
+                &[any::type_name::<GameTickUpdateSystem>()],
+            )
+            .with(
+                StateChecksumSystem::new(),
+                any::type_name::<StateChecksumSystem>(),
+                &[any::type_name::<GameTickUpdateSystem>()],
+            )
             .with(
                 GameModeSelectionSfxSystem::new(),
                 any::type_name::<GameModeSelectionSfxSystem>(),
@@ -765,6 +1000,13 @@ where
 
     let app = CoreApplication::<_, AppEvent, AppEventReader>::build(assets_dir, state)?
         .with_resource(session_server_config)
+        .with_resource(session_sync_mode)
+        .with_resource(rollback_config)
+        .with_resource(desync_config)
+        .with_resource(replay_mode)
+        .with_resource(rng_seed)
+        .with_resource(GameRng::new(rng_seed))
+        .with_resource(ai_behaviours)
         .with_resource(player_controllers)
         .with_resource(player_input_configs)
         .with_frame_limit_config(frame_rate_limit_config(will_config.frame_rate))
@@ -0,0 +1,20 @@
+use game_input_model::play::ControllerInput;
+
+use crate::{config::AiBehaviour, play::AiObservation};
+
+/// Decides the input for a computer controlled entity.
+///
+/// Implementations must be deterministic, as the input is recomputed when game play is rolled
+/// back.
+pub trait AiController {
+    /// Returns the `ControllerInput` the entity should have based on what it observes.
+    ///
+    /// # Parameters
+    ///
+    /// * `ai_observation`: What the entity observes of itself and the nearest enemy.
+    /// * `ai_behaviour`: Behaviour configuration for the entity's difficulty.
+    fn controller_input(
+        ai_observation: &AiObservation,
+        ai_behaviour: &AiBehaviour,
+    ) -> ControllerInput;
+}
@@ -0,0 +1,9 @@
+//! Contains the types that represent the configuration on disk.
+
+pub use self::{
+    ai_behaviour::AiBehaviour, ai_behaviours::AiBehaviours, ai_difficulty::AiDifficulty,
+};
+
+mod ai_behaviour;
+mod ai_behaviours;
+mod ai_difficulty;
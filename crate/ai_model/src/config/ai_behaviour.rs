@@ -0,0 +1,46 @@
+use character_model::config::{CharacterSequenceName, CharacterSequenceNameString};
+use charge_model::config::ChargePoints;
+use derivative::Derivative;
+use derive_new::new;
+use object_model::play::HealthPoints;
+use sequence_model::config::SequenceNameString;
+use serde::{Deserialize, Serialize};
+
+/// How a computer controlled character behaves.
+#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiBehaviour {
+    /// Number of ticks between each decision.
+    #[derivative(Default(value = "10"))]
+    pub decision_interval: usize,
+    /// Distance along the X axis within which the character attacks.
+    #[derivative(Default(value = "60."))]
+    pub attack_distance: f32,
+    /// Distance along the Z axis within which the character is in line with its enemy.
+    #[derivative(Default(value = "10."))]
+    pub z_tolerance: f32,
+    /// Enemy sequences to defend against when the enemy is within attack distance.
+    #[derivative(Default(value = "AiBehaviour::defend_against_default()"))]
+    pub defend_against: Vec<CharacterSequenceNameString>,
+    /// Health points at or below which the character moves away from its enemy.
+    #[derivative(Default(value = "HealthPoints::new(0)"))]
+    pub retreat_hp: HealthPoints,
+    /// Charge at or above which the character uses its special attack.
+    ///
+    /// `0` means the character never uses its special attack.
+    #[derivative(Default(value = "ChargePoints::new(0)"))]
+    pub special_charge: ChargePoints,
+}
+
+impl AiBehaviour {
+    /// Returns the enemy sequences that are defended against by default.
+    pub fn defend_against_default() -> Vec<CharacterSequenceNameString> {
+        vec![
+            SequenceNameString::Name(CharacterSequenceName::StandAttack0),
+            SequenceNameString::Name(CharacterSequenceName::StandAttack1),
+            SequenceNameString::Name(CharacterSequenceName::JumpAttack),
+            SequenceNameString::Name(CharacterSequenceName::DashAttack),
+        ]
+    }
+}
@@ -0,0 +1,48 @@
+use charge_model::config::ChargePoints;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AiBehaviour, AiDifficulty};
+
+/// Behaviour of computer controlled characters for each `AiDifficulty`.
+///
+/// This is loaded from `ai_behaviours.yaml` in the resources directory.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct AiBehaviours {
+    /// Behaviour for `AiDifficulty::Easy`.
+    pub easy: AiBehaviour,
+    /// Behaviour for `AiDifficulty::Normal`.
+    pub normal: AiBehaviour,
+    /// Behaviour for `AiDifficulty::Hard`.
+    pub hard: AiBehaviour,
+}
+
+impl AiBehaviours {
+    /// Returns the `AiBehaviour` for the given difficulty.
+    pub fn behaviour(&self, ai_difficulty: AiDifficulty) -> &AiBehaviour {
+        match ai_difficulty {
+            AiDifficulty::Easy => &self.easy,
+            AiDifficulty::Normal => &self.normal,
+            AiDifficulty::Hard => &self.hard,
+        }
+    }
+}
+
+impl Default for AiBehaviours {
+    fn default() -> Self {
+        AiBehaviours {
+            easy: AiBehaviour {
+                decision_interval: 20,
+                defend_against: Vec::new(),
+                ..Default::default()
+            },
+            normal: AiBehaviour::default(),
+            hard: AiBehaviour {
+                decision_interval: 4,
+                special_charge: ChargePoints::new(50),
+                ..Default::default()
+            },
+        }
+    }
+}
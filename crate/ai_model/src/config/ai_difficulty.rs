@@ -0,0 +1,45 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Difficulty level of a computer controlled character.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumIter,
+    EnumString,
+    Hash,
+    PartialEq,
+    Eq,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AiDifficulty {
+    /// Slow to react, and does not defend.
+    Easy,
+    /// Reacts moderately quickly, and defends against attacks.
+    #[derivative(Default)]
+    Normal,
+    /// Reacts quickly, defends against attacks, and uses special attacks.
+    Hard,
+}
+
+impl AiDifficulty {
+    /// Returns the difficulty to switch to when cycling through computer controlled slots.
+    ///
+    /// `None` represents a slot that is controlled by a player.
+    pub fn next(ai_difficulty: Option<AiDifficulty>) -> Option<AiDifficulty> {
+        match ai_difficulty {
+            None => Some(AiDifficulty::Easy),
+            Some(AiDifficulty::Easy) => Some(AiDifficulty::Normal),
+            Some(AiDifficulty::Normal) => Some(AiDifficulty::Hard),
+            Some(AiDifficulty::Hard) => None,
+        }
+    }
+}
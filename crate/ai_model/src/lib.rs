@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used for computer controlled characters.
+
+pub use crate::ai_controller::AiController;
+
+pub mod config;
+pub mod play;
+
+mod ai_controller;
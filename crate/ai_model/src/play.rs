@@ -0,0 +1,11 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    ai_controlled::AiControlled, ai_decision_clock::AiDecisionClock,
+    ai_enemy_observation::AiEnemyObservation, ai_observation::AiObservation,
+};
+
+mod ai_controlled;
+mod ai_decision_clock;
+mod ai_enemy_observation;
+mod ai_observation;
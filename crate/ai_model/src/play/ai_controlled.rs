@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::HashMapStorage, Component};
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::config::AiDifficulty;
+
+/// Marks a computer controlled entity.
+///
+/// We use a `HashMapStorage` because there wouldn't be that many entities that are computer
+/// controlled.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(HashMapStorage)]
+pub struct AiControlled {
+    /// ID of the controller slot that the entity was selected in.
+    pub controller_id: ControllerId,
+    /// Difficulty of the computer controller.
+    pub ai_difficulty: AiDifficulty,
+}
@@ -0,0 +1,12 @@
+use character_model::config::CharacterSequenceNameString;
+use derive_new::new;
+use kinematic_model::config::Position;
+
+/// What a computer controlled entity observes of an enemy.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct AiEnemyObservation {
+    /// Position of the enemy.
+    pub position: Position<f32>,
+    /// Sequence the enemy is currently in, if it is a character.
+    pub sequence_name: Option<CharacterSequenceNameString>,
+}
@@ -0,0 +1,22 @@
+use charge_model::config::ChargePoints;
+use derive_new::new;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+
+use crate::play::AiEnemyObservation;
+
+/// What a computer controlled entity observes of itself and its surroundings.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct AiObservation {
+    /// Position of the entity.
+    pub position: Position<f32>,
+    /// Whether the entity is facing left.
+    pub mirrored: Mirrored,
+    /// Health points of the entity.
+    pub health_points: HealthPoints,
+    /// Charge the entity has accumulated.
+    pub charge_points: ChargePoints,
+    /// The nearest living enemy, if any.
+    pub enemy: Option<AiEnemyObservation>,
+}
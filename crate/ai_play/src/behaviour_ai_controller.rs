@@ -0,0 +1,74 @@
+use ai_model::{
+    config::AiBehaviour,
+    play::{AiEnemyObservation, AiObservation},
+    AiController,
+};
+use game_input_model::play::ControllerInput;
+
+/// Controls an entity based on its `AiBehaviour`.
+///
+/// * Moves towards the nearest enemy until it is within attack distance.
+/// * Turns to face the enemy, then attacks, or uses its special attack when it has enough charge.
+/// * Defends when the enemy is in one of the `defend_against` sequences.
+/// * Moves away from the enemy when its health points are at or below `retreat_hp`.
+#[derive(Debug, Default)]
+pub struct BehaviourAiController;
+
+impl AiController for BehaviourAiController {
+    fn controller_input(
+        ai_observation: &AiObservation,
+        ai_behaviour: &AiBehaviour,
+    ) -> ControllerInput {
+        let mut controller_input = ControllerInput::default();
+
+        let AiEnemyObservation {
+            position: enemy_position,
+            sequence_name: enemy_sequence_name,
+        } = if let Some(enemy) = ai_observation.enemy.as_ref() {
+            enemy
+        } else {
+            return controller_input;
+        };
+
+        let dx = enemy_position[0] - ai_observation.position[0];
+        let dz = enemy_position[2] - ai_observation.position[2];
+        let direction_x = if dx < 0. { -1. } else { 1. };
+
+        if ai_observation.health_points.0 <= ai_behaviour.retreat_hp.0 {
+            controller_input.x_axis_value = -direction_x;
+            return controller_input;
+        }
+
+        let in_line = dz.abs() <= ai_behaviour.z_tolerance;
+        let in_range = in_line && dx.abs() <= ai_behaviour.attack_distance;
+        if !in_range {
+            if dx.abs() > ai_behaviour.attack_distance {
+                controller_input.x_axis_value = direction_x;
+            }
+            if !in_line {
+                controller_input.z_axis_value = if dz < 0. { -1. } else { 1. };
+            }
+            return controller_input;
+        }
+
+        let enemy_attacking = enemy_sequence_name
+            .as_ref()
+            .map(|sequence_name| ai_behaviour.defend_against.contains(sequence_name))
+            .unwrap_or(false);
+        let facing_enemy = *ai_observation.mirrored == (dx < 0.);
+        let special_ready = ai_behaviour.special_charge.0 > 0
+            && ai_observation.charge_points.0 >= ai_behaviour.special_charge.0;
+
+        if enemy_attacking {
+            controller_input.defend = true;
+        } else if !facing_enemy {
+            controller_input.x_axis_value = direction_x;
+        } else if special_ready {
+            controller_input.special = true;
+        } else {
+            controller_input.attack = true;
+        }
+
+        controller_input
+    }
+}
@@ -0,0 +1,8 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for computer controlled characters.
+
+pub use crate::{behaviour_ai_controller::BehaviourAiController, system::AiControllerSystem};
+
+mod behaviour_ai_controller;
+mod system;
@@ -0,0 +1,3 @@
+pub use self::ai_controller_system::AiControllerSystem;
+
+mod ai_controller_system;
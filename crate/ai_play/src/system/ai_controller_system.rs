@@ -0,0 +1,306 @@
+use std::{convert::TryFrom, marker::PhantomData};
+
+use ai_model::{
+    config::AiBehaviours,
+    play::{AiControlled, AiDecisionClock, AiEnemyObservation, AiObservation},
+    AiController,
+};
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use asset_model::loaded::AssetId;
+use character_model::config::CharacterSequenceName;
+use charge_model::{config::ChargePoints, play::ChargeTrackerClock};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    config::{Axis, ControlAction, ControllerId},
+    play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+};
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::{AssetSequenceIdMappings, SequenceId};
+use team_model::play::Team;
+
+/// Sends `ControlInputEvent`s for computer controlled entities.
+///
+/// Each entity decides its input every `decision_interval` ticks of its `AiBehaviour`. Events are
+/// only sent for the axes and control actions whose values differ from the current input.
+///
+/// # Type Parameters
+///
+/// * `C`: `AiController` that decides the input.
+#[derive(Debug, Default, new)]
+pub struct AiControllerSystem<C> {
+    /// Pre-allocated vector.
+    #[new(value = "Vec::with_capacity(64)")]
+    control_input_events: Vec<ControlInputEvent>,
+    /// Marker.
+    marker: PhantomData<C>,
+}
+
+/// `AiControllerSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AiControllerSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `AiBehaviours` resource.
+    #[derivative(Debug = "ignore")]
+    pub ai_behaviours: Read<'s, AiBehaviours>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `AiDecisionClock` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_decision_clocks: WriteStorage<'s, AiDecisionClock>,
+    /// `ControllerInput` components.
+    #[derivative(Debug = "ignore")]
+    pub controller_inputs: ReadStorage<'s, ControllerInput>,
+    /// `AiObservationResources`.
+    pub ai_observation_resources: AiObservationResources<'s>,
+    /// `ControlInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub control_input_ec: Write<'s, EventChannel<ControlInputEvent>>,
+}
+
+/// Resources used to observe the world.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct AiObservationResources<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `ChargeTrackerClock` components.
+    #[derivative(Debug = "ignore")]
+    pub charge_tracker_clocks: ReadStorage<'s, ChargeTrackerClock>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `AssetSequenceIdMappings<CharacterSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_character:
+        Read<'s, AssetSequenceIdMappings<CharacterSequenceName>>,
+}
+
+impl<C> AiControllerSystem<C> {
+    /// Returns what the entity observes, or `None` if the entity cannot act.
+    fn observe(
+        AiObservationResources {
+            entities,
+            positions,
+            mirroreds,
+            health_pointses,
+            charge_tracker_clocks,
+            teams,
+            asset_ids,
+            sequence_ids,
+            asset_sequence_id_mappings_character,
+        }: &AiObservationResources<'_>,
+        entity: Entity,
+    ) -> Option<AiObservation> {
+        let position = *positions.get(entity)?;
+        let health_points = health_pointses.get(entity).copied()?;
+        if health_points.0 == 0 {
+            return None;
+        }
+        let mirrored = mirroreds.get(entity).copied().unwrap_or_default();
+        let charge_points = charge_tracker_clocks
+            .get(entity)
+            .map(|charge_tracker_clock| {
+                ChargePoints::new(u32::try_from(charge_tracker_clock.value).unwrap_or(u32::MAX))
+            })
+            .unwrap_or_default();
+        let team = teams.get(entity);
+
+        let enemy = (&**entities, teams, health_pointses, positions)
+            .join()
+            .filter(|(enemy_entity, enemy_team, enemy_health_points, _)| {
+                *enemy_entity != entity && team != Some(*enemy_team) && enemy_health_points.0 > 0
+            })
+            .map(|(enemy_entity, _, _, enemy_position)| {
+                let distance_squared = (enemy_position.0 - position.0).norm_squared();
+                (enemy_entity, *enemy_position, distance_squared)
+            })
+            .fold(
+                None,
+                |nearest: Option<(Entity, Position<f32>, f32)>, candidate| match nearest {
+                    Some((_, _, nearest_distance_squared))
+                        if nearest_distance_squared <= candidate.2 =>
+                    {
+                        nearest
+                    }
+                    _ => Some(candidate),
+                },
+            )
+            .map(|(enemy_entity, enemy_position, _)| {
+                let sequence_name = asset_ids
+                    .get(enemy_entity)
+                    .and_then(|asset_id| asset_sequence_id_mappings_character.get(*asset_id))
+                    .and_then(|sequence_id_mappings| {
+                        sequence_ids
+                            .get(enemy_entity)
+                            .and_then(|sequence_id| sequence_id_mappings.name(*sequence_id))
+                    })
+                    .cloned();
+
+                AiEnemyObservation::new(enemy_position, sequence_name)
+            });
+
+        Some(AiObservation::new(
+            position,
+            mirrored,
+            health_points,
+            charge_points,
+            enemy,
+        ))
+    }
+
+    /// Queues the `ControlInputEvent`s to change the current input to the desired input.
+    fn input_events_queue(
+        control_input_events: &mut Vec<ControlInputEvent>,
+        controller_id: ControllerId,
+        entity: Entity,
+        controller_input_current: ControllerInput,
+        controller_input_desired: ControllerInput,
+    ) {
+        let axes = [
+            (
+                Axis::X,
+                controller_input_current.x_axis_value,
+                controller_input_desired.x_axis_value,
+            ),
+            (
+                Axis::Z,
+                controller_input_current.z_axis_value,
+                controller_input_desired.z_axis_value,
+            ),
+        ];
+        axes.iter()
+            .copied()
+            .filter(|(_, value_current, value_desired)| value_current != value_desired)
+            .for_each(|(axis, _, value)| {
+                control_input_events.push(ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id,
+                    entity,
+                    axis,
+                    value,
+                }));
+            });
+
+        let control_actions = [
+            (
+                ControlAction::Defend,
+                controller_input_current.defend,
+                controller_input_desired.defend,
+            ),
+            (
+                ControlAction::Jump,
+                controller_input_current.jump,
+                controller_input_desired.jump,
+            ),
+            (
+                ControlAction::Attack,
+                controller_input_current.attack,
+                controller_input_desired.attack,
+            ),
+            (
+                ControlAction::Special,
+                controller_input_current.special,
+                controller_input_desired.special,
+            ),
+        ];
+        control_actions
+            .iter()
+            .copied()
+            .filter(|(_, pressed_current, pressed_desired)| pressed_current != pressed_desired)
+            .for_each(|(control_action, _, pressed_desired)| {
+                let control_action_event_data = ControlActionEventData {
+                    controller_id,
+                    entity,
+                    control_action,
+                };
+                let control_input_event = if pressed_desired {
+                    ControlInputEvent::ControlActionPress(control_action_event_data)
+                } else {
+                    ControlInputEvent::ControlActionRelease(control_action_event_data)
+                };
+                control_input_events.push(control_input_event);
+            });
+    }
+}
+
+impl<'s, C> System<'s> for AiControllerSystem<C>
+where
+    C: AiController + Send + Sync + 'static,
+{
+    type SystemData = AiControllerSystemData<'s>;
+
+    fn run(
+        &mut self,
+        AiControllerSystemData {
+            entities,
+            ai_behaviours,
+            ai_controlleds,
+            mut ai_decision_clocks,
+            controller_inputs,
+            ai_observation_resources,
+            mut control_input_ec,
+        }: Self::SystemData,
+    ) {
+        let control_input_events = &mut self.control_input_events;
+
+        (&entities, &ai_controlleds)
+            .join()
+            .for_each(|(entity, ai_controlled)| {
+                let ai_behaviour = ai_behaviours.behaviour(ai_controlled.ai_difficulty);
+
+                if let Ok(entry) = ai_decision_clocks.entry(entity) {
+                    let ai_decision_clock = entry
+                        .or_insert_with(|| AiDecisionClock::new(ai_behaviour.decision_interval));
+                    ai_decision_clock.tick();
+                    if ai_decision_clock.is_complete() {
+                        ai_decision_clock.reset();
+                    } else {
+                        return;
+                    }
+                }
+
+                let controller_input_current =
+                    controller_inputs.get(entity).copied().unwrap_or_default();
+                let controller_input_desired = Self::observe(&ai_observation_resources, entity)
+                    .map_or_else(ControllerInput::default, |ai_observation| {
+                        C::controller_input(&ai_observation, ai_behaviour)
+                    });
+
+                Self::input_events_queue(
+                    control_input_events,
+                    ai_controlled.controller_id,
+                    entity,
+                    controller_input_current,
+                    controller_input_desired,
+                );
+            });
+
+        control_input_ec.drain_vec_write(control_input_events);
+    }
+}
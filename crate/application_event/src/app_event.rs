@@ -11,7 +11,9 @@ use control_settings_model::ControlSettingsEvent;
 use derive_more::From;
 use game_input_model::play::ControlInputEvent;
 use game_mode_selection_model::GameModeSelectionEvent;
-use game_play_model::GamePlayEvent;
+use game_play_model::{GamePlayEvent, GamePlayPauseEvent};
+use game_play_stats_model::GamePlayStatsEvent;
+use game_rules_selection_model::GameRulesSelectionEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
@@ -40,6 +42,12 @@ pub enum AppEvent {
     GameModeSelection(GameModeSelectionEvent),
     /// `game_play` events.
     GamePlay(GamePlayEvent),
+    /// `game_play_pause` events.
+    GamePlayPause(GamePlayPauseEvent),
+    /// `game_play_stats` events.
+    GamePlayStats(GamePlayStatsEvent),
+    /// `game_rules_selection` events.
+    GameRulesSelection(GameRulesSelectionEvent),
     /// `network_mode_selection` events.
     NetworkModeSelection(NetworkModeSelectionEvent),
     /// `session_host` events.
@@ -1,4 +1,7 @@
 use game_mode_selection_model::GameModeIndex;
+use game_play_model::GamePlayPauseIndex;
+use game_play_stats_model::GamePlayStatsIndex;
+use game_rules_selection_model::GameRulesIndex;
 use network_mode_selection_model::NetworkModeIndex;
 use serde::{Deserialize, Serialize};
 
@@ -8,6 +11,12 @@ use serde::{Deserialize, Serialize};
 pub enum MenuIndex {
     /// Game mode menu indicies.
     GameMode(GameModeIndex),
+    /// Game play pause menu indicies.
+    GamePlayPause(GamePlayPauseIndex),
+    /// Game play statistics menu indicies.
+    GamePlayStats(GamePlayStatsIndex),
+    /// Game rules menu indicies.
+    GameRules(GameRulesIndex),
     /// Network mode menu indicies.
     NetworkMode(NetworkModeIndex),
 }
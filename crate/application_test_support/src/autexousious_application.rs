@@ -20,12 +20,14 @@ use background_loading::BackgroundLoadingBundle;
 use character_loading::CharacterLoadingBundle;
 use character_selection::CharacterSelectionSystem;
 use character_selection_model::{CharacterSelections, CharacterSelectionsStatus};
+use chase_loading::ChaseLoadingBundle;
 use collision_audio_loading::CollisionAudioLoadingBundle;
 use collision_loading::CollisionLoadingBundle;
 use energy_loading::EnergyLoadingBundle;
 use game_input_model::config::ControlBindings;
 use game_loading::GameLoadingState;
 use input_reaction_loading::InputReactionLoadingBundle;
+use item_loading::ItemLoadingBundle;
 use kinematic_loading::KinematicLoadingBundle;
 use loading::{LoadingBundle, LoadingState};
 use map_loading::MapLoadingBundle;
@@ -113,6 +115,7 @@ impl AutexousiousApplication {
             .with_bundle(SequenceLoadingBundle::new())
             .with_bundle(AudioLoadingBundle::new())
             .with_bundle(KinematicLoadingBundle::new())
+            .with_bundle(ChaseLoadingBundle::new())
             .with_bundle(LoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(CollisionLoadingBundle::new())
             .with_bundle(SpawnLoadingBundle::new())
@@ -121,6 +124,7 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
@@ -198,6 +202,7 @@ impl AutexousiousApplication {
             .with_bundle(SequenceLoadingBundle::new())
             .with_bundle(AudioLoadingBundle::new())
             .with_bundle(KinematicLoadingBundle::new())
+            .with_bundle(ChaseLoadingBundle::new())
             .with_bundle(LoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(CollisionLoadingBundle::new())
             .with_bundle(SpawnLoadingBundle::new())
@@ -206,6 +211,7 @@ impl AutexousiousApplication {
             .with_bundle(MapLoadingBundle::new())
             .with_bundle(CharacterLoadingBundle::new())
             .with_bundle(EnergyLoadingBundle::new())
+            .with_bundle(ItemLoadingBundle::new())
             .with_bundle(InputReactionLoadingBundle::new())
             .with_bundle(CollisionAudioLoadingBundle::new(ASSETS_PATH.clone()))
             .with_bundle(UiAudioLoadingBundle::new(ASSETS_PATH.clone()))
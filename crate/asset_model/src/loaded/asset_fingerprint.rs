@@ -0,0 +1,56 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{AssetIndex, AssetRecord, AssetType};
+
+/// Fingerprint of the set of assets discovered on a device (`u64` newtype).
+///
+/// Devices in a network session must share the same set of assets, otherwise the game play state
+/// diverges as soon as an asset that differs is used.
+#[derive(
+    Clone, Copy, Debug, Default, Deref, DerefMut, Deserialize, Hash, PartialEq, Eq, Serialize, new,
+)]
+pub struct AssetFingerprint(pub u64);
+
+impl AssetFingerprint {
+    /// Returns the fingerprint of the assets in the given `AssetIndex`.
+    ///
+    /// The fingerprint is computed from the type and slug of each asset, and does not depend on
+    /// the order that assets were discovered in.
+    pub fn from_asset_index(asset_index: &AssetIndex) -> Self {
+        let mut asset_type_and_records = asset_index
+            .iter()
+            .flat_map(|(asset_type, asset_records)| {
+                asset_records
+                    .iter()
+                    .map(move |asset_record| (asset_type, asset_record))
+            })
+            .collect::<Vec<(&AssetType, &AssetRecord)>>();
+        asset_type_and_records.sort_unstable_by(|(_, record_a), (_, record_b)| {
+            record_a.asset_slug.cmp(&record_b.asset_slug)
+        });
+
+        let mut hasher = DefaultHasher::new();
+        asset_type_and_records
+            .iter()
+            .for_each(|(asset_type, asset_record)| {
+                asset_type.hash(&mut hasher);
+                asset_record.asset_slug.hash(&mut hasher);
+            });
+
+        AssetFingerprint(hasher.finish())
+    }
+}
+
+impl Display for AssetFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
@@ -20,13 +20,15 @@ use asset_ui_model::{
 };
 use audio_model::loaded::SourceSequenceHandles;
 use character_model::loaded::CharacterIrsHandles;
-use chase_model::play::ChaseModeStick;
+use chase_model::{loaded::ChaseSequenceHandles, play::ChaseModeStick};
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
 use derive_new::new;
 use game_input_model::play::{
     ButtonInputControlled, InputControlled, NormalInputControlled, SharedInputControlled,
 };
+use game_play_stats_ui_model::loaded::PlayerStatsWidget;
 use input_reaction_model::loaded::InputReactionsSequenceHandles;
+use item_model::loaded::WeaponPointSequenceHandles;
 use kinematic_model::{
     config::{PositionInit, ScaleInit, VelocityInit},
     loaded::ObjectAccelerationSequenceHandles,
@@ -80,9 +82,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         asset_world.register::<BodySequenceHandles>();
         asset_world.register::<InteractionsSequenceHandles>();
         asset_world.register::<SpawnsSequenceHandles>();
+        asset_world.register::<ChaseSequenceHandles>();
         asset_world.register::<TintSequenceHandles>();
         asset_world.register::<ScaleSequenceHandles>();
         asset_world.register::<CharacterIrsHandles>();
+        asset_world.register::<WeaponPointSequenceHandles>();
         asset_world.register::<InputReactionsSequenceHandles>();
         asset_world.register::<WidgetStatusSequences>();
         asset_world.register::<UiForm>();
@@ -113,6 +117,8 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
         asset_world.register::<SessionCodeLabel>();
         asset_world.register::<SessionDevicesWidget>();
 
+        asset_world.register::<PlayerStatsWidget>();
+
         world.insert(asset_world);
 
         builder.add_barrier();
@@ -214,6 +220,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             any::type_name::<ItemComponentComponentAugmentSystem<SpawnsSequenceHandles>>(),
             &[],
         );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<ChaseSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<ChaseSequenceHandles>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<TintSequenceHandles>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<TintSequenceHandles>>(),
@@ -229,6 +240,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             any::type_name::<ItemComponentComponentAugmentSystem<CharacterIrsHandles>>(),
             &[],
         );
+        builder.add(
+            ItemComponentComponentAugmentSystem::<WeaponPointSequenceHandles>::new(),
+            any::type_name::<ItemComponentComponentAugmentSystem<WeaponPointSequenceHandles>>(),
+            &[],
+        );
         builder.add(
             ItemComponentComponentAugmentSystem::<InputReactionsSequenceHandles>::new(),
             any::type_name::<ItemComponentComponentAugmentSystem<InputReactionsSequenceHandles>>(),
@@ -390,6 +406,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for AssetPlayBundle {
             &any::type_name::<ItemComponentComponentAugmentSystem<SessionDevicesWidget>>(),
             &[],
         );
+
+        // Game Play Stats UI
+        builder.add(
+            ItemComponentComponentAugmentSystem::<PlayerStatsWidget>::new(),
+            &any::type_name::<ItemComponentComponentAugmentSystem<PlayerStatsWidget>>(),
+            &[],
+        );
         builder.add_barrier();
         Ok(())
     }
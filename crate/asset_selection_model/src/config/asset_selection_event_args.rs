@@ -12,6 +12,8 @@ use structopt_derive::StructOpt;
 /// * `asset_selection switch -c 0 -s default/heat`
 /// * `asset_selection select -c 0 -s default/heat`
 /// * `asset_selection deselect -c 0`
+/// * `asset_selection ai_switch -c 1`
+/// * `asset_selection team_switch -c 1`
 /// * `asset_selection confirm`
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
 #[serde(deny_unknown_fields, rename_all = "snake_case")]
@@ -65,6 +67,22 @@ pub enum AssetSelectionEventArgs {
         #[structopt(short, long)]
         controller_id: ControllerId,
     },
+    /// Computer difficulty for the slot has been switched.
+    AiSwitch {
+        /// Controller ID.
+        ///
+        /// 0 for the first player, 1 for the second player, etcetera.
+        #[structopt(short, long)]
+        controller_id: ControllerId,
+    },
+    /// Team for the slot has been switched.
+    TeamSwitch {
+        /// Controller ID.
+        ///
+        /// 0 for the first player, 1 for the second player, etcetera.
+        #[structopt(short, long)]
+        controller_id: ControllerId,
+    },
     /// Asset selections have been confirmed.
     Confirm,
 }
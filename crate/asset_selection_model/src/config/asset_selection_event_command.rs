@@ -18,6 +18,10 @@ pub enum AssetSelectionEventCommand {
     Select,
     /// Asset has been deselected.
     Deselect,
+    /// Computer difficulty for the slot has been switched.
+    AiSwitch,
+    /// Team for the slot has been switched.
+    TeamSwitch,
     /// Asset selections have been confirmed.
     Confirm,
 }
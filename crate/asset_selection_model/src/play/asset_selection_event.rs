@@ -57,6 +57,24 @@ pub enum AssetSelectionEvent {
         /// ID of the controller that sent the event.
         controller_id: ControllerId,
     },
+    /// Computer difficulty for the slot has been switched.
+    AiSwitch {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+    },
+    /// Team for the slot has been switched.
+    TeamSwitch {
+        /// Entity that the event originated from.
+        ///
+        /// This may be `None` if sent as a CLI command.
+        entity: Option<Entity>,
+        /// ID of the controller that sent the event.
+        controller_id: ControllerId,
+    },
     /// Confirm `AssetSelection`s.
     Confirm,
 }
@@ -112,6 +112,18 @@ impl StdinMapper for AssetSelectionEventStdinMapper {
                 entity: None,
                 controller_id,
             }),
+            AssetSelectionEventArgs::AiSwitch { controller_id } => {
+                Ok(AssetSelectionEvent::AiSwitch {
+                    entity: None,
+                    controller_id,
+                })
+            }
+            AssetSelectionEventArgs::TeamSwitch { controller_id } => {
+                Ok(AssetSelectionEvent::TeamSwitch {
+                    entity: None,
+                    controller_id,
+                })
+            }
             AssetSelectionEventArgs::Confirm => Ok(AssetSelectionEvent::Confirm),
         }
     }
@@ -8,10 +8,15 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use asset_model::{config::AssetType, loaded::AssetTypeMappings};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetTypeMappings},
+};
 use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
 use asset_selection_ui_model::play::{ApwMain, ApwPreview};
-use asset_ui_model::play::{AssetSelectionHighlightMain, AssetSelectionParent};
+use asset_ui_model::play::{
+    AssetSelectionHighlightMain, AssetSelectionParent, AssetSelectionStatus,
+};
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::{
@@ -22,6 +27,9 @@ use log::error;
 
 use self::{character_preview_spawn::CharacterPreviewSpawn, map_preview_spawn::MapPreviewSpawn};
 
+/// Number of ticks to show each preview for while *Random* is highlighted.
+const RANDOM_PREVIEW_CYCLE_TICKS: usize = 30;
+
 /// Trait of different asset preview widget spawn behaviours.
 pub trait PreviewSpawner<'s> {
     type SystemData: SystemData<'s>;
@@ -49,6 +57,9 @@ pub struct ApwPreviewSpawnSystem<PS> {
     /// Reader ID for the `AssetSelectionEvent` channel.
     #[new(default)]
     asset_selection_event_rid: Option<ReaderId<AssetSelectionEvent>>,
+    /// Number of ticks this system has run, used to cycle previews while *Random* is highlighted.
+    #[new(default)]
+    random_preview_tick: usize,
     /// Marker.
     marker: PhantomData<PS>,
 }
@@ -99,6 +110,9 @@ where
     /// `AssetSelection` components.
     #[derivative(Debug = "ignore")]
     pub asset_selections: ReadStorage<'s, AssetSelection>,
+    /// `AssetSelectionStatus` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_selection_statuses: ReadStorage<'s, AssetSelectionStatus>,
     /// `PreviewSpawnResources`.
     #[derivative(Debug = "ignore")]
     pub preview_spawn_resources: <PS as PreviewSpawner<'s>>::SystemData,
@@ -250,6 +264,89 @@ where
             }
         }
     }
+
+    /// Spawns the next preview for active ASH entities that have *Random* highlighted.
+    ///
+    /// Previews only cycle when the asset selection cells are for this preview's asset type.
+    fn random_previews_cycle(
+        apw_preview_spawn_resources: &mut ApwPreviewSpawnResources<PS>,
+        cycle: usize,
+    ) {
+        let asset_ids = {
+            let ApwPreviewSpawnResources {
+                asset_type_mappings,
+                asset_selections,
+                ..
+            } = &*apw_preview_spawn_resources;
+
+            let cells_are_asset_type = asset_selections.join().any(|asset_selection| {
+                if let AssetSelection::Id(asset_id) = asset_selection {
+                    asset_type_mappings.get(*asset_id) == Some(&PS::ASSET_TYPE)
+                } else {
+                    false
+                }
+            });
+            if !cells_are_asset_type {
+                return;
+            }
+
+            asset_type_mappings
+                .iter_ids(&PS::ASSET_TYPE)
+                .copied()
+                .collect::<Vec<AssetId>>()
+        };
+        if asset_ids.is_empty() {
+            return;
+        }
+        let asset_selection = AssetSelection::Id(asset_ids[cycle % asset_ids.len()]);
+
+        let ash_entities = {
+            let ApwPreviewSpawnResources {
+                entities,
+                ash_mains,
+                input_controlleds,
+                shared_input_controlleds,
+                asset_selections,
+                asset_selection_statuses,
+                ..
+            } = &*apw_preview_spawn_resources;
+
+            (
+                entities,
+                ash_mains,
+                asset_selections,
+                asset_selection_statuses.maybe(),
+                input_controlleds.maybe(),
+                shared_input_controlleds.maybe(),
+            )
+                .join()
+                .filter(|(_, _, asset_selection, asset_selection_status, _, _)| {
+                    **asset_selection == AssetSelection::Random
+                        && *asset_selection_status != Some(&AssetSelectionStatus::Inactive)
+                })
+                .filter_map(
+                    |(entity, _, _, _, input_controlled, shared_input_controlled)| {
+                        input_controlled
+                            .map(|input_controlled| input_controlled.controller_id)
+                            .or_else(|| shared_input_controlled.map(|_| ControllerId::default()))
+                            .map(|controller_id| (entity, controller_id))
+                    },
+                )
+                .collect::<Vec<(Entity, ControllerId)>>()
+        };
+
+        ash_entities
+            .into_iter()
+            .for_each(|(ash_entity, controller_id)| {
+                Self::delete_preview_entities(apw_preview_spawn_resources, ash_entity);
+                Self::spawn_preview_entities(
+                    apw_preview_spawn_resources,
+                    ash_entity,
+                    controller_id,
+                    Some(asset_selection),
+                );
+            });
+    }
 }
 
 impl<'s, PS> System<'s> for ApwPreviewSpawnSystem<PS>
@@ -329,8 +426,18 @@ where
                 }
                 // No update needed -- preview entities are already correct.
                 AssetSelectionEvent::Select { .. } | AssetSelectionEvent::Deselect { .. } => {}
-                AssetSelectionEvent::Confirm => {}
+                AssetSelectionEvent::AiSwitch { .. }
+                | AssetSelectionEvent::TeamSwitch { .. }
+                | AssetSelectionEvent::Confirm => {}
             });
+
+        self.random_preview_tick = self.random_preview_tick.wrapping_add(1);
+        if self.random_preview_tick % RANDOM_PREVIEW_CYCLE_TICKS == 0 {
+            Self::random_previews_cycle(
+                apw_preview_spawn_resources,
+                self.random_preview_tick / RANDOM_PREVIEW_CYCLE_TICKS,
+            );
+        }
     }
 
     fn setup(&mut self, world: &mut World) {
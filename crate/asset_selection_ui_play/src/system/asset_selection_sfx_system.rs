@@ -67,6 +67,8 @@ impl<'s> System<'s> for AssetSelectionSfxSystem {
                     AssetSelectionEvent::Select { .. } => UiSfxId::Select,
                     AssetSelectionEvent::Deselect { .. } => UiSfxId::Deselect,
                     AssetSelectionEvent::Leave { .. } => UiSfxId::Deselect,
+                    AssetSelectionEvent::AiSwitch { .. } => UiSfxId::Switch,
+                    AssetSelectionEvent::TeamSwitch { .. } => UiSfxId::Switch,
                     AssetSelectionEvent::Confirm => UiSfxId::Confirm,
                 };
 
@@ -0,0 +1,24 @@
+use derivative::Derivative;
+
+/// How the game camera chooses what to focus on.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum CameraMode {
+    /// Camera follows `CameraTracked` entities.
+    #[derivative(Default)]
+    Tracking,
+    /// Camera is moved by control input.
+    ///
+    /// This is used when spectating a network session.
+    Free,
+}
+
+impl CameraMode {
+    /// Returns the other camera mode.
+    pub fn toggle(self) -> Self {
+        match self {
+            CameraMode::Tracking => CameraMode::Free,
+            CameraMode::Free => CameraMode::Tracking,
+        }
+    }
+}
@@ -7,7 +7,7 @@ pub use crate::{
     camera_creator::CameraCreator,
     camera_creator_resources::CameraCreatorResources,
     camera_play_bundle::CameraPlayBundle,
-    system::{CameraTrackingSystem, CameraVelocitySystem},
+    system::{CameraFreeSystem, CameraFreeSystemDesc, CameraTrackingSystem, CameraVelocitySystem},
 };
 
 mod camera_component_storages;
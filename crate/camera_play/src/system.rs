@@ -1,6 +1,9 @@
 pub use self::{
-    camera_tracking_system::CameraTrackingSystem, camera_velocity_system::CameraVelocitySystem,
+    camera_free_system::{CameraFreeSystem, CameraFreeSystemDesc},
+    camera_tracking_system::CameraTrackingSystem,
+    camera_velocity_system::CameraVelocitySystem,
 };
 
+mod camera_free_system;
 mod camera_tracking_system;
 mod camera_velocity_system;
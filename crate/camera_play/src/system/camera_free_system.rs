@@ -0,0 +1,105 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Join, Read, ReadStorage, System, World, Write, WriteStorage},
+    input::InputEvent,
+    renderer::camera::Camera,
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use camera_model::play::{CameraMode, CameraTargetCoordinates};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::config::{Axis, ControlAction, ControlBindings};
+use network_session_model::play::SessionDeviceType;
+
+/// Number of pixels the camera moves per tick when the axis is fully pressed.
+const CAMERA_FREE_SPEED_DEFAULT: f32 = 8.;
+
+/// Moves the camera with control input when the `CameraMode` is `Free`.
+///
+/// Spectators toggle between `Tracking` and `Free` mode by pressing `Special`. Players always use
+/// `Tracking` mode.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(CameraFreeSystemDesc))]
+pub struct CameraFreeSystem {
+    /// Reader ID for the `InputEvent<ControlBindings>` channel.
+    #[system_desc(event_channel_reader)]
+    input_event_rid: ReaderId<InputEvent<ControlBindings>>,
+    /// Last received X axis value.
+    #[system_desc(skip)]
+    #[new(default)]
+    x_axis_value: f32,
+    /// Last received Z axis value.
+    #[system_desc(skip)]
+    #[new(default)]
+    z_axis_value: f32,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CameraFreeSystemData<'s> {
+    /// `InputEvent<ControlBindings>` channel.
+    #[derivative(Debug = "ignore")]
+    pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
+    /// `SessionDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_type: Read<'s, SessionDeviceType>,
+    /// `CameraMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub camera_mode: Write<'s, CameraMode>,
+    /// `Camera` components.
+    #[derivative(Debug = "ignore")]
+    pub cameras: ReadStorage<'s, Camera>,
+    /// `CameraTargetCoordinates` components.
+    #[derivative(Debug = "ignore")]
+    pub camera_target_coordinateses: WriteStorage<'s, CameraTargetCoordinates>,
+}
+
+impl<'s> System<'s> for CameraFreeSystem {
+    type SystemData = CameraFreeSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CameraFreeSystemData {
+            input_ec,
+            session_device_type,
+            mut camera_mode,
+            cameras,
+            mut camera_target_coordinateses,
+        }: Self::SystemData,
+    ) {
+        let input_events = input_ec.read(&mut self.input_event_rid);
+
+        if *session_device_type != SessionDeviceType::Spectator {
+            *camera_mode = CameraMode::Tracking;
+            return;
+        }
+
+        input_events.for_each(|ev| match ev {
+            InputEvent::AxisMoved { axis, value } => match axis.axis {
+                Axis::X => self.x_axis_value = *value,
+                Axis::Z => self.z_axis_value = *value,
+            },
+            InputEvent::ActionPressed(player_action_control)
+                if player_action_control.action == ControlAction::Special =>
+            {
+                *camera_mode = camera_mode.toggle();
+            }
+            _ => {}
+        });
+
+        if *camera_mode != CameraMode::Free {
+            return;
+        }
+
+        // Subtract Z because Z+ is rendered downwards.
+        let dx = self.x_axis_value * CAMERA_FREE_SPEED_DEFAULT;
+        let dy = -self.z_axis_value * CAMERA_FREE_SPEED_DEFAULT;
+        (&cameras, &mut camera_target_coordinateses)
+            .join()
+            .for_each(|(_, camera_target_coordinates)| {
+                camera_target_coordinates.x += dx;
+                camera_target_coordinates.y += dy;
+            });
+    }
+}
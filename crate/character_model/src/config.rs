@@ -3,21 +3,27 @@
 pub use self::{
     character_definition::{CharacterDefinition, CharacterDefinitionHandle},
     character_frame::CharacterFrame,
+    character_hit_reactions::CharacterHitReactions,
     character_input_reactions::CharacterInputReactions,
     character_irr::CharacterIrr,
     character_irr_part::CharacterIrrPart,
     character_sequence::CharacterSequence,
     character_sequence_name::CharacterSequenceName,
     character_sequence_name_string::CharacterSequenceNameString,
+    hit_direction_sequences::HitDirectionSequences,
     input_reaction_requirement_params::InputReactionRequirementParams,
+    stun_threshold::StunThreshold,
 };
 
 mod character_definition;
 mod character_frame;
+mod character_hit_reactions;
 mod character_input_reactions;
 mod character_irr;
 mod character_irr_part;
 mod character_sequence;
 mod character_sequence_name;
 mod character_sequence_name_string;
+mod hit_direction_sequences;
 mod input_reaction_requirement_params;
+mod stun_threshold;
@@ -2,9 +2,10 @@ use asset_derive::Asset;
 use charge_model::config::{ChargeDelay, ChargeLimit, ChargeRetentionMode, ChargeUseMode};
 use derive_new::new;
 use object_model::config::ObjectDefinition;
+use object_status_model::config::DefenseLimit;
 use serde::{Deserialize, Serialize};
 
-use crate::config::CharacterSequence;
+use crate::config::{CharacterHitReactions, CharacterSequence};
 
 /// Contains all of the sequences for a `Character`.
 #[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
@@ -25,4 +26,10 @@ pub struct CharacterDefinition {
     /// How charge is retained when no longer charging.
     #[serde(default)]
     pub charge_retention_mode: ChargeRetentionMode,
+    /// Defense points this character may absorb before its guard is broken.
+    #[serde(default)]
+    pub defense_limit: DefenseLimit,
+    /// Stun thresholds, stun recovery, and sequences to transition to when hit.
+    #[serde(default)]
+    pub hit_reactions: CharacterHitReactions,
 }
@@ -1,4 +1,5 @@
 use derive_new::new;
+use item_model::config::WeaponPoint;
 use object_model::config::{GameObjectFrame, ObjectFrame};
 use sequence_model::config::Wait;
 use serde::{Deserialize, Serialize};
@@ -15,6 +16,10 @@ pub struct CharacterFrame {
     /// Sequence ID to transition to when a `ControlAction` is pressed, held, or released.
     #[serde(default)]
     pub input_reactions: CharacterInputReactions,
+    /// Position of a held weapon relative to the character, when facing right.
+    #[serde(default)]
+    #[new(default)]
+    pub weapon_point: WeaponPoint,
 }
 
 impl AsRef<Wait> for CharacterFrame {
@@ -29,6 +34,12 @@ impl AsRef<CharacterInputReactions> for CharacterFrame {
     }
 }
 
+impl AsRef<WeaponPoint> for CharacterFrame {
+    fn as_ref(&self) -> &WeaponPoint {
+        &self.weapon_point
+    }
+}
+
 impl GameObjectFrame for CharacterFrame {
     fn object_frame(&self) -> &ObjectFrame {
         &self.object_frame
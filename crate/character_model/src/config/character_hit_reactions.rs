@@ -0,0 +1,47 @@
+use derive_new::new;
+use object_status_model::config::{StunPoints, StunRecovery};
+use sequence_model::config::SequenceNameString;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{CharacterSequenceName, HitDirectionSequences, StunThreshold};
+
+/// How a character reacts to being hit.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct CharacterHitReactions {
+    /// Number of stun points the character recovers each tick.
+    pub stun_recovery: StunRecovery,
+    /// Sequences to transition to for each stun point threshold.
+    ///
+    /// These are sorted in ascending `limit` order when loaded.
+    pub stun_thresholds: Vec<StunThreshold>,
+    /// Sequences to transition to when stun points exceed every threshold, or HP reaches 0.
+    pub falling: HitDirectionSequences,
+}
+
+impl Default for CharacterHitReactions {
+    fn default() -> Self {
+        let stun_threshold = |limit, character_sequence_name| {
+            StunThreshold::new(
+                StunPoints::new(limit),
+                SequenceNameString::Name(character_sequence_name),
+                None,
+            )
+        };
+
+        CharacterHitReactions {
+            stun_recovery: StunRecovery::default(),
+            stun_thresholds: vec![
+                stun_threshold(40, CharacterSequenceName::Flinch0),
+                stun_threshold(80, CharacterSequenceName::Flinch1),
+                stun_threshold(120, CharacterSequenceName::Dazed),
+            ],
+            falling: HitDirectionSequences::new(
+                SequenceNameString::Name(CharacterSequenceName::FallForwardAscend),
+                Some(SequenceNameString::Name(
+                    CharacterSequenceName::FallBackAscend,
+                )),
+            ),
+        }
+    }
+}
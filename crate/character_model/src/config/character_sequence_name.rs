@@ -111,6 +111,43 @@ pub enum CharacterSequenceName {
     FallForwardLand,
     /// Lying on ground face down.
     LieFaceDown,
+    /// Knocked off balance from behind, moving upwards.
+    FallBackAscend,
+    /// Knocked off balance from behind, moving downwards.
+    FallBackDescend,
+    /// Knocked off balance from behind, landed on ground (bounce).
+    FallBackLand,
+    /// Lying on ground face up.
+    LieFaceUp,
+    /// Character is reaching out to grab another object.
+    ///
+    /// Frames in this sequence should have a `grab` interaction.
+    Grab,
+    /// Character is holding a grabbed object.
+    ///
+    /// The grab is released when the character leaves this sequence for any sequence other than
+    /// `Throw`.
+    GrabHold,
+    /// Character throws the object it holds.
+    Throw,
+    /// Character is held by another object.
+    Grabbed,
+    /// Character has been thrown, moving upwards.
+    Thrown,
+    /// Character is defending.
+    ///
+    /// Hits from the direction the character is facing are blocked.
+    Defend,
+    /// Character has blocked a hit while defending.
+    DefendHit,
+    /// Character's guard has been broken.
+    ///
+    /// The character becomes `Dazed` when this sequence ends.
+    GuardBreak,
+    /// Character is frozen by a `Freeze` status effect.
+    ///
+    /// The character is held in this sequence while the effect is active.
+    Frozen,
 }
 
 impl SequenceName for CharacterSequenceName {}
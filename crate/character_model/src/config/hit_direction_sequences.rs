@@ -0,0 +1,17 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CharacterSequenceNameString;
+
+/// Sequences to transition to depending on which side a character is hit from.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct HitDirectionSequences {
+    /// Sequence to transition to when hit from the front.
+    pub front: CharacterSequenceNameString,
+    /// Sequence to transition to when hit from the back.
+    ///
+    /// Defaults to `front`, which is also used when the character does not have this sequence.
+    #[serde(default)]
+    pub back: Option<CharacterSequenceNameString>,
+}
@@ -0,0 +1,20 @@
+use derive_new::new;
+use object_status_model::config::StunPoints;
+use serde::{Deserialize, Serialize};
+
+use crate::config::CharacterSequenceNameString;
+
+/// Sequences to transition to when hit while below a stun point limit.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct StunThreshold {
+    /// Stun points below which this threshold applies.
+    pub limit: StunPoints,
+    /// Sequence to transition to when hit from the front.
+    pub front: CharacterSequenceNameString,
+    /// Sequence to transition to when hit from the back.
+    ///
+    /// Defaults to `front`, which is also used when the character does not have this sequence.
+    #[serde(default)]
+    pub back: Option<CharacterSequenceNameString>,
+}
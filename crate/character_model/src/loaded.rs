@@ -5,17 +5,25 @@
 
 pub use self::{
     asset_character_definition_handle::AssetCharacterDefinitionHandle,
+    character_defend_transitions::CharacterDefendTransitions,
+    character_grab_transitions::CharacterGrabTransitions,
     character_hit_transitions::CharacterHitTransitions,
     character_input_reaction::CharacterInputReaction,
     character_input_reactions::{CharacterInputReactions, CharacterInputReactionsHandle},
     character_irs::{CharacterIrs, CharacterIrsHandle},
     character_irs_handles::CharacterIrsHandles,
+    hit_direction_transitions::HitDirectionTransitions,
+    stun_transition::StunTransition,
 };
 
 mod asset_character_definition_handle;
+mod character_defend_transitions;
+mod character_grab_transitions;
 mod character_hit_transitions;
 mod character_input_reaction;
 mod character_input_reactions;
 
 mod character_irs;
 mod character_irs_handles;
+mod hit_direction_transitions;
+mod stun_transition;
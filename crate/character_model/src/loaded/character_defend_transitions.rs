@@ -0,0 +1,27 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when defending against hits.
+///
+/// This is only attached to characters that have a `Defend` sequence, and is used by the
+/// `CharacterHitEffectSystem` to block hits, similar to `CharacterHitTransitions`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct CharacterDefendTransitions {
+    /// Sequence ID in which the character blocks hits.
+    pub defend: SequenceId,
+    /// Sequence ID to transition to when a hit is blocked.
+    pub defend_hit: SequenceId,
+    /// Sequence ID to transition to when the character's guard is broken.
+    pub guard_break: SequenceId,
+}
+
+impl CharacterDefendTransitions {
+    /// Returns whether a character in the given sequence blocks a hit.
+    ///
+    /// Hits are blocked when the character is defending and facing the attacker.
+    pub fn blocks(&self, sequence_id: SequenceId, from_front: bool) -> bool {
+        from_front && (sequence_id == self.defend || sequence_id == self.defend_hit)
+    }
+}
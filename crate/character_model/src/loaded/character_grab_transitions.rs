@@ -0,0 +1,22 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when grabbing or being grabbed.
+///
+/// This is used by the grab systems to transition character sequences, similar to
+/// `CharacterHitTransitions`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct CharacterGrabTransitions {
+    /// Sequence ID to transition to when holding a grabbed object.
+    pub grab_hold: SequenceId,
+    /// Sequence ID that releases the held object as a throw.
+    pub throw: SequenceId,
+    /// Sequence ID to transition to when held by another object.
+    pub grabbed: SequenceId,
+    /// Sequence ID to transition to when thrown.
+    pub thrown: SequenceId,
+    /// Sequence ID to transition to when a grab ends without a throw.
+    pub stand: SequenceId,
+}
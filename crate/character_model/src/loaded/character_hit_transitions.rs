@@ -1,20 +1,36 @@
 use amethyst::ecs::{storage::DenseVecStorage, Component};
 use derive_new::new;
+use object_status_model::config::StunPoints;
 use sequence_model::loaded::SequenceId;
 
+use crate::loaded::{HitDirectionTransitions, StunTransition};
+
 /// Sequence to transition to when hit by another entity.
 ///
 /// This is a hack to allow the `CharacterHitEffectSystem` to transition character sequences.
 // TODO: Commonize Transition systems <https://gitlab.com/azriel91/autexousious/issues/157>
-#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[derive(Clone, Component, Debug, PartialEq, new)]
 #[storage(DenseVecStorage)]
 pub struct CharacterHitTransitions {
-    /// Sequence ID to transition to when stun points are low.
-    pub low_stun: SequenceId,
-    /// Sequence ID to transition to when stun points are at a moderate level.
-    pub mid_stun: SequenceId,
-    /// Sequence ID to transition to when stun points are high.
-    pub high_stun: SequenceId,
-    /// Sequence ID to transition to when falling.
-    pub falling: SequenceId,
+    /// Transitions for each stun point threshold, in ascending `limit` order.
+    pub stun_transitions: Vec<StunTransition>,
+    /// Transitions when stun points exceed every threshold, or HP reaches 0.
+    pub falling: HitDirectionTransitions,
+}
+
+impl CharacterHitTransitions {
+    /// Returns the sequence ID to transition to for the given stun points.
+    ///
+    /// # Parameters
+    ///
+    /// * `stun_points`: Stun points of the character after being hit.
+    /// * `from_front`: Whether the character was hit from the front.
+    pub fn transition(&self, stun_points: StunPoints, from_front: bool) -> SequenceId {
+        self.stun_transitions
+            .iter()
+            .find(|stun_transition| stun_points < stun_transition.limit)
+            .map(|stun_transition| &stun_transition.transitions)
+            .unwrap_or(&self.falling)
+            .sequence_id(from_front)
+    }
 }
@@ -0,0 +1,39 @@
+use derive_new::new;
+use kinematic_model::config::Position;
+use sequence_model::loaded::SequenceId;
+
+/// Sequence IDs to transition to depending on which side a character is hit from.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct HitDirectionTransitions {
+    /// Sequence ID to transition to when hit from the front.
+    pub front: SequenceId,
+    /// Sequence ID to transition to when hit from the back.
+    pub back: SequenceId,
+}
+
+impl HitDirectionTransitions {
+    /// Returns whether a character is hit from the front, given the attacker's and character's
+    /// positions and whether they are mirrored.
+    pub fn from_front(
+        position_from: Option<&Position<f32>>,
+        position_to: Option<&Position<f32>>,
+        mirrored_from: bool,
+        mirrored_to: bool,
+    ) -> bool {
+        match (position_from, position_to) {
+            (Some(position_from), Some(position_to)) if position_from.x != position_to.x => {
+                (position_from.x < position_to.x) == mirrored_to
+            }
+            _ => mirrored_from != mirrored_to,
+        }
+    }
+
+    /// Returns the sequence ID for the side the character was hit from.
+    pub fn sequence_id(self, from_front: bool) -> SequenceId {
+        if from_front {
+            self.front
+        } else {
+            self.back
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use derive_new::new;
+use object_status_model::config::StunPoints;
+
+use crate::loaded::HitDirectionTransitions;
+
+/// Sequence IDs to transition to when hit while below a stun point limit.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct StunTransition {
+    /// Stun points below which this transition applies.
+    pub limit: StunPoints,
+    /// Sequence IDs to transition to.
+    pub transitions: HitDirectionTransitions,
+}
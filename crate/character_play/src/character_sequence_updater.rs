@@ -3,10 +3,11 @@ use character_model::config::CharacterSequenceName;
 use crate::{
     sequence_handler::{
         CharacterSequenceHandler, DashAttack, DashBack, DashBackAscend, DashBackDescend,
-        DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend, Dodge,
-        FallForwardAscend, FallForwardDescend, FallForwardLand, Jump, JumpAscend, JumpAttack,
-        JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, Run, RunStop, Stand, StandAttack,
-        StandOnSequenceEnd, Walk,
+        DashDescendLand, DashForward, DashForwardAscend, DashForwardDescend, Defend, DefendHit,
+        Dodge, FallBackAscend, FallBackDescend, FallBackLand, FallForwardAscend,
+        FallForwardDescend, FallForwardLand, Frozen, GrabHold, Grabbed, GuardBreak, Jump,
+        JumpAscend, JumpAttack, JumpDescend, JumpDescendLand, JumpOff, LieFaceDown, LieFaceUp, Run,
+        RunStop, Stand, StandAttack, StandOnSequenceEnd, Walk,
     },
     CharacterSequenceUpdateComponents,
 };
@@ -48,6 +49,10 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::FallForwardDescend => &FallForwardDescend::update,
             CharacterSequenceName::FallForwardLand => &FallForwardLand::update,
             CharacterSequenceName::LieFaceDown => &LieFaceDown::update,
+            CharacterSequenceName::FallBackAscend => &FallBackAscend::update,
+            CharacterSequenceName::FallBackDescend => &FallBackDescend::update,
+            CharacterSequenceName::FallBackLand => &FallBackLand::update,
+            CharacterSequenceName::LieFaceUp => &LieFaceUp::update,
             CharacterSequenceName::DashForward => &DashForward::update,
             CharacterSequenceName::DashForwardAscend => &DashForwardAscend::update,
             CharacterSequenceName::DashForwardDescend => &DashForwardDescend::update,
@@ -56,6 +61,16 @@ impl CharacterSequenceUpdater {
             CharacterSequenceName::DashBackDescend => &DashBackDescend::update,
             CharacterSequenceName::DashDescendLand => &DashDescendLand::update,
             CharacterSequenceName::DashAttack => &DashAttack::update,
+            CharacterSequenceName::Grab | CharacterSequenceName::Throw => {
+                &StandOnSequenceEnd::update
+            }
+            CharacterSequenceName::GrabHold => &GrabHold::update,
+            CharacterSequenceName::Grabbed => &Grabbed::update,
+            CharacterSequenceName::Thrown => &FallForwardAscend::update,
+            CharacterSequenceName::Defend => &Defend::update,
+            CharacterSequenceName::DefendHit => &DefendHit::update,
+            CharacterSequenceName::GuardBreak => &GuardBreak::update,
+            CharacterSequenceName::Frozen => &Frozen::update,
         };
 
         sequence_handler(components)
@@ -8,13 +8,16 @@ pub use self::{
     dash_attack::DashAttack, dash_back::DashBack, dash_back_ascend::DashBackAscend,
     dash_back_descend::DashBackDescend, dash_descend_land::DashDescendLand,
     dash_forward::DashForward, dash_forward_ascend::DashForwardAscend,
-    dash_forward_descend::DashForwardDescend, dodge::Dodge, fall_forward_ascend::FallForwardAscend,
-    fall_forward_descend::FallForwardDescend, fall_forward_land::FallForwardLand, jump::Jump,
+    dash_forward_descend::DashForwardDescend, defend::Defend, defend_hit::DefendHit, dodge::Dodge,
+    fall_back_ascend::FallBackAscend, fall_back_descend::FallBackDescend,
+    fall_back_land::FallBackLand, fall_forward_ascend::FallForwardAscend,
+    fall_forward_descend::FallForwardDescend, fall_forward_land::FallForwardLand, frozen::Frozen,
+    grab_hold::GrabHold, grabbed::Grabbed, guard_break::GuardBreak, jump::Jump,
     jump_ascend::JumpAscend, jump_attack::JumpAttack, jump_descend::JumpDescend,
-    jump_descend_land::JumpDescendLand, jump_off::JumpOff, lie_face_down::LieFaceDown, run::Run,
-    run_stop::RunStop, sequence_handler_util::SequenceHandlerUtil, stand::Stand,
-    stand_attack::StandAttack, stand_on_sequence_end::StandOnSequenceEnd,
-    switch_sequence_on_descend::SwitchSequenceOnDescend,
+    jump_descend_land::JumpDescendLand, jump_off::JumpOff, lie_face_down::LieFaceDown,
+    lie_face_up::LieFaceUp, run::Run, run_stop::RunStop,
+    sequence_handler_util::SequenceHandlerUtil, stand::Stand, stand_attack::StandAttack,
+    stand_on_sequence_end::StandOnSequenceEnd, switch_sequence_on_descend::SwitchSequenceOnDescend,
     switch_sequence_on_end::SwitchSequenceOnEnd,
     switch_sequence_on_end_y_velocity::SwitchSequenceOnEndYVelocity,
     switch_sequence_on_land::SwitchSequenceOnLand, walk::Walk,
@@ -30,10 +33,19 @@ mod dash_descend_land;
 mod dash_forward;
 mod dash_forward_ascend;
 mod dash_forward_descend;
+mod defend;
+mod defend_hit;
 mod dodge;
+mod fall_back_ascend;
+mod fall_back_descend;
+mod fall_back_land;
 mod fall_forward_ascend;
 mod fall_forward_descend;
 mod fall_forward_land;
+mod frozen;
+mod grab_hold;
+mod grabbed;
+mod guard_break;
 mod jump;
 mod jump_ascend;
 mod jump_attack;
@@ -41,6 +53,7 @@ mod jump_descend;
 mod jump_descend_land;
 mod jump_off;
 mod lie_face_down;
+mod lie_face_up;
 mod run;
 mod run_stop;
 mod sequence_handler_util;
@@ -0,0 +1,24 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{common::status::AliveCheck, CharacterSequenceHandler},
+    CharacterSequenceUpdateComponents,
+};
+
+/// `Defend` sequence update.
+///
+/// The character keeps defending while the defend button is held.
+#[derive(Debug)]
+pub struct Defend;
+
+impl CharacterSequenceHandler for Defend {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        AliveCheck::update(components).or_else(|| {
+            if components.controller_input.defend {
+                None
+            } else {
+                Some(CharacterSequenceName::Stand)
+            }
+        })
+    }
+}
@@ -0,0 +1,18 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{common::status::AliveCheck, CharacterSequenceHandler, SwitchSequenceOnEnd},
+    CharacterSequenceUpdateComponents,
+};
+
+const DEFEND_HIT: SwitchSequenceOnEnd = SwitchSequenceOnEnd(CharacterSequenceName::Defend);
+
+/// `DefendHit` sequence update.
+#[derive(Debug)]
+pub struct DefendHit;
+
+impl CharacterSequenceHandler for DefendHit {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        AliveCheck::update(components).or_else(|| DEFEND_HIT.update(components.sequence_status))
+    }
+}
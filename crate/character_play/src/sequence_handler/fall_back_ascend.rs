@@ -0,0 +1,19 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{CharacterSequenceHandler, SwitchSequenceOnDescend},
+    CharacterSequenceUpdateComponents,
+};
+
+const FALL_BACK_ASCEND: SwitchSequenceOnDescend =
+    SwitchSequenceOnDescend(CharacterSequenceName::FallBackDescend);
+
+/// `FallBackAscend` sequence update.
+#[derive(Debug)]
+pub struct FallBackAscend;
+
+impl CharacterSequenceHandler for FallBackAscend {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        FALL_BACK_ASCEND.update(components)
+    }
+}
@@ -0,0 +1,26 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{common::SequenceRepeat, CharacterSequenceHandler, SwitchSequenceOnLand},
+    CharacterSequenceUpdateComponents,
+};
+
+const FALL_BACK_DESCEND_BOUNCE: SwitchSequenceOnLand =
+    SwitchSequenceOnLand(CharacterSequenceName::FallBackLand);
+const FALL_BACK_DESCEND_LIE: SwitchSequenceOnLand =
+    SwitchSequenceOnLand(CharacterSequenceName::LieFaceUp);
+
+/// `FallBackDescend` sequence update.
+#[derive(Debug)]
+pub struct FallBackDescend;
+
+impl CharacterSequenceHandler for FallBackDescend {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        if components.velocity[1] <= -10. {
+            FALL_BACK_DESCEND_BOUNCE.update(components)
+        } else {
+            FALL_BACK_DESCEND_LIE.update(components)
+        }
+        .or_else(|| SequenceRepeat::update(components))
+    }
+}
@@ -0,0 +1,18 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{CharacterSequenceHandler, SwitchSequenceOnEnd},
+    CharacterSequenceUpdateComponents,
+};
+
+const FALL_BACK_LAND: SwitchSequenceOnEnd = SwitchSequenceOnEnd(CharacterSequenceName::LieFaceUp);
+
+/// `FallBackLand` sequence update.
+#[derive(Debug)]
+pub struct FallBackLand;
+
+impl CharacterSequenceHandler for FallBackLand {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        FALL_BACK_LAND.update(components.sequence_status)
+    }
+}
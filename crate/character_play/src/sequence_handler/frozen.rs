@@ -0,0 +1,20 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{common::status::AliveCheck, CharacterSequenceHandler, SwitchSequenceOnEnd},
+    CharacterSequenceUpdateComponents,
+};
+
+const FROZEN: SwitchSequenceOnEnd = SwitchSequenceOnEnd(CharacterSequenceName::Stand);
+
+/// `Frozen` sequence update.
+///
+/// The `StatusEffectTickSystem` returns the character to this sequence while it is still frozen.
+#[derive(Debug)]
+pub struct Frozen;
+
+impl CharacterSequenceHandler for Frozen {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        AliveCheck::update(components).or_else(|| FROZEN.update(components.sequence_status))
+    }
+}
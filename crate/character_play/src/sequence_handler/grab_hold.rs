@@ -0,0 +1,9 @@
+use crate::sequence_handler::CharacterSequenceHandler;
+
+/// `GrabHold` sequence update.
+///
+/// The grab is released by the `CharacterGrabReleaseSystem`, so this does not transition.
+#[derive(Debug)]
+pub struct GrabHold;
+
+impl CharacterSequenceHandler for GrabHold {}
@@ -0,0 +1,9 @@
+use crate::sequence_handler::CharacterSequenceHandler;
+
+/// `Grabbed` sequence update.
+///
+/// The grab is released by the `CharacterGrabReleaseSystem`, so this does not transition.
+#[derive(Debug)]
+pub struct Grabbed;
+
+impl CharacterSequenceHandler for Grabbed {}
@@ -0,0 +1,18 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{common::status::AliveCheck, CharacterSequenceHandler, SwitchSequenceOnEnd},
+    CharacterSequenceUpdateComponents,
+};
+
+const GUARD_BREAK: SwitchSequenceOnEnd = SwitchSequenceOnEnd(CharacterSequenceName::Dazed);
+
+/// `GuardBreak` sequence update.
+#[derive(Debug)]
+pub struct GuardBreak;
+
+impl CharacterSequenceHandler for GuardBreak {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        AliveCheck::update(components).or_else(|| GUARD_BREAK.update(components.sequence_status))
+    }
+}
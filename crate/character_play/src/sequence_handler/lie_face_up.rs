@@ -0,0 +1,22 @@
+use character_model::config::CharacterSequenceName;
+
+use crate::{
+    sequence_handler::{CharacterSequenceHandler, SwitchSequenceOnEnd},
+    CharacterSequenceUpdateComponents,
+};
+
+const LIE_FACE_UP: SwitchSequenceOnEnd = SwitchSequenceOnEnd(CharacterSequenceName::Stand);
+
+/// `LieFaceUp` sequence update.
+#[derive(Debug)]
+pub struct LieFaceUp;
+
+impl CharacterSequenceHandler for LieFaceUp {
+    fn update(components: CharacterSequenceUpdateComponents<'_>) -> Option<CharacterSequenceName> {
+        if components.health_points > 0 {
+            LIE_FACE_UP.update(components.sequence_status)
+        } else {
+            None
+        }
+    }
+}
@@ -3,14 +3,20 @@ use std::convert::TryInto;
 use amethyst::ecs::Entity;
 use asset_model::loaded::AssetId;
 use character_model::{
-    config::CharacterSequenceName, loaded::CharacterHitTransitions, play::RunCounter,
+    config::{CharacterSequenceName, CharacterSequenceNameString, HitDirectionSequences},
+    loaded::{
+        CharacterDefendTransitions, CharacterGrabTransitions, CharacterHitTransitions,
+        HitDirectionTransitions, StunTransition,
+    },
+    play::RunCounter,
 };
 use charge_model::play::{ChargeRetention, ChargeTrackerClock};
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
-use object_status_model::config::StunPoints;
+use object_status_model::config::{DefensePoints, StunPoints};
 use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+use status_effect_model::loaded::StatusEffectTransitions;
 
 use crate::{CharacterComponentStorages, CharacterSpawningResources};
 
@@ -40,6 +46,9 @@ impl CharacterEntityAugmenter {
             controller_inputs,
             health_pointses,
             stun_pointses,
+            stun_recoveries,
+            defense_pointses,
+            defense_limits,
             run_counters,
             masses,
             map_boundeds,
@@ -49,6 +58,9 @@ impl CharacterEntityAugmenter {
             charge_use_modes,
             charge_retentions,
             character_hit_transitionses,
+            character_grab_transitionses,
+            character_defend_transitionses,
+            status_effect_transitionses,
         }: &mut CharacterComponentStorages<'s>,
         asset_id: AssetId,
         entity: Entity,
@@ -73,31 +85,67 @@ impl CharacterEntityAugmenter {
                     asset_id
                 )
             });
-        let low_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Flinch0))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let mid_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Flinch1))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let high_stun = sequence_id_mappings
-            .id(&SequenceNameString::Name(CharacterSequenceName::Dazed))
-            .copied()
-            .unwrap_or(SequenceId(0));
-        let falling = sequence_id_mappings
-            .id(&SequenceNameString::Name(
-                CharacterSequenceName::FallForwardAscend,
-            ))
-            .copied()
-            .unwrap_or(SequenceId(0));
+        let sequence_id_opt = |character_sequence_name| {
+            sequence_id_mappings
+                .id(&SequenceNameString::Name(character_sequence_name))
+                .copied()
+        };
+        let sequence_id = |character_sequence_name| {
+            sequence_id_opt(character_sequence_name).unwrap_or(SequenceId(0))
+        };
+        let hit_direction_transitions =
+            |front: &CharacterSequenceNameString, back: Option<&CharacterSequenceNameString>| {
+                let front = sequence_id_mappings
+                    .id(front)
+                    .copied()
+                    .unwrap_or(SequenceId(0));
+                // Characters without a back sequence use the front sequence.
+                let back = back
+                    .and_then(|back| sequence_id_mappings.id(back))
+                    .copied()
+                    .unwrap_or(front);
+                HitDirectionTransitions::new(front, back)
+            };
 
-        let character_hit_transitions = CharacterHitTransitions {
-            low_stun,
-            mid_stun,
-            high_stun,
-            falling,
+        let hit_reactions = &character_definition.hit_reactions;
+        let mut stun_transitions = hit_reactions
+            .stun_thresholds
+            .iter()
+            .map(|stun_threshold| {
+                StunTransition::new(
+                    stun_threshold.limit,
+                    hit_direction_transitions(&stun_threshold.front, stun_threshold.back.as_ref()),
+                )
+            })
+            .collect::<Vec<StunTransition>>();
+        // `CharacterHitTransitions` uses the first threshold above the character's stun points, so
+        // thresholds must be in ascending order regardless of how they are configured.
+        stun_transitions.sort_by_key(|stun_transition| stun_transition.limit.0);
+        let falling = {
+            let HitDirectionSequences { front, back } = &hit_reactions.falling;
+            hit_direction_transitions(front, back.as_ref())
         };
+        let character_hit_transitions = CharacterHitTransitions::new(stun_transitions, falling);
+        let character_grab_transitions = CharacterGrabTransitions {
+            grab_hold: sequence_id(CharacterSequenceName::GrabHold),
+            throw: sequence_id(CharacterSequenceName::Throw),
+            grabbed: sequence_id(CharacterSequenceName::Grabbed),
+            thrown: sequence_id(CharacterSequenceName::Thrown),
+            stand: sequence_id(CharacterSequenceName::Stand),
+        };
+        // Characters without a `Defend` sequence cannot block hits.
+        let character_defend_transitions =
+            sequence_id_opt(CharacterSequenceName::Defend).map(|defend| {
+                CharacterDefendTransitions {
+                    defend,
+                    defend_hit: sequence_id_opt(CharacterSequenceName::DefendHit).unwrap_or(defend),
+                    guard_break: sequence_id_opt(CharacterSequenceName::GuardBreak)
+                        .unwrap_or_else(|| sequence_id(CharacterSequenceName::Dazed)),
+                }
+            });
+        // Characters without a `Frozen` sequence are only immobilized when frozen.
+        let status_effect_transitions =
+            sequence_id_opt(CharacterSequenceName::Frozen).map(StatusEffectTransitions::new);
 
         // Controller of this entity
         controller_inputs
@@ -109,6 +157,15 @@ impl CharacterEntityAugmenter {
         stun_pointses
             .insert(entity, StunPoints::default())
             .expect("Failed to insert `StunPoints` component.");
+        stun_recoveries
+            .insert(entity, hit_reactions.stun_recovery)
+            .expect("Failed to insert `StunRecovery` component.");
+        defense_pointses
+            .insert(entity, DefensePoints::new(0))
+            .expect("Failed to insert `DefensePoints` component.");
+        defense_limits
+            .insert(entity, character_definition.defense_limit)
+            .expect("Failed to insert `DefenseLimit` component.");
         run_counters
             .insert(entity, RunCounter::default())
             .expect("Failed to insert `RunCounter` component.");
@@ -146,5 +203,18 @@ impl CharacterEntityAugmenter {
         character_hit_transitionses
             .insert(entity, character_hit_transitions)
             .expect("Failed to insert `CharacterHitTransitions` component.");
+        character_grab_transitionses
+            .insert(entity, character_grab_transitions)
+            .expect("Failed to insert `CharacterGrabTransitions` component.");
+        if let Some(character_defend_transitions) = character_defend_transitions {
+            character_defend_transitionses
+                .insert(entity, character_defend_transitions)
+                .expect("Failed to insert `CharacterDefendTransitions` component.");
+        }
+        if let Some(status_effect_transitions) = status_effect_transitions {
+            status_effect_transitionses
+                .insert(entity, status_effect_transitions)
+                .expect("Failed to insert `StatusEffectTransitions` component.");
+        }
     }
 }
@@ -2,7 +2,10 @@ use amethyst::{
     ecs::{World, WriteStorage},
     shred::{ResourceId, SystemData},
 };
-use character_model::{loaded::CharacterHitTransitions, play::RunCounter};
+use character_model::{
+    loaded::{CharacterDefendTransitions, CharacterGrabTransitions, CharacterHitTransitions},
+    play::RunCounter,
+};
 use charge_model::{
     config::{ChargeDelay, ChargeLimit, ChargeUseMode},
     play::{ChargeRetention, ChargeTrackerClock},
@@ -11,7 +14,8 @@ use derivative::Derivative;
 use game_input_model::play::ControllerInput;
 use map_model::play::MapBounded;
 use object_model::{config::Mass, play::HealthPoints};
-use object_status_model::config::StunPoints;
+use object_status_model::config::{DefenseLimit, DefensePoints, StunPoints, StunRecovery};
+use status_effect_model::loaded::StatusEffectTransitions;
 
 /// Character specific `Component` storages.
 ///
@@ -29,6 +33,15 @@ pub struct CharacterComponentStorages<'s> {
     /// `StunPoints` component storage.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `StunRecovery` component storage.
+    #[derivative(Debug = "ignore")]
+    pub stun_recoveries: WriteStorage<'s, StunRecovery>,
+    /// `DefensePoints` component storage.
+    #[derivative(Debug = "ignore")]
+    pub defense_pointses: WriteStorage<'s, DefensePoints>,
+    /// `DefenseLimit` component storage.
+    #[derivative(Debug = "ignore")]
+    pub defense_limits: WriteStorage<'s, DefenseLimit>,
     /// `RunCounter` component storage.
     #[derivative(Debug = "ignore")]
     pub run_counters: WriteStorage<'s, RunCounter>,
@@ -56,4 +69,13 @@ pub struct CharacterComponentStorages<'s> {
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: WriteStorage<'s, CharacterHitTransitions>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: WriteStorage<'s, CharacterGrabTransitions>,
+    /// `CharacterDefendTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_defend_transitionses: WriteStorage<'s, CharacterDefendTransitions>,
+    /// `StatusEffectTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_transitionses: WriteStorage<'s, StatusEffectTransitions>,
 }
@@ -6,13 +6,19 @@ use amethyst::{
 };
 use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder, AutexState};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetTypeMappings},
+};
 use asset_selection_model::play::AssetSelectionEvent;
 use character_selection_model::{
     CharacterSelectionEntity, CharacterSelections, CharacterSelectionsStatus,
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_model::play::GameRng;
 use log::debug;
+use object_type::ObjectType;
 use state_registry::StateId;
 
 /// `State` where character selection takes place.
@@ -77,6 +83,20 @@ where
         world.insert(CharacterSelectionsStatus::Waiting);
         world.insert(CharacterSelections::default());
     }
+
+    /// Resolves *Random* character selections using the `GameRng`.
+    fn random_selections_resolve(world: &mut World) {
+        let asset_ids = world
+            .read_resource::<AssetTypeMappings>()
+            .iter_ids(&AssetType::Object(ObjectType::Character))
+            .copied()
+            .collect::<Vec<AssetId>>();
+        let mut game_rng = world.write_resource::<GameRng>();
+
+        world
+            .write_resource::<CharacterSelections>()
+            .random_resolve(&asset_ids, &mut **game_rng);
+    }
 }
 
 impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent>
@@ -111,6 +131,8 @@ where
             match asset_selection_event {
                 AssetSelectionEvent::Return => Trans::Pop,
                 AssetSelectionEvent::Confirm => {
+                    Self::random_selections_resolve(data.world);
+
                     let character_selections = data.world.read_resource::<CharacterSelections>();
                     debug!(
                         "character_selections: `{:?}`",
@@ -6,7 +6,7 @@ pub use crate::{
     character_selection_state::{
         CharacterSelectionState, CharacterSelectionStateBuilder, CharacterSelectionStateDelegate,
     },
-    system::CharacterSelectionSystem,
+    system::{CharacterSelectionSystem, CswTeamTintSystem, CswTeamTintSystemData},
 };
 
 mod character_selection_state;
@@ -1,3 +1,7 @@
-pub use self::character_selection_system::CharacterSelectionSystem;
+pub use self::{
+    character_selection_system::CharacterSelectionSystem,
+    csw_team_tint_system::{CswTeamTintSystem, CswTeamTintSystemData},
+};
 
 mod character_selection_system;
+mod csw_team_tint_system;
@@ -1,3 +1,4 @@
+use ai_model::config::AiDifficulty;
 use amethyst::{
     ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
@@ -10,6 +11,7 @@ use derivative::Derivative;
 use derive_new::new;
 use log::warn;
 use object_type::ObjectType;
+use team_model::play::TeamCounter;
 
 /// Populates the `CharacterSelections` based on user input.
 #[derive(Debug, Default, new)]
@@ -60,18 +62,18 @@ impl<'s> System<'s> for CharacterSelectionSystem {
                     let asset_id = match asset_selection {
                         AssetSelection::Id(asset_id) => asset_id,
                         AssetSelection::Random => {
-                            // TODO: Implement Random
-                            // TODO: <https://gitlab.com/azriel91/autexousious/issues/137>
-                            asset_type_mappings
-                                .iter_ids(&AssetType::Object(ObjectType::Character))
-                                .next()
-                                .copied()
-                                .expect("Expected at least one character to be loaded.")
+                            // Resolved when the selections are confirmed.
+                            character_selections.selections.remove(&controller_id);
+                            character_selections.random_selections.insert(controller_id);
+                            return;
                         }
                     };
 
                     let asset_type = asset_type_mappings.get(asset_id);
                     if let Some(AssetType::Object(ObjectType::Character)) = asset_type {
+                        character_selections
+                            .random_selections
+                            .remove(&controller_id);
                         character_selections
                             .selections
                             .insert(controller_id, asset_id);
@@ -85,6 +87,48 @@ impl<'s> System<'s> for CharacterSelectionSystem {
                 }
                 AssetSelectionEvent::Deselect { controller_id, .. } => {
                     character_selections.selections.remove(&controller_id);
+                    character_selections
+                        .random_selections
+                        .remove(&controller_id);
+                }
+                AssetSelectionEvent::AiSwitch { controller_id, .. } => {
+                    let ai_difficulty = AiDifficulty::next(
+                        character_selections
+                            .ai_difficulties
+                            .get(&controller_id)
+                            .copied(),
+                    );
+
+                    if let Some(ai_difficulty) = ai_difficulty {
+                        character_selections
+                            .ai_difficulties
+                            .insert(controller_id, ai_difficulty);
+                    } else {
+                        character_selections.ai_difficulties.remove(&controller_id);
+                    }
+                }
+                AssetSelectionEvent::TeamSwitch { controller_id, .. } => {
+                    let team_counter = TeamCounter::next(
+                        character_selections
+                            .team_counters
+                            .get(&controller_id)
+                            .copied(),
+                    );
+
+                    if let Some(team_counter) = team_counter {
+                        character_selections
+                            .team_counters
+                            .insert(controller_id, team_counter);
+                    } else {
+                        character_selections.team_counters.remove(&controller_id);
+                    }
+                }
+                AssetSelectionEvent::Leave { controller_id, .. } => {
+                    character_selections
+                        .random_selections
+                        .remove(&controller_id);
+                    character_selections.ai_difficulties.remove(&controller_id);
+                    character_selections.team_counters.remove(&controller_id);
                 }
                 _ => {}
             });
@@ -0,0 +1,84 @@
+use amethyst::{
+    ecs::{BitSet, Entities, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+};
+use asset_ui_model::loaded::AswPortraits;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::InputControlled;
+use team_model::play::TeamColour;
+
+/// Tints each character selection widget portrait with the colour of its selected team.
+///
+/// When a portrait's controller switches back to an independent team, its `Tint` is reset to
+/// white.
+#[derive(Debug, Default, new)]
+pub struct CswTeamTintSystem {
+    /// Entities that were tinted in the previous tick.
+    #[new(default)]
+    entities_tinted: BitSet,
+}
+
+/// `CswTeamTintSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CswTeamTintSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AswPortraits` components.
+    #[derivative(Debug = "ignore")]
+    pub asw_portraitses: ReadStorage<'s, AswPortraits>,
+    /// `Tint` components.
+    #[derivative(Debug = "ignore")]
+    pub tints: WriteStorage<'s, Tint>,
+}
+
+impl<'s> System<'s> for CswTeamTintSystem {
+    type SystemData = CswTeamTintSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CswTeamTintSystemData {
+            entities,
+            character_selections,
+            input_controlleds,
+            asw_portraitses,
+            mut tints,
+        }: Self::SystemData,
+    ) {
+        let mut entities_tinted = BitSet::new();
+
+        (&entities, &input_controlleds, &asw_portraitses)
+            .join()
+            .for_each(|(entity, input_controlled, _)| {
+                let team_counter = character_selections
+                    .team_counters
+                    .get(&input_controlled.controller_id)
+                    .copied();
+
+                if let Some(team_counter) = team_counter {
+                    let [r, g, b, a] = TeamColour::from(team_counter).rgba();
+                    tints
+                        .insert(entity, Tint(Srgba::new(r, g, b, a)))
+                        .expect("Failed to insert `Tint` component.");
+                    entities_tinted.add(entity.id());
+                }
+            });
+
+        (&entities, &self.entities_tinted, &mut tints)
+            .join()
+            .filter(|(entity, _, _)| !entities_tinted.contains(entity.id()))
+            .for_each(|(_, _, tint)| *tint = Tint(Srgba::new(1., 1., 1., 1.)));
+
+        self.entities_tinted = entities_tinted;
+    }
+}
@@ -1,8 +1,11 @@
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
+use ai_model::config::AiDifficulty;
 use asset_model::loaded::AssetId;
 use derive_new::new;
 use game_input_model::config::ControllerId;
+use rand::{seq::SliceRandom, Rng};
+use team_model::play::TeamCounter;
 
 /// Stores the selected characters for each controller.
 ///
@@ -11,4 +14,40 @@ use game_input_model::config::ControllerId;
 pub struct CharacterSelections {
     /// Map of controller ID to character asset ID.
     pub selections: HashMap<ControllerId, AssetId>,
+    /// Controllers that have selected *Random*, which are resolved when selections are confirmed.
+    #[new(default)]
+    pub random_selections: BTreeSet<ControllerId>,
+    /// Map of controller ID to the difficulty of the computer controlling that slot.
+    ///
+    /// Slots without an entry are controlled by players.
+    #[new(default)]
+    pub ai_difficulties: HashMap<ControllerId, AiDifficulty>,
+    /// Map of controller ID to the numbered team selected for that slot.
+    ///
+    /// Slots without an entry are on independent teams.
+    #[new(default)]
+    pub team_counters: HashMap<ControllerId, TeamCounter>,
+}
+
+impl CharacterSelections {
+    /// Resolves each *Random* selection to one of the given character asset IDs.
+    ///
+    /// Selections are resolved in controller ID order, so the same `rng` state always produces the
+    /// same selections.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_ids`: Character asset IDs to choose from.
+    /// * `rng`: Random number generator to choose with.
+    pub fn random_resolve<R>(&mut self, asset_ids: &[AssetId], rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        let random_selections = std::mem::take(&mut self.random_selections);
+        random_selections.into_iter().for_each(|controller_id| {
+            if let Some(asset_id) = asset_ids.choose(rng) {
+                self.selections.insert(controller_id, *asset_id);
+            }
+        });
+    }
 }
@@ -0,0 +1,29 @@
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use chase_model::loaded::ChaseSequence;
+use derive_new::new;
+
+/// Adds the following systems to the dispatcher.
+///
+/// * `Processor::<ChaseSequence>` is added with id `"chase_sequence_processor"`.
+#[derive(Debug, new)]
+pub struct ChaseLoadingBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for ChaseLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<ChaseSequence>::new(),
+            "chase_sequence_processor",
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Processes chase configuration into the loaded chase model.
+
+pub use crate::chase_loading_bundle::ChaseLoadingBundle;
+
+mod chase_loading_bundle;
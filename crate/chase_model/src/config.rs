@@ -1,5 +1,6 @@
 //! User defined configuration types for chases.
 
-pub use self::chase_mode::ChaseMode;
+pub use self::{chase::Chase, chase_mode::ChaseMode};
 
+mod chase;
 mod chase_mode;
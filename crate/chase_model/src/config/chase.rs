@@ -0,0 +1,23 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ChaseMode;
+
+/// How an object chases its target object on a frame.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Chase {
+    /// Mode that the object chases the target object.
+    pub mode: ChaseMode,
+    /// Speed of the object along the ground (X and Z axes).
+    #[derivative(Default(value = "5."))]
+    pub speed: f32,
+    /// Maximum angle in radians that the object's heading may turn each tick.
+    #[derivative(Default(value = "0.1"))]
+    pub turn_rate: f32,
+    /// Distance to keep from the target object when orbiting.
+    #[derivative(Default(value = "80."))]
+    pub radius: f32,
+}
@@ -1,10 +1,22 @@
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
 /// Mode that this entity chases the target object.
-#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
 pub enum ChaseMode {
     /// Sticks to the target object.
     ///
     /// The `Position` and `Transform` of this entity is copied from the target entity.
     Stick,
+    /// Steers toward the target object.
+    ///
+    /// The entity's heading turns toward the target by at most the chase `turn_rate` each tick.
+    #[derivative(Default)]
+    Seek,
+    /// Circles the target object at the chase `radius`.
+    Orbit,
+    /// Steers away from the target object.
+    Flee,
 }
@@ -0,0 +1,11 @@
+//! Types that represent processed configuration.
+
+pub use self::{
+    chase_opt::ChaseOpt,
+    chase_sequence::{ChaseSequence, ChaseSequenceHandle},
+    chase_sequence_handles::ChaseSequenceHandles,
+};
+
+mod chase_opt;
+mod chase_sequence;
+mod chase_sequence_handles;
@@ -0,0 +1,9 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::Chase;
+
+/// Newtype for an `Option<Chase>`, as we need to implement `Component` on it.
+#[derive(Clone, Component, Copy, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct ChaseOpt(pub Option<Chase>);
@@ -0,0 +1,7 @@
+use sequence_model_derive::frame_component_data;
+
+use crate::loaded::ChaseOpt;
+
+/// Sequence of `ChaseOpt` values.
+#[frame_component_data(ChaseOpt, copy)]
+pub struct ChaseSequence;
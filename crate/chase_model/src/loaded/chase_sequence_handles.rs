@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::ChaseSequenceHandle;
+
+/// Sequence of `ChaseSequenceHandle`s.
+#[sequence_component_data(ChaseSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct ChaseSequenceHandles;
+
+impl<'s> ItemComponent<'s> for ChaseSequenceHandles {
+    type SystemData = ();
+}
@@ -2,6 +2,6 @@
 
 //! Provides logic for game objects used during game play.
 
-pub use crate::system::StickToTargetObjectSystem;
+pub use crate::system::{ChaseSystem, ChaseTargetAcquisitionSystem, StickToTargetObjectSystem};
 
 mod system;
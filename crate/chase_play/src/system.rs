@@ -1,3 +1,8 @@
-pub use self::stick_to_target_object_system::StickToTargetObjectSystem;
+pub use self::{
+    chase_system::ChaseSystem, chase_target_acquisition_system::ChaseTargetAcquisitionSystem,
+    stick_to_target_object_system::StickToTargetObjectSystem,
+};
 
+mod chase_system;
+mod chase_target_acquisition_system;
 mod stick_to_target_object_system;
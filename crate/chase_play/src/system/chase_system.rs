@@ -0,0 +1,163 @@
+use std::f32::consts::PI;
+
+use amethyst::{
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::{
+    config::{Chase, ChaseMode},
+    loaded::ChaseOpt,
+    play::TargetObject,
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{Position, Velocity};
+use mirrored_model::play::Mirrored;
+
+/// Steers chasing entities relative to their `TargetObject` based on their `ChaseMode`.
+///
+/// Steering happens along the ground, so only the **X** and **Z** velocity is updated.
+#[derive(Debug, Default, new)]
+pub struct ChaseSystem;
+
+/// `ChaseSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ChaseSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChaseOpt` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_opts: ReadStorage<'s, ChaseOpt>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: ReadStorage<'s, TargetObject>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+}
+
+impl ChaseSystem {
+    /// Returns the angle of the direction the chaser wants to head in.
+    ///
+    /// # Parameters
+    ///
+    /// * `chase`: Chase configuration of the chaser.
+    /// * `dx`: Distance along the X axis from the chaser to the target.
+    /// * `dz`: Distance along the Z axis from the chaser to the target.
+    fn desired_heading(chase: Chase, dx: f32, dz: f32) -> Option<f32> {
+        let distance = (dx * dx + dz * dz).sqrt();
+        if distance <= std::f32::EPSILON {
+            return None;
+        }
+
+        match chase.mode {
+            ChaseMode::Stick => None,
+            ChaseMode::Seek => Some(dz.atan2(dx)),
+            ChaseMode::Flee => Some((-dz).atan2(-dx)),
+            ChaseMode::Orbit => {
+                let (radial_x, radial_z) = (dx / distance, dz / distance);
+                let (tangent_x, tangent_z) = (-radial_z, radial_x);
+                let correction = if chase.radius > 0. {
+                    ((distance - chase.radius) / chase.radius).max(-1.).min(1.)
+                } else {
+                    1.
+                };
+
+                let heading_x = tangent_x + radial_x * correction;
+                let heading_z = tangent_z + radial_z * correction;
+                Some(heading_z.atan2(heading_x))
+            }
+        }
+    }
+
+    /// Returns the heading after turning towards the desired heading by at most `turn_rate`.
+    fn turn(heading: f32, heading_desired: f32, turn_rate: f32) -> f32 {
+        let mut difference = (heading_desired - heading) % (2. * PI);
+        if difference > PI {
+            difference -= 2. * PI;
+        } else if difference < -PI {
+            difference += 2. * PI;
+        }
+
+        heading + difference.max(-turn_rate).min(turn_rate)
+    }
+}
+
+impl<'s> System<'s> for ChaseSystem {
+    type SystemData = ChaseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ChaseSystemData {
+            entities,
+            chase_opts,
+            target_objects,
+            mut positions,
+            mut velocities,
+            mut mirroreds,
+        }: Self::SystemData,
+    ) {
+        (&entities, &chase_opts, &target_objects)
+            .join()
+            .filter_map(|(entity, chase_opt, target_object)| {
+                chase_opt.map(|chase| (entity, chase, target_object))
+            })
+            .for_each(|(entity, chase, target_object)| {
+                let target_position = positions.get(target_object.entity).copied();
+                let target_position = if let Some(target_position) = target_position {
+                    target_position
+                } else {
+                    return;
+                };
+
+                if chase.mode == ChaseMode::Stick {
+                    if let Some(position) = positions.get_mut(entity) {
+                        *position = target_position;
+                    }
+                    return;
+                }
+
+                let position = if let Some(position) = positions.get(entity) {
+                    *position
+                } else {
+                    return;
+                };
+                let dx = target_position[0] - position[0];
+                let dz = target_position[2] - position[2];
+
+                let heading_desired = Self::desired_heading(chase, dx, dz);
+                if let (Some(heading_desired), Some(velocity)) =
+                    (heading_desired, velocities.get_mut(entity))
+                {
+                    let heading = if velocity[0] == 0. && velocity[2] == 0. {
+                        heading_desired
+                    } else {
+                        Self::turn(
+                            velocity[2].atan2(velocity[0]),
+                            heading_desired,
+                            chase.turn_rate,
+                        )
+                    };
+
+                    velocity[0] = chase.speed * heading.cos();
+                    velocity[2] = chase.speed * heading.sin();
+
+                    if let Some(mirrored) = mirroreds.get_mut(entity) {
+                        if velocity[0] < 0. {
+                            *mirrored = Mirrored::new(true);
+                        } else if velocity[0] > 0. {
+                            *mirrored = Mirrored::new(false);
+                        }
+                    }
+                }
+            });
+    } // kcov-ignore
+}
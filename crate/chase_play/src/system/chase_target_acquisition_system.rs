@@ -0,0 +1,144 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::{loaded::ChaseOpt, play::TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Position;
+use object_model::play::HealthPoints;
+use spawn_model::play::SpawnParent;
+use team_model::play::Team;
+
+/// Assigns a `TargetObject` to chasing entities that do not have a live target.
+///
+/// The spawn parent's target object is used if it has one, otherwise the nearest living object on
+/// a different `Team` is chosen.
+#[derive(Debug, Default, new)]
+pub struct ChaseTargetAcquisitionSystem;
+
+/// `ChaseTargetAcquisitionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ChaseTargetAcquisitionSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ChaseOpt` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_opts: ReadStorage<'s, ChaseOpt>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+}
+
+impl ChaseTargetAcquisitionSystem {
+    /// Returns the nearest living entity that is on a different team to the chaser.
+    fn nearest_enemy(
+        ChaseTargetAcquisitionSystemData {
+            entities,
+            spawn_parents,
+            teams,
+            health_pointses,
+            positions,
+            ..
+        }: &ChaseTargetAcquisitionSystemData<'_>,
+        entity: Entity,
+    ) -> Option<Entity> {
+        let position = positions.get(entity)?;
+        let team = teams.get(entity);
+        let spawn_parent_entity = spawn_parents
+            .get(entity)
+            .map(|spawn_parent| spawn_parent.entity);
+
+        (&**entities, teams, health_pointses, positions)
+            .join()
+            .filter(|(target_entity, target_team, health_points, _)| {
+                *target_entity != entity
+                    && Some(*target_entity) != spawn_parent_entity
+                    && team != Some(*target_team)
+                    && health_points.0 > 0
+            })
+            .map(|(target_entity, _, _, target_position)| {
+                let distance_squared = (target_position.0 - position.0).norm_squared();
+                (target_entity, distance_squared)
+            })
+            .fold(
+                None,
+                |nearest: Option<(Entity, f32)>, (target_entity, distance_squared)| match nearest {
+                    Some((_, nearest_distance_squared))
+                        if nearest_distance_squared <= distance_squared =>
+                    {
+                        nearest
+                    }
+                    _ => Some((target_entity, distance_squared)),
+                },
+            )
+            .map(|(target_entity, _)| target_entity)
+    }
+}
+
+impl<'s> System<'s> for ChaseTargetAcquisitionSystem {
+    type SystemData = ChaseTargetAcquisitionSystemData<'s>;
+
+    fn run(&mut self, mut system_data: Self::SystemData) {
+        let target_updates = {
+            let ChaseTargetAcquisitionSystemData {
+                entities,
+                chase_opts,
+                spawn_parents,
+                target_objects,
+                ..
+            } = &system_data;
+
+            (&**entities, chase_opts)
+                .join()
+                .filter(|(_, chase_opt)| chase_opt.is_some())
+                .filter(|(entity, _)| {
+                    target_objects
+                        .get(*entity)
+                        .map(|target_object| !entities.is_alive(target_object.entity))
+                        .unwrap_or(true)
+                })
+                .map(|(entity, _)| {
+                    let parent_target_object = spawn_parents
+                        .get(entity)
+                        .and_then(|spawn_parent| target_objects.get(spawn_parent.entity))
+                        .copied()
+                        .filter(|target_object| entities.is_alive(target_object.entity));
+
+                    let target_object = parent_target_object.or_else(|| {
+                        Self::nearest_enemy(&system_data, entity).map(TargetObject::new)
+                    });
+
+                    (entity, target_object)
+                })
+                .collect::<Vec<(Entity, Option<TargetObject>)>>()
+        };
+
+        let target_objects = &mut system_data.target_objects;
+        target_updates
+            .into_iter()
+            .for_each(|(entity, target_object)| {
+                if let Some(target_object) = target_object {
+                    target_objects
+                        .insert(entity, target_object)
+                        .expect("Failed to insert `TargetObject` component.");
+                } else {
+                    target_objects.remove(entity);
+                }
+            });
+    } // kcov-ignore
+}
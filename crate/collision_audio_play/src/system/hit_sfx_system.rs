@@ -43,17 +43,18 @@ impl<'s> System<'s> for HitSfxSystem {
         if let Some(output) = output {
             events_iterator.for_each(|ev| {
                 // Play sound for `Hit` interactions.
-                let Interaction {
+                if let Interaction {
                     kind: InteractionKind::Hit(Hit { .. }),
                     ..
-                } = ev.interaction;
+                } = ev.interaction
+                {
+                    let hit_sfx = collision_sfx_map
+                        .get(&CollisionSfxId::HitNormal)
+                        .and_then(|hit_sfx_handle| source_assets.get(hit_sfx_handle));
 
-                let hit_sfx = collision_sfx_map
-                    .get(&CollisionSfxId::HitNormal)
-                    .and_then(|hit_sfx_handle| source_assets.get(hit_sfx_handle));
-
-                if let Some(hit_sfx) = hit_sfx {
-                    output.play_once(hit_sfx, VOLUME);
+                    if let Some(hit_sfx) = hit_sfx {
+                        output.play_once(hit_sfx, VOLUME);
+                    }
                 }
             });
         }
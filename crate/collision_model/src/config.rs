@@ -1,14 +1,20 @@
 //! Types representing collision configuration.
 
 pub use self::{
-    body::Body, hit::Hit, hit_limit::HitLimit, hit_repeat_delay::HitRepeatDelay,
-    interaction::Interaction, interaction_kind::InteractionKind, interactions::Interactions,
+    body::Body, grab::Grab, grab_hold_duration::GrabHoldDuration, hit::Hit, hit_limit::HitLimit,
+    hit_repeat_delay::HitRepeatDelay, interaction::Interaction, interaction_kind::InteractionKind,
+    interaction_tag::InteractionTag, interactions::Interactions, pick_up::PickUp, throw::Throw,
 };
 
 mod body;
+mod grab;
+mod grab_hold_duration;
 mod hit;
 mod hit_limit;
 mod hit_repeat_delay;
 mod interaction;
 mod interaction_kind;
+mod interaction_tag;
 mod interactions;
+mod pick_up;
+mod throw;
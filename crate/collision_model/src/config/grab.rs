@@ -0,0 +1,20 @@
+use derive_new::new;
+use kinematic_model::config::Position;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{GrabHoldDuration, Throw};
+
+/// Configuration of a grab interaction.
+///
+/// The grabbed object is held at `offset` from the grabbing object until it is thrown, or until the
+/// hold duration has passed.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Grab {
+    /// Number of ticks the grabbed object is held before it breaks free.
+    pub hold_duration: GrabHoldDuration,
+    /// Position of the grabbed object relative to the grabbing object, when facing right.
+    pub offset: Position<i32>,
+    /// Effects on the grabbed object when it is thrown.
+    pub throw: Throw,
+}
@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Default number of ticks a grabbed object is held for.
+const GRAB_HOLD_DURATION_DEFAULT: u32 = 60;
+
+/// Number of ticks a grabbed object is held before it breaks free.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[derivative(Default)]
+#[storage(VecStorage)]
+pub struct GrabHoldDuration(#[derivative(Default(value = "GRAB_HOLD_DURATION_DEFAULT"))] pub u32);
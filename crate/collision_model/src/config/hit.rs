@@ -1,7 +1,8 @@
 use derive_new::new;
 use kinematic_model::config::Acceleration;
-use object_status_model::config::StunPoints;
+use object_status_model::config::{DefensePoints, StunPoints};
 use serde::{Deserialize, Serialize};
+use status_effect_model::config::StatusEffect;
 
 use crate::config::{HitLimit, HitRepeatDelay};
 
@@ -21,4 +22,11 @@ pub struct Hit {
     pub stun: StunPoints,
     /// Acceleration to inflict on collision.
     pub acceleration: Acceleration<i32>,
+    /// Amount of health points (HP) to subtract when the hit is blocked.
+    pub chip_damage: u32,
+    /// Amount of defense points to inflict when the hit is blocked.
+    pub guard_damage: DefensePoints,
+    /// Status effect to apply on collision.
+    #[new(default)]
+    pub status_effect: Option<StatusEffect>,
 }
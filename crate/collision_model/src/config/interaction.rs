@@ -2,7 +2,7 @@ use derive_new::new;
 use serde::{Deserialize, Serialize};
 use shape_model::Volume;
 
-use crate::config::InteractionKind;
+use crate::config::{InteractionKind, InteractionTag};
 
 /// Effects of one object on another
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
@@ -16,4 +16,8 @@ pub struct Interaction {
     /// Whether this will collide with multiple objects. Defaults to `false`.
     #[serde(default)]
     pub multiple: bool,
+    /// Tags that objects may react to when hit by this interaction.
+    #[serde(default)]
+    #[new(default)]
+    pub tags: Vec<InteractionTag>,
 }
@@ -1,7 +1,7 @@
 use derivative::Derivative;
 use serde::{Deserialize, Serialize};
 
-use crate::config::Hit;
+use crate::config::{Grab, Hit, PickUp};
 
 /// Type of collision -- hit, picking weapon, grabbing, and so on.
 #[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
@@ -12,4 +12,8 @@ pub enum InteractionKind {
     /// other entities.
     #[derivative(Default)]
     Hit(Hit),
+    /// Collision latches the target object to the from entity, which may then throw it.
+    Grab(Grab),
+    /// Collision picks up the target object, which is then held by the from entity.
+    PickUp(PickUp),
 }
@@ -0,0 +1,35 @@
+use std::{
+    convert::Infallible,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Label on an `Interaction` that objects may react to (`String` newtype).
+///
+/// For example, an energy may transition to a reflected sequence when hit by an interaction tagged
+/// with `counter`.
+#[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
+pub struct InteractionTag(pub String);
+
+impl Display for InteractionTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", &self.0)
+    }
+}
+
+impl From<String> for InteractionTag {
+    fn from(s: String) -> InteractionTag {
+        InteractionTag(s)
+    }
+}
+
+impl FromStr for InteractionTag {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Infallible> {
+        Ok(InteractionTag::new(String::from(s)))
+    }
+}
@@ -0,0 +1,9 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration of a pick up interaction.
+///
+/// The picked up object is held at the picking object's weapon point until it is dropped.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct PickUp {}
@@ -0,0 +1,16 @@
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use object_status_model::config::StunPoints;
+use serde::{Deserialize, Serialize};
+
+/// Effects on a grabbed object when it is thrown.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+pub struct Throw {
+    /// Amount of health points (HP) to subtract when thrown.
+    pub hp_damage: u32,
+    /// Amount of stun points to inflict when thrown.
+    pub stun: StunPoints,
+    /// Velocity of the thrown object, when the thrower faces right.
+    pub velocity: Velocity<i32>,
+}
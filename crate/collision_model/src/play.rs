@@ -1,15 +1,22 @@
 //! Types used during game play.
 
 pub use self::{
-    collision_event::CollisionEvent, contact_event::ContactEvent, hit_event::HitEvent,
+    collision_event::CollisionEvent, contact_event::ContactEvent, grab_event::GrabEvent,
+    grab_hold_clock::GrabHoldClock, grabbed_by::GrabbedBy, grabbing::Grabbing, hit_event::HitEvent,
     hit_object_count::HitObjectCount, hit_repeat_clock::HitRepeatClock,
     hit_repeat_tracker::HitRepeatTracker, hit_repeat_trackers::HitRepeatTrackers,
+    pick_up_event::PickUpEvent,
 };
 
 mod collision_event;
 mod contact_event;
+mod grab_event;
+mod grab_hold_clock;
+mod grabbed_by;
+mod grabbing;
 mod hit_event;
 mod hit_object_count;
 mod hit_repeat_clock;
 mod hit_repeat_tracker;
 mod hit_repeat_trackers;
+mod pick_up_event;
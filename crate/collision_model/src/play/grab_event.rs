@@ -0,0 +1,18 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+use shape_model::Volume;
+
+use crate::config::Interaction;
+
+/// Event indicating an object has grabbed another.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct GrabEvent {
+    /// Entity with the interaction.
+    pub from: Entity,
+    /// Entity whose body was grabbed.
+    pub to: Entity,
+    /// Interaction of the collision.
+    pub interaction: Interaction,
+    /// Body that was grabbed.
+    pub body: Volume,
+}
@@ -0,0 +1,8 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a grabbed entity to the entity holding it.
+///
+/// This component should be attached to the grabbed entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct GrabbedBy(pub Entity);
@@ -0,0 +1,17 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+use crate::{config::Throw, play::GrabHoldClock};
+
+/// Links a grabbing entity to the entity it holds.
+///
+/// This component should be attached to the grabbing entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct Grabbing {
+    /// The grabbed entity.
+    pub entity: Entity,
+    /// Logic clock to track how long the grabbed entity has been held.
+    pub clock: GrabHoldClock,
+    /// Effects on the grabbed entity when it is thrown.
+    pub throw: Throw,
+}
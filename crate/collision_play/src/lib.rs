@@ -3,8 +3,9 @@
 //! Provides logic used during game play.
 
 pub use crate::system::{
-    CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
-    HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+    CollisionDetectionSystem, ContactDetectionSystem, GrabDetectionSystem, HitDetectionSystem,
+    HitEffectSystem, HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem,
+    HittingEffectSystem, PickUpDetectionSystem,
 };
 
 mod system;
@@ -1,16 +1,18 @@
 pub use self::{
     collision_detection_system::CollisionDetectionSystem,
-    contact_detection_system::ContactDetectionSystem, hit_detection_system::HitDetectionSystem,
-    hit_effect_system::HitEffectSystem,
+    contact_detection_system::ContactDetectionSystem, grab_detection_system::GrabDetectionSystem,
+    hit_detection_system::HitDetectionSystem, hit_effect_system::HitEffectSystem,
     hit_repeat_trackers_augment_system::HitRepeatTrackersAugmentSystem,
     hit_repeat_trackers_ticker_system::HitRepeatTrackersTickerSystem,
-    hitting_effect_system::HittingEffectSystem,
+    hitting_effect_system::HittingEffectSystem, pick_up_detection_system::PickUpDetectionSystem,
 };
 
 mod collision_detection_system;
 mod contact_detection_system;
+mod grab_detection_system;
 mod hit_detection_system;
 mod hit_effect_system;
 mod hit_repeat_trackers_augment_system;
 mod hit_repeat_trackers_ticker_system;
 mod hitting_effect_system;
+mod pick_up_detection_system;
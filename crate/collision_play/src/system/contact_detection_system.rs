@@ -6,13 +6,15 @@ use amethyst::{
 use collision_model::play::{CollisionEvent, ContactEvent};
 use derivative::Derivative;
 use derive_new::new;
+use game_rules_model::config::GameRules;
 use spawn_model::play::SpawnParent;
 use team_model::play::Team;
 
 /// Detects whether a `ContactEvent` occurs when a `CollisionEvent` happens.
 ///
 /// This system determines if contact happens or not -- e.g. objects on the same team may or may not
-/// contact each other depending on the type of `Interaction`.
+/// contact each other depending on the type of `Interaction`, and whether `GameRules` allow friendly
+/// fire.
 #[derive(Debug, Default, new)]
 pub struct ContactDetectionSystem {
     /// Reader ID for the `CollisionEvent` event channel.
@@ -26,6 +28,9 @@ pub struct ContactDetectionSystemData<'s> {
     /// `CollisionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub collision_ec: Read<'s, EventChannel<CollisionEvent>>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
     /// `SpawnParent` components.
     #[derivative(Debug = "ignore")]
     pub spawn_parents: ReadStorage<'s, SpawnParent>,
@@ -44,6 +49,7 @@ impl<'s> System<'s> for ContactDetectionSystem {
         &mut self,
         ContactDetectionSystemData {
             collision_ec,
+            game_rules,
             spawn_parents,
             teams,
             mut contact_ec,
@@ -69,7 +75,7 @@ impl<'s> System<'s> for ContactDetectionSystem {
                 let team_from = teams.get(entity_hitter);
                 let team_to = teams.get(entity_hit);
                 let dont_hit_team = if let (Some(team_from), Some(team_to)) = (team_from, team_to) {
-                    team_from != team_to
+                    game_rules.friendly_fire || team_from != team_to
                 } else {
                     true
                 };
@@ -0,0 +1,81 @@
+use amethyst::{
+    ecs::{Entity, Read, ReadStorage, System, SystemData, World, Write},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::InteractionKind,
+    play::{ContactEvent, GrabEvent, GrabbedBy, Grabbing},
+};
+use derive_new::new;
+
+/// Detects whether a `GrabEvent` occurs when there is contact between a `Grab` interaction and a
+/// `Volume`.
+#[derive(Debug, Default, new)]
+pub struct GrabDetectionSystem {
+    /// Reader ID for the `ContactEvent` event channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+type GrabDetectionSystemData<'s> = (
+    Read<'s, EventChannel<ContactEvent>>,
+    ReadStorage<'s, Grabbing>,
+    ReadStorage<'s, GrabbedBy>,
+    Write<'s, EventChannel<GrabEvent>>,
+);
+
+impl<'s> System<'s> for GrabDetectionSystem {
+    type SystemData = GrabDetectionSystemData<'s>;
+
+    fn run(&mut self, (contact_ec, grabbings, grabbed_bys, mut grab_ec): Self::SystemData) {
+        // Each object may only grab, or be grabbed by, one object at a time.
+        let mut entities_grabbing = Vec::<Entity>::new();
+        let mut entities_grabbed = Vec::<Entity>::new();
+
+        let grab_events = contact_ec
+            .read(
+                self.contact_event_rid
+                    .as_mut()
+                    .expect("Expected `contact_event_rid` to exist for `GrabDetectionSystem`."),
+            )
+            .filter(|ev| {
+                // Only `Grab` interactions send `GrabEvent`s.
+                if !matches!(ev.interaction.kind, InteractionKind::Grab(..)) {
+                    return false;
+                }
+
+                let entity_grabber = ev.from;
+                let entity_grabbed = ev.to;
+
+                let can_grab = entity_grabber != entity_grabbed
+                    && !grabbings.contains(entity_grabber)
+                    && !grabbed_bys.contains(entity_grabber)
+                    && !grabbings.contains(entity_grabbed)
+                    && !grabbed_bys.contains(entity_grabbed)
+                    && !entities_grabbing.contains(&entity_grabber)
+                    && !entities_grabbing.contains(&entity_grabbed)
+                    && !entities_grabbed.contains(&entity_grabber)
+                    && !entities_grabbed.contains(&entity_grabbed);
+
+                if can_grab {
+                    entities_grabbing.push(entity_grabber);
+                    entities_grabbed.push(entity_grabbed);
+                }
+
+                can_grab
+            })
+            .map(|ev| GrabEvent::new(ev.from, ev.to, ev.interaction.clone(), ev.body))
+            .collect::<Vec<GrabEvent>>();
+
+        grab_ec.iter_write(grab_events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
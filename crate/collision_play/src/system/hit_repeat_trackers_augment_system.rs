@@ -44,10 +44,15 @@ impl<'s> System<'s> for HitRepeatTrackersAugmentSystem {
             )
             .for_each(|ev| {
                 // Only add trackers for `Hit` interactions.
-                let Interaction {
+                let repeat_delay = if let Interaction {
                     kind: InteractionKind::Hit(Hit { repeat_delay, .. }),
                     ..
-                } = ev.interaction;
+                } = ev.interaction
+                {
+                    repeat_delay
+                } else {
+                    return;
+                };
 
                 // This assumes `ev.to` is the hit object entity. If we have a separate
                 // entity for each `Body`, then this assumption breaks, and we need to
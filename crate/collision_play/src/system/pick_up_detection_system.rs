@@ -0,0 +1,73 @@
+use amethyst::{
+    ecs::{Entity, Read, System, SystemData, World, Write},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{
+    config::InteractionKind,
+    play::{ContactEvent, PickUpEvent},
+};
+use derive_new::new;
+
+/// Detects whether a `PickUpEvent` occurs when there is contact between a `PickUp` interaction and
+/// a `Volume`.
+#[derive(Debug, Default, new)]
+pub struct PickUpDetectionSystem {
+    /// Reader ID for the `ContactEvent` event channel.
+    #[new(default)]
+    contact_event_rid: Option<ReaderId<ContactEvent>>,
+}
+
+type PickUpDetectionSystemData<'s> = (
+    Read<'s, EventChannel<ContactEvent>>,
+    Write<'s, EventChannel<PickUpEvent>>,
+);
+
+impl<'s> System<'s> for PickUpDetectionSystem {
+    type SystemData = PickUpDetectionSystemData<'s>;
+
+    fn run(&mut self, (contact_ec, mut pick_up_ec): Self::SystemData) {
+        // Each object may only pick up, or be picked up by, one object per tick.
+        let mut entities_picking_up = Vec::<Entity>::new();
+        let mut entities_picked_up = Vec::<Entity>::new();
+
+        let pick_up_events = contact_ec
+            .read(
+                self.contact_event_rid
+                    .as_mut()
+                    .expect("Expected `contact_event_rid` to exist for `PickUpDetectionSystem`."),
+            )
+            .filter(|ev| {
+                // Only `PickUp` interactions send `PickUpEvent`s.
+                if !matches!(ev.interaction.kind, InteractionKind::PickUp(..)) {
+                    return false;
+                }
+
+                let entity_picker = ev.from;
+                let entity_picked = ev.to;
+
+                let can_pick_up = entity_picker != entity_picked
+                    && !entities_picking_up.contains(&entity_picker)
+                    && !entities_picked_up.contains(&entity_picked);
+
+                if can_pick_up {
+                    entities_picking_up.push(entity_picker);
+                    entities_picked_up.push(entity_picked);
+                }
+
+                can_pick_up
+            })
+            .map(|ev| PickUpEvent::new(ev.from, ev.to, ev.interaction.clone(), ev.body))
+            .collect::<Vec<PickUpEvent>>();
+
+        pick_up_ec.iter_write(pick_up_events);
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.contact_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<ContactEvent>>()
+                .register_reader(),
+        );
+    }
+}
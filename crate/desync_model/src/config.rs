@@ -0,0 +1,5 @@
+//! User defined configuration types.
+
+pub use self::desync_config::DesyncConfig;
+
+mod desync_config;
@@ -0,0 +1,28 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for desync detection.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct DesyncConfig {
+    /// Number of ticks between each state checksum.
+    pub checksum_interval: u64,
+    /// Whether to send a dump of the game play state with each checksum.
+    ///
+    /// This is useful for debugging desyncs, but greatly increases the size of each message.
+    pub state_dump: bool,
+}
+
+impl DesyncConfig {
+    /// Default number of ticks between each state checksum.
+    pub const CHECKSUM_INTERVAL_DEFAULT: u64 = 60;
+}
+
+impl Default for DesyncConfig {
+    fn default() -> Self {
+        DesyncConfig {
+            checksum_interval: Self::CHECKSUM_INTERVAL_DEFAULT,
+            state_dump: false,
+        }
+    }
+}
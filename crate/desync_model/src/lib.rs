@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to detect when game play state diverges between devices in a network session.
+//!
+//! Each device computes a checksum of its game play state every few ticks, and sends it to the
+//! session server. The session server compares the checksums from all devices for the same tick,
+//! and notifies the session when they differ.
+
+pub mod config;
+pub mod play;
@@ -0,0 +1,10 @@
+//! Data types used at runtime.
+
+pub use self::{
+    object_state::ObjectState, object_states::ObjectStates,
+    pending_state_checksums::PendingStateChecksums,
+};
+
+mod object_state;
+mod object_states;
+mod pending_state_checksums;
@@ -0,0 +1,63 @@
+use std::fmt::{self, Display};
+
+/// Game play state of an object that is included in the state checksum.
+///
+/// Positions are stored as their bit representation, as `f32` does not implement `Hash` or `Ord`.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ObjectState {
+    /// Bit representation of the object's position.
+    pub position_bits: [u32; 3],
+    /// Health points of the object, if it has any.
+    pub health_points: Option<u32>,
+    /// Skill points of the object, if it has any.
+    pub skill_points: Option<u32>,
+    /// Index of the object's current sequence.
+    pub sequence_id: usize,
+    /// Frame index within the current sequence, if tracked.
+    pub frame_index: Option<usize>,
+}
+
+impl ObjectState {
+    /// Returns a new `ObjectState`.
+    ///
+    /// # Parameters
+    ///
+    /// * `position`: Position of the object.
+    /// * `health_points`: Health points of the object, if it has any.
+    /// * `skill_points`: Skill points of the object, if it has any.
+    /// * `sequence_id`: Index of the object's current sequence.
+    /// * `frame_index`: Frame index within the current sequence, if tracked.
+    pub fn new(
+        position: [f32; 3],
+        health_points: Option<u32>,
+        skill_points: Option<u32>,
+        sequence_id: usize,
+        frame_index: Option<usize>,
+    ) -> Self {
+        let [x, y, z] = position;
+        ObjectState {
+            position_bits: [x.to_bits(), y.to_bits(), z.to_bits()],
+            health_points,
+            skill_points,
+            sequence_id,
+            frame_index,
+        }
+    }
+
+    /// Returns the object's position.
+    pub fn position(&self) -> [f32; 3] {
+        let [x, y, z] = self.position_bits;
+        [f32::from_bits(x), f32::from_bits(y), f32::from_bits(z)]
+    }
+}
+
+impl Display for ObjectState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let [x, y, z] = self.position();
+        write!(
+            f,
+            "position: ({}, {}, {}), hp: {:?}, sp: {:?}, sequence_id: {}, frame_index: {:?}",
+            x, y, z, self.health_points, self.skill_points, self.sequence_id, self.frame_index
+        )
+    }
+}
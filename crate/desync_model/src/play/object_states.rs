@@ -0,0 +1,51 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::play::GameTick;
+use network_session_model::play::StateChecksum;
+
+use crate::play::ObjectState;
+
+/// Game play state of all objects at a tick (`Vec<ObjectState>` newtype).
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct ObjectStates(pub Vec<ObjectState>);
+
+impl ObjectStates {
+    /// Returns the `StateChecksum` of these object states.
+    ///
+    /// Entity IDs may differ between devices, so the object states are sorted before they are
+    /// hashed. This means the checksum does not depend on the order that objects were collected in.
+    ///
+    /// The hashing algorithm is only stable within the same build, so all devices in a session
+    /// must run the same version of the game.
+    ///
+    /// # Parameters
+    ///
+    /// * `tick`: Tick that the state was recorded at.
+    /// * `state_dump`: Whether to include a human readable dump of the state.
+    pub fn state_checksum(mut self, tick: GameTick, state_dump: bool) -> StateChecksum {
+        self.0.sort_unstable();
+
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        let checksum = hasher.finish();
+
+        let state_dump = if state_dump {
+            let state_dump = self
+                .0
+                .iter()
+                .map(ObjectState::to_string)
+                .collect::<Vec<String>>()
+                .join("\n");
+            Some(state_dump)
+        } else {
+            None
+        };
+
+        StateChecksum::new(tick, checksum, state_dump)
+    }
+}
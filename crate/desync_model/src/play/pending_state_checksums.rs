@@ -0,0 +1,29 @@
+use std::collections::BTreeMap;
+
+use derive_deref::{Deref, DerefMut};
+use game_input_model::play::GameTick;
+use network_session_model::play::StateChecksum;
+
+/// `StateChecksum`s that have been computed, but not yet sent to the session server.
+///
+/// In rollback mode, the state for a tick may still change when late input arrives, so checksums
+/// are held until the tick is outside the rollback window.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq)]
+pub struct PendingStateChecksums(pub BTreeMap<GameTick, StateChecksum>);
+
+impl PendingStateChecksums {
+    /// Records a `StateChecksum`, replacing any checksum previously recorded for the same tick.
+    pub fn insert(&mut self, state_checksum: StateChecksum) {
+        self.0.insert(state_checksum.tick, state_checksum);
+    }
+
+    /// Removes and returns the checksums for ticks up to and including the given tick.
+    pub fn take_confirmed(&mut self, tick: GameTick) -> Vec<StateChecksum> {
+        let unconfirmed = self.0.split_off(&GameTick(*tick + 1));
+        let confirmed = std::mem::replace(&mut self.0, unconfirmed);
+        confirmed
+            .into_iter()
+            .map(|(_, state_checksum)| state_checksum)
+            .collect()
+    }
+}
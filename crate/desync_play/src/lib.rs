@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to detect when game play state diverges between devices in a network session.
+
+pub use crate::system::{StateChecksumSystem, StateChecksumSystemData};
+
+mod system;
@@ -0,0 +1,3 @@
+pub use self::state_checksum_system::{StateChecksumSystem, StateChecksumSystemData};
+
+mod state_checksum_system;
@@ -0,0 +1,207 @@
+use amethyst::{
+    ecs::{Join, Read, ReadStorage, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use asset_model::loaded::AssetId;
+use derivative::Derivative;
+use derive_new::new;
+use desync_model::{
+    config::DesyncConfig,
+    play::{ObjectState, ObjectStates, PendingStateChecksums},
+};
+use game_input_model::play::GameTick;
+use kinematic_model::config::Position;
+use net_model::play::NetMessageEvent;
+use network_session_model::{play::SessionStatus, SessionMessageEvent};
+use object_model::play::{HealthPoints, SkillPoints};
+use rollback_model::{
+    config::{RollbackConfig, SessionSyncMode},
+    play::RollbackStatus,
+};
+use sequence_model::{loaded::SequenceId, play::FrameIndexClock};
+use state_registry::StateId;
+
+/// Computes a checksum of game play state every few ticks, and sends it to the session server.
+///
+/// This must run after the `GameTickUpdateSystem`, so the checksum is of the state at the start of
+/// the current tick.
+///
+/// In rollback mode, checksums are only sent once the tick is outside the rollback window, as the
+/// state may change when late input arrives.
+#[derive(Debug, Default, new)]
+pub struct StateChecksumSystem {
+    /// Tick that the state was last checked at.
+    #[new(default)]
+    tick_last: Option<GameTick>,
+}
+
+/// `StateChecksumSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StateChecksumSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `RollbackConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_config: Read<'s, RollbackConfig>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `DesyncConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub desync_config: Read<'s, DesyncConfig>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `PendingStateChecksums` resource.
+    #[derivative(Debug = "ignore")]
+    pub pending_state_checksums: Write<'s, PendingStateChecksums>,
+    /// `NetMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+    /// `AssetId` components.
+    #[derivative(Debug = "ignore")]
+    pub asset_ids: ReadStorage<'s, AssetId>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: ReadStorage<'s, SkillPoints>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+}
+
+impl StateChecksumSystem {
+    /// Returns the state of all game objects.
+    fn object_states(
+        asset_ids: &ReadStorage<'_, AssetId>,
+        positions: &ReadStorage<'_, Position<f32>>,
+        sequence_ids: &ReadStorage<'_, SequenceId>,
+        health_pointses: &ReadStorage<'_, HealthPoints>,
+        skill_pointses: &ReadStorage<'_, SkillPoints>,
+        frame_index_clocks: &ReadStorage<'_, FrameIndexClock>,
+    ) -> ObjectStates {
+        let object_states = (
+            asset_ids,
+            positions,
+            sequence_ids,
+            health_pointses.maybe(),
+            skill_pointses.maybe(),
+            frame_index_clocks.maybe(),
+        )
+            .join()
+            .map(
+                |(_, position, sequence_id, health_points, skill_points, frame_index_clock)| {
+                    ObjectState::new(
+                        [position.x, position.y, position.z],
+                        health_points.map(|health_points| health_points.0),
+                        skill_points.map(|skill_points| skill_points.0),
+                        sequence_id.0,
+                        frame_index_clock.map(|frame_index_clock| frame_index_clock.value),
+                    )
+                },
+            )
+            .collect::<Vec<ObjectState>>();
+
+        ObjectStates::new(object_states)
+    }
+}
+
+impl<'s> System<'s> for StateChecksumSystem {
+    type SystemData = StateChecksumSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StateChecksumSystemData {
+            state_id,
+            session_status,
+            session_sync_mode,
+            rollback_config,
+            rollback_status,
+            desync_config,
+            game_tick,
+            mut pending_state_checksums,
+            mut net_message_ec,
+            asset_ids,
+            positions,
+            sequence_ids,
+            health_pointses,
+            skill_pointses,
+            frame_index_clocks,
+        }: Self::SystemData,
+    ) {
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        let game_playing = matches!(*state_id, StateId::GamePlay | StateId::GamePlayPause);
+        if !session_established || !game_playing {
+            self.tick_last = None;
+            pending_state_checksums.clear();
+            return;
+        }
+
+        // The match does not advance while paused, so pending checksums are kept until it resumes.
+        if *state_id == StateId::GamePlayPause {
+            return;
+        }
+
+        let game_tick = *game_tick;
+        let DesyncConfig {
+            checksum_interval,
+            state_dump,
+        } = *desync_config;
+
+        // Only check the state once per tick, as this runs every frame in lockstep mode while
+        // waiting for input.
+        if self.tick_last != Some(game_tick) {
+            self.tick_last = Some(game_tick);
+
+            if checksum_interval != 0 && *game_tick % checksum_interval == 0 {
+                let state_checksum = Self::object_states(
+                    &asset_ids,
+                    &positions,
+                    &sequence_ids,
+                    &health_pointses,
+                    &skill_pointses,
+                    &frame_index_clocks,
+                )
+                .state_checksum(game_tick, state_dump);
+                pending_state_checksums.insert(state_checksum);
+            }
+        }
+
+        if rollback_status.is_resimulating() {
+            return;
+        }
+
+        let confirmed_tick = match *session_sync_mode {
+            SessionSyncMode::Lockstep => game_tick,
+            SessionSyncMode::Rollback => {
+                GameTick(game_tick.saturating_sub(rollback_config.rollback_window))
+            }
+        };
+        pending_state_checksums
+            .take_confirmed(confirmed_tick)
+            .into_iter()
+            .for_each(|state_checksum| {
+                net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
+                    SessionMessageEvent::StateChecksum(state_checksum),
+                ));
+            });
+    }
+}
@@ -5,9 +5,17 @@ pub use self::{
     energy_frame::EnergyFrame,
     energy_sequence::EnergySequence,
     energy_sequence_name::EnergySequenceName,
+    energy_sequence_name_string::EnergySequenceNameString,
+    energy_tagged_transition::EnergyTaggedTransition,
+    energy_transition::EnergyTransition,
+    energy_transitions::EnergyTransitions,
 };
 
 mod energy_definition;
 mod energy_frame;
 mod energy_sequence;
 mod energy_sequence_name;
+mod energy_sequence_name_string;
+mod energy_tagged_transition;
+mod energy_transition;
+mod energy_transitions;
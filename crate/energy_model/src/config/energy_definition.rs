@@ -3,7 +3,7 @@ use derive_new::new;
 use object_model::config::ObjectDefinition;
 use serde::{Deserialize, Serialize};
 
-use crate::config::EnergySequence;
+use crate::config::{EnergySequence, EnergyTransitions};
 
 /// Contains all of the sequences for an `Energy`.
 #[derive(Asset, Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
@@ -11,4 +11,8 @@ pub struct EnergyDefinition {
     /// Sequences of actions this object can perform.
     #[serde(flatten)]
     pub object_definition: ObjectDefinition<EnergySequence>,
+    /// Sequences to transition to on collision outcomes.
+    #[serde(default)]
+    #[new(default)]
+    pub transitions: EnergyTransitions,
 }
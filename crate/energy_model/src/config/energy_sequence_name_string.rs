@@ -0,0 +1,6 @@
+use sequence_model::config::SequenceNameString;
+
+use crate::config::EnergySequenceName;
+
+/// Alias for `SequenceNameString<EnergySequenceName>`.
+pub type EnergySequenceNameString = SequenceNameString<EnergySequenceName>;
@@ -0,0 +1,21 @@
+use collision_model::config::InteractionTag;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::EnergySequenceNameString;
+
+/// Transition for an energy when hit by an interaction with a particular tag.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct EnergyTaggedTransition {
+    /// Tag of the `Interaction` that triggers this transition.
+    pub tag: InteractionTag,
+    /// Sequence to transition to.
+    pub next: EnergySequenceNameString,
+    /// Whether the energy is reflected.
+    ///
+    /// Reflected energies flip their `Mirrored` direction and velocity, and change `Team` to the
+    /// attacker's team.
+    #[serde(default)]
+    pub reflect: bool,
+}
@@ -0,0 +1,15 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::EnergySequenceNameString;
+
+/// Sequence an energy transitions to, and whether it is reflected.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct EnergyTransition {
+    /// Sequence to transition to.
+    pub next: EnergySequenceNameString,
+    /// Whether the energy flips its `Mirrored` direction and velocity.
+    #[serde(default)]
+    pub reflect: bool,
+}
@@ -0,0 +1,27 @@
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::config::SequenceNameString;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{
+    EnergySequenceName, EnergySequenceNameString, EnergyTaggedTransition, EnergyTransition,
+};
+
+/// Sequences an energy transitions to based on collision outcomes.
+#[derive(Clone, Debug, Derivative, Deserialize, PartialEq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnergyTransitions {
+    /// Sequence to transition to when hitting another object.
+    #[derivative(Default(value = "SequenceNameString::Name(EnergySequenceName::Hitting)"))]
+    pub hitting: EnergySequenceNameString,
+    /// Sequence to transition to when hit by another object.
+    #[derivative(Default(value = "SequenceNameString::Name(EnergySequenceName::Hit)"))]
+    pub hit: EnergySequenceNameString,
+    /// Transition when the energy exits the map boundary.
+    pub map_boundary: Option<EnergyTransition>,
+    /// Transitions when hit by tagged interactions, in order of precedence.
+    ///
+    /// These take precedence over the `hit` transition.
+    pub tagged_hits: Vec<EnergyTaggedTransition>,
+}
@@ -3,6 +3,14 @@
 //! This differs from the plain configuration types as they would have been processed into the form
 //! that will be used in game.
 
-pub use self::asset_energy_definition_handle::AssetEnergyDefinitionHandle;
+pub use self::{
+    asset_energy_definition_handle::AssetEnergyDefinitionHandle,
+    energy_boundary_transition::EnergyBoundaryTransition,
+    energy_tagged_hit_transition::EnergyTaggedHitTransition,
+    energy_tagged_hit_transitions::EnergyTaggedHitTransitions,
+};
 
 mod asset_energy_definition_handle;
+mod energy_boundary_transition;
+mod energy_tagged_hit_transition;
+mod energy_tagged_hit_transitions;
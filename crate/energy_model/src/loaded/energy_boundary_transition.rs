@@ -0,0 +1,13 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequence to transition to when an energy exits the map boundary.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct EnergyBoundaryTransition {
+    /// Sequence ID to transition to.
+    pub sequence_id: SequenceId,
+    /// Whether the energy bounces off the boundary.
+    pub reflect: bool,
+}
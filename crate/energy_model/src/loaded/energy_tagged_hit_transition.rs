@@ -0,0 +1,14 @@
+use collision_model::config::InteractionTag;
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequence to transition to when an energy is hit by a tagged interaction.
+#[derive(Clone, Debug, PartialEq, new)]
+pub struct EnergyTaggedHitTransition {
+    /// Tag of the `Interaction` that triggers this transition.
+    pub tag: InteractionTag,
+    /// Sequence ID to transition to.
+    pub sequence_id: SequenceId,
+    /// Whether the energy is reflected to the attacker's team.
+    pub reflect: bool,
+}
@@ -0,0 +1,20 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use collision_model::config::Interaction;
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::loaded::EnergyTaggedHitTransition;
+
+/// Transitions for an energy when hit by tagged interactions, in order of precedence.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct EnergyTaggedHitTransitions(pub Vec<EnergyTaggedHitTransition>);
+
+impl EnergyTaggedHitTransitions {
+    /// Returns the first transition whose tag is on the given `Interaction`.
+    pub fn transition(&self, interaction: &Interaction) -> Option<&EnergyTaggedHitTransition> {
+        self.0
+            .iter()
+            .find(|transition| interaction.tags.contains(&transition.tag))
+    }
+}
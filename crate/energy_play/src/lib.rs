@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for energy objects used during game play.
+
+pub use crate::system::{
+    EnergyBoundaryTransitionSystem, EnergyBoundaryTransitionSystemData,
+    EnergyTaggedHitTransitionSystem, EnergyTaggedHitTransitionSystemData,
+};
+
+mod system;
@@ -0,0 +1,11 @@
+pub use self::{
+    energy_boundary_transition_system::{
+        EnergyBoundaryTransitionSystem, EnergyBoundaryTransitionSystemData,
+    },
+    energy_tagged_hit_transition_system::{
+        EnergyTaggedHitTransitionSystem, EnergyTaggedHitTransitionSystemData,
+    },
+};
+
+mod energy_boundary_transition_system;
+mod energy_tagged_hit_transition_system;
@@ -0,0 +1,123 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use energy_model::loaded::EnergyBoundaryTransition;
+use kinematic_model::config::Velocity;
+use map_model::play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData};
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Transitions energies to their boundary sequence when they exit the map.
+///
+/// Reflecting energies bounce off the boundary by reversing their velocity along each axis of the
+/// crossed faces.
+#[derive(Debug, Default, new)]
+pub struct EnergyBoundaryTransitionSystem {
+    /// Reader ID for the `MapBoundaryEvent` channel.
+    #[new(default)]
+    map_boundary_event_rid: Option<ReaderId<MapBoundaryEvent>>,
+}
+
+/// `EnergyBoundaryTransitionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct EnergyBoundaryTransitionSystemData<'s> {
+    /// `MapBoundaryEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub map_boundary_ec: Read<'s, EventChannel<MapBoundaryEvent>>,
+    /// `EnergyBoundaryTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub energy_boundary_transitions: ReadStorage<'s, EnergyBoundaryTransition>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for EnergyBoundaryTransitionSystem {
+    type SystemData = EnergyBoundaryTransitionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        EnergyBoundaryTransitionSystemData {
+            map_boundary_ec,
+            energy_boundary_transitions,
+            mut mirroreds,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        let map_boundary_event_rid = self
+            .map_boundary_event_rid
+            .as_mut()
+            .expect("Expected `map_boundary_event_rid` field to be set.");
+
+        map_boundary_ec.read(map_boundary_event_rid).for_each(|ev| {
+            if let MapBoundaryEvent::Exit(MapBoundaryEventData {
+                entity,
+                boundary_faces,
+            }) = ev
+            {
+                let entity = *entity;
+                let energy_boundary_transition = energy_boundary_transitions.get(entity).copied();
+
+                if let Some(EnergyBoundaryTransition {
+                    sequence_id,
+                    reflect,
+                }) = energy_boundary_transition
+                {
+                    sequence_ids
+                        .insert(entity, sequence_id)
+                        .expect("Failed to insert `SequenceId` component.");
+
+                    if !reflect {
+                        return;
+                    }
+
+                    let reflect_x = boundary_faces.contains(BoundaryFace::Left)
+                        || boundary_faces.contains(BoundaryFace::Right);
+                    let reflect_y = boundary_faces.contains(BoundaryFace::Bottom)
+                        || boundary_faces.contains(BoundaryFace::Top);
+                    let reflect_z = boundary_faces.contains(BoundaryFace::Back)
+                        || boundary_faces.contains(BoundaryFace::Front);
+
+                    if let Some(velocity) = velocities.get_mut(entity) {
+                        if reflect_x {
+                            velocity[0] = -velocity[0];
+                        }
+                        if reflect_y {
+                            velocity[1] = -velocity[1];
+                        }
+                        if reflect_z {
+                            velocity[2] = -velocity[2];
+                        }
+                    }
+                    if reflect_x {
+                        if let Some(mirrored) = mirroreds.get_mut(entity) {
+                            *mirrored = !*mirrored;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.map_boundary_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<MapBoundaryEvent>>()
+                .register_reader(),
+        );
+    }
+}
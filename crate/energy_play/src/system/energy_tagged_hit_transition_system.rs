@@ -0,0 +1,107 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::play::HitEvent;
+use derivative::Derivative;
+use derive_new::new;
+use energy_model::loaded::EnergyTaggedHitTransitions;
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+use team_model::play::Team;
+
+/// Transitions energies when they are hit by a tagged interaction.
+///
+/// This overrides the sequence set by the `HitEffectSystem`. Reflected energies flip their
+/// `Mirrored` direction and X velocity, and join the attacker's `Team`.
+#[derive(Debug, Default, new)]
+pub struct EnergyTaggedHitTransitionSystem {
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `EnergyTaggedHitTransitionSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct EnergyTaggedHitTransitionSystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `EnergyTaggedHitTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub energy_tagged_hit_transitionses: ReadStorage<'s, EnergyTaggedHitTransitions>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: WriteStorage<'s, Team>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for EnergyTaggedHitTransitionSystem {
+    type SystemData = EnergyTaggedHitTransitionSystemData<'s>;
+
+    fn run(
+        &mut self,
+        EnergyTaggedHitTransitionSystemData {
+            hit_ec,
+            energy_tagged_hit_transitionses,
+            mut mirroreds,
+            mut velocities,
+            mut teams,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        hit_ec
+            .read(
+                self.hit_event_rid.as_mut().expect(
+                    "Expected `hit_event_rid` to exist for `EnergyTaggedHitTransitionSystem`.",
+                ),
+            )
+            .for_each(|ev| {
+                let energy_tagged_hit_transition = energy_tagged_hit_transitionses
+                    .get(ev.to)
+                    .and_then(|transitions| transitions.transition(&ev.interaction));
+
+                if let Some(energy_tagged_hit_transition) = energy_tagged_hit_transition {
+                    sequence_ids
+                        .insert(ev.to, energy_tagged_hit_transition.sequence_id)
+                        .expect("Failed to insert `SequenceId` component.");
+
+                    if !energy_tagged_hit_transition.reflect {
+                        return;
+                    }
+
+                    if let Some(mirrored) = mirroreds.get_mut(ev.to) {
+                        *mirrored = !*mirrored;
+                    }
+                    if let Some(velocity) = velocities.get_mut(ev.to) {
+                        velocity[0] = -velocity[0];
+                    }
+                    if let Some(team) = teams.get(ev.from).copied() {
+                        teams
+                            .insert(ev.to, team)
+                            .expect("Failed to insert `Team` component.");
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -1,9 +1,13 @@
 use amethyst::ecs::Entity;
+use asset_model::loaded::AssetId;
 use collision_model::loaded::{HitTransition, HittingTransition};
+use energy_model::{
+    config::EnergyTransitions,
+    loaded::{EnergyBoundaryTransition, EnergyTaggedHitTransition, EnergyTaggedHitTransitions},
+};
 use map_model::play::MapUnboundedDelete;
-use sequence_model::loaded::SequenceId;
 
-use crate::EnergyComponentStorages;
+use crate::{EnergyComponentStorages, EnergySpawningResources};
 
 /// Augments an entity with `Energy` components.
 #[derive(Debug)]
@@ -12,29 +16,114 @@ pub struct EnergyEntityAugmenter;
 impl EnergyEntityAugmenter {
     /// Augments an entity with `Energy` components.
     ///
+    /// Transitions whose sequence does not exist for the energy are not inserted.
+    ///
     /// # Parameters
     ///
-    /// * `entity`: The entity to augment.
+    /// * `energy_spawning_resources`: Resources needed to spawn the energy.
     /// * `energy_component_storages`: Energy specific `Component` storages.
+    /// * `asset_id`: Asset ID of the energy.
+    /// * `entity`: The entity to augment.
     pub fn augment<'s>(
-        entity: Entity,
+        EnergySpawningResources {
+            asset_sequence_id_mappings_energy,
+            asset_energy_definition_handle,
+            energy_definition_assets,
+        }: &EnergySpawningResources<'s>,
         EnergyComponentStorages {
             map_unbounded_deletes,
             hit_transitions,
             hitting_transitions,
+            energy_boundary_transitions,
+            energy_tagged_hit_transitionses,
         }: &mut EnergyComponentStorages<'s>,
+        asset_id: AssetId,
+        entity: Entity,
     ) {
+        let energy_definition_handle =
+            asset_energy_definition_handle
+                .get(asset_id)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "Expected `EnergyDefinitionHandle` to exist for `{:?}`.",
+                        asset_id
+                    )
+                });
+        let energy_definition = energy_definition_assets
+            .get(&energy_definition_handle)
+            .expect("Expected `EnergyDefinition` to be loaded.");
+
+        let sequence_id_mappings = asset_sequence_id_mappings_energy
+            .get(asset_id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Expected `SequenceIdMappings<EnergySequenceName>` to exist for `{:?}`.",
+                    asset_id
+                )
+            });
+
+        let EnergyTransitions {
+            hitting,
+            hit,
+            map_boundary,
+            tagged_hits,
+        } = &energy_definition.transitions;
+        let hitting_transition = sequence_id_mappings
+            .id(hitting)
+            .copied()
+            .map(HittingTransition::new);
+        let hit_transition = sequence_id_mappings
+            .id(hit)
+            .copied()
+            .map(HitTransition::new);
+        let energy_boundary_transition = map_boundary.as_ref().and_then(|map_boundary| {
+            let reflect = map_boundary.reflect;
+            sequence_id_mappings
+                .id(&map_boundary.next)
+                .copied()
+                .map(|sequence_id| EnergyBoundaryTransition::new(sequence_id, reflect))
+        });
+        let energy_tagged_hit_transitions = tagged_hits
+            .iter()
+            .filter_map(|tagged_hit| {
+                sequence_id_mappings
+                    .id(&tagged_hit.next)
+                    .copied()
+                    .map(|sequence_id| {
+                        EnergyTaggedHitTransition::new(
+                            tagged_hit.tag.clone(),
+                            sequence_id,
+                            tagged_hit.reflect,
+                        )
+                    })
+            })
+            .collect::<Vec<EnergyTaggedHitTransition>>();
+
         map_unbounded_deletes
             .insert(entity, MapUnboundedDelete::default())
             .expect("Failed to insert `MapUnboundedDelete` component.");
-
-        // Hack: this should be read off an asset.
-        hit_transitions
-            .insert(entity, HitTransition::new(SequenceId::new(2)))
-            .expect("Failed to insert `HitTransition` component.");
-        hitting_transitions
-            .insert(entity, HittingTransition::new(SequenceId::new(2)))
-            .expect("Failed to insert `HittingTransition` component.");
-        // End Hack.
+        if let Some(hit_transition) = hit_transition {
+            hit_transitions
+                .insert(entity, hit_transition)
+                .expect("Failed to insert `HitTransition` component.");
+        }
+        if let Some(hitting_transition) = hitting_transition {
+            hitting_transitions
+                .insert(entity, hitting_transition)
+                .expect("Failed to insert `HittingTransition` component.");
+        }
+        if let Some(energy_boundary_transition) = energy_boundary_transition {
+            energy_boundary_transitions
+                .insert(entity, energy_boundary_transition)
+                .expect("Failed to insert `EnergyBoundaryTransition` component.");
+        }
+        if !energy_tagged_hit_transitions.is_empty() {
+            energy_tagged_hit_transitionses
+                .insert(
+                    entity,
+                    EnergyTaggedHitTransitions::new(energy_tagged_hit_transitions),
+                )
+                .expect("Failed to insert `EnergyTaggedHitTransitions` component.");
+        }
     }
 }
@@ -3,7 +3,8 @@
 //! Provides the prefab types and processing logic for energys.
 
 pub use crate::{
-    energy_entity_augmenter::EnergyEntityAugmenter, system_data::EnergyComponentStorages,
+    energy_entity_augmenter::EnergyEntityAugmenter,
+    system_data::{EnergyComponentStorages, EnergySpawningResources},
 };
 
 mod energy_entity_augmenter;
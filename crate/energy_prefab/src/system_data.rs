@@ -1,3 +1,7 @@
-pub use self::energy_component_storages::EnergyComponentStorages;
+pub use self::{
+    energy_component_storages::EnergyComponentStorages,
+    energy_spawning_resources::EnergySpawningResources,
+};
 
 mod energy_component_storages;
+mod energy_spawning_resources;
@@ -4,6 +4,7 @@ use amethyst::{
 };
 use collision_model::loaded::{HitTransition, HittingTransition};
 use derivative::Derivative;
+use energy_model::loaded::{EnergyBoundaryTransition, EnergyTaggedHitTransitions};
 use map_model::play::MapUnboundedDelete;
 
 /// Energy specific `Component` storages.
@@ -22,4 +23,10 @@ pub struct EnergyComponentStorages<'s> {
     /// `HittingTransition` components.
     #[derivative(Debug = "ignore")]
     pub hitting_transitions: WriteStorage<'s, HittingTransition>,
+    /// `EnergyBoundaryTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub energy_boundary_transitions: WriteStorage<'s, EnergyBoundaryTransition>,
+    /// `EnergyTaggedHitTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub energy_tagged_hit_transitionses: WriteStorage<'s, EnergyTaggedHitTransitions>,
 }
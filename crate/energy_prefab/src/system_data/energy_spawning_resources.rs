@@ -0,0 +1,26 @@
+use amethyst::{
+    assets::AssetStorage,
+    ecs::{Read, World},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use energy_model::{
+    config::{EnergyDefinition, EnergySequenceName},
+    loaded::AssetEnergyDefinitionHandle,
+};
+use sequence_model::loaded::AssetSequenceIdMappings;
+
+/// Resources used to spawn energy entities.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct EnergySpawningResources<'s> {
+    /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetEnergyDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_energy_definition_handle: Read<'s, AssetEnergyDefinitionHandle>,
+    /// `EnergyDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub energy_definition_assets: Read<'s, AssetStorage<EnergyDefinition>>,
+}
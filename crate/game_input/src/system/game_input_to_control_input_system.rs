@@ -1,6 +1,6 @@
 use amethyst::{
     derive::SystemDesc,
-    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
@@ -14,8 +14,13 @@ use game_input_model::{
     },
     GameInputEvent,
 };
+use network_session_model::play::SessionCondition;
+use state_registry::StateItemEntities;
 
 /// Sends `ControlInputEvent`s based on the `InputHandler` state.
+///
+/// While game play is paused, `InputControlled` entities only receive input if they are state item
+/// entities, such as the pause menu's controller entities.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(GameInputToControlInputSystemDesc))]
 pub struct GameInputToControlInputSystem {
@@ -37,6 +42,12 @@ pub struct GameInputToControlInputSystemData<'s> {
     /// `Entities` resource.
     #[derivative(Debug = "ignore")]
     pub entities: Entities<'s>,
+    /// `SessionCondition` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_condition: Read<'s, SessionCondition>,
+    /// `StateItemEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_item_entities: Read<'s, StateItemEntities>,
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
@@ -56,11 +67,17 @@ impl<'s> System<'s> for GameInputToControlInputSystem {
         GameInputToControlInputSystemData {
             game_input_ec,
             entities,
+            session_condition,
+            state_item_entities,
             input_controlleds,
             shared_input_controlleds,
             mut control_game_input_ec,
         }: Self::SystemData,
     ) {
+        let paused = session_condition.is_paused();
+        let input_enabled =
+            |entity: &Entity| !paused || state_item_entities.entities.contains(entity);
+
         game_input_ec
             .read(&mut self.input_event_rid)
             .for_each(|ev| {
@@ -83,6 +100,7 @@ impl<'s> System<'s> for GameInputToControlInputSystem {
                                     None
                                 }
                             })
+                            .filter(input_enabled)
                             .chain(shared_input_controlled_entities)
                             .map(|entity| {
                                 ControlInputEvent::ControlActionPress(ControlActionEventData {
@@ -109,6 +127,7 @@ impl<'s> System<'s> for GameInputToControlInputSystem {
                                     None
                                 }
                             })
+                            .filter(input_enabled)
                             .chain(shared_input_controlled_entities)
                             .map(|entity| {
                                 ControlInputEvent::ControlActionRelease(ControlActionEventData {
@@ -138,6 +157,7 @@ impl<'s> System<'s> for GameInputToControlInputSystem {
                                     None
                                 }
                             })
+                            .filter(input_enabled)
                             .chain(shared_input_controlled_entities)
                             .map(|entity| {
                                 ControlInputEvent::AxisMoved(AxisMoveEventData {
@@ -11,8 +11,12 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::{config::ControlBindings, GameInputEvent};
 use network_session_model::play::SessionStatus;
+use replay_model::play::ReplayMode;
+use state_registry::StateId;
 
 /// Sends `GameInputEvent`s based on a subset of `InputEvent`s.
+///
+/// Live input is ignored during game play when a replay is being played back.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(InputToGameInputSystemDesc))]
 pub struct InputToGameInputSystem {
@@ -27,6 +31,12 @@ pub struct InputToGameInputSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
     /// `InputEvent<ControlBindings>` channel.
     #[derivative(Debug = "ignore")]
     pub input_ec: Read<'s, EventChannel<InputEvent<ControlBindings>>>,
@@ -42,6 +52,8 @@ impl<'s> System<'s> for InputToGameInputSystem {
         &mut self,
         InputToGameInputSystemData {
             session_status,
+            replay_mode,
+            state_id,
             input_ec,
             mut game_input_ec,
         }: Self::SystemData,
@@ -49,9 +61,11 @@ impl<'s> System<'s> for InputToGameInputSystem {
         let input_events = input_ec.read(&mut self.input_event_rid);
 
         let session_status = &*session_status;
+        let replay_playing = *replay_mode == ReplayMode::Playback && *state_id == StateId::GamePlay;
 
         if session_status != &SessionStatus::HostEstablished
             && session_status != &SessionStatus::JoinEstablished
+            && !replay_playing
         {
             input_events
                 .filter_map(|input_event| GameInputEvent::try_from(input_event).ok())
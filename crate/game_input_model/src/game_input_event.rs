@@ -2,7 +2,7 @@ use amethyst::input::InputEvent;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
-use crate::config::{ControlBindings, PlayerActionControl, PlayerAxisControl};
+use crate::config::{ControlBindings, ControllerId, PlayerActionControl, PlayerAxisControl};
 
 /// Input events that came through a device, whether local or remote.
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
@@ -21,6 +21,17 @@ pub enum GameInputEvent {
     ActionReleased(PlayerActionControl),
 }
 
+impl GameInputEvent {
+    /// Returns the ID of the controller that this event came from.
+    pub fn controller_id(&self) -> ControllerId {
+        match self {
+            GameInputEvent::AxisMoved { axis, .. } => axis.player,
+            GameInputEvent::ActionPressed(player_action_control)
+            | GameInputEvent::ActionReleased(player_action_control) => player_action_control.player,
+        }
+    }
+}
+
 impl<'event> TryFrom<&'event InputEvent<ControlBindings>> for GameInputEvent {
     type Error = &'event InputEvent<ControlBindings>;
 
@@ -4,9 +4,10 @@ pub use self::{
     axis_move_event_data::AxisMoveEventData, button_input_controlled::ButtonInputControlled,
     control_action_event_data::ControlActionEventData, control_input_event::ControlInputEvent,
     controller_id_offset::ControllerIdOffset, controller_input::ControllerInput,
-    game_input_model_error::GameInputModelError, input_controlled::InputControlled,
-    move_direction::MoveDirection, normal_input_controlled::NormalInputControlled,
-    shared_input_controlled::SharedInputControlled,
+    game_input_model_error::GameInputModelError, game_tick::GameTick,
+    input_controlled::InputControlled, move_direction::MoveDirection,
+    normal_input_controlled::NormalInputControlled, shared_input_controlled::SharedInputControlled,
+    ticked_game_input_event::TickedGameInputEvent,
 };
 
 mod axis_move_event_data;
@@ -16,7 +17,9 @@ mod control_input_event;
 mod controller_id_offset;
 mod controller_input;
 mod game_input_model_error;
+mod game_tick;
 mod input_controlled;
 mod move_direction;
 mod normal_input_controlled;
 mod shared_input_controlled;
+mod ticked_game_input_event;
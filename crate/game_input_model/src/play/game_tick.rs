@@ -0,0 +1,33 @@
+use std::fmt::{self, Display};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Number of logic ticks since game play began (`u64` newtype).
+///
+/// This is used to identify which tick a `GameInputEvent` applies to, so that input from remote
+/// devices can be applied deterministically.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Deref,
+    DerefMut,
+    Deserialize,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    new,
+)]
+pub struct GameTick(pub u64);
+
+impl Display for GameTick {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
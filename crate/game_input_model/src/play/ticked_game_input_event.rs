@@ -0,0 +1,13 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{play::GameTick, GameInputEvent};
+
+/// `GameInputEvent` stamped with the tick it should be applied on.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct TickedGameInputEvent {
+    /// Tick that the event should be applied on.
+    pub tick: GameTick,
+    /// The `GameInputEvent`.
+    pub game_input_event: GameInputEvent,
+}
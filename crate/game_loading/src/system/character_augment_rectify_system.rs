@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     assets::PrefabData,
     ecs::{
@@ -40,6 +41,9 @@ pub struct CharacterAugmentRectifySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `CameraTracked` components.
     #[derivative(Debug = "ignore")]
     pub camera_trackeds: WriteStorage<'s, CameraTracked>,
@@ -55,7 +59,8 @@ pub struct CharacterAugmentRectifySystemData<'s> {
 }
 
 impl CharacterAugmentRectifySystem {
-    fn hp_bar_augment(world: &World, game_object_entity: Entity) {
+    /// Spawns an HP bar entity that tracks the given game object entity.
+    pub fn hp_bar_augment(world: &World, game_object_entity: Entity) {
         let (entities, mut hp_bar_prefab_system_data, mut game_play_entities) = world
             .system_data::<(
                 Entities<'_>,
@@ -73,7 +78,8 @@ impl CharacterAugmentRectifySystem {
             .expect("Failed to insert `GamePlayEntity` component.");
     }
 
-    fn cp_bar_augment(world: &World, game_object_entity: Entity) {
+    /// Spawns a CP bar entity that tracks the given game object entity.
+    pub fn cp_bar_augment(world: &World, game_object_entity: Entity) {
         let (entities, mut cp_bar_prefab_system_data, mut game_play_entities) = world
             .system_data::<(
                 Entities<'_>,
@@ -103,6 +109,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
             map_selection,
             asset_map_bounds,
             input_controlleds,
+            ai_controlleds,
             mut camera_trackeds,
             mut positions,
             lazy_update,
@@ -138,7 +145,7 @@ impl<'s> System<'s> for CharacterAugmentRectifySystem {
         // This `Position` moves the entity to the middle of a screen wide map.
         let position = Position::<f32>::new(width / 2., height / 2., depth / 2.);
 
-        (&entities, &input_controlleds)
+        (&entities, input_controlleds.mask() | ai_controlleds.mask())
             .join()
             .for_each(|(entity, _)| {
                 // Set character `position` based on the map.
@@ -1,3 +1,4 @@
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Entity, Read, System, World, Write, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -11,8 +12,12 @@ use derivative::Derivative;
 use derive_new::new;
 use game_input_model::play::InputControlled;
 use game_model::play::GameEntities;
+use game_rules_model::{
+    config::{GameRules, TeamAssignment},
+    play::Stocks,
+};
 use object_type::ObjectType;
-use team_model::play::{IndependentCounter, Team};
+use team_model::play::{IndependentCounter, Team, TeamCounter};
 
 use crate::{CharacterAugmentStatus, GameLoadingStatus};
 
@@ -33,6 +38,9 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `GameLoadingStatus` resource.
     #[derivative(Debug = "ignore")]
     pub game_loading_status: Write<'s, GameLoadingStatus>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
     /// `IndependentCounter` resource.
     #[derivative(Debug = "ignore")]
     pub independent_counter: Write<'s, IndependentCounter>,
@@ -57,9 +65,15 @@ pub struct CharacterSelectionSpawningSystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: WriteStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
     /// `Team` components.
     #[derivative(Debug = "ignore")]
     pub teams: WriteStorage<'s, Team>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
     /// `GameEntities` resource.
     #[derivative(Debug = "ignore")]
     pub game_entities: Write<'s, GameEntities>,
@@ -74,6 +88,7 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             entities,
             character_selections,
             mut game_loading_status,
+            game_rules,
             mut independent_counter,
             asset_item_ids,
             asset_id_mappings,
@@ -82,7 +97,9 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
             character_spawning_resources,
             mut character_component_storages,
             mut input_controlleds,
+            mut ai_controlleds,
             mut teams,
+            mut stockses,
             mut game_entities,
         }: Self::SystemData,
     ) {
@@ -123,15 +140,35 @@ impl<'s> System<'s> for CharacterSelectionSpawningSystem {
                 item_ids
                     .insert(entity, item_id)
                     .expect("Failed to insert `ItemId` for character.");
-                input_controlleds
-                    .insert(entity, InputControlled::new(*controller_id))
-                    .expect("Failed to insert `InputControlled` for character.");
+                if let Some(ai_difficulty) = character_selections.ai_difficulties.get(controller_id)
+                {
+                    ai_controlleds
+                        .insert(entity, AiControlled::new(*controller_id, *ai_difficulty))
+                        .expect("Failed to insert `AiControlled` for character.");
+                } else {
+                    input_controlleds
+                        .insert(entity, InputControlled::new(*controller_id))
+                        .expect("Failed to insert `InputControlled` for character.");
+                }
+                // Teams picked during character selection are used, unless the rules place every
+                // player on the same team.
+                let team_counter = character_selections.team_counters.get(controller_id);
+                let team = match (game_rules.team_assignment, team_counter) {
+                    (TeamAssignment::Single, _) => Team::Number(TeamCounter::new(0)),
+                    (_, Some(team_counter)) => Team::Number(*team_counter),
+                    (TeamAssignment::Independent, None) => {
+                        Team::Independent(independent_counter.get_and_increment())
+                    }
+                    (TeamAssignment::RoundRobin { team_count }, None) => {
+                        Team::Number(TeamCounter::new(*controller_id % team_count.max(1)))
+                    }
+                };
                 teams
-                    .insert(
-                        entity,
-                        Team::Independent(independent_counter.get_and_increment()),
-                    )
+                    .insert(entity, team)
                     .expect("Failed to insert `Team` for character.");
+                stockses
+                    .insert(entity, Stocks::new(game_rules.stocks))
+                    .expect("Failed to insert `Stocks` for character.");
 
                 entity
             })
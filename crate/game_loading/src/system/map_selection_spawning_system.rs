@@ -47,7 +47,7 @@ impl<'s> System<'s> for MapSelectionSpawningSystem {
             return;
         }
 
-        // TODO: implement Random
+        // *Random* map selections are resolved when the map selection is confirmed.
         let asset_id = map_selection
             .asset_id()
             .expect("Expected map selection to have an `AssetId`.");
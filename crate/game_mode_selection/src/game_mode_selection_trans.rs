@@ -4,11 +4,13 @@ use amethyst::{GameData, State, Trans};
 use application_event::AppEvent;
 use character_selection::{
     CharacterSelectionStateBuilder, CharacterSelectionStateDelegate, CharacterSelectionSystem,
+    CswTeamTintSystem,
 };
 use control_settings::ControlSettingsState;
 use game_loading::GameLoadingState;
 use game_mode_selection_model::GameModeIndex;
 use game_play::GamePlayState;
+use game_rules_selection::{GameRulesSelectionStateBuilder, GameRulesSelectionStateDelegate};
 use map_selection::{MapSelectionBundle, MapSelectionStateBuilder, MapSelectionStateDelegate};
 use network_mode_selection::{NetworkModeSelectionStateBuilder, NetworkModeSelectionStateDelegate};
 
@@ -25,8 +27,8 @@ impl GameModeSelectionTrans {
     pub fn trans(game_mode_index: GameModeIndex) -> Trans<GameData<'static, 'static>, AppEvent> {
         match game_mode_index {
             GameModeIndex::StartGame => {
-                let character_selection_state = Self::character_selection_state();
-                Trans::Push(character_selection_state)
+                let game_rules_selection_state = Self::game_rules_selection_state();
+                Trans::Push(game_rules_selection_state)
             }
             GameModeIndex::NetworkPlay => {
                 let network_mode_selection_state = Self::network_mode_selection_state();
@@ -37,7 +39,7 @@ impl GameModeSelectionTrans {
         }
     } // kcov-ignore
 
-    fn character_selection_state() -> Box<dyn State<GameData<'static, 'static>, AppEvent>> {
+    fn game_rules_selection_state() -> Box<dyn State<GameData<'static, 'static>, AppEvent>> {
         // kcov-ignore-start
         let game_play_fn = || Box::new(GamePlayState::new());
         let game_loading_fn = move || Box::new(GameLoadingState::new(game_play_fn));
@@ -49,15 +51,28 @@ impl GameModeSelectionTrans {
 
             Box::new(state)
         };
+        let character_selection_fn = move || {
+            let state = CharacterSelectionStateBuilder::new(CharacterSelectionStateDelegate::new(
+                map_selection_fn,
+            ))
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            )
+            .with_system(
+                CswTeamTintSystem::new(),
+                any::type_name::<CswTeamTintSystem>(),
+                &[any::type_name::<CharacterSelectionSystem>()],
+            )
+            .build();
+
+            Box::new(state)
+        };
         // kcov-ignore-end
-        let state = CharacterSelectionStateBuilder::new(CharacterSelectionStateDelegate::new(
-            map_selection_fn,
+        let state = GameRulesSelectionStateBuilder::new(GameRulesSelectionStateDelegate::new(
+            character_selection_fn,
         ))
-        .with_system(
-            CharacterSelectionSystem::new(),
-            any::type_name::<CharacterSelectionSystem>(),
-            &[],
-        )
         .build();
 
         Box::new(state)
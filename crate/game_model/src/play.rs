@@ -1,5 +1,7 @@
 //! Types used for game play.
 
-pub use self::game_entities::GameEntities;
+pub use self::{game_entities::GameEntities, game_rng::GameRng, rng_seed::RngSeed};
 
 mod game_entities;
+mod game_rng;
+mod rng_seed;
@@ -0,0 +1,23 @@
+use derive_deref::{Deref, DerefMut};
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::play::RngSeed;
+
+/// Random number generator for random choices, such as *Random* asset selections.
+///
+/// This is seeded from the `RngSeed`, so choices are reproducible given the same seed.
+#[derive(Clone, Debug, Deref, DerefMut)]
+pub struct GameRng(pub StdRng);
+
+impl GameRng {
+    /// Returns a new `GameRng` seeded from the given `RngSeed`.
+    pub fn new(rng_seed: RngSeed) -> Self {
+        GameRng(StdRng::seed_from_u64(*rng_seed))
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        GameRng::new(RngSeed::default())
+    }
+}
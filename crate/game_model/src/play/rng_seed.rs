@@ -0,0 +1,8 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Seed for random number generation during a game.
+///
+/// Recorded with replays so that random choices are the same when a game is played back.
+#[derive(Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq, Eq, new)]
+pub struct RngSeed(pub u64);
@@ -1,5 +1,6 @@
 use std::any;
 
+use ai_play::{AiControllerSystem, BehaviourAiController};
 use amethyst::{
     core::{bundle::SystemBundle, SystemDesc, SystemExt},
     ecs::{DispatcherBuilder, World},
@@ -7,7 +8,9 @@ use amethyst::{
 };
 use audio_model::loaded::{SourceSequence, SourceSequenceHandles};
 use audio_play::SequenceAudioPlaySystem;
-use camera_play::{CameraTrackingSystem, CameraVelocitySystem};
+use camera_play::{
+    CameraFreeSystem, CameraFreeSystemDesc, CameraTrackingSystem, CameraVelocitySystem,
+};
 use character_model::{
     config::CharacterIrr,
     loaded::{CharacterIrs, CharacterIrsHandles},
@@ -16,16 +19,19 @@ use charge_play::{
     ChargeIncrementSystem, ChargeInitializeDelaySystem, ChargeInitializeDetectionSystem,
     ChargeRetentionSystem, ChargeUsageSystem,
 };
-use chase_play::StickToTargetObjectSystem;
+use chase_model::loaded::{ChaseSequence, ChaseSequenceHandles};
+use chase_play::{ChaseSystem, ChaseTargetAcquisitionSystem, StickToTargetObjectSystem};
 use collision_audio_play::HitSfxSystem;
 use collision_model::loaded::{
     BodySequence, BodySequenceHandles, InteractionsSequence, InteractionsSequenceHandles,
 };
 use collision_play::{
-    CollisionDetectionSystem, ContactDetectionSystem, HitDetectionSystem, HitEffectSystem,
-    HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem, HittingEffectSystem,
+    CollisionDetectionSystem, ContactDetectionSystem, GrabDetectionSystem, HitDetectionSystem,
+    HitEffectSystem, HitRepeatTrackersAugmentSystem, HitRepeatTrackersTickerSystem,
+    HittingEffectSystem, PickUpDetectionSystem,
 };
 use derive_new::new;
+use energy_play::{EnergyBoundaryTransitionSystem, EnergyTaggedHitTransitionSystem};
 use game_input_model::play::ControllerInput;
 use game_play_hud::{CpBarUpdateSystem, HpBarUpdateSystem};
 use input_reaction_model::{
@@ -36,6 +42,8 @@ use input_reaction_play::{
     ButtonInputReactionsTransitionSystem, ButtonInputReactionsTransitionSystemDesc,
     InputReactionsTransitionSystem, InteractableObjectSyncSystem,
 };
+use item_model::loaded::{WeaponPointSequence, WeaponPointSequenceHandles};
+use item_play::{ItemHeldSystem, ItemPickUpSystem, ItemThrowSystem};
 use kinematic_model::{
     config::Position,
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
@@ -48,7 +56,7 @@ use network_session_model::play::SessionCondition;
 use object_play::{
     ObjectAccelerationSystem, ObjectGravitySystem, ObjectGroundingSystem, ObjectMirroringSystem,
 };
-use object_status_play::StunPointsReductionSystem;
+use object_status_play::{DefensePointsReductionSystem, StunPointsReductionSystem};
 use sequence_model::loaded::{SequenceEndTransitions, WaitSequence, WaitSequenceHandles};
 use sequence_play::{
     FrameComponentUpdateSystem, SequenceComponentUpdateSystem, SequenceEndTransitionSystem,
@@ -62,13 +70,16 @@ use sprite_model::loaded::{
 };
 use sprite_play::SpriteScaleUpdateSystem;
 use state_registry::StateId;
+use status_effect_play::{StatusEffectApplySystem, StatusEffectTickSystem, StatusEffectTintSystem};
 use tracker::LastTrackerSystem;
 
 use crate::{
-    CharacterHitEffectSystem, CharacterSequenceUpdateSystem, FrameFreezeClockAugmentSystem,
+    CharacterGrabEffectSystem, CharacterGrabReleaseSystem, CharacterHitEffectSystem,
+    CharacterRespawnSystem, CharacterSequenceUpdateSystem, FrameFreezeClockAugmentSystem,
     GamePlayEndDetectionSystem, GamePlayEndTransitionDelaySystem, GamePlayEndTransitionSystem,
-    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GroundingFrictionSystem,
-    ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    GamePlayRemovalAugmentSystem, GamePlayStatusDisplaySystem, GameTickUpdateSystem,
+    GroundingFrictionSystem, ObjectKinematicsUpdateSystem, ObjectTransformUpdateSystem,
+    PlayerStatsUpdateSystem, SurvivalWaveSpawnSystem, TeamTintSystem,
 };
 
 /// Adds the object type update systems to the provided dispatcher.
@@ -111,11 +122,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         sequence_component_update_system!(BodySequenceHandles);
         sequence_component_update_system!(InteractionsSequenceHandles);
         sequence_component_update_system!(SpawnsSequenceHandles);
+        sequence_component_update_system!(ChaseSequenceHandles);
         sequence_component_update_system!(SequenceEndTransitions);
         sequence_component_update_system!(TintSequenceHandles);
         sequence_component_update_system!(ScaleSequenceHandles);
         sequence_component_update_system!(CharacterIrsHandles);
         sequence_component_update_system!(InputReactionsSequenceHandles);
+        sequence_component_update_system!(WeaponPointSequenceHandles);
 
         // TODO: The `SequenceUpdateSystem`s depend on the following systems:
         //
@@ -153,10 +166,12 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
         frame_component_update_system!(BodySequence);
         frame_component_update_system!(InteractionsSequence);
         frame_component_update_system!(SpawnsSequence);
+        frame_component_update_system!(ChaseSequence);
         frame_component_update_system!(TintSequence);
         frame_component_update_system!(ScaleSequence);
         frame_component_update_system!(CharacterIrs);
         frame_component_update_system!(InputReactionsSequence);
+        frame_component_update_system!(WeaponPointSequence);
 
         builder.add(
             FrameFreezeClockAugmentSystem::new(),
@@ -218,13 +233,52 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[],
         ); // kcov-ignore
 
+        // Chasing objects acquire a target and steer relative to it.
+        builder.add(
+            ChaseTargetAcquisitionSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<ChaseTargetAcquisitionSystem>(),
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            ChaseSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<ChaseSystem>(),
+            &[
+                any::type_name::<ObjectAccelerationSystem>(),
+                any::type_name::<ChaseTargetAcquisitionSystem>(),
+            ],
+        ); // kcov-ignore
+
+        // Status effect damage over time, and vel adjustments.
+        builder.add(
+            StatusEffectTickSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<StatusEffectTickSystem>(),
+            &[
+                any::type_name::<ObjectAccelerationSystem>(),
+                any::type_name::<ChaseSystem>(),
+            ],
+        ); // kcov-ignore
+        builder.add(
+            StatusEffectTintSystem::new(),
+            any::type_name::<StatusEffectTintSystem>(),
+            &[any::type_name::<StatusEffectTickSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            TeamTintSystem::new(),
+            any::type_name::<TeamTintSystem>(),
+            &[any::type_name::<StatusEffectTintSystem>()],
+        ); // kcov-ignore
+
         // pos += vel
         // This must be between the `FrameFreezeClockAugmentSystem` and `SequenceUpdateSystem`s
         // since it needs to wait for the `FrameFreezeClock` to tick.
         builder.add(
             ObjectKinematicsUpdateSystem::new().pausable(SessionCondition::Ready),
             any::type_name::<ObjectKinematicsUpdateSystem>(),
-            &[any::type_name::<ObjectAccelerationSystem>()],
+            &[
+                any::type_name::<ObjectAccelerationSystem>(),
+                any::type_name::<ChaseSystem>(),
+                any::type_name::<StatusEffectTickSystem>(),
+            ],
         ); // kcov-ignore
 
         // `Position` correction based on margins.
@@ -270,6 +324,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<MapOutOfBoundsClockAugmentSystem>(),
             &[any::type_name::<MapOutOfBoundsDeletionSystem>()],
         ); // kcov-ignore
+        builder.add(
+            EnergyBoundaryTransitionSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<EnergyBoundaryTransitionSystem>(),
+            &[any::type_name::<MapEnterExitDetectionSystem>()],
+        ); // kcov-ignore
 
         builder.add(
             ObjectTransformUpdateSystem::new(),
@@ -289,10 +348,20 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<ObjectMirroringSystem>(),
             &[any::type_name::<ObjectTransformUpdateSystem>()],
         ); // kcov-ignore
+
+        // Held items are moved to the holder's weapon point before sticking to the holder.
+        builder.add(
+            ItemHeldSystem::new(),
+            any::type_name::<ItemHeldSystem>(),
+            &[any::type_name::<ObjectTransformUpdateSystem>()],
+        ); // kcov-ignore
         builder.add(
             StickToTargetObjectSystem::new(),
             any::type_name::<StickToTargetObjectSystem>(),
-            &[any::type_name::<ObjectTransformUpdateSystem>()],
+            &[
+                any::type_name::<ObjectTransformUpdateSystem>(),
+                any::type_name::<ItemHeldSystem>(),
+            ],
         ); // kcov-ignore
 
         // Reduces charge when not charging.
@@ -309,6 +378,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[],
         ); // kcov-ignore
 
+        // Reduces `DefensePoints` each tick.
+        builder.add(
+            DefensePointsReductionSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<DefensePointsReductionSystem>(),
+            &[],
+        ); // kcov-ignore
+
         builder.add(
             HitRepeatTrackersTickerSystem::new().pausable(SessionCondition::Ready),
             any::type_name::<HitRepeatTrackersTickerSystem>(),
@@ -324,6 +400,7 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<CollisionDetectionSystem>(),
             &[
                 any::type_name::<StunPointsReductionSystem>(),
+                any::type_name::<DefensePointsReductionSystem>(),
                 any::type_name::<HitRepeatTrackersTickerSystem>(),
             ],
         ); // kcov-ignore
@@ -337,6 +414,16 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             any::type_name::<HitDetectionSystem>(),
             &[any::type_name::<ContactDetectionSystem>()],
         ); // kcov-ignore
+        builder.add(
+            GrabDetectionSystem::new(),
+            any::type_name::<GrabDetectionSystem>(),
+            &[any::type_name::<ContactDetectionSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            PickUpDetectionSystem::new(),
+            any::type_name::<PickUpDetectionSystem>(),
+            &[any::type_name::<ContactDetectionSystem>()],
+        ); // kcov-ignore
 
         builder.add_barrier();
 
@@ -380,13 +467,50 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &any::type_name::<InputReactionsTransitionSystem<CharacterIrr>>(),
             &[any::type_name::<CharacterSequenceUpdateSystem>()],
         ); // kcov-ignore
+
+        // Status effects are applied before hits change the hit character's sequence, so that
+        // blocked hits can be detected.
         builder.add(
-            CharacterHitEffectSystem::new(),
-            any::type_name::<CharacterHitEffectSystem>(),
+            StatusEffectApplySystem::new(),
+            any::type_name::<StatusEffectApplySystem>(),
             &[&any::type_name::<
                 InputReactionsTransitionSystem<CharacterIrr>,
             >()],
         ); // kcov-ignore
+        builder.add(
+            CharacterHitEffectSystem::new(),
+            any::type_name::<CharacterHitEffectSystem>(),
+            &[
+                &any::type_name::<InputReactionsTransitionSystem<CharacterIrr>>(),
+                any::type_name::<StatusEffectApplySystem>(),
+            ],
+        ); // kcov-ignore
+
+        // Grabs are applied after hits, so that a character that is both hit and grabbed in the
+        // same tick is held in its `Grabbed` sequence.
+        builder.add(
+            CharacterGrabEffectSystem::new(),
+            any::type_name::<CharacterGrabEffectSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            CharacterGrabReleaseSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<CharacterGrabReleaseSystem>(),
+            &[any::type_name::<CharacterGrabEffectSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            ItemPickUpSystem::new(),
+            any::type_name::<ItemPickUpSystem>(),
+            &[any::type_name::<CharacterHitEffectSystem>()],
+        ); // kcov-ignore
+        builder.add(
+            ItemThrowSystem::new(),
+            any::type_name::<ItemThrowSystem>(),
+            &[
+                any::type_name::<CharacterGrabReleaseSystem>(),
+                any::type_name::<ItemPickUpSystem>(),
+            ],
+        ); // kcov-ignore
 
         // Charging
         builder.add(
@@ -429,6 +553,13 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<HittingEffectSystem>()],
         ); // kcov-ignore
 
+        // Tagged hit transitions overwrite the `Hit` sequence.
+        builder.add(
+            EnergyTaggedHitTransitionSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<EnergyTaggedHitTransitionSystem>(),
+            &[any::type_name::<HitEffectSystem>()],
+        ); // kcov-ignore
+
         // Perhaps this should be straight after the `StickToTargetObjectSystem`, but we put it here
         // so that the renderer will show the HP including the damage dealt this frame, instead of
         // one frame later.
@@ -447,11 +578,32 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
 
         // === Helper Systems === //
 
+        // Knocked out characters lose a stock, and respawn if they have stocks remaining.
+        builder.add(
+            CharacterRespawnSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<CharacterRespawnSystem>(),
+            &[],
+        ); // kcov-ignore
+
         // Detects when the winning condition has been met.
         builder.add(
             GamePlayEndDetectionSystem::new(),
             any::type_name::<GamePlayEndDetectionSystem>(),
-            &[],
+            &[any::type_name::<CharacterRespawnSystem>()],
+        ); // kcov-ignore
+
+        // Spawns the next survival wave once the current wave is defeated.
+        builder.add(
+            SurvivalWaveSpawnSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<SurvivalWaveSpawnSystem>(),
+            &[any::type_name::<GamePlayEndDetectionSystem>()],
+        ); // kcov-ignore
+
+        // Records damage, hits and knock outs for the round's statistics.
+        builder.add(
+            PlayerStatsUpdateSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<PlayerStatsUpdateSystem>(),
+            &[any::type_name::<CharacterRespawnSystem>()],
         ); // kcov-ignore
 
         builder.add(
@@ -474,15 +626,25 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<GamePlayEndTransitionDelaySystem>()],
         ); // kcov-ignore
 
+        builder.add(
+            CameraFreeSystemDesc::default()
+                .build(world)
+                .pausable(StateId::GamePlay),
+            any::type_name::<CameraFreeSystem>(),
+            &[],
+        ); // kcov-ignore
         builder.add(
             CameraTrackingSystem::default().pausable(StateId::GamePlay),
             any::type_name::<CameraTrackingSystem>(),
-            &[],
+            &[any::type_name::<CameraFreeSystem>()],
         ); // kcov-ignore
         builder.add(
             CameraVelocitySystem::default().pausable(SessionCondition::Ready),
             any::type_name::<CameraVelocitySystem>(),
-            &[any::type_name::<CameraTrackingSystem>()],
+            &[
+                any::type_name::<CameraFreeSystem>(),
+                any::type_name::<CameraTrackingSystem>(),
+            ],
         ); // kcov-ignore
 
         let position_tracker_system =
@@ -499,6 +661,22 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayBundle {
             &[any::type_name::<GamePlayEndTransitionSystem>()],
         ); // kcov-ignore
 
+        builder.add_barrier();
+
+        builder.add(
+            GameTickUpdateSystem::new().pausable(SessionCondition::Ready),
+            any::type_name::<GameTickUpdateSystem>(),
+            &[],
+        ); // kcov-ignore
+
+        // Computer input is sent after the input reactions are processed, so that the
+        // `ControlInputEvent`s are consistent with `ControllerInput` when read next tick.
+        builder.add(
+            AiControllerSystem::<BehaviourAiController>::new().pausable(SessionCondition::Ready),
+            any::type_name::<AiControllerSystem<BehaviourAiController>>(),
+            &[],
+        ); // kcov-ignore
+
         Ok(())
     }
 }
@@ -1,14 +1,26 @@
+use ai_model::play::AiControlled;
 use amethyst::{
-    ecs::{Entity, World, WorldExt},
+    ecs::{Entity, Join, ReadStorage, World, WorldExt},
     input::{is_key_down, VirtualKeyCode},
+    shrev::EventChannel,
     GameData, State, StateData, Trans,
 };
 use application_event::AppEvent;
+use asset_model::loaded::AssetId;
+use control_settings::ControlSettingsState;
 use derivative::Derivative;
 use derive_new::new;
+use game_input_model::play::{GameTick, InputControlled};
+use game_loading::GameLoadingState;
 use game_model::play::GameEntities;
-use game_play_model::{GamePlayEntity, GamePlayEvent, GamePlayStatus};
+use game_play_model::{GamePlayEntity, GamePlayEvent, GamePlayPauseIndex, GamePlayStatus};
+use game_play_stats::{GamePlayStatsStateBuilder, GamePlayStatsStateDelegate};
+use game_rules_model::play::SurvivalWave;
+use game_stats_model::play::{GamePlayStats, PlayerStats, PlayerStatsEntry, WinStatus};
 use log::debug;
+use menu_model::MenuEvent;
+use network_session_model::play::{SessionCondition, SessionStatus};
+use rollback_model::play::{InputHistory, RollbackStatus};
 use state_registry::StateId;
 use state_support::StateEntityUtils;
 
@@ -41,36 +53,180 @@ impl GamePlayState {
 
         StateEntityUtils::clear::<GamePlayEntity>(world);
     }
+
+    /// Returns the `GamePlayStats` of each player, ordered by controller ID.
+    fn game_play_stats(world: &World) -> GamePlayStats {
+        let (player_statses, asset_ids, input_controlleds, ai_controlleds) =
+            world.system_data::<(
+                ReadStorage<'_, PlayerStats>,
+                ReadStorage<'_, AssetId>,
+                ReadStorage<'_, InputControlled>,
+                ReadStorage<'_, AiControlled>,
+            )>();
+
+        let mut player_stats_entries = (
+            &player_statses,
+            &asset_ids,
+            input_controlleds.maybe(),
+            ai_controlleds.maybe(),
+        )
+            .join()
+            .filter_map(|(player_stats, asset_id, input_controlled, ai_controlled)| {
+                let controller_id = input_controlled
+                    .map(|input_controlled| input_controlled.controller_id)
+                    .or_else(|| ai_controlled.map(|ai_controlled| ai_controlled.controller_id));
+
+                controller_id.map(|controller_id| {
+                    PlayerStatsEntry::new(
+                        controller_id,
+                        *asset_id,
+                        ai_controlled.is_some(),
+                        *player_stats,
+                    )
+                })
+            })
+            .collect::<Vec<PlayerStatsEntry>>();
+        player_stats_entries.sort_by_key(|player_stats_entry| player_stats_entry.controller_id);
+
+        GamePlayStats::new(player_stats_entries)
+    }
+
+    /// Returns whether the application is in a network session.
+    fn session_established(world: &World) -> bool {
+        world
+            .try_fetch::<SessionStatus>()
+            .map(|session_status| {
+                *session_status == SessionStatus::JoinEstablished
+                    || *session_status == SessionStatus::HostEstablished
+            })
+            .unwrap_or(false)
+    }
+
+    /// Simulates the ticks up to the present within the same frame when rolling back.
+    ///
+    /// This is also used to catch up to the session's tick when reconnecting. Resimulation stops
+    /// early if the game tick does not advance, such as when the round is paused.
+    fn resimulate(world: &World, game_data: &mut GameData<'_, '_>) {
+        while world.read_resource::<RollbackStatus>().is_resimulating() {
+            let game_tick = *world.read_resource::<GameTick>();
+            game_data.update(world);
+
+            if *world.read_resource::<GameTick>() == game_tick {
+                break;
+            }
+        }
+    }
+
+    /// Returns whether the round is paused.
+    fn paused(world: &World) -> bool {
+        *world.read_resource::<GamePlayStatus>() == GamePlayStatus::Paused
+    }
+
+    /// Pauses the round and shows the pause menu.
+    ///
+    /// Game play systems are frozen through the `SessionCondition`, and the pause menu items are
+    /// spawned for the `GamePlayPause` state ID.
+    fn pause(world: &mut World) {
+        if *world.read_resource::<GamePlayStatus>() != GamePlayStatus::Playing {
+            return;
+        }
+
+        debug!("Pausing round.");
+        world.insert(StateId::GamePlayPause);
+        world.insert(GamePlayStatus::Paused);
+        world.write_resource::<SessionCondition>().pause();
+    }
+
+    /// Hides the pause menu and resumes the round.
+    fn resume(world: &mut World) {
+        if !Self::paused(world) {
+            return;
+        }
+
+        debug!("Resuming round.");
+        world.insert(StateId::GamePlay);
+        world.insert(GamePlayStatus::Playing);
+        world.write_resource::<SessionCondition>().resume();
+    }
+
+    /// Sends a `GamePlayEvent` to be handled in a subsequent frame.
+    ///
+    /// In a network session, this allows the request to be sent to the session server.
+    fn game_play_event_send(world: &World, game_play_event: GamePlayEvent) {
+        world
+            .write_resource::<EventChannel<GamePlayEvent>>()
+            .single_write(game_play_event);
+    }
+
+    /// Restarts the round with the current character and map selections.
+    ///
+    /// Game entities are deleted when this state stops, and are spawned again by the
+    /// `GameLoadingState`. Assets are not reloaded.
+    fn restart(world: &mut World) -> Trans<GameData<'static, 'static>, AppEvent> {
+        debug!("Restarting round.");
+        world.insert(WinStatus::default());
+        world.insert(GamePlayStatus::None);
+
+        let game_play_fn = || Box::new(GamePlayState::new());
+        Trans::Switch(Box::new(GameLoadingState::new(game_play_fn)))
+    }
+
+    /// Returns the `GamePlayStatsState`, which switches to a new round for a rematch.
+    fn game_play_stats_state() -> Box<dyn State<GameData<'static, 'static>, AppEvent>> {
+        // kcov-ignore-start
+        let game_play_fn = || Box::new(GamePlayState::new());
+        let game_loading_fn = move || Box::new(GameLoadingState::new(game_play_fn));
+        // kcov-ignore-end
+        let state =
+            GamePlayStatsStateBuilder::new(GamePlayStatsStateDelegate::new(game_loading_fn))
+                .build();
+
+        Box::new(state)
+    }
 }
 
-impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
+impl State<GameData<'static, 'static>, AppEvent> for GamePlayState {
     fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
         data.world.insert(StateId::GamePlay);
         data.world.insert(GamePlayStatus::Playing);
+        data.world.insert(GameTick::default());
+        data.world.insert(SurvivalWave::default());
+        data.world.insert(InputHistory::default());
+        data.world.insert(RollbackStatus::default());
     }
 
     fn on_stop(&mut self, mut data: StateData<'_, GameData<'_, '_>>) {
         self.terminate_entities(&mut data.world);
+
+        // Unfreeze systems if the round was left from the pause menu.
+        data.world.write_resource::<SessionCondition>().resume();
     }
 
-    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
-        data.world.insert(StateId::GamePlay);
+    fn on_resume(&mut self, data: StateData<'_, GameData<'static, 'static>>) {
+        let state_id = if Self::paused(data.world) {
+            StateId::GamePlayPause
+        } else {
+            StateId::GamePlay
+        };
+        data.world.insert(state_id);
     }
 
     fn handle_event(
         &mut self,
         data: StateData<'_, GameData<'_, '_>>,
         event: AppEvent,
-    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
         match event {
             AppEvent::Window(window_event) => {
                 if is_key_down(&window_event, VirtualKeyCode::Escape) {
-                    debug!("Returning from `GamePlayState`.");
-                    data.world.insert(GamePlayStatus::None);
-                    Trans::Pop
-                } else {
-                    Trans::None
+                    let game_play_event = if Self::paused(data.world) {
+                        GamePlayEvent::Resume
+                    } else {
+                        GamePlayEvent::Pause
+                    };
+                    Self::game_play_event_send(data.world, game_play_event);
                 }
+                Trans::None
             }
             AppEvent::GamePlay(game_play_event) => {
                 match game_play_event {
@@ -80,20 +236,74 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
                         Trans::Pop
                     }
                     GamePlayEvent::Restart => {
-                        // TODO: Switch to `GameLoadingState`
-                        Trans::None
+                        // In a network session, the round is restarted when the session server
+                        // notifies all devices.
+                        if Self::session_established(data.world) {
+                            Trans::None
+                        } else {
+                            Self::restart(data.world)
+                        }
                     }
+                    GamePlayEvent::RestartNotify => Self::restart(data.world),
                     GamePlayEvent::Pause => {
-                        data.world.insert(GamePlayStatus::Paused);
+                        // In a network session, the round is paused when the session server
+                        // notifies all devices.
+                        if !Self::session_established(data.world) {
+                            Self::pause(data.world);
+                        }
+                        Trans::None
+                    }
+                    GamePlayEvent::PauseNotify => {
+                        Self::pause(data.world);
                         Trans::None
                     }
                     GamePlayEvent::Resume => {
-                        data.world.insert(GamePlayStatus::Playing);
+                        if !Self::session_established(data.world) {
+                            Self::resume(data.world);
+                        }
+                        Trans::None
+                    }
+                    GamePlayEvent::ResumeNotify => {
+                        Self::resume(data.world);
                         Trans::None
                     }
                     GamePlayEvent::End => Trans::None,
                     GamePlayEvent::EndStats => {
-                        // TODO: `GamePlayStats` state.
+                        debug!("Switching to `GamePlayStatsState`.");
+                        let game_play_stats = Self::game_play_stats(data.world);
+                        data.world.insert(game_play_stats);
+                        data.world.insert(GamePlayStatus::None);
+                        Trans::Switch(Self::game_play_stats_state())
+                    }
+                }
+            }
+            AppEvent::GamePlayPause(game_play_pause_event) => {
+                if !Self::paused(data.world) {
+                    return Trans::None;
+                }
+
+                match game_play_pause_event {
+                    MenuEvent::Select(GamePlayPauseIndex::Resume) | MenuEvent::Close => {
+                        Self::game_play_event_send(data.world, GamePlayEvent::Resume);
+                        Trans::None
+                    }
+                    MenuEvent::Select(GamePlayPauseIndex::Restart) => {
+                        Self::game_play_event_send(data.world, GamePlayEvent::Restart);
+                        Trans::None
+                    }
+                    MenuEvent::Select(GamePlayPauseIndex::Controls) => {
+                        // Session notifications are not received by the control settings state,
+                        // so the round could be resumed by other devices while it is open.
+                        if Self::session_established(data.world) {
+                            debug!("Control settings are unavailable in a network session.");
+                            Trans::None
+                        } else {
+                            Trans::Push(Box::new(ControlSettingsState::new()))
+                        }
+                    }
+                    MenuEvent::Select(GamePlayPauseIndex::Quit) => {
+                        debug!("Returning from `GamePlayState`.");
+                        data.world.insert(GamePlayStatus::None);
                         Trans::Pop
                     }
                 }
@@ -105,11 +315,14 @@ impl<'a, 'b> State<GameData<'a, 'b>, AppEvent> for GamePlayState {
     fn update(
         &mut self,
         data: StateData<'_, GameData<'_, '_>>,
-    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+    ) -> Trans<GameData<'static, 'static>, AppEvent> {
         // Note: The built-in dispatcher must be run before the state specific dispatcher as the
         // `"input_system"` is registered in the main dispatcher, and is a dependency of the
         // `ControllerInputUpdateSystem`.
         data.data.update(&data.world);
+
+        Self::resimulate(&data.world, data.data);
+
         Trans::None
     }
 }
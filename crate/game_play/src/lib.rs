@@ -9,16 +9,20 @@ pub use crate::{
     game_play_bundle::GamePlayBundle,
     game_play_state::GamePlayState,
     system::{
-        CharacterHitEffectSystem, CharacterHitEffectSystemData, CharacterSequenceUpdateSystem,
+        CharacterGrabEffectSystem, CharacterGrabEffectSystemData, CharacterGrabReleaseSystem,
+        CharacterGrabReleaseSystemData, CharacterHitEffectSystem, CharacterHitEffectSystemData,
+        CharacterRespawnSystem, CharacterRespawnSystemData, CharacterSequenceUpdateSystem,
         CharacterSequenceUpdateSystemData, FrameFreezeClockAugmentSystem,
         FrameFreezeClockAugmentSystemData, GamePlayEndDetectionSystem,
         GamePlayEndDetectionSystemData, GamePlayEndTransitionDelaySystem,
         GamePlayEndTransitionDelaySystemData, GamePlayEndTransitionSystem,
         GamePlayEndTransitionSystemData, GamePlayRemovalAugmentSystem,
         GamePlayRemovalAugmentSystemData, GamePlayStatusDisplaySystem,
-        GamePlayStatusDisplaySystemData, GroundingFrictionSystem, GroundingFrictionSystemData,
-        ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
-        ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
+        GamePlayStatusDisplaySystemData, GameTickUpdateSystem, GameTickUpdateSystemData,
+        GroundingFrictionSystem, GroundingFrictionSystemData, ObjectKinematicsUpdateSystem,
+        ObjectKinematicsUpdateSystemData, ObjectTransformUpdateSystem,
+        ObjectTransformUpdateSystemData, PlayerStatsUpdateSystem, PlayerStatsUpdateSystemData,
+        SurvivalWaveSpawnSystem, SurvivalWaveSpawnSystemData, TeamTintSystem, TeamTintSystemData,
         GAME_PLAY_END_TRANSITION_DELAY_DEFAULT,
     },
 };
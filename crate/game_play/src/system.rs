@@ -1,5 +1,8 @@
 pub use self::{
+    character_grab_effect_system::{CharacterGrabEffectSystem, CharacterGrabEffectSystemData},
+    character_grab_release_system::{CharacterGrabReleaseSystem, CharacterGrabReleaseSystemData},
     character_hit_effect_system::{CharacterHitEffectSystem, CharacterHitEffectSystemData},
+    character_respawn_system::{CharacterRespawnSystem, CharacterRespawnSystemData},
     character_sequence_update_system::{
         CharacterSequenceUpdateSystem, CharacterSequenceUpdateSystemData,
     },
@@ -17,6 +20,7 @@ pub use self::{
     game_play_status_display_system::{
         GamePlayStatusDisplaySystem, GamePlayStatusDisplaySystemData,
     },
+    game_tick_update_system::{GameTickUpdateSystem, GameTickUpdateSystemData},
     grounding_friction_system::{GroundingFrictionSystem, GroundingFrictionSystemData},
     object_kinematics_update_system::{
         ObjectKinematicsUpdateSystem, ObjectKinematicsUpdateSystemData,
@@ -24,17 +28,27 @@ pub use self::{
     object_transform_update_system::{
         ObjectTransformUpdateSystem, ObjectTransformUpdateSystemData,
     },
+    player_stats_update_system::{PlayerStatsUpdateSystem, PlayerStatsUpdateSystemData},
     sequence::{FrameFreezeClockAugmentSystem, FrameFreezeClockAugmentSystemData},
+    survival_wave_spawn_system::{SurvivalWaveSpawnSystem, SurvivalWaveSpawnSystemData},
+    team_tint_system::{TeamTintSystem, TeamTintSystemData},
 };
 
+mod character_grab_effect_system;
+mod character_grab_release_system;
 mod character_hit_effect_system;
+mod character_respawn_system;
 mod character_sequence_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
 mod game_play_end_transition_system;
 mod game_play_removal_augment_system;
 mod game_play_status_display_system;
+mod game_tick_update_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
+mod player_stats_update_system;
 mod sequence;
+mod survival_wave_spawn_system;
+mod team_tint_system;
@@ -0,0 +1,162 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use character_model::loaded::CharacterGrabTransitions;
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::{Grab, Interaction, InteractionKind},
+    play::{GrabEvent, GrabHoldClock, GrabbedBy, Grabbing},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::{Position, Velocity};
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Latches grabbed characters to the character grabbing them.
+#[derive(Debug, Default, new)]
+pub struct CharacterGrabEffectSystem {
+    /// Reader ID for the `GrabEvent` event channel.
+    #[new(default)]
+    grab_event_rid: Option<ReaderId<GrabEvent>>,
+}
+
+/// `CharacterGrabEffectSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterGrabEffectSystemData<'s> {
+    /// `GrabEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub grab_ec: Read<'s, EventChannel<GrabEvent>>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: ReadStorage<'s, CharacterGrabTransitions>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `Grabbing` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbings: WriteStorage<'s, Grabbing>,
+    /// `GrabbedBy` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_bys: WriteStorage<'s, GrabbedBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for CharacterGrabEffectSystem {
+    type SystemData = CharacterGrabEffectSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterGrabEffectSystemData {
+            grab_ec,
+            character_grab_transitionses,
+            mut mirroreds,
+            mut grabbings,
+            mut grabbed_bys,
+            mut target_objects,
+            mut chase_mode_sticks,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        grab_ec
+            .read(
+                self.grab_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for CharacterGrabEffectSystem."),
+            )
+            .for_each(|ev| {
+                let grab = if let Interaction {
+                    kind: InteractionKind::Grab(grab),
+                    ..
+                } = &ev.interaction
+                {
+                    *grab
+                } else {
+                    return;
+                };
+
+                let transitions_from = character_grab_transitionses.get(ev.from).copied();
+                let transitions_to = character_grab_transitionses.get(ev.to).copied();
+                let (transitions_from, transitions_to) =
+                    if let (Some(transitions_from), Some(transitions_to)) =
+                        (transitions_from, transitions_to)
+                    {
+                        (transitions_from, transitions_to)
+                    } else {
+                        return;
+                    };
+
+                let Grab {
+                    hold_duration,
+                    offset,
+                    throw,
+                } = grab;
+
+                let mirrored = mirroreds
+                    .get(ev.from)
+                    .copied()
+                    .unwrap_or_else(Mirrored::default);
+                let offset_x = if *mirrored {
+                    -offset.x as f32
+                } else {
+                    offset.x as f32
+                };
+                let offset = Position::<f32>::new(offset_x, offset.y as f32, offset.z as f32);
+
+                grabbings
+                    .insert(
+                        ev.from,
+                        Grabbing::new(ev.to, GrabHoldClock::new(*hold_duration as usize), throw),
+                    )
+                    .expect("Failed to insert `Grabbing` component.");
+                grabbed_bys
+                    .insert(ev.to, GrabbedBy::new(ev.from))
+                    .expect("Failed to insert `GrabbedBy` component.");
+                target_objects
+                    .insert(ev.to, TargetObject::new(ev.from))
+                    .expect("Failed to insert `TargetObject` component.");
+                chase_mode_sticks
+                    .insert(ev.to, ChaseModeStick::new(Some(offset)))
+                    .expect("Failed to insert `ChaseModeStick` component.");
+
+                // The grabbed object faces the grabbing object.
+                mirroreds
+                    .insert(ev.to, !mirrored)
+                    .expect("Failed to insert `Mirrored` component.");
+                if let Some(velocity) = velocities.get_mut(ev.to) {
+                    *velocity = Velocity::default();
+                }
+
+                if let Some(sequence_id) = sequence_ids.get_mut(ev.from) {
+                    *sequence_id = transitions_from.grab_hold;
+                }
+                if let Some(sequence_id) = sequence_ids.get_mut(ev.to) {
+                    *sequence_id = transitions_to.grabbed;
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.grab_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<GrabEvent>>()
+                .register_reader(),
+        );
+    }
+}
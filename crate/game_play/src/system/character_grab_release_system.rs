@@ -0,0 +1,291 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use character_model::loaded::CharacterGrabTransitions;
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::{
+    config::Throw,
+    play::{GrabbedBy, Grabbing},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use object_status_model::config::StunPoints;
+use sequence_model::loaded::SequenceId;
+
+/// Throws or releases grabbed characters.
+///
+/// A grabbed character is thrown when the grabbing character switches to its `Throw` sequence,
+/// and breaks free when the grab hold duration has passed, or when the grabbing character leaves
+/// its `GrabHold` sequence.
+#[derive(Debug, Default, new)]
+pub struct CharacterGrabReleaseSystem;
+
+/// `CharacterGrabReleaseSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterGrabReleaseSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: ReadStorage<'s, CharacterGrabTransitions>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Grabbing` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbings: WriteStorage<'s, Grabbing>,
+    /// `GrabbedBy` components.
+    #[derivative(Debug = "ignore")]
+    pub grabbed_bys: WriteStorage<'s, GrabbedBy>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `StunPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl CharacterGrabReleaseSystem {
+    /// Applies the `Throw` effects to the grabbed entity.
+    fn throw(
+        CharacterGrabReleaseSystemData {
+            character_grab_transitionses,
+            mirroreds,
+            health_pointses,
+            stun_pointses,
+            velocities,
+            sequence_ids,
+            ..
+        }: &mut CharacterGrabReleaseSystemData<'_>,
+        entity_grabber: Entity,
+        entity_grabbed: Entity,
+        throw: Throw,
+    ) {
+        let Throw {
+            hp_damage,
+            stun,
+            velocity: throw_velocity,
+        } = throw;
+
+        if let Some(health_points) = health_pointses.get_mut(entity_grabbed) {
+            if health_points.0 < hp_damage {
+                *health_points = HealthPoints(0);
+            } else {
+                (*health_points) -= hp_damage;
+            }
+        }
+        if let Some(stun_points) = stun_pointses.get_mut(entity_grabbed) {
+            *stun_points += stun;
+        }
+        if let Some(velocity) = velocities.get_mut(entity_grabbed) {
+            let mirrored = mirroreds
+                .get(entity_grabber)
+                .map(|mirrored| **mirrored)
+                .unwrap_or(false);
+            velocity.x = if mirrored {
+                -throw_velocity.x as f32
+            } else {
+                throw_velocity.x as f32
+            };
+            velocity.y = throw_velocity.y as f32;
+            velocity.z = throw_velocity.z as f32;
+        }
+
+        let thrown = character_grab_transitionses
+            .get(entity_grabbed)
+            .map(|character_grab_transitions| character_grab_transitions.thrown);
+        if let (Some(thrown), Some(sequence_id)) = (thrown, sequence_ids.get_mut(entity_grabbed)) {
+            *sequence_id = thrown;
+        }
+    }
+
+    /// Returns an entity to its `Stand` sequence if it is still in the given grab sequence.
+    fn stand_if_in(
+        character_grab_transitionses: &ReadStorage<'_, CharacterGrabTransitions>,
+        sequence_ids: &mut WriteStorage<'_, SequenceId>,
+        entity: Entity,
+        grab_sequence_id_fn: fn(&CharacterGrabTransitions) -> SequenceId,
+    ) {
+        if let (Some(character_grab_transitions), Some(sequence_id)) = (
+            character_grab_transitionses.get(entity),
+            sequence_ids.get_mut(entity),
+        ) {
+            if *sequence_id == grab_sequence_id_fn(character_grab_transitions) {
+                *sequence_id = character_grab_transitions.stand;
+            }
+        }
+    }
+
+    /// Removes the components that latch the grabbed entity to the grabbing entity.
+    fn detach(
+        CharacterGrabReleaseSystemData {
+            grabbed_bys,
+            target_objects,
+            chase_mode_sticks,
+            ..
+        }: &mut CharacterGrabReleaseSystemData<'_>,
+        entity_grabbed: Entity,
+    ) {
+        grabbed_bys.remove(entity_grabbed);
+        target_objects.remove(entity_grabbed);
+        chase_mode_sticks.remove(entity_grabbed);
+    }
+}
+
+impl<'s> System<'s> for CharacterGrabReleaseSystem {
+    type SystemData = CharacterGrabReleaseSystemData<'s>;
+
+    fn run(&mut self, mut character_grab_release_system_data: Self::SystemData) {
+        let CharacterGrabReleaseSystemData {
+            entities,
+            character_grab_transitionses,
+            grabbings,
+            grabbed_bys,
+            sequence_ids,
+            ..
+        } = &mut character_grab_release_system_data;
+
+        // Collect releases first, as applying them writes to the same storages.
+        let releases = (&*entities, &mut *grabbings)
+            .join()
+            .filter_map(|(entity_grabber, grabbing)| {
+                grabbing.clock.tick();
+
+                let entity_grabbed = grabbing.entity;
+                let still_held = entities.is_alive(entity_grabbed)
+                    && grabbed_bys.get(entity_grabbed) == Some(&GrabbedBy::new(entity_grabber));
+                let grabber_sequence = (
+                    character_grab_transitionses.get(entity_grabber),
+                    sequence_ids.get(entity_grabber),
+                );
+                let grabbed_in_grabbed = match (
+                    character_grab_transitionses.get(entity_grabbed),
+                    sequence_ids.get(entity_grabbed),
+                ) {
+                    (Some(transitions), Some(sequence_id)) => *sequence_id == transitions.grabbed,
+                    _ => false,
+                };
+
+                let thrown = match grabber_sequence {
+                    (Some(transitions), Some(&sequence_id)) if still_held => {
+                        if sequence_id == transitions.throw {
+                            true
+                        } else if sequence_id == transitions.grab_hold
+                            && grabbed_in_grabbed
+                            && !grabbing.clock.is_complete()
+                        {
+                            // Still holding.
+                            return None;
+                        } else {
+                            false
+                        }
+                    }
+                    _ => false,
+                };
+
+                Some((
+                    entity_grabber,
+                    entity_grabbed,
+                    grabbing.throw,
+                    still_held,
+                    thrown,
+                ))
+            })
+            .collect::<Vec<(Entity, Entity, Throw, bool, bool)>>();
+
+        releases.into_iter().for_each(
+            |(entity_grabber, entity_grabbed, throw, still_held, thrown)| {
+                let CharacterGrabReleaseSystemData {
+                    character_grab_transitionses,
+                    grabbings,
+                    sequence_ids,
+                    ..
+                } = &mut character_grab_release_system_data;
+
+                grabbings.remove(entity_grabber);
+
+                if thrown {
+                    Self::throw(
+                        &mut character_grab_release_system_data,
+                        entity_grabber,
+                        entity_grabbed,
+                        throw,
+                    );
+                } else {
+                    Self::stand_if_in(
+                        character_grab_transitionses,
+                        sequence_ids,
+                        entity_grabber,
+                        |transitions| transitions.grab_hold,
+                    );
+                    if still_held {
+                        Self::stand_if_in(
+                            character_grab_transitionses,
+                            sequence_ids,
+                            entity_grabbed,
+                            |transitions| transitions.grabbed,
+                        );
+                    }
+                }
+
+                if still_held {
+                    Self::detach(&mut character_grab_release_system_data, entity_grabbed);
+                }
+            },
+        );
+
+        // Release grabbed entities whose grabbing entity is gone, or no longer holds them.
+        let CharacterGrabReleaseSystemData {
+            entities,
+            grabbings,
+            grabbed_bys,
+            ..
+        } = &character_grab_release_system_data;
+        let orphans = (&*entities, &*grabbed_bys)
+            .join()
+            .filter(|(entity_grabbed, grabbed_by)| {
+                let entity_grabber = grabbed_by.0;
+                !entities.is_alive(entity_grabber)
+                    || grabbings
+                        .get(entity_grabber)
+                        .map(|grabbing| grabbing.entity != *entity_grabbed)
+                        .unwrap_or(true)
+            })
+            .map(|(entity_grabbed, _)| entity_grabbed)
+            .collect::<Vec<Entity>>();
+
+        orphans.into_iter().for_each(|entity_grabbed| {
+            let CharacterGrabReleaseSystemData {
+                character_grab_transitionses,
+                sequence_ids,
+                ..
+            } = &mut character_grab_release_system_data;
+
+            Self::stand_if_in(
+                character_grab_transitionses,
+                sequence_ids,
+                entity_grabbed,
+                |transitions| transitions.grabbed,
+            );
+            Self::detach(&mut character_grab_release_system_data, entity_grabbed);
+        });
+    }
+}
@@ -3,24 +3,29 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
-use character_model::loaded::CharacterHitTransitions;
+use character_model::loaded::{
+    CharacterDefendTransitions, CharacterHitTransitions, HitDirectionTransitions,
+};
 use collision_model::{
     config::{Hit, Interaction, InteractionKind},
     play::HitEvent,
 };
 use derivative::Derivative;
 use derive_new::new;
-use kinematic_model::config::Velocity;
+use kinematic_model::config::{Position, Velocity};
 use mirrored_model::play::Mirrored;
 use object_model::play::HealthPoints;
-use object_status_model::config::StunPoints;
+use object_status_model::config::{DefenseLimit, DefensePoints, StunPoints};
 use sequence_model::loaded::SequenceId;
 
-const STUN_THRESHOLD_LOW: StunPoints = StunPoints(40);
-const STUN_THRESHOLD_MID: StunPoints = StunPoints(80);
-const STUN_THRESHOLD_HIGH: StunPoints = StunPoints(120);
-
 /// Determines collision effects for characters.
+///
+/// The sequence a character transitions to is looked up from its `CharacterHitTransitions`, based
+/// on its stun points and whether it was hit from the front or back.
+///
+/// A character is hit from the front when the attacker is on the side the character is facing. If
+/// the attacker and character share the same X position, the character is hit from the front when
+/// they face each other.
 #[derive(Debug, Default, new)]
 pub struct CharacterHitEffectSystem {
     /// Reader ID for the `HitEvent` event channel.
@@ -38,15 +43,27 @@ pub struct CharacterHitEffectSystemData<'s> {
     /// `Mirrored` components.
     #[derivative(Debug = "ignore")]
     pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
     /// `CharacterHitTransitions` components.
     #[derivative(Debug = "ignore")]
     pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
+    /// `CharacterDefendTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_defend_transitionses: ReadStorage<'s, CharacterDefendTransitions>,
+    /// `DefenseLimit` components.
+    #[derivative(Debug = "ignore")]
+    pub defense_limits: ReadStorage<'s, DefenseLimit>,
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: WriteStorage<'s, HealthPoints>,
     /// `StunPoints` components.
     #[derivative(Debug = "ignore")]
     pub stun_pointses: WriteStorage<'s, StunPoints>,
+    /// `DefensePoints` components.
+    #[derivative(Debug = "ignore")]
+    pub defense_pointses: WriteStorage<'s, DefensePoints>,
     /// `Velocity<f32>` components.
     #[derivative(Debug = "ignore")]
     pub velocities: WriteStorage<'s, Velocity<f32>>,
@@ -63,9 +80,13 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
         CharacterHitEffectSystemData {
             hit_ec,
             mirroreds,
+            positions,
             character_hit_transitionses,
+            character_defend_transitionses,
+            defense_limits,
             mut health_pointses,
             mut stun_pointses,
+            mut defense_pointses,
             mut velocities,
             mut sequence_ids,
         }: Self::SystemData,
@@ -90,36 +111,102 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                 let sequence_id = sequence_ids.get_mut(ev.to);
 
                 if let (
+                    Interaction {
+                        kind:
+                            InteractionKind::Hit(Hit {
+                                hp_damage,
+                                stun,
+                                acceleration,
+                                chip_damage,
+                                guard_damage,
+                                ..
+                            }),
+                        ..
+                    },
                     Some(character_hit_transitions),
                     Some(health_points),
                     Some(stun_points),
                     Some(velocity),
                     Some(sequence_id),
                 ) = (
+                    &ev.interaction,
                     character_hit_transitions,
                     health_points,
                     stun_points,
                     velocity,
                     sequence_id,
                 ) {
+                    let mirrored_to = mirroreds
+                        .get(ev.to)
+                        .map(|mirrored| **mirrored)
+                        .unwrap_or(false);
+                    let from_front = HitDirectionTransitions::from_front(
+                        positions.get(ev.from),
+                        positions.get(ev.to),
+                        mirrored,
+                        mirrored_to,
+                    );
+
+                    let character_defend_transitions = character_defend_transitionses
+                        .get(ev.to)
+                        .filter(|character_defend_transitions| {
+                            character_defend_transitions.blocks(*sequence_id, from_front)
+                        });
+                    if let Some(character_defend_transitions) = character_defend_transitions {
+                        let chip_damage = *chip_damage;
+                        if health_points.0 < chip_damage {
+                            *health_points = HealthPoints(0);
+                        } else {
+                            (*health_points) -= chip_damage;
+                        }
+
+                        // Blocked hits only push the character back.
+                        if mirrored {
+                            velocity.x -= (*acceleration).x as f32;
+                        } else {
+                            velocity.x += (*acceleration).x as f32;
+                        }
+
+                        let guard_broken =
+                            if let Some(defense_points) = defense_pointses.get_mut(ev.to) {
+                                *defense_points += *guard_damage;
+
+                                let defense_limit = defense_limits
+                                    .get(ev.to)
+                                    .copied()
+                                    .unwrap_or_else(DefenseLimit::default);
+                                if defense_points.0 >= defense_limit.0 {
+                                    *defense_points = DefensePoints::new(0);
+                                    true
+                                } else {
+                                    false
+                                }
+                            } else {
+                                false
+                            };
+
+                        let next_sequence_id = if *health_points == 0 {
+                            character_hit_transitions.falling.sequence_id(from_front)
+                        } else if guard_broken {
+                            *stun_points += *stun;
+                            character_defend_transitions.guard_break
+                        } else {
+                            character_defend_transitions.defend_hit
+                        };
+
+                        *sequence_id = next_sequence_id;
+                        return;
+                    }
+
                     // TODO: Split this system with health check system.
-                    let Interaction {
-                        kind:
-                            InteractionKind::Hit(Hit {
-                                hp_damage,
-                                stun,
-                                acceleration,
-                                ..
-                            }),
-                        ..
-                    } = ev.interaction;
+                    let hp_damage = *hp_damage;
                     if health_points.0 < hp_damage {
                         *health_points = HealthPoints(0);
                     } else {
                         (*health_points) -= hp_damage;
                     }
 
-                    *stun_points += stun;
+                    *stun_points += *stun;
 
                     if mirrored {
                         velocity.x -= (*acceleration).x as f32;
@@ -130,15 +217,9 @@ impl<'s> System<'s> for CharacterHitEffectSystem {
                     velocity.z += (*acceleration).z as f32;
 
                     let next_sequence_id = if *health_points == 0 {
-                        character_hit_transitions.falling
-                    } else if *stun_points < STUN_THRESHOLD_LOW {
-                        character_hit_transitions.low_stun
-                    } else if *stun_points < STUN_THRESHOLD_MID {
-                        character_hit_transitions.mid_stun
-                    } else if *stun_points < STUN_THRESHOLD_HIGH {
-                        character_hit_transitions.high_stun
+                        character_hit_transitions.falling.sequence_id(from_front)
                     } else {
-                        character_hit_transitions.falling
+                        character_hit_transitions.transition(*stun_points, from_front)
                     };
 
                     // Set sequence id
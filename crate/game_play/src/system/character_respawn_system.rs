@@ -0,0 +1,146 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayStatus;
+use game_rules_model::{
+    config::GameRules,
+    play::{RespawnClock, Stocks},
+};
+use kinematic_model::config::{Position, Velocity};
+use map_model::loaded::AssetMapBounds;
+use map_selection_model::MapSelection;
+use object_model::play::HealthPoints;
+
+/// Respawns characters that are knocked out while they have `Stocks` remaining.
+///
+/// When a character's `HealthPoints` reaches `0`, it loses a stock. If it has stocks remaining, a
+/// `RespawnClock` is started, and when the clock completes, the character's `HealthPoints` are
+/// restored and it is moved to the middle of the map.
+#[derive(Debug, Default, new)]
+pub struct CharacterRespawnSystem {
+    /// Pre-allocated `Vec` of entities to start or finish respawning.
+    #[new(default)]
+    respawn_entities: Vec<Entity>,
+}
+
+/// `CharacterRespawnSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct CharacterRespawnSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: WriteStorage<'s, Stocks>,
+    /// `RespawnClock` components.
+    #[derivative(Debug = "ignore")]
+    pub respawn_clocks: WriteStorage<'s, RespawnClock>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+}
+
+impl<'s> System<'s> for CharacterRespawnSystem {
+    type SystemData = CharacterRespawnSystemData<'s>;
+
+    fn run(
+        &mut self,
+        CharacterRespawnSystemData {
+            entities,
+            game_play_status,
+            game_rules,
+            map_selection,
+            asset_map_bounds,
+            mut health_pointses,
+            mut stockses,
+            mut respawn_clocks,
+            mut positions,
+            mut velocities,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        // Knocked out characters lose a stock, and wait to respawn if they have stocks remaining.
+        let respawn_entities = &mut self.respawn_entities;
+        respawn_entities.clear();
+        (&entities, &health_pointses, &mut stockses, !&respawn_clocks)
+            .join()
+            .filter(|(_, health_points, stocks, _)| **health_points == 0 && **stocks > 0)
+            .for_each(|(entity, _, stocks, _)| {
+                *stocks -= 1;
+                if *stocks > 0 {
+                    respawn_entities.push(entity);
+                }
+            });
+
+        let respawn_delay = game_rules.respawn_delay as usize;
+        respawn_entities.drain(..).for_each(|entity| {
+            respawn_clocks
+                .insert(entity, RespawnClock::new(respawn_delay))
+                .expect("Failed to insert `RespawnClock` component.");
+        });
+
+        (&entities, &mut respawn_clocks)
+            .join()
+            .for_each(|(entity, respawn_clock)| {
+                respawn_clock.tick();
+                if respawn_clock.is_complete() {
+                    respawn_entities.push(entity);
+                }
+            });
+
+        if respawn_entities.is_empty() {
+            return;
+        }
+
+        // Respawned characters are placed in the middle of the map.
+        let position = map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_map_bounds.get(asset_id))
+            .map(|bounds| {
+                Position::<f32>::new(
+                    bounds.width as f32 / 2.,
+                    bounds.height as f32 / 2.,
+                    bounds.depth as f32 / 2.,
+                )
+            })
+            .unwrap_or_default();
+
+        respawn_entities.drain(..).for_each(|entity| {
+            respawn_clocks.remove(entity);
+            health_pointses
+                .insert(entity, HealthPoints::default())
+                .expect("Failed to insert `HealthPoints` component.");
+            positions
+                .insert(entity, position)
+                .expect("Failed to insert `Position<f32>` component.");
+            velocities
+                .insert(entity, Velocity::<f32>::default())
+                .expect("Failed to insert `Velocity<f32>` component.");
+        });
+    }
+}
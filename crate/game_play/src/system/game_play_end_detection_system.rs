@@ -1,43 +1,67 @@
 use std::collections::HashMap;
 
 use amethyst::{
-    ecs::{Join, ReadStorage, System, World, Write},
+    ecs::{Entities, Join, Read, ReadStorage, System, World, Write},
     shred::{ResourceId, SystemData},
     shrev::EventChannel,
 };
 use derivative::Derivative;
 use derive_new::new;
+use game_input_model::play::GameTick;
 use game_play_model::{GamePlayEvent, GamePlayStatus};
+use game_rules_model::{
+    config::{GameRules, WinCondition},
+    play::{RespawnClock, Stocks, SurvivalWave},
+};
 use game_stats_model::play::{WinOutcome, WinStatus};
 use object_model::play::HealthPoints;
 use team_model::play::Team;
 
 /// Detects the end of a game play round, and fires a `GamePlayEvent::End`.
 ///
-/// In the future this will be type parameterized to specify the detection function.
+/// The round ends according to the `WinCondition` of the `GameRules`, or when the time limit is
+/// reached.
 #[derive(Debug, Default, new)]
 pub struct GamePlayEndDetectionSystem {
-    /// Pre-allocated `HashMap` to track number of alive players.
+    /// Pre-allocated `HashMap` to track the stocks and health points of each team in play.
     #[new(default)]
-    team_alive_counter: HashMap<Team, u32>,
+    team_scores: HashMap<Team, (u32, u32)>,
 }
 
 /// `GamePlayEndDetectionSystemData`.
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct GamePlayEndDetectionSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
     /// `GamePlayStatus` resource.
     #[derivative(Debug = "ignore")]
     pub game_play_status: Write<'s, GamePlayStatus>,
     /// `GamePlayEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `SurvivalWave` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_wave: Read<'s, SurvivalWave>,
     /// `Team` components.
     #[derivative(Debug = "ignore")]
     pub teams: ReadStorage<'s, Team>,
     /// `HealthPoints` components.
     #[derivative(Debug = "ignore")]
     pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Stocks` components.
+    #[derivative(Debug = "ignore")]
+    pub stockses: ReadStorage<'s, Stocks>,
+    /// `RespawnClock` components.
+    #[derivative(Debug = "ignore")]
+    pub respawn_clocks: ReadStorage<'s, RespawnClock>,
     /// `WinStatus` resource.
     #[derivative(Debug = "ignore")]
     pub win_status: Write<'s, WinStatus>,
@@ -48,50 +72,99 @@ impl GamePlayEndDetectionSystem {
     fn win_status(
         &mut self,
         GamePlayEndDetectionSystemData {
+            entities,
+            game_rules,
+            game_tick,
+            survival_wave,
             teams,
             health_pointses,
+            stockses,
+            respawn_clocks,
             ..
         }: &mut GamePlayEndDetectionSystemData,
     ) -> Option<WinStatus> {
-        let team_alive_count = self.team_alive_count(&teams, &health_pointses);
-        if team_alive_count == 0 {
-            let win_outcome = WinOutcome::Draw;
-            let win_status = WinStatus::new(win_outcome);
-            Some(win_status)
-        } else if team_alive_count == 1 {
-            let winning_team = self
-                .team_alive_counter
-                .keys()
-                .next()
-                .copied()
-                .expect("Expected `Team` entry to exist.");
-            let win_outcome = WinOutcome::WinLoss { winning_team };
-            let win_status = WinStatus::new(win_outcome);
-            Some(win_status)
-        } else {
-            None
-        }
+        self.team_scores_update(entities, teams, health_pointses, stockses, respawn_clocks);
+
+        let timed_out = game_rules
+            .time_limit
+            .map(|time_limit| game_tick.0 >= u64::from(time_limit))
+            .unwrap_or(false);
+
+        let win_outcome = match game_rules.win_condition {
+            WinCondition::LastTeamStanding => match self.team_scores.len() {
+                0 => Some(WinOutcome::Draw),
+                1 => {
+                    let winning_team = self
+                        .team_scores
+                        .keys()
+                        .next()
+                        .copied()
+                        .expect("Expected `Team` entry to exist.");
+                    Some(WinOutcome::WinLoss { winning_team })
+                }
+                _ if timed_out => Some(self.decision()),
+                _ => None,
+            },
+            WinCondition::SurvivalWaves { wave_count } => {
+                let players_in_play = self.team_scores.contains_key(&SurvivalWave::TEAM_PLAYERS);
+                let wave_in_play = self.team_scores.contains_key(&SurvivalWave::TEAM_WAVE);
+                if !players_in_play || timed_out {
+                    Some(WinOutcome::Overwhelmed {
+                        wave: survival_wave.wave,
+                    })
+                } else if !wave_in_play && survival_wave.wave >= wave_count {
+                    Some(WinOutcome::Survived { waves: wave_count })
+                } else {
+                    None
+                }
+            }
+        };
+
+        win_outcome.map(WinStatus::new)
     }
 
-    /// Returns the number of teams remaining in play.
-    fn team_alive_count(
+    /// Updates the stocks and health points of each team remaining in play.
+    ///
+    /// A character is in play if it has health points remaining, or is waiting to respawn.
+    fn team_scores_update(
         &mut self,
+        entities: &Entities<'_>,
         teams: &ReadStorage<'_, Team>,
         health_pointses: &ReadStorage<'_, HealthPoints>,
-    ) -> usize {
-        self.team_alive_counter.clear();
+        stockses: &ReadStorage<'_, Stocks>,
+        respawn_clocks: &ReadStorage<'_, RespawnClock>,
+    ) {
+        self.team_scores.clear();
 
-        // Game ends when there is one or less teams remaining
-        (teams, health_pointses)
+        (entities, teams, health_pointses, stockses.maybe())
             .join()
-            .for_each(|(team, health_points)| {
-                if *health_points > 0 {
-                    let alive_count = self.team_alive_counter.entry(*team).or_insert(0);
-                    *alive_count += 1;
-                };
+            .filter(|(entity, _, health_points, _)| {
+                **health_points > 0 || respawn_clocks.contains(*entity)
+            })
+            .for_each(|(_, team, health_points, stocks)| {
+                let stocks = stocks.copied().unwrap_or_default();
+                let team_score = self.team_scores.entry(*team).or_insert((0, 0));
+                team_score.0 += stocks.0;
+                team_score.1 += health_points.0;
             });
+    }
 
-        self.team_alive_counter.len()
+    /// Returns the outcome of a round that has timed out.
+    ///
+    /// The team with the most stocks wins, followed by the team with the most health points. If
+    /// multiple teams are tied, the round is a draw.
+    fn decision(&self) -> WinOutcome {
+        let best_score = self.team_scores.values().max().copied();
+        let mut leading_teams = self
+            .team_scores
+            .iter()
+            .filter(|(_, team_score)| Some(**team_score) == best_score)
+            .map(|(team, _)| *team);
+
+        match (leading_teams.next(), leading_teams.next()) {
+            (Some(winning_team), None) => WinOutcome::Decision { winning_team },
+            _ => WinOutcome::Draw,
+        }
     }
 }
 
@@ -1,5 +1,6 @@
 use std::convert::TryInto;
 
+use ai_model::play::AiControlled;
 use amethyst::{
     ecs::{Entities, Join, Read, ReadExpect, ReadStorage, System, World, WriteStorage},
     shred::{ResourceId, SystemData},
@@ -58,6 +59,9 @@ pub struct GamePlayStatusDisplaySystemData<'s> {
     /// `InputControlled` components.
     #[derivative(Debug = "ignore")]
     pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Read<'s, PlayerControllers>,
@@ -93,42 +97,85 @@ impl GamePlayStatusDisplaySystem {
         win_status: WinStatus,
         teams: &ReadStorage<'_, Team>,
         input_controlleds: &ReadStorage<'_, InputControlled>,
+        ai_controlleds: &ReadStorage<'_, AiControlled>,
         player_controllers: &PlayerControllers,
     ) -> String {
         match win_status.outcome {
             WinOutcome::None => String::from("Ongoing Match"),
             WinOutcome::WinLoss { winning_team } => {
-                let winner = (teams, input_controlleds)
-                    .join()
-                    .find(|(team, _)| **team == winning_team)
-                    .map(|(team, input_controlled)| match team {
-                        Team::Independent(..) => {
-                            let controller_id = input_controlled.controller_id;
-                            player_controllers
-                                .get(
-                                    TryInto::<usize>::try_into(controller_id)
-                                        .expect("Failed to convert `u32` into `usize`"),
-                                )
-                                .map(|player_controller: &PlayerController| {
-                                    player_controller.name.clone()
-                                })
-                                .unwrap_or_else(|| {
-                                    error!(
-                                        "Expected `PlayerControllers` to have at least \
-                                         {} controllers.",
-                                        controller_id + 1
-                                    );
-
-                                    String::from("")
-                                })
-                        }
-                        Team::Number(team_counter) => format!("Team {}", team_counter),
-                    })
-                    .expect("Expected entity for winning team to exist.");
+                let winner = Self::team_name(
+                    winning_team,
+                    teams,
+                    input_controlleds,
+                    ai_controlleds,
+                    player_controllers,
+                );
                 format!("Winner: {}", winner)
             }
+            WinOutcome::Decision { winning_team } => {
+                let winner = Self::team_name(
+                    winning_team,
+                    teams,
+                    input_controlleds,
+                    ai_controlleds,
+                    player_controllers,
+                );
+                format!("Time Up! Winner: {}", winner)
+            }
             WinOutcome::Draw => String::from("Draw"),
+            WinOutcome::Survived { waves } => format!("Survived {} Waves", waves),
+            WinOutcome::Overwhelmed { wave } => format!("Defeated on Wave {}", wave),
+        }
+    }
+
+    /// Returns the display name of a team.
+    ///
+    /// Numbered teams are named by their colour, and independent teams by their player's name.
+    fn team_name(
+        winning_team: Team,
+        teams: &ReadStorage<'_, Team>,
+        input_controlleds: &ReadStorage<'_, InputControlled>,
+        ai_controlleds: &ReadStorage<'_, AiControlled>,
+        player_controllers: &PlayerControllers,
+    ) -> String {
+        if let Some(team_colour) = winning_team.colour() {
+            return format!("{} Team", team_colour);
         }
+
+        (teams, input_controlleds.maybe(), ai_controlleds.maybe())
+            .join()
+            .filter(|(team, _, _)| **team == winning_team)
+            .find_map(|(_, input_controlled, ai_controlled)| {
+                input_controlled
+                    .map(|input_controlled| (input_controlled.controller_id, false))
+                    .or_else(|| {
+                        ai_controlled.map(|ai_controlled| (ai_controlled.controller_id, true))
+                    })
+            })
+            .map(|(controller_id, is_ai)| {
+                let name = player_controllers
+                    .get(
+                        TryInto::<usize>::try_into(controller_id)
+                            .expect("Failed to convert `u32` into `usize`"),
+                    )
+                    .map(|player_controller: &PlayerController| player_controller.name.clone())
+                    .unwrap_or_else(|| {
+                        error!(
+                            "Expected `PlayerControllers` to have at least \
+                             {} controllers.",
+                            controller_id + 1
+                        );
+
+                        String::from("")
+                    });
+
+                if is_ai {
+                    format!("{} (CPU)", name)
+                } else {
+                    name
+                }
+            })
+            .expect("Expected entity for winning team to exist.")
     }
 }
 
@@ -143,6 +190,7 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
             win_status,
             teams,
             input_controlleds,
+            ai_controlleds,
             player_controllers,
             mut game_play_status_entities,
             mut game_play_entities,
@@ -187,6 +235,7 @@ impl<'s> System<'s> for GamePlayStatusDisplaySystem {
                         *win_status,
                         &teams,
                         &input_controlleds,
+                        &ai_controlleds,
                         &player_controllers,
                     );
 
@@ -0,0 +1,40 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::GameTick;
+use state_registry::StateId;
+
+/// Increments the `GameTick` at the end of each game play tick.
+#[derive(Debug, Default, new)]
+pub struct GameTickUpdateSystem;
+
+/// `GameTickUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameTickUpdateSystemData<'s> {
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Write<'s, GameTick>,
+}
+
+impl<'s> System<'s> for GameTickUpdateSystem {
+    type SystemData = GameTickUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameTickUpdateSystemData {
+            state_id,
+            mut game_tick,
+        }: Self::SystemData,
+    ) {
+        if *state_id == StateId::GamePlay {
+            *game_tick += 1;
+        }
+    }
+}
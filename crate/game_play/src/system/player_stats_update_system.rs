@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use ai_model::play::AiControlled;
+use amethyst::{
+    ecs::{Entities, Entity, Join, Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use collision_model::{config::InteractionKind, play::HitEvent};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::InputControlled;
+use game_play_model::GamePlayStatus;
+use game_rules_model::play::SurvivalWaveCharacter;
+use game_stats_model::play::{PlayerPointsPrev, PlayerStats};
+use object_model::play::{HealthPoints, SkillPoints};
+use spawn_model::play::SpawnParent;
+
+/// Updates each player's `PlayerStats` from `HitEvent`s and changes in HP and SP.
+///
+/// Damage is attributed to the player that hit the damaged player in the same tick. Hits from
+/// spawned objects, such as energy blasts, are attributed to the player that spawned them.
+///
+/// The points in the previous tick are stored in each player's `PlayerPointsPrev` component, so
+/// that they are restored on rollback.
+#[derive(Debug, Default, new)]
+pub struct PlayerStatsUpdateSystem {
+    /// Reader ID for the `HitEvent` channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `PlayerStatsUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsUpdateSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `InputControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub input_controlleds: ReadStorage<'s, InputControlled>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: ReadStorage<'s, AiControlled>,
+    /// `SurvivalWaveCharacter` components.
+    #[derivative(Debug = "ignore")]
+    pub survival_wave_characters: ReadStorage<'s, SurvivalWaveCharacter>,
+    /// `SpawnParent` components.
+    #[derivative(Debug = "ignore")]
+    pub spawn_parents: ReadStorage<'s, SpawnParent>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `SkillPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub skill_pointses: ReadStorage<'s, SkillPoints>,
+    /// `PlayerStats` components.
+    #[derivative(Debug = "ignore")]
+    pub player_statses: WriteStorage<'s, PlayerStats>,
+    /// `PlayerPointsPrev` components.
+    #[derivative(Debug = "ignore")]
+    pub player_points_prevs: WriteStorage<'s, PlayerPointsPrev>,
+}
+
+impl<'s> System<'s> for PlayerStatsUpdateSystem {
+    type SystemData = PlayerStatsUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        PlayerStatsUpdateSystemData {
+            entities,
+            hit_ec,
+            game_play_status,
+            input_controlleds,
+            ai_controlleds,
+            survival_wave_characters,
+            spawn_parents,
+            health_pointses,
+            skill_pointses,
+            mut player_statses,
+            mut player_points_prevs,
+        }: Self::SystemData,
+    ) {
+        let hit_events = hit_ec.read(
+            self.hit_event_rid
+                .as_mut()
+                .expect("Expected `hit_event_rid` field to be set."),
+        );
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+
+        // Players are characters controlled by a player or the computer, except for characters
+        // spawned in survival waves.
+        (
+            &entities,
+            &health_pointses,
+            input_controlleds.mask() | ai_controlleds.mask(),
+            !&survival_wave_characters,
+        )
+            .join()
+            .for_each(|(entity, _, _, _)| {
+                if let Ok(entry) = player_statses.entry(entity) {
+                    entry.or_insert_with(PlayerStats::default).combo_tick();
+                }
+            });
+
+        // Each hit player mapped to the player that hit them this tick.
+        let mut attackers = HashMap::<Entity, Entity>::new();
+        hit_events
+            .filter(|ev| matches!(ev.interaction.kind, InteractionKind::Hit(_)))
+            .for_each(|ev| {
+                let attacker = Some(ev.from)
+                    .filter(|from| player_statses.contains(*from))
+                    .or_else(|| {
+                        spawn_parents
+                            .get(ev.from)
+                            .map(|spawn_parent| spawn_parent.entity)
+                            .filter(|parent| player_statses.contains(*parent))
+                    });
+
+                if let Some(attacker) = attacker {
+                    if attacker != ev.to && player_statses.contains(ev.to) {
+                        if let Some(player_stats) = player_statses.get_mut(attacker) {
+                            player_stats.hit_land();
+                        }
+                        attackers.entry(ev.to).or_insert(attacker);
+                    }
+                }
+            });
+
+        let damage_dealts = (
+            &entities,
+            &health_pointses,
+            skill_pointses.maybe(),
+            &mut player_statses,
+        )
+            .join()
+            .filter_map(|(entity, health_points, skill_points, player_stats)| {
+                let health_points = *health_points;
+                let skill_points = skill_points.copied().unwrap_or_default();
+                let PlayerPointsPrev {
+                    health_points: health_points_prev,
+                    skill_points: skill_points_prev,
+                } = player_points_prevs
+                    .insert(entity, PlayerPointsPrev::new(health_points, skill_points))
+                    .expect("Failed to insert `PlayerPointsPrev` component.")
+                    .unwrap_or_else(|| PlayerPointsPrev::new(health_points, skill_points));
+
+                if health_points.0 > 0 {
+                    player_stats.ticks_alive += 1;
+                }
+                player_stats.sp_used += skill_points_prev.0.saturating_sub(skill_points.0);
+
+                let damage = health_points_prev.0.saturating_sub(health_points.0);
+                player_stats.damage_taken += damage;
+
+                let knocked_out = health_points_prev.0 > 0 && health_points.0 == 0;
+                attackers
+                    .get(&entity)
+                    .map(|attacker| (*attacker, damage, knocked_out))
+            })
+            .collect::<Vec<(Entity, u32, bool)>>();
+
+        damage_dealts
+            .into_iter()
+            .for_each(|(attacker, damage, knocked_out)| {
+                if let Some(player_stats) = player_statses.get_mut(attacker) {
+                    player_stats.damage_dealt += damage;
+                    if knocked_out {
+                        player_stats.kos += 1;
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use std::convert::TryFrom;
+
+use ai_model::{config::AiDifficulty, play::AiControlled};
+use amethyst::{
+    assets::PrefabData,
+    ecs::{Entity, Join, LazyUpdate, Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::{config::AssetType, loaded::AssetId};
+use character_model::config::CharacterSequenceName;
+use derivative::Derivative;
+use derive_new::new;
+use game_loading::CharacterAugmentRectifySystem;
+use game_play_hud::{CpBarPrefab, HpBarPrefab};
+use game_play_model::{GamePlayEntity, GamePlayStatus};
+use game_rules_model::{
+    config::{GameRules, WinCondition},
+    play::{SurvivalWave, SurvivalWaveCharacter},
+};
+use kinematic_model::config::{Position, Velocity};
+use map_model::loaded::AssetMapBounds;
+use map_selection_model::MapSelection;
+use object_model::play::HealthPoints;
+use object_type::ObjectType;
+use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+use spawn_model::loaded::Spawn;
+use spawn_play::{GameObjectSpawner, SpawnGameObjectResources};
+use team_model::play::Team;
+
+/// Spawns waves of computer controlled characters in a survival round.
+///
+/// The next wave is spawned when all characters of the current wave are knocked out. Each wave
+/// has one more character than the previous wave.
+///
+/// Wave characters are spawned through the `GameObjectSpawner`, so they are removed when game play
+/// ends, and deleted when rolling back to a tick before they were spawned.
+#[derive(Debug, Default, new)]
+pub struct SurvivalWaveSpawnSystem {
+    /// Entity that wave characters are spawned from.
+    ///
+    /// This has no `Position`, so wave characters are positioned relative to the map.
+    #[new(default)]
+    entity_parent: Option<Entity>,
+}
+
+/// `SurvivalWaveSpawnSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SurvivalWaveSpawnSystemData<'s> {
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
+    /// `SurvivalWave` resource.
+    #[derivative(Debug = "ignore")]
+    pub survival_wave: Write<'s, SurvivalWave>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `AssetMapBounds` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_map_bounds: Read<'s, AssetMapBounds>,
+    /// `SpawnGameObjectResources`.
+    pub spawn_game_object_resources: SpawnGameObjectResources<'s>,
+    /// `AiControlled` components.
+    #[derivative(Debug = "ignore")]
+    pub ai_controlleds: WriteStorage<'s, AiControlled>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: WriteStorage<'s, Team>,
+    /// `SurvivalWaveCharacter` components.
+    #[derivative(Debug = "ignore")]
+    pub survival_wave_characters: WriteStorage<'s, SurvivalWaveCharacter>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: WriteStorage<'s, Position<f32>>,
+    /// `GamePlayEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub game_play_entities: WriteStorage<'s, GamePlayEntity>,
+    /// `LazyUpdate` resource.
+    #[derivative(Debug = "ignore")]
+    pub lazy_update: Read<'s, LazyUpdate>,
+}
+
+impl SurvivalWaveSpawnSystem {
+    /// Returns whether any character of the current wave has health points remaining.
+    fn wave_in_play(
+        survival_wave_characters: &WriteStorage<'_, SurvivalWaveCharacter>,
+        health_pointses: &WriteStorage<'_, HealthPoints>,
+    ) -> bool {
+        (survival_wave_characters, health_pointses)
+            .join()
+            .any(|(_, health_points)| *health_points > 0)
+    }
+}
+
+impl<'s> System<'s> for SurvivalWaveSpawnSystem {
+    type SystemData = SurvivalWaveSpawnSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SurvivalWaveSpawnSystemData {
+            game_play_status,
+            game_rules,
+            mut survival_wave,
+            map_selection,
+            asset_map_bounds,
+            mut spawn_game_object_resources,
+            mut ai_controlleds,
+            mut teams,
+            mut survival_wave_characters,
+            mut positions,
+            mut game_play_entities,
+            lazy_update,
+        }: Self::SystemData,
+    ) {
+        if *game_play_status != GamePlayStatus::Playing {
+            return;
+        }
+        let wave_count = match game_rules.win_condition {
+            WinCondition::SurvivalWaves { wave_count } => wave_count,
+            WinCondition::LastTeamStanding => return,
+        };
+        if survival_wave.wave >= wave_count
+            || Self::wave_in_play(
+                &survival_wave_characters,
+                &spawn_game_object_resources
+                    .character_component_storages
+                    .health_pointses,
+            )
+        {
+            return;
+        }
+
+        let character_asset_ids = spawn_game_object_resources
+            .asset_type_mappings
+            .iter_ids(&AssetType::Object(ObjectType::Character))
+            .copied()
+            .filter(|asset_id| {
+                spawn_game_object_resources
+                    .asset_item_ids
+                    .get(*asset_id)
+                    .map(|item_ids| !item_ids.is_empty())
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<AssetId>>();
+        if character_asset_ids.is_empty() {
+            return;
+        }
+
+        let (width, height, depth) = map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_map_bounds.get(asset_id))
+            .map(|bounds| {
+                (
+                    bounds.width as f32,
+                    bounds.height as f32,
+                    bounds.depth as f32,
+                )
+            })
+            .unwrap_or_default();
+
+        // The parent is deleted with the other game play entities when game play ends.
+        let entity_parent = match self.entity_parent {
+            Some(entity_parent) if spawn_game_object_resources.entities.is_alive(entity_parent) => {
+                entity_parent
+            }
+            _ => {
+                let entity_parent = spawn_game_object_resources.entities.create();
+                game_play_entities
+                    .insert(entity_parent, GamePlayEntity)
+                    .expect("Failed to insert `GamePlayEntity` component.");
+                self.entity_parent = Some(entity_parent);
+                entity_parent
+            }
+        };
+
+        survival_wave.wave += 1;
+        let wave = survival_wave.wave;
+        let character_count = wave as usize;
+
+        (0..character_count).for_each(|index| {
+            let asset_id = character_asset_ids[(wave as usize + index) % character_asset_ids.len()];
+            let sequence_id = spawn_game_object_resources
+                .character_spawning_resources
+                .asset_sequence_id_mappings_character
+                .get(asset_id)
+                .and_then(|sequence_id_mappings| {
+                    sequence_id_mappings
+                        .id(&SequenceNameString::Name(CharacterSequenceName::Stand))
+                        .copied()
+                })
+                .unwrap_or(SequenceId(0));
+
+            // Wave characters are spread across the width of the map.
+            let x = width * (index + 1) as f32 / (character_count + 1) as f32;
+            let position = Position::<f32>::new(x, height / 2., depth / 2.);
+            let spawn = Spawn::new(asset_id, position, Velocity::default(), sequence_id);
+            let entity =
+                GameObjectSpawner::spawn(&mut spawn_game_object_resources, entity_parent, &spawn);
+
+            let controller_id = u32::try_from(index).unwrap_or(u32::MAX);
+            ai_controlleds
+                .insert(
+                    entity,
+                    AiControlled::new(controller_id, AiDifficulty::default()),
+                )
+                .expect("Failed to insert `AiControlled` for character.");
+            teams
+                .insert(entity, SurvivalWave::TEAM_WAVE)
+                .expect("Failed to insert `Team` for character.");
+            survival_wave_characters
+                .insert(entity, SurvivalWaveCharacter)
+                .expect("Failed to insert `SurvivalWaveCharacter` for character.");
+            // Inserted immediately so that the character is in the next tick's rollback snapshot.
+            positions
+                .insert(entity, position)
+                .expect("Failed to insert `Position<f32>` for character.");
+
+            lazy_update
+                .exec(move |world| CharacterAugmentRectifySystem::hp_bar_augment(world, entity));
+            lazy_update
+                .exec(move |world| CharacterAugmentRectifySystem::cp_bar_augment(world, entity));
+        });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+
+        <HpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
+        <CpBarPrefab as PrefabData<'_>>::SystemData::setup(world);
+    }
+}
@@ -0,0 +1,108 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Entities, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::play::FrameIndexClock;
+use sprite_model::loaded::TintSequence;
+use sprite_play::SequenceTint;
+use status_effect_model::play::ActiveStatusEffects;
+use team_model::play::Team;
+
+/// Proportion of the team colour blended into an object's tint.
+const TEAM_TINT_STRENGTH: f32 = 0.35;
+
+/// Tints objects on numbered teams with their team colour.
+///
+/// The team colour is blended with the object's sequence tint, so the tint is recalculated each
+/// tick instead of being compounded.
+///
+/// Objects with active status effects keep the status effect tint. This should run after the
+/// `StatusEffectTintSystem`, so that the team tint is restored when status effects expire.
+#[derive(Debug, Default, new)]
+pub struct TeamTintSystem;
+
+/// `TeamTintSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct TeamTintSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
+    /// `ActiveStatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub active_status_effectses: ReadStorage<'s, ActiveStatusEffects>,
+    /// `Handle<TintSequence>` components.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_handles: ReadStorage<'s, Handle<TintSequence>>,
+    /// `TintSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_assets: Read<'s, AssetStorage<TintSequence>>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+    /// `Tint` components.
+    #[derivative(Debug = "ignore")]
+    pub tints: WriteStorage<'s, Tint>,
+}
+
+impl TeamTintSystem {
+    /// Returns the sequence tint blended with a team colour.
+    fn tint(Tint(sequence_tint): Tint, [r, g, b, _]: [f32; 4]) -> Tint {
+        let blend = |channel: f32| 1. - TEAM_TINT_STRENGTH + TEAM_TINT_STRENGTH * channel;
+        Tint(Srgba::new(
+            sequence_tint.red * blend(r),
+            sequence_tint.green * blend(g),
+            sequence_tint.blue * blend(b),
+            sequence_tint.alpha,
+        ))
+    }
+}
+
+impl<'s> System<'s> for TeamTintSystem {
+    type SystemData = TeamTintSystemData<'s>;
+
+    fn run(
+        &mut self,
+        TeamTintSystemData {
+            entities,
+            teams,
+            active_status_effectses,
+            tint_sequence_handles,
+            tint_sequence_assets,
+            frame_index_clocks,
+            mut tints,
+        }: Self::SystemData,
+    ) {
+        (
+            &entities,
+            &teams,
+            active_status_effectses.maybe(),
+            &mut tints,
+        )
+            .join()
+            .filter(|(_, _, active_status_effects, _)| {
+                active_status_effects
+                    .map(|active_status_effects| active_status_effects.is_empty())
+                    .unwrap_or(true)
+            })
+            .filter_map(|(entity, team, _, tint)| {
+                team.colour().map(|team_colour| (entity, team_colour, tint))
+            })
+            .for_each(|(entity, team_colour, tint)| {
+                let sequence_tint = SequenceTint::tint(
+                    &tint_sequence_handles,
+                    &tint_sequence_assets,
+                    &frame_index_clocks,
+                    entity,
+                );
+                *tint = Self::tint(sequence_tint, team_colour.rgba());
+            });
+    }
+}
@@ -1,7 +1,7 @@
 use amethyst::{
     assets::PrefabData,
     core::{math::Vector3, Transform},
-    ecs::{Entity, World, WriteStorage},
+    ecs::{Entity, ReadStorage, World, WriteStorage},
     renderer::{transparent::Transparent, SpriteRender},
     shred::{ResourceId, SystemData},
     Error,
@@ -11,11 +11,14 @@ use chase_model::play::{ChaseModeStick, TargetObject};
 use derivative::Derivative;
 use derive_new::new;
 use parent_model::play::ParentEntity;
+use team_model::play::Team;
 
 use crate::{HpBar, HP_BAR_HEIGHT, HP_BAR_LENGTH, HP_BAR_SPRITE_COUNT};
 
 const COLOUR_HP_LOW: [f32; 4] = [0.8, 0., 0., 0.8];
 const COLOUR_HP_HIGH: [f32; 4] = [0.1, 0.9, 0.1, 0.8];
+const TEAM_COLOUR_LOW_FACTOR: f32 = 0.4;
+const TEAM_COLOUR_ALPHA: f32 = 0.8;
 
 /// Prefab to attach all components of a HP bar.
 ///
@@ -24,7 +27,8 @@ const COLOUR_HP_HIGH: [f32; 4] = [0.1, 0.9, 0.1, 0.8];
 /// * `HpBar`: Tag component.
 /// * `Transform`: Coordinates of the HP bar to draw.
 /// * `Parent`: Link to the parent entity whose `HealthPoints` the `HpBar` entity will display.
-/// * `SpriteRender`: Indicates which "sprite" (colour) of the `HpBar` to draw.
+/// * `SpriteRender`: Indicates which "sprite" (colour) of the `HpBar` to draw. When the game object
+///   is on a numbered `Team`, the bar is drawn in the team's colour.
 /// * `Transparent`: Tags the `HpBar` for sorting when rendering.
 ///
 /// Ideally, the `Parent` component will be inserted by the `PrefabLoaderSystem`, so the (game
@@ -50,6 +54,9 @@ pub struct HpBarPrefabSystemData<'s> {
     /// `ParentEntity` components.
     #[derivative(Debug = "ignore")]
     pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `Team` components.
+    #[derivative(Debug = "ignore")]
+    pub teams: ReadStorage<'s, Team>,
     /// `TargetObject` components.
     #[derivative(Debug = "ignore")]
     pub target_objects: WriteStorage<'s, TargetObject>,
@@ -78,6 +85,7 @@ impl<'s> PrefabData<'s> for HpBarPrefab {
             hp_bars,
             transforms,
             parent_entities,
+            teams,
             target_objects,
             chase_mode_sticks,
             colour_sprite_sheet_gen_data,
@@ -103,10 +111,26 @@ impl<'s> PrefabData<'s> for HpBarPrefab {
         target_objects.insert(entity, TargetObject::new(self.game_object_entity))?;
         chase_mode_sticks.insert(entity, Default::default())?;
 
+        let (colour_low, colour_high) = teams
+            .get(self.game_object_entity)
+            .and_then(|team| team.colour())
+            .map(|team_colour| {
+                let [r, g, b, _] = team_colour.rgba();
+                let colour_low = [
+                    r * TEAM_COLOUR_LOW_FACTOR,
+                    g * TEAM_COLOUR_LOW_FACTOR,
+                    b * TEAM_COLOUR_LOW_FACTOR,
+                    TEAM_COLOUR_ALPHA,
+                ];
+                let colour_high = [r, g, b, TEAM_COLOUR_ALPHA];
+                (colour_low, colour_high)
+            })
+            .unwrap_or((COLOUR_HP_LOW, COLOUR_HP_HIGH));
+
         let sprite_render = ColourSpriteSheetGen::gradient(
             colour_sprite_sheet_gen_data,
-            COLOUR_HP_LOW,
-            COLOUR_HP_HIGH,
+            colour_low,
+            colour_high,
             HP_BAR_SPRITE_COUNT,
         );
         sprite_renders.insert(entity, sprite_render)?;
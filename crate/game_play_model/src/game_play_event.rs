@@ -4,11 +4,23 @@ pub enum GamePlayEvent {
     /// Returns to the menu.
     Return,
     /// Restarts the round.
+    ///
+    /// In a network session, this requests the session server to restart the round.
     Restart,
+    /// Notification from the session server to restart the round.
+    RestartNotify,
     /// Pauses the round.
+    ///
+    /// In a network session, this requests the session server to pause the round.
     Pause,
+    /// Notification from the session server to pause the round.
+    PauseNotify,
     /// Resumes the round.
+    ///
+    /// In a network session, this requests the session server to resume the round.
     Resume,
+    /// Notification from the session server to resume the round.
+    ResumeNotify,
     /// Signals the end of the round.
     End,
     /// Signals to go to the round statistics.
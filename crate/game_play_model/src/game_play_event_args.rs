@@ -7,8 +7,11 @@ use structopt_derive::StructOpt;
 ///
 /// * `game_play return`
 /// * `game_play restart`
+/// * `game_play restart_notify`
 /// * `game_play pause`
+/// * `game_play pause_notify`
 /// * `game_play resume`
+/// * `game_play resume_notify`
 /// * `game_play end`
 /// * `game_play end_stats`
 #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
@@ -19,10 +22,16 @@ pub enum GamePlayEventArgs {
     Return,
     /// Restarts the round.
     Restart,
+    /// Notification from the session server to restart the round.
+    RestartNotify,
     /// Pauses the round.
     Pause,
+    /// Notification from the session server to pause the round.
+    PauseNotify,
     /// Resumes the round.
     Resume,
+    /// Notification from the session server to resume the round.
+    ResumeNotify,
     /// Signals the end of the round.
     End,
     /// Signals to go to the round statistics.
@@ -0,0 +1,6 @@
+use menu_model::MenuEvent;
+
+use crate::GamePlayPauseIndex;
+
+/// Event indicating game play pause menu selection.
+pub type GamePlayPauseEvent = MenuEvent<GamePlayPauseIndex>;
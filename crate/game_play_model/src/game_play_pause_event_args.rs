@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::GamePlayPauseIndex;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `game_play_pause select -s resume`
+/// * `game_play_pause close`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum GamePlayPauseEventArgs {
+    /// Select event.
+    Select {
+        /// Index of the selection.
+        index: GamePlayPauseIndex,
+    },
+    /// Close event.
+    Close,
+}
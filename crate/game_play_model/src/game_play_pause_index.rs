@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Game play pause menu indicies.
+///
+/// These are named differently to the other menu indicies, as `MenuIndex` is deserialized
+/// untagged.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GamePlayPauseIndex {
+    /// Resumes the round.
+    Resume,
+    /// Restarts the round.
+    Restart,
+    /// Opens control settings.
+    Controls,
+    /// Quits the round and returns to the menu.
+    Quit,
+}
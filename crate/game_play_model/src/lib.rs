@@ -4,7 +4,9 @@
 
 pub use crate::{
     game_play_entity::GamePlayEntity, game_play_event::GamePlayEvent,
-    game_play_event_args::GamePlayEventArgs, game_play_status::GamePlayStatus,
+    game_play_event_args::GamePlayEventArgs, game_play_pause_event::GamePlayPauseEvent,
+    game_play_pause_event_args::GamePlayPauseEventArgs, game_play_pause_index::GamePlayPauseIndex,
+    game_play_status::GamePlayStatus,
 };
 
 pub mod play;
@@ -12,4 +14,7 @@ pub mod play;
 mod game_play_entity;
 mod game_play_event;
 mod game_play_event_args;
+mod game_play_pause_event;
+mod game_play_pause_event_args;
+mod game_play_pause_index;
 mod game_play_status;
@@ -0,0 +1,95 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use amethyst::{GameData, State, StateData, Trans};
+use application_event::AppEvent;
+use application_state::{AppState, AppStateBuilder, AutexState};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_stats_model::{GamePlayStatsEntity, GamePlayStatsIndex};
+use log::debug;
+use menu_model::MenuEvent;
+use state_registry::StateId;
+
+/// `State` where the statistics of the previous round are displayed.
+///
+/// This state is not intended to be constructed directly, but through the
+/// [`GamePlayStatsStateBuilder`][state_builder].
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to switch to for a rematch.
+/// * `S`: State to switch to.
+///
+/// [state_builder]: game_play_stats_state/struct.GamePlayStatsStateBuilder.html
+pub type GamePlayStatsState<'a, 'b, F, S> =
+    AppState<'a, 'b, GamePlayStatsStateDelegate<'a, 'b, F, S>, GamePlayStatsEntity>;
+
+/// Builder for a `GamePlayStatsState`.
+///
+/// `SystemBundle`s to run in the `GamePlayStatsState`'s dispatcher are registered on this
+/// builder.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to switch to for a rematch.
+/// * `S`: `State` to delegate to.
+pub type GamePlayStatsStateBuilder<'a, 'b, F, S> =
+    AppStateBuilder<'a, 'b, GamePlayStatsStateDelegate<'a, 'b, F, S>, GamePlayStatsEntity>;
+
+/// Delegate `State` for displaying game play statistics.
+///
+/// This state is not intended to be used directly, but wrapped in an `AppState`. The
+/// `GamePlayStatsState` is an alias with this as a delegate state.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to switch to for a rematch.
+/// * `S`: State to switch to.
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct GamePlayStatsStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    /// Function to construct the `State` for a rematch.
+    #[derivative(Debug(bound = "F: Debug"))]
+    rematch_state_fn: F,
+    /// `PhantomData`.
+    marker: PhantomData<dyn AutexState<'a, 'b>>,
+}
+
+impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent> for GamePlayStatsStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    fn on_start(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::GamePlayStats);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::GamePlayStats);
+    }
+
+    fn handle_event(
+        &mut self,
+        _data: StateData<'_, GameData<'a, 'b>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        if let AppEvent::GamePlayStats(game_play_stats_event) = event {
+            debug!(
+                "Received game_play_stats_event: {:?}",
+                game_play_stats_event
+            );
+            match game_play_stats_event {
+                MenuEvent::Select(GamePlayStatsIndex::Rematch) => {
+                    Trans::Switch((self.rematch_state_fn)())
+                }
+                MenuEvent::Select(GamePlayStatsIndex::Return) | MenuEvent::Close => Trans::Pop,
+            }
+        } else {
+            Trans::None
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! State where the statistics of the previous round are displayed.
+
+pub use crate::game_play_stats_state::{
+    GamePlayStatsState, GamePlayStatsStateBuilder, GamePlayStatsStateDelegate,
+};
+
+mod game_play_stats_state;
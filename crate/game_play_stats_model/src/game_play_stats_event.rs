@@ -0,0 +1,6 @@
+use menu_model::MenuEvent;
+
+use crate::GamePlayStatsIndex;
+
+/// Event indicating game play statistics menu selection.
+pub type GamePlayStatsEvent = MenuEvent<GamePlayStatsIndex>;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::GamePlayStatsIndex;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `game_play_stats select -s rematch`
+/// * `game_play_stats close`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum GamePlayStatsEventArgs {
+    /// Select event.
+    Select {
+        /// Index of the selection.
+        index: GamePlayStatsIndex,
+    },
+    /// Close event.
+    Close,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Game play statistics menu indicies.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GamePlayStatsIndex {
+    /// Play another round with the same characters and map.
+    Rematch,
+    /// Return to the previous menu.
+    Return,
+}
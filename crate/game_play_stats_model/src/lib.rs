@@ -0,0 +1,13 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used in the game play statistics state.
+
+pub use crate::{
+    game_play_stats_entity::GamePlayStatsEntity, game_play_stats_event::GamePlayStatsEvent,
+    game_play_stats_event_args::GamePlayStatsEventArgs, game_play_stats_index::GamePlayStatsIndex,
+};
+
+mod game_play_stats_entity;
+mod game_play_stats_event;
+mod game_play_stats_event_args;
+mod game_play_stats_index;
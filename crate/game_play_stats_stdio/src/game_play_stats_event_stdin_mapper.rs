@@ -0,0 +1,21 @@
+use amethyst::Error;
+use game_play_stats_model::{GamePlayStatsEvent, GamePlayStatsEventArgs};
+use menu_model::MenuEvent;
+use stdio_spi::StdinMapper;
+
+/// Builds a `GamePlayStatsEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct GamePlayStatsEventStdinMapper;
+
+impl StdinMapper for GamePlayStatsEventStdinMapper {
+    type SystemData = ();
+    type Event = GamePlayStatsEvent;
+    type Args = GamePlayStatsEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        match args {
+            GamePlayStatsEventArgs::Select { index } => Ok(MenuEvent::Select(index)),
+            GamePlayStatsEventArgs::Close => Ok(MenuEvent::Close),
+        }
+    }
+}
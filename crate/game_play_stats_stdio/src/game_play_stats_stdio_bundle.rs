@@ -0,0 +1,31 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::GamePlayStatsEventStdinMapper;
+
+/// Adds a `MapperSystem<GamePlayStatsEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct GamePlayStatsStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayStatsStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<GamePlayStatsEventStdinMapper>::new(AppEventVariant::GamePlayStats),
+            any::type_name::<MapperSystem<GamePlayStatsEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
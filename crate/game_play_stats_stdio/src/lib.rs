@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable `game_play_stats` to be controlled by stdio.
+
+pub use crate::{
+    game_play_stats_event_stdin_mapper::GamePlayStatsEventStdinMapper,
+    game_play_stats_stdio_bundle::GamePlayStatsStdioBundle,
+};
+
+mod game_play_stats_event_stdin_mapper;
+mod game_play_stats_stdio_bundle;
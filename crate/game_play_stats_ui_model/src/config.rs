@@ -0,0 +1,10 @@
+//! User defined configuration types for the game play statistics UI.
+
+pub use self::{
+    game_play_stats_ui::GamePlayStatsUi, player_stats_widget::PlayerStatsWidget,
+    player_stats_widget_template::PlayerStatsWidgetTemplate,
+};
+
+mod game_play_stats_ui;
+mod player_stats_widget;
+mod player_stats_widget_template;
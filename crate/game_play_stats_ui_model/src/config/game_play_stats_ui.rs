@@ -0,0 +1,15 @@
+use application_menu::MenuIndex;
+use serde::{Deserialize, Serialize};
+use ui_menu_item_model::config::UiMenuItems;
+
+use crate::config::PlayerStatsWidget;
+
+/// Configuration for initializing the game play statistics UI.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GamePlayStatsUi {
+    /// Widget to display each player's statistics.
+    pub player_stats: PlayerStatsWidget,
+    /// Menu items to rematch or return.
+    pub menu: UiMenuItems<MenuIndex>,
+}
@@ -0,0 +1,14 @@
+use kinematic_model::config::PositionInit;
+use serde::{Deserialize, Serialize};
+
+use crate::config::PlayerStatsWidgetTemplate;
+
+/// Configuration of the widget to display all players' statistics.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub struct PlayerStatsWidget {
+    /// Position of the widget.
+    pub position: PositionInit,
+    /// Widget template for displaying a player's statistics.
+    pub player_stats_widget_template: PlayerStatsWidgetTemplate,
+}
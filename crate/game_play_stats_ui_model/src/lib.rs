@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to represent game play statistics UI data.
+
+pub mod config;
+pub mod loaded;
+pub mod play;
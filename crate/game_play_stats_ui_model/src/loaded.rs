@@ -0,0 +1,5 @@
+//! Types representing loaded configuration.
+
+pub use self::player_stats_widget::PlayerStatsWidget;
+
+mod player_stats_widget;
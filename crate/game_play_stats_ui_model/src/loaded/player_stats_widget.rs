@@ -0,0 +1,51 @@
+use amethyst::{
+    ecs::{storage::DenseVecStorage, Component, Entity, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::{loaded::ItemId, ItemComponent};
+use derivative::Derivative;
+use derive_new::new;
+
+use crate::play::PlayerStatsEntities;
+
+/// Marks the `PlayerStatsWidget` entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct PlayerStatsWidget {
+    /// `ItemId` for entities that display a player's name.
+    pub item_id_player_name: ItemId,
+    /// `ItemId` for entities that display a player's statistics.
+    pub item_id_player_stats: ItemId,
+}
+
+/// `PlayerStatsWidgetSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsWidgetSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Write<'s, PlayerStatsEntities>,
+    /// `PlayerStatsWidget` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgets: WriteStorage<'s, PlayerStatsWidget>,
+}
+
+impl<'s> ItemComponent<'s> for PlayerStatsWidget {
+    type SystemData = PlayerStatsWidgetSystemData<'s>;
+
+    fn augment(&self, system_data: &mut Self::SystemData, entity: Entity) {
+        let PlayerStatsWidgetSystemData {
+            player_stats_entities,
+            player_stats_widgets,
+        } = system_data;
+
+        player_stats_entities.player_stats_widget_entity = Some(entity);
+        player_stats_entities.player_stats_row_entities.clear();
+
+        if !player_stats_widgets.contains(entity) {
+            player_stats_widgets
+                .insert(entity, *self)
+                .expect("Failed to insert `PlayerStatsWidget` component.");
+        }
+    }
+}
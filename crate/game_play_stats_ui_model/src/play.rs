@@ -0,0 +1,6 @@
+//! Data types used at runtime.
+
+pub use self::{player_stats_entities::PlayerStatsEntities, player_stats_row::PlayerStatsRow};
+
+mod player_stats_entities;
+mod player_stats_row;
@@ -0,0 +1,13 @@
+use amethyst::ecs::Entity;
+use derive_new::new;
+
+/// Entities of the `PlayerStatsWidget`.
+///
+/// This is used to track the main widget entity, as well as each `PlayerStatsRow` entity.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct PlayerStatsEntities {
+    /// Main `PlayerStatsWidget` entity.
+    pub player_stats_widget_entity: Option<Entity>,
+    /// Entities for each player stats row.
+    pub player_stats_row_entities: Vec<Entity>,
+}
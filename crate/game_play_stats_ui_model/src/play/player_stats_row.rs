@@ -0,0 +1,11 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Entities that make up a row of the `PlayerStatsWidget`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct PlayerStatsRow {
+    /// Entity for the player's name.
+    pub entity_name: Entity,
+    /// Entity for the player's statistics.
+    pub entity_stats: Entity,
+}
@@ -0,0 +1,7 @@
+pub use self::{
+    player_stats_entity_create_delete_system::PlayerStatsEntityCreateDeleteSystem,
+    player_stats_widget_update_system::PlayerStatsWidgetUpdateSystem,
+};
+
+mod player_stats_entity_create_delete_system;
+mod player_stats_widget_update_system;
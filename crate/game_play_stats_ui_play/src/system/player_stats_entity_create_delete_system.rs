@@ -0,0 +1,195 @@
+use std::cmp::Ordering;
+
+use amethyst::{
+    ecs::{Entities, Read, ReadStorage, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use asset_model::loaded::ItemId;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_stats_ui_model::{
+    loaded::PlayerStatsWidget,
+    play::{PlayerStatsEntities, PlayerStatsRow},
+};
+use game_stats_model::play::GamePlayStats;
+use kinematic_model::{
+    config::Position,
+    play::{PositionInitOffset, PositionInitParent},
+};
+use log::debug;
+use parent_model::play::ParentEntity;
+use ui_model_spi::config::Dimensions;
+
+/// Updates `PlayerStatsEntities` to have the right number of entities for each `PlayerStatsEntry`.
+#[derive(Debug, new)]
+pub struct PlayerStatsEntityCreateDeleteSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsEntityCreateDeleteSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Write<'s, PlayerStatsEntities>,
+    /// `PlayerStatsWidget` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_widgets: ReadStorage<'s, PlayerStatsWidget>,
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `Dimensions` components.
+    #[derivative(Debug = "ignore")]
+    pub dimensionses: ReadStorage<'s, Dimensions>,
+    /// `GamePlayStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_stats: Read<'s, GamePlayStats>,
+    /// `ItemId` components.
+    #[derivative(Debug = "ignore")]
+    pub item_ids: WriteStorage<'s, ItemId>,
+    /// `ParentEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub parent_entities: WriteStorage<'s, ParentEntity>,
+    /// `PositionInitParent` components.
+    #[derivative(Debug = "ignore")]
+    pub position_init_parents: WriteStorage<'s, PositionInitParent>,
+    /// `PositionInitOffset` components.
+    #[derivative(Debug = "ignore")]
+    pub position_init_offsets: WriteStorage<'s, PositionInitOffset>,
+    /// `PlayerStatsRow` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_rows: WriteStorage<'s, PlayerStatsRow>,
+}
+
+impl<'s> System<'s> for PlayerStatsEntityCreateDeleteSystem {
+    type SystemData = PlayerStatsEntityCreateDeleteSystemData<'s>;
+
+    fn run(
+        &mut self,
+        PlayerStatsEntityCreateDeleteSystemData {
+            mut player_stats_entities,
+            player_stats_widgets,
+            entities,
+            dimensionses,
+            game_play_stats,
+            mut item_ids,
+            mut parent_entities,
+            mut position_init_parents,
+            mut position_init_offsets,
+            mut player_stats_rows,
+        }: Self::SystemData,
+    ) {
+        let PlayerStatsEntities {
+            player_stats_widget_entity,
+            player_stats_row_entities,
+        } = &mut *player_stats_entities;
+
+        let player_stats_widget_entity =
+            player_stats_widget_entity.and_then(|player_stats_widget_entity| {
+                if entities.is_alive(player_stats_widget_entity) {
+                    Some(player_stats_widget_entity)
+                } else {
+                    None
+                }
+            });
+        if let Some(player_stats_widget_entity) = player_stats_widget_entity {
+            let player_stats_entries = &game_play_stats.player_stats_entries;
+
+            match player_stats_entries
+                .len()
+                .cmp(&player_stats_row_entities.len())
+            {
+                Ordering::Equal => {}
+                Ordering::Less => {
+                    debug!(
+                        "Removing extra player stats row entities. Required: {}, Actual: {}",
+                        player_stats_entries.len(),
+                        player_stats_row_entities.len()
+                    );
+
+                    // Remove extra entities.
+                    player_stats_row_entities
+                        .drain(player_stats_entries.len()..)
+                        .for_each(|player_stats_row_entity| {
+                            let player_stats_row =
+                                player_stats_rows.get(player_stats_row_entity).copied();
+
+                            if let Some(player_stats_row) = player_stats_row {
+                                let PlayerStatsRow {
+                                    entity_name,
+                                    entity_stats,
+                                } = player_stats_row;
+
+                                entities
+                                    .delete(entity_name)
+                                    .expect("Failed to delete `entity_name`.");
+                                entities
+                                    .delete(entity_stats)
+                                    .expect("Failed to delete `entity_stats`.");
+                            }
+
+                            entities
+                                .delete(player_stats_row_entity)
+                                .expect("Failed to delete `player_stats_row_entity`.");
+                        });
+                }
+                Ordering::Greater => {
+                    debug!(
+                        "Creating additional player stats row entities. Required: {}, Actual: {}",
+                        player_stats_entries.len(),
+                        player_stats_row_entities.len()
+                    );
+
+                    // Create additional entities.
+                    let player_stats_widget =
+                        player_stats_widgets.get(player_stats_widget_entity).expect(
+                            "Expected `PlayerStatsWidget` to exist for \
+                            `player_stats_widget_entity`.",
+                        );
+                    let dimensions = dimensionses
+                        .get(player_stats_widget_entity)
+                        .expect("Expected `Dimensions` to exist for `player_stats_widget_entity`.");
+
+                    let player_stats_row_entities_new = (0..player_stats_entries.len())
+                        .skip(player_stats_row_entities.len())
+                        .map(|n| {
+                            let parent_entity = ParentEntity::new(player_stats_widget_entity);
+                            let position_init_parent =
+                                PositionInitParent::new(player_stats_widget_entity);
+                            let y_offset = -((n * dimensions.h as usize) as f32);
+                            let position_init_offset =
+                                PositionInitOffset::new(Position::new(0., y_offset, 0.));
+
+                            let item_id_player_name = player_stats_widget.item_id_player_name;
+                            let item_id_player_stats = player_stats_widget.item_id_player_stats;
+
+                            let entity_name = entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(item_id_player_name, &mut item_ids)
+                                .build();
+                            let entity_stats = entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(item_id_player_stats, &mut item_ids)
+                                .build();
+
+                            let player_stats_row = PlayerStatsRow::new(entity_name, entity_stats);
+
+                            entities
+                                .build_entity()
+                                .with(parent_entity, &mut parent_entities)
+                                .with(position_init_parent, &mut position_init_parents)
+                                .with(position_init_offset, &mut position_init_offsets)
+                                .with(player_stats_row, &mut player_stats_rows)
+                                .build()
+                        });
+
+                    player_stats_row_entities.extend(player_stats_row_entities_new);
+                }
+            }
+        }
+    }
+}
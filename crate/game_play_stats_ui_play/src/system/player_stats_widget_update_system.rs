@@ -0,0 +1,129 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    ui::UiText,
+};
+use asset_model::loaded::AssetIdMappings;
+use derivative::Derivative;
+use derive_new::new;
+use game_play_stats_ui_model::play::{PlayerStatsEntities, PlayerStatsRow};
+use game_stats_model::play::{GamePlayStats, PlayerStats, PlayerStatsEntry};
+
+/// Number of game ticks per second, used to display the time a player was alive for.
+const TICKS_PER_SECOND: u32 = 60;
+
+/// Updates the text in each `PlayerStatsRow` with the player's name and statistics.
+#[derive(Debug, new)]
+pub struct PlayerStatsWidgetUpdateSystem;
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct PlayerStatsWidgetUpdateSystemData<'s> {
+    /// `PlayerStatsEntities` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_entities: Read<'s, PlayerStatsEntities>,
+    /// `GamePlayStats` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_stats: Read<'s, GamePlayStats>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `PlayerStatsRow` components.
+    #[derivative(Debug = "ignore")]
+    pub player_stats_rows: ReadStorage<'s, PlayerStatsRow>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl PlayerStatsWidgetUpdateSystem {
+    fn player_name(
+        asset_id_mappings: &AssetIdMappings,
+        player_stats_entry: &PlayerStatsEntry,
+    ) -> String {
+        let character_name = asset_id_mappings
+            .slug(player_stats_entry.asset_id)
+            .map(|asset_slug| asset_slug.name.as_str())
+            .unwrap_or("?");
+
+        if player_stats_entry.ai_controlled {
+            format!("{} (CPU)", character_name)
+        } else {
+            format!(
+                "P{} {}",
+                player_stats_entry.controller_id + 1,
+                character_name
+            )
+        }
+    }
+
+    fn player_stats_text(player_stats: &PlayerStats) -> String {
+        format!(
+            "Dealt: {} Taken: {} Hits: {} Combo: {} KOs: {} SP: {} Alive: {}s",
+            player_stats.damage_dealt,
+            player_stats.damage_taken,
+            player_stats.hits_landed,
+            player_stats.combo_max,
+            player_stats.kos,
+            player_stats.sp_used,
+            player_stats.ticks_alive / TICKS_PER_SECOND,
+        )
+    }
+}
+
+impl<'s> System<'s> for PlayerStatsWidgetUpdateSystem {
+    type SystemData = PlayerStatsWidgetUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        PlayerStatsWidgetUpdateSystemData {
+            player_stats_entities,
+            game_play_stats,
+            asset_id_mappings,
+            player_stats_rows,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        let PlayerStatsEntities {
+            player_stats_row_entities,
+            ..
+        } = &*player_stats_entities;
+        let player_stats_entries = &game_play_stats.player_stats_entries;
+
+        if player_stats_entries.len() == player_stats_row_entities.len() {
+            player_stats_entries
+                .iter()
+                .zip(
+                    player_stats_row_entities
+                        .iter()
+                        .copied()
+                        .map(|player_stats_row_entity| {
+                            player_stats_rows.get(player_stats_row_entity).copied()
+                        }),
+                )
+                .for_each(|(player_stats_entry, player_stats_row)| {
+                    if let Some(player_stats_row) = player_stats_row {
+                        let PlayerStatsRow {
+                            entity_name,
+                            entity_stats,
+                        } = player_stats_row;
+
+                        if let Some(ui_text_name) = ui_texts.get_mut(entity_name) {
+                            let player_name =
+                                Self::player_name(&asset_id_mappings, player_stats_entry);
+                            if ui_text_name.text != player_name {
+                                ui_text_name.text = player_name;
+                            }
+                        }
+                        if let Some(ui_text_stats) = ui_texts.get_mut(entity_stats) {
+                            let player_stats_text =
+                                Self::player_stats_text(&player_stats_entry.player_stats);
+                            if ui_text_stats.text != player_stats_text {
+                                ui_text_stats.text = player_stats_text;
+                            }
+                        }
+                    }
+                });
+        }
+    }
+}
@@ -15,8 +15,11 @@ impl StdinMapper for GamePlayEventStdinMapper {
         match args {
             GamePlayEventArgs::Return => Ok(GamePlayEvent::Return),
             GamePlayEventArgs::Restart => Ok(GamePlayEvent::Restart),
+            GamePlayEventArgs::RestartNotify => Ok(GamePlayEvent::RestartNotify),
             GamePlayEventArgs::Pause => Ok(GamePlayEvent::Pause),
+            GamePlayEventArgs::PauseNotify => Ok(GamePlayEvent::PauseNotify),
             GamePlayEventArgs::Resume => Ok(GamePlayEvent::Resume),
+            GamePlayEventArgs::ResumeNotify => Ok(GamePlayEvent::ResumeNotify),
             GamePlayEventArgs::End => Ok(GamePlayEvent::End),
             GamePlayEventArgs::EndStats => Ok(GamePlayEvent::EndStats),
         }
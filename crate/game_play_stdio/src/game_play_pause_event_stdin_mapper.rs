@@ -0,0 +1,20 @@
+use amethyst::Error;
+use game_play_model::{GamePlayPauseEvent, GamePlayPauseEventArgs};
+use stdio_spi::StdinMapper;
+
+/// Builds a `GamePlayPauseEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct GamePlayPauseEventStdinMapper;
+
+impl StdinMapper for GamePlayPauseEventStdinMapper {
+    type SystemData = ();
+    type Event = GamePlayPauseEvent;
+    type Args = GamePlayPauseEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        match args {
+            GamePlayPauseEventArgs::Select { index } => Ok(GamePlayPauseEvent::Select(index)),
+            GamePlayPauseEventArgs::Close => Ok(GamePlayPauseEvent::Close),
+        }
+    }
+}
@@ -9,9 +9,9 @@ use application_event::AppEventVariant;
 use derive_new::new;
 use stdio_spi::MapperSystem;
 
-use crate::GamePlayEventStdinMapper;
+use crate::{GamePlayEventStdinMapper, GamePlayPauseEventStdinMapper};
 
-/// Adds a `MapperSystem<GamePlayEventStdinMapper>` to the `World`.
+/// Adds the `MapperSystem`s for `game_play` events to the `World`.
 #[derive(Debug, new)]
 pub struct GamePlayStdioBundle;
 
@@ -26,6 +26,11 @@ impl<'a, 'b> SystemBundle<'a, 'b> for GamePlayStdioBundle {
             any::type_name::<MapperSystem<GamePlayEventStdinMapper>>(),
             &[],
         ); // kcov-ignore
+        builder.add(
+            MapperSystem::<GamePlayPauseEventStdinMapper>::new(AppEventVariant::GamePlayPause),
+            any::type_name::<MapperSystem<GamePlayPauseEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
         Ok(())
     }
 }
@@ -4,8 +4,10 @@
 
 pub use crate::{
     game_play_event_stdin_mapper::GamePlayEventStdinMapper,
+    game_play_pause_event_stdin_mapper::GamePlayPauseEventStdinMapper,
     game_play_stdio_bundle::GamePlayStdioBundle,
 };
 
 mod game_play_event_stdin_mapper;
+mod game_play_pause_event_stdin_mapper;
 mod game_play_stdio_bundle;
@@ -0,0 +1,9 @@
+//! Contains data types used for configuration.
+
+pub use self::{
+    game_rules::GameRules, team_assignment::TeamAssignment, win_condition::WinCondition,
+};
+
+mod game_rules;
+mod team_assignment;
+mod win_condition;
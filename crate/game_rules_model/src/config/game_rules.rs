@@ -0,0 +1,62 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{TeamAssignment, WinCondition};
+
+/// Number of ticks in a minute.
+const TICKS_PER_MINUTE: u32 = 60 * 60;
+
+/// Rules of a round.
+///
+/// The default rules are a single life for each character, with the last team standing winning.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct GameRules {
+    /// Number of ticks before the round times out, if any.
+    pub time_limit: Option<u32>,
+    /// Number of lives each player has.
+    #[derivative(Default(value = "1"))]
+    pub stocks: u32,
+    /// Number of ticks before a knocked out character respawns, if it has lives remaining.
+    #[derivative(Default(value = "120"))]
+    pub respawn_delay: u32,
+    /// How players are assigned to teams.
+    pub team_assignment: TeamAssignment,
+    /// Whether objects on the same team may hit each other.
+    pub friendly_fire: bool,
+    /// Condition for the round to be won.
+    pub win_condition: WinCondition,
+}
+
+impl GameRules {
+    /// Returns rules for a timed round where each player has multiple lives.
+    ///
+    /// When time runs out, the team with the most lives remaining wins.
+    pub fn timed_stock() -> Self {
+        GameRules {
+            time_limit: Some(3 * TICKS_PER_MINUTE),
+            stocks: 3,
+            ..Default::default()
+        }
+    }
+
+    /// Returns rules for players to survive waves of computer controlled characters.
+    pub fn survival() -> Self {
+        GameRules {
+            stocks: 3,
+            team_assignment: TeamAssignment::Single,
+            win_condition: WinCondition::SurvivalWaves { wave_count: 5 },
+            ..Default::default()
+        }
+    }
+
+    /// Returns rules for players to battle in two teams.
+    pub fn team_battle() -> Self {
+        GameRules {
+            team_assignment: TeamAssignment::RoundRobin { team_count: 2 },
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// How players are assigned to teams at the start of a round.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum TeamAssignment {
+    /// Each player is on their own team.
+    #[derivative(Default)]
+    Independent,
+    /// All players are on the same team.
+    Single,
+    /// Players are assigned to numbered teams in turn, by controller ID.
+    RoundRobin {
+        /// Number of teams to assign players to.
+        team_count: u32,
+    },
+}
@@ -0,0 +1,22 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// Condition for a round to be won.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Serialize)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum WinCondition {
+    /// The round is won by the last team with characters in play.
+    ///
+    /// If the round times out, the team with the most lives remaining wins, followed by the team
+    /// with the most health points.
+    #[derivative(Default)]
+    LastTeamStanding,
+    /// Players win the round by defeating waves of computer controlled characters.
+    ///
+    /// Each wave spawns one more character than the previous wave.
+    SurvivalWaves {
+        /// Number of waves to defeat.
+        wave_count: u32,
+    },
+}
@@ -0,0 +1,6 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types for the rules of a round, such as the time limit, stocks, and win condition.
+
+pub mod config;
+pub mod play;
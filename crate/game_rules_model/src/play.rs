@@ -0,0 +1,11 @@
+//! Contains data types used at runtime.
+
+pub use self::{
+    respawn_clock::RespawnClock, stocks::Stocks, survival_wave::SurvivalWave,
+    survival_wave_character::SurvivalWaveCharacter,
+};
+
+mod respawn_clock;
+mod stocks;
+mod survival_wave;
+mod survival_wave_character;
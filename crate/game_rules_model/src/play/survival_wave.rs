@@ -0,0 +1,20 @@
+use derive_new::new;
+use team_model::play::{Team, TeamCounter};
+
+/// Progress through the waves of a survival round.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct SurvivalWave {
+    /// Number of the most recently spawned wave, starting from `1`.
+    ///
+    /// This is `0` before the first wave is spawned.
+    pub wave: u32,
+}
+
+impl SurvivalWave {
+    /// Team that players are on in a survival round.
+    ///
+    /// This is the team that `TeamAssignment::Single` assigns players to.
+    pub const TEAM_PLAYERS: Team = Team::Number(TeamCounter(0));
+    /// Team that wave characters are on in a survival round.
+    pub const TEAM_WAVE: Team = Team::Number(TeamCounter(1));
+}
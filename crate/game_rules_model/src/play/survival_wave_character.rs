@@ -0,0 +1,8 @@
+use amethyst::ecs::{storage::NullStorage, Component};
+
+/// Marks a computer controlled character spawned in a survival wave.
+///
+/// These characters are opponents of the players, and are not players themselves.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq)]
+#[storage(NullStorage)]
+pub struct SurvivalWaveCharacter;
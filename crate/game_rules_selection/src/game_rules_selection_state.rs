@@ -0,0 +1,104 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use amethyst::{GameData, State, StateData, Trans};
+use application_event::AppEvent;
+use application_state::{AppState, AppStateBuilder, AutexState};
+use derivative::Derivative;
+use derive_new::new;
+use game_rules_selection_model::GameRulesSelectionEntity;
+use log::debug;
+use menu_model::MenuEvent;
+use state_registry::StateId;
+
+/// `State` where the rules of a round are selected.
+///
+/// This state is not intended to be constructed directly, but through the
+/// [`GameRulesSelectionStateBuilder`][state_builder].
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to push once the rules are selected.
+/// * `S`: State to push.
+///
+/// [state_builder]: game_rules_selection_state/struct.GameRulesSelectionStateBuilder.html
+pub type GameRulesSelectionState<'a, 'b, F, S> =
+    AppState<'a, 'b, GameRulesSelectionStateDelegate<'a, 'b, F, S>, GameRulesSelectionEntity>;
+
+/// Builder for a `GameRulesSelectionState`.
+///
+/// `SystemBundle`s to run in the `GameRulesSelectionState`'s dispatcher are registered on this
+/// builder.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to push once the rules are selected.
+/// * `S`: `State` to delegate to.
+pub type GameRulesSelectionStateBuilder<'a, 'b, F, S> = AppStateBuilder<
+    'a,
+    'b,
+    GameRulesSelectionStateDelegate<'a, 'b, F, S>,
+    GameRulesSelectionEntity,
+>;
+
+/// Delegate `State` for game rules selection.
+///
+/// This state is not intended to be used directly, but wrapped in an `AppState`. The
+/// `GameRulesSelectionState` is an alias with this as a delegate state.
+///
+/// # Type Parameters
+///
+/// * `F`: Function to construct the state to push once the rules are selected.
+/// * `S`: State to push.
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct GameRulesSelectionStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    /// Function to construct the `State` to push once the rules are selected.
+    #[derivative(Debug(bound = "F: Debug"))]
+    next_state_fn: F,
+    /// `PhantomData`.
+    marker: PhantomData<dyn AutexState<'a, 'b>>,
+}
+
+impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent>
+    for GameRulesSelectionStateDelegate<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    fn on_start(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::GameRulesSelection);
+    }
+
+    fn on_resume(&mut self, data: StateData<'_, GameData<'a, 'b>>) {
+        data.world.insert(StateId::GameRulesSelection);
+    }
+
+    fn handle_event(
+        &mut self,
+        data: StateData<'_, GameData<'a, 'b>>,
+        event: AppEvent,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        if let AppEvent::GameRulesSelection(game_rules_selection_event) = event {
+            debug!(
+                "Received game_rules_selection_event: {:?}",
+                game_rules_selection_event
+            );
+            match game_rules_selection_event {
+                MenuEvent::Select(game_rules_index) => {
+                    let game_rules = game_rules_index.game_rules();
+                    debug!("Selected game rules: {:?}", game_rules);
+                    data.world.insert(game_rules);
+
+                    Trans::Push((self.next_state_fn)())
+                }
+                MenuEvent::Close => Trans::Pop,
+            }
+        } else {
+            Trans::None
+        }
+    }
+}
@@ -0,0 +1,9 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! State where the rules of a round are selected.
+
+pub use crate::game_rules_selection_state::{
+    GameRulesSelectionState, GameRulesSelectionStateBuilder, GameRulesSelectionStateDelegate,
+};
+
+mod game_rules_selection_state;
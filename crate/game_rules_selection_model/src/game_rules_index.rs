@@ -0,0 +1,32 @@
+use game_rules_model::config::GameRules;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter, EnumString};
+
+/// Game rules menu indicies.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Display, EnumIter, EnumString, PartialEq, Eq, Serialize,
+)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum GameRulesIndex {
+    /// Last team standing with a single life.
+    Classic,
+    /// Timed round where each player has multiple lives.
+    TimedStock,
+    /// Survive waves of computer controlled characters.
+    Survival,
+    /// Last team standing with players split into two teams.
+    TeamBattle,
+}
+
+impl GameRulesIndex {
+    /// Returns the `GameRules` for this selection.
+    pub fn game_rules(self) -> GameRules {
+        match self {
+            GameRulesIndex::Classic => GameRules::default(),
+            GameRulesIndex::TimedStock => GameRules::timed_stock(),
+            GameRulesIndex::Survival => GameRules::survival(),
+            GameRulesIndex::TeamBattle => GameRules::team_battle(),
+        }
+    }
+}
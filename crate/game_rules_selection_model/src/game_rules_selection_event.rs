@@ -0,0 +1,6 @@
+use menu_model::MenuEvent;
+
+use crate::GameRulesIndex;
+
+/// Event indicating game rules selection.
+pub type GameRulesSelectionEvent = MenuEvent<GameRulesIndex>;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use structopt_derive::StructOpt;
+
+use crate::GameRulesIndex;
+
+/// Parameters to the mapper.
+///
+/// # Examples
+///
+/// * `game_rules_selection select -s timed_stock`
+/// * `game_rules_selection close`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize, StructOpt)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[structopt(rename_all = "snake_case")]
+pub enum GameRulesSelectionEventArgs {
+    /// Select event.
+    Select {
+        /// Index of the selection.
+        index: GameRulesIndex,
+    },
+    /// Close event.
+    Close,
+}
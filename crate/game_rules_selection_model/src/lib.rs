@@ -0,0 +1,14 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used during game rules selection.
+
+pub use crate::{
+    game_rules_index::GameRulesIndex, game_rules_selection_entity::GameRulesSelectionEntity,
+    game_rules_selection_event::GameRulesSelectionEvent,
+    game_rules_selection_event_args::GameRulesSelectionEventArgs,
+};
+
+mod game_rules_index;
+mod game_rules_selection_entity;
+mod game_rules_selection_event;
+mod game_rules_selection_event_args;
@@ -0,0 +1,21 @@
+use amethyst::Error;
+use game_rules_selection_model::{GameRulesSelectionEvent, GameRulesSelectionEventArgs};
+use menu_model::MenuEvent;
+use stdio_spi::StdinMapper;
+
+/// Builds a `GameRulesSelectionEvent` from stdin tokens.
+#[derive(Debug)]
+pub struct GameRulesSelectionEventStdinMapper;
+
+impl StdinMapper for GameRulesSelectionEventStdinMapper {
+    type SystemData = ();
+    type Event = GameRulesSelectionEvent;
+    type Args = GameRulesSelectionEventArgs;
+
+    fn map(_: &(), args: Self::Args) -> Result<Self::Event, Error> {
+        match args {
+            GameRulesSelectionEventArgs::Select { index } => Ok(MenuEvent::Select(index)),
+            GameRulesSelectionEventArgs::Close => Ok(MenuEvent::Close),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::any;
+
+use amethyst::{
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use application_event::AppEventVariant;
+use derive_new::new;
+use stdio_spi::MapperSystem;
+
+use crate::GameRulesSelectionEventStdinMapper;
+
+/// Adds a `MapperSystem<GameRulesSelectionEventStdinMapper>` to the `World`.
+#[derive(Debug, new)]
+pub struct GameRulesSelectionStdioBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for GameRulesSelectionStdioBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            MapperSystem::<GameRulesSelectionEventStdinMapper>::new(
+                AppEventVariant::GameRulesSelection,
+            ),
+            any::type_name::<MapperSystem<GameRulesSelectionEventStdinMapper>>(),
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Extension to enable `game_rules_selection` to be controlled by stdio.
+
+pub use crate::{
+    game_rules_selection_event_stdin_mapper::GameRulesSelectionEventStdinMapper,
+    game_rules_selection_stdio_bundle::GameRulesSelectionStdioBundle,
+};
+
+mod game_rules_selection_event_stdin_mapper;
+mod game_rules_selection_stdio_bundle;
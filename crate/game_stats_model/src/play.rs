@@ -1,6 +1,14 @@
 //! Contains data types used at runtime.
 
-pub use self::{win_outcome::WinOutcome, win_status::WinStatus};
+pub use self::{
+    game_play_stats::GamePlayStats, player_points_prev::PlayerPointsPrev,
+    player_stats::PlayerStats, player_stats_entry::PlayerStatsEntry, win_outcome::WinOutcome,
+    win_status::WinStatus,
+};
 
+mod game_play_stats;
+mod player_points_prev;
+mod player_stats;
+mod player_stats_entry;
 mod win_outcome;
 mod win_status;
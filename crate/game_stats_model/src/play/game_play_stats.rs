@@ -0,0 +1,10 @@
+use derive_new::new;
+
+use crate::play::PlayerStatsEntry;
+
+/// Statistics of each player, recorded when a round ends.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct GamePlayStats {
+    /// Statistics of each player, in controller ID order.
+    pub player_stats_entries: Vec<PlayerStatsEntry>,
+}
@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use object_model::play::{HealthPoints, SkillPoints};
+
+/// Health and skill points of a player in the previous tick.
+///
+/// This is used to calculate the damage taken and skill points used in each tick.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct PlayerPointsPrev {
+    /// Health points in the previous tick.
+    pub health_points: HealthPoints,
+    /// Skill points in the previous tick.
+    pub skill_points: SkillPoints,
+}
@@ -0,0 +1,51 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+
+/// Statistics of a player's performance during a round.
+///
+/// This is attached to each player controlled and computer controlled character.
+#[derive(Clone, Component, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+    /// Health points of damage dealt to other players.
+    pub damage_dealt: u32,
+    /// Health points of damage taken.
+    pub damage_taken: u32,
+    /// Number of hits landed on other players.
+    pub hits_landed: u32,
+    /// Number of hits in the current combo.
+    pub combo_current: u32,
+    /// Largest number of hits landed in a single combo.
+    pub combo_max: u32,
+    /// Number of ticks left for the next hit to continue the current combo.
+    pub combo_ticks_remaining: u32,
+    /// Number of other players knocked out.
+    pub kos: u32,
+    /// Skill points used.
+    pub sp_used: u32,
+    /// Number of ticks the player was alive for.
+    pub ticks_alive: u32,
+}
+
+impl PlayerStats {
+    /// Number of ticks after landing a hit within which the next hit continues the combo.
+    pub const COMBO_WINDOW_TICKS: u32 = 60;
+
+    /// Records a hit landed on another player, continuing the combo if it has not lapsed.
+    pub fn hit_land(&mut self) {
+        self.hits_landed += 1;
+        self.combo_current = if self.combo_ticks_remaining > 0 {
+            self.combo_current + 1
+        } else {
+            1
+        };
+        self.combo_max = self.combo_max.max(self.combo_current);
+        self.combo_ticks_remaining = Self::COMBO_WINDOW_TICKS;
+    }
+
+    /// Advances the combo window by one tick, ending the combo when it lapses.
+    pub fn combo_tick(&mut self) {
+        self.combo_ticks_remaining = self.combo_ticks_remaining.saturating_sub(1);
+        if self.combo_ticks_remaining == 0 {
+            self.combo_current = 0;
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use asset_model::loaded::AssetId;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+
+use crate::play::PlayerStats;
+
+/// Statistics of a player at the end of a round.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct PlayerStatsEntry {
+    /// ID of the controller of the player.
+    pub controller_id: ControllerId,
+    /// Asset ID of the player's character.
+    pub asset_id: AssetId,
+    /// Whether the player is computer controlled.
+    pub ai_controlled: bool,
+    /// The player's statistics.
+    pub player_stats: PlayerStats,
+}
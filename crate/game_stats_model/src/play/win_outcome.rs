@@ -13,6 +13,21 @@ pub enum WinOutcome {
         /// Team that won the round.
         winning_team: Team,
     },
+    /// The round timed out, and a team won with the most lives and health points remaining.
+    Decision {
+        /// Team that won the round.
+        winning_team: Team,
+    },
     /// The round ended in a draw.
     Draw,
+    /// The players survived all waves of a survival round.
+    Survived {
+        /// Number of waves survived.
+        waves: u32,
+    },
+    /// The players were defeated in a survival round.
+    Overwhelmed {
+        /// Wave that the players were defeated in.
+        wave: u32,
+    },
 }
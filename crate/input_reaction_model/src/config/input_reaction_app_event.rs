@@ -2,7 +2,9 @@ use asset_selection_model::config::AssetSelectionEventCommand;
 use control_settings_model::ControlSettingsEvent;
 use derive_more::From;
 use game_mode_selection_model::GameModeSelectionEventArgs;
-use game_play_model::GamePlayEventArgs;
+use game_play_model::{GamePlayEventArgs, GamePlayPauseEventArgs};
+use game_play_stats_model::GamePlayStatsEventArgs;
+use game_rules_selection_model::GameRulesSelectionEventArgs;
 use network_mode_selection_model::NetworkModeSelectionEventArgs;
 use serde::{Deserialize, Serialize};
 use session_host_model::config::SessionHostEventCommand;
@@ -26,6 +28,12 @@ pub enum InputReactionAppEvent {
     GameModeSelection(GameModeSelectionEventArgs),
     /// `game_play` events.
     GamePlay(GamePlayEventArgs),
+    /// `game_play_pause` events.
+    GamePlayPause(GamePlayPauseEventArgs),
+    /// `game_play_stats` events.
+    GamePlayStats(GamePlayStatsEventArgs),
+    /// `game_rules_selection` events.
+    GameRulesSelection(GameRulesSelectionEventArgs),
     /// `session_host` events.
     SessionHost(SessionHostEventCommand),
     /// `session_join` events.
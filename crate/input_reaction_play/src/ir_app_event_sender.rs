@@ -3,7 +3,11 @@ use asset_model::loaded::{AssetId, AssetIdMappings};
 use control_settings_model::ControlSettingsEvent;
 use game_input_model::config::ControllerId;
 use game_mode_selection_model::{GameModeSelectionEvent, GameModeSelectionEventArgs};
-use game_play_model::{GamePlayEvent, GamePlayEventArgs};
+use game_play_model::{
+    GamePlayEvent, GamePlayEventArgs, GamePlayPauseEvent, GamePlayPauseEventArgs,
+};
+use game_play_stats_model::{GamePlayStatsEvent, GamePlayStatsEventArgs};
+use game_rules_selection_model::{GameRulesSelectionEvent, GameRulesSelectionEventArgs};
 use input_reaction_model::config::InputReactionAppEvent;
 use log::{debug, error};
 use network_mode_selection_model::{NetworkModeSelectionEvent, NetworkModeSelectionEventArgs};
@@ -71,6 +75,24 @@ impl IrAppEventSender {
             InputReactionAppEvent::GamePlay(game_play_event_args) => {
                 Self::handle_game_play_event(ir_app_event_sender_system_data, game_play_event_args);
             }
+            InputReactionAppEvent::GamePlayPause(game_play_pause_event_args) => {
+                Self::handle_game_play_pause_event(
+                    ir_app_event_sender_system_data,
+                    game_play_pause_event_args,
+                );
+            }
+            InputReactionAppEvent::GamePlayStats(game_play_stats_event_args) => {
+                Self::handle_game_play_stats_event(
+                    ir_app_event_sender_system_data,
+                    game_play_stats_event_args,
+                );
+            }
+            InputReactionAppEvent::GameRulesSelection(game_rules_selection_event_args) => {
+                Self::handle_game_rules_selection_event(
+                    ir_app_event_sender_system_data,
+                    game_rules_selection_event_args,
+                );
+            }
             InputReactionAppEvent::SessionHost(session_host_event_command) => {
                 IrSessionHostEventSender::handle_event(
                     ir_app_event_sender_system_data,
@@ -130,8 +152,11 @@ impl IrAppEventSender {
         let game_play_event = match game_play_event_args {
             GamePlayEventArgs::Return => GamePlayEvent::Return,
             GamePlayEventArgs::Restart => GamePlayEvent::Restart,
+            GamePlayEventArgs::RestartNotify => GamePlayEvent::RestartNotify,
             GamePlayEventArgs::Pause => GamePlayEvent::Pause,
+            GamePlayEventArgs::PauseNotify => GamePlayEvent::PauseNotify,
             GamePlayEventArgs::Resume => GamePlayEvent::Resume,
+            GamePlayEventArgs::ResumeNotify => GamePlayEvent::ResumeNotify,
             GamePlayEventArgs::End => GamePlayEvent::End,
             GamePlayEventArgs::EndStats => GamePlayEvent::EndStats,
         };
@@ -141,6 +166,48 @@ impl IrAppEventSender {
             .single_write(game_play_event);
     }
 
+    fn handle_game_play_pause_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        game_play_pause_event_args: GamePlayPauseEventArgs,
+    ) {
+        let game_play_pause_event = match game_play_pause_event_args {
+            GamePlayPauseEventArgs::Select { index } => GamePlayPauseEvent::Select(index),
+            GamePlayPauseEventArgs::Close => GamePlayPauseEvent::Close,
+        };
+
+        ir_app_event_sender_system_data
+            .game_play_pause_ec
+            .single_write(game_play_pause_event);
+    }
+
+    fn handle_game_play_stats_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        game_play_stats_event_args: GamePlayStatsEventArgs,
+    ) {
+        let game_play_stats_event = match game_play_stats_event_args {
+            GamePlayStatsEventArgs::Select { index } => GamePlayStatsEvent::Select(index),
+            GamePlayStatsEventArgs::Close => GamePlayStatsEvent::Close,
+        };
+
+        ir_app_event_sender_system_data
+            .game_play_stats_ec
+            .single_write(game_play_stats_event);
+    }
+
+    fn handle_game_rules_selection_event(
+        ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
+        game_rules_selection_event_args: GameRulesSelectionEventArgs,
+    ) {
+        let game_rules_selection_event = match game_rules_selection_event_args {
+            GameRulesSelectionEventArgs::Select { index } => GameRulesSelectionEvent::Select(index),
+            GameRulesSelectionEventArgs::Close => GameRulesSelectionEvent::Close,
+        };
+
+        ir_app_event_sender_system_data
+            .game_rules_selection_ec
+            .single_write(game_rules_selection_event);
+    }
+
     fn handle_network_mode_selection_event(
         ir_app_event_sender_system_data: &mut IrAppEventSenderSystemData,
         network_mode_selection_event_args: NetworkModeSelectionEventArgs,
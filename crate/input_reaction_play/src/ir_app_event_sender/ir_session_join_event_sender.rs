@@ -1,5 +1,6 @@
 use amethyst::ecs::Entity;
-use network_session_model::play::{SessionCode, SessionDeviceName};
+use game_input_model::loaded::PlayerControllers;
+use network_session_model::play::{SessionCode, SessionDeviceName, SessionDeviceType};
 use session_join_model::{
     config::SessionJoinEventCommand, play::SessionJoinRequestParams, SessionJoinEvent,
 };
@@ -19,7 +20,25 @@ impl IrSessionJoinEventSender {
         let session_join_event = match session_join_event_command {
             SessionJoinEventCommand::SessionJoinRequest => {
                 if let Some(session_join_request_params) =
-                    Self::session_join_request_params_discover(ir_app_event_sender_system_data)
+                    Self::session_join_request_params_discover(
+                        ir_app_event_sender_system_data,
+                        SessionDeviceType::Player,
+                    )
+                {
+                    Some(SessionJoinEvent::SessionJoinRequest(
+                        session_join_request_params,
+                    ))
+                } else {
+                    // TODO: Feedback that the form needs to be filled.
+                    None
+                }
+            }
+            SessionJoinEventCommand::SessionSpectateRequest => {
+                if let Some(session_join_request_params) =
+                    Self::session_join_request_params_discover(
+                        ir_app_event_sender_system_data,
+                        SessionDeviceType::Spectator,
+                    )
                 {
                     Some(SessionJoinEvent::SessionJoinRequest(
                         session_join_request_params,
@@ -42,6 +61,7 @@ impl IrSessionJoinEventSender {
 
     fn session_join_request_params_discover(
         ir_app_event_sender_system_data: &IrAppEventSenderSystemData,
+        session_device_type: SessionDeviceType,
     ) -> Option<SessionJoinRequestParams> {
         let IrAppEventSenderSystemData {
             player_controllers,
@@ -62,7 +82,11 @@ impl IrSessionJoinEventSender {
             .map(|ui_text| ui_text.text.clone())
             .map(SessionCode::new);
 
-        let player_controllers = (*player_controllers).clone();
+        // Spectators join without controllers.
+        let player_controllers = match session_device_type {
+            SessionDeviceType::Player => (*player_controllers).clone(),
+            SessionDeviceType::Spectator => PlayerControllers::default(),
+        };
 
         if let (Some(session_device_name), Some(session_code)) = (session_device_name, session_code)
         {
@@ -70,6 +94,7 @@ impl IrSessionJoinEventSender {
                 session_code,
                 session_device_name,
                 player_controllers,
+                session_device_type,
             ))
         } else {
             None
@@ -12,7 +12,9 @@ use control_settings_model::ControlSettingsEvent;
 use derivative::Derivative;
 use game_input_model::{loaded::PlayerControllers, play::InputControlled};
 use game_mode_selection_model::GameModeSelectionEvent;
-use game_play_model::GamePlayEvent;
+use game_play_model::{GamePlayEvent, GamePlayPauseEvent};
+use game_play_stats_model::GamePlayStatsEvent;
+use game_rules_selection_model::GameRulesSelectionEvent;
 use network_mode_selection_model::NetworkModeSelectionEvent;
 use network_session_model::play::SessionCode;
 use session_host_model::SessionHostEvent;
@@ -82,6 +84,15 @@ pub struct IrAppEventSenderSystemData<'s> {
     /// `GamePlayEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
+    /// `GamePlayPauseEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_pause_ec: Write<'s, EventChannel<GamePlayPauseEvent>>,
+    /// `GamePlayStatsEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_stats_ec: Write<'s, EventChannel<GamePlayStatsEvent>>,
+    /// `GameRulesSelectionEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_rules_selection_ec: Write<'s, EventChannel<GameRulesSelectionEvent>>,
     /// `NetworkModeSelectionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_mode_selection_ec: Write<'s, EventChannel<NetworkModeSelectionEvent>>,
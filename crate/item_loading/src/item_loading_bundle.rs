@@ -0,0 +1,35 @@
+use amethyst::{
+    assets::Processor,
+    core::bundle::SystemBundle,
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use derive_new::new;
+use item_model::{config::ItemDefinition, loaded::WeaponPointSequence};
+
+/// Adds the following processor `System`s to the world:
+///
+/// * `Processor::<ItemDefinition>`
+/// * `Processor::<WeaponPointSequence>`
+#[derive(Debug, new)]
+pub struct ItemLoadingBundle;
+
+impl<'a, 'b> SystemBundle<'a, 'b> for ItemLoadingBundle {
+    fn build(
+        self,
+        _world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            Processor::<ItemDefinition>::new(),
+            "item_definition_processor",
+            &[],
+        ); // kcov-ignore
+        builder.add(
+            Processor::<WeaponPointSequence>::new(),
+            "weapon_point_sequence_processor",
+            &[],
+        ); // kcov-ignore
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+//! Contains the types that represent the configuration on disk.
+
+pub use self::{
+    item_definition::{ItemDefinition, ItemDefinitionHandle},
+    item_frame::ItemFrame,
+    item_sequence::ItemSequence,
+    item_sequence_name::ItemSequenceName,
+    weapon_point::WeaponPoint,
+};
+
+mod item_definition;
+mod item_frame;
+mod item_sequence;
+mod item_sequence_name;
+mod weapon_point;
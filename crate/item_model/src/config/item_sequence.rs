@@ -0,0 +1,30 @@
+use derive_new::new;
+use object_model::config::{GameObjectSequence, ObjectSequence};
+use sequence_model::config::Sequence;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{ItemFrame, ItemSequenceName};
+
+/// Represents an independent action sequence of an `Item`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
+// #[serde(deny_unknown_fields)] // See <https://github.com/serde-rs/serde/issues/1547>
+pub struct ItemSequence {
+    /// Object sequence for common object fields.
+    #[serde(flatten)]
+    pub object_sequence: ObjectSequence<ItemSequenceName, ItemFrame>,
+}
+
+impl AsRef<Sequence<ItemSequenceName, ItemFrame>> for ItemSequence {
+    fn as_ref(&self) -> &Sequence<ItemSequenceName, ItemFrame> {
+        &self.object_sequence.sequence
+    }
+}
+
+impl GameObjectSequence for ItemSequence {
+    type SequenceName = ItemSequenceName;
+    type GameObjectFrame = ItemFrame;
+
+    fn object_sequence(&self) -> &ObjectSequence<Self::SequenceName, Self::GameObjectFrame> {
+        &self.object_sequence
+    }
+}
@@ -0,0 +1,36 @@
+use derivative::Derivative;
+use sequence_model::config::SequenceName;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString, IntoStaticStr};
+
+/// `Item` sequence names.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Derivative,
+    Deserialize,
+    Display,
+    EnumString,
+    IntoStaticStr,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ItemSequenceName {
+    /// Default sequence for items, lying on the ground waiting to be picked up.
+    #[derivative(Default)]
+    OnGround,
+    /// Sequence to switch to when held by another object.
+    Held,
+    /// Sequence to switch to when thrown by the holding object.
+    Thrown,
+    /// Sequence to switch to when hit by another object.
+    Hit,
+}
+
+impl SequenceName for ItemSequenceName {}
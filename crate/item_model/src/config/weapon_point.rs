@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derive_new::new;
+use kinematic_model::config::Position;
+use serde::{Deserialize, Serialize};
+
+/// Position of a held item relative to the holding object, when facing right.
+///
+/// This is specified per frame, so that held items follow the holder's hand.
+#[derive(Clone, Component, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(default, deny_unknown_fields)]
+#[storage(VecStorage)]
+pub struct WeaponPoint {
+    /// Offset of the held item from the holding object.
+    pub position: Position<i32>,
+}
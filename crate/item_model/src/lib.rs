@@ -0,0 +1,7 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types that represent item objects, such as weapons.
+
+pub mod config;
+pub mod loaded;
+pub mod play;
@@ -0,0 +1,16 @@
+//! Contains the types that represent processed configuration.
+//!
+//! This differs from the plain configuration types as they would have been processed into the form
+//! that will be used in game.
+
+pub use self::{
+    asset_item_definition_handle::AssetItemDefinitionHandle,
+    item_transitions::ItemTransitions,
+    weapon_point_sequence::{WeaponPointSequence, WeaponPointSequenceHandle},
+    weapon_point_sequence_handles::WeaponPointSequenceHandles,
+};
+
+mod asset_item_definition_handle;
+mod item_transitions;
+mod weapon_point_sequence;
+mod weapon_point_sequence_handles;
@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to when an item is picked up, thrown, or dropped.
+///
+/// This is used by the item systems to transition item sequences, similar to
+/// `CharacterGrabTransitions`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct ItemTransitions {
+    /// Sequence ID to transition to when lying on the ground.
+    pub on_ground: SequenceId,
+    /// Sequence ID to transition to when held by another object.
+    pub held: SequenceId,
+    /// Sequence ID to transition to when thrown.
+    pub thrown: SequenceId,
+}
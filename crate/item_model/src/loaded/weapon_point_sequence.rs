@@ -0,0 +1,7 @@
+use sequence_model::frame_component_data;
+
+use crate::config::WeaponPoint;
+
+/// Sequence of `WeaponPoint` values.
+#[frame_component_data(WeaponPoint, copy)]
+pub struct WeaponPointSequence;
@@ -0,0 +1,15 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use asset_model::ItemComponent;
+use sequence_model_derive::sequence_component_data;
+
+use crate::loaded::WeaponPointSequenceHandle;
+
+/// Sequence of `WeaponPointSequenceHandle`s.
+#[sequence_component_data(WeaponPointSequenceHandle)]
+#[derive(Component)]
+#[storage(DenseVecStorage)]
+pub struct WeaponPointSequenceHandles;
+
+impl<'s> ItemComponent<'s> for WeaponPointSequenceHandles {
+    type SystemData = ();
+}
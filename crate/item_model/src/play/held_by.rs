@@ -0,0 +1,8 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a held item to the entity holding it.
+///
+/// This component should be attached to the item entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HeldBy(pub Entity);
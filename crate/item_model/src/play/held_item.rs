@@ -0,0 +1,8 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component, Entity};
+use derive_new::new;
+
+/// Links a holding entity to the item it holds.
+///
+/// This component should be attached to the holding entity.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+pub struct HeldItem(pub Entity);
@@ -0,0 +1,8 @@
+pub use self::{
+    item_held_system::ItemHeldSystem, item_pick_up_system::ItemPickUpSystem,
+    item_throw_system::ItemThrowSystem,
+};
+
+mod item_held_system;
+mod item_pick_up_system;
+mod item_throw_system;
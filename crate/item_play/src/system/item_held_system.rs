@@ -0,0 +1,146 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    config::WeaponPoint,
+    loaded::ItemTransitions,
+    play::{HeldBy, HeldItem},
+};
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+
+/// Keeps held items at their holder's weapon point, and drops them when the holder is gone.
+///
+/// This must run before the `StickToTargetObjectSystem`, so that the item's position is updated
+/// with the holder's current weapon point.
+#[derive(Debug, Default, new)]
+pub struct ItemHeldSystem;
+
+/// `ItemHeldSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemHeldSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `WeaponPoint` components.
+    #[derivative(Debug = "ignore")]
+    pub weapon_points: ReadStorage<'s, WeaponPoint>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: WriteStorage<'s, Mirrored>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemHeldSystem {
+    type SystemData = ItemHeldSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemHeldSystemData {
+            entities,
+            weapon_points,
+            health_pointses,
+            item_transitionses,
+            mut held_bys,
+            mut held_items,
+            mut target_objects,
+            mut chase_mode_sticks,
+            mut mirroreds,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        // Collect dropped items first, as dropping them writes to the `HeldBy` storage.
+        let items_dropped = (&entities, &held_bys)
+            .join()
+            .filter_map(|(entity_item, held_by)| {
+                let entity_holder = held_by.0;
+                let holder_has_item =
+                    held_items.get(entity_holder) == Some(&HeldItem::new(entity_item));
+                let holder_alive = entities.is_alive(entity_holder)
+                    && health_pointses
+                        .get(entity_holder)
+                        .map(|health_points| health_points.0 > 0)
+                        .unwrap_or(true);
+
+                if holder_alive && holder_has_item {
+                    let mirrored = mirroreds
+                        .get(entity_holder)
+                        .copied()
+                        .unwrap_or_else(Mirrored::default);
+                    let weapon_point = weapon_points
+                        .get(entity_holder)
+                        .copied()
+                        .unwrap_or_else(WeaponPoint::default);
+                    let offset_x = if *mirrored {
+                        -weapon_point.position.x as f32
+                    } else {
+                        weapon_point.position.x as f32
+                    };
+                    let offset = Position::<f32>::new(
+                        offset_x,
+                        weapon_point.position.y as f32,
+                        weapon_point.position.z as f32,
+                    );
+
+                    if let Some(chase_mode_stick) = chase_mode_sticks.get_mut(entity_item) {
+                        chase_mode_stick.offset = Some(offset);
+                    }
+                    mirroreds
+                        .insert(entity_item, mirrored)
+                        .expect("Failed to insert `Mirrored` component.");
+
+                    None
+                } else {
+                    Some((entity_item, entity_holder, holder_has_item))
+                }
+            })
+            .collect::<Vec<(Entity, Entity, bool)>>();
+
+        items_dropped
+            .into_iter()
+            .for_each(|(entity_item, entity_holder, holder_has_item)| {
+                held_bys.remove(entity_item);
+                target_objects.remove(entity_item);
+                chase_mode_sticks.remove(entity_item);
+                if holder_has_item {
+                    held_items.remove(entity_holder);
+                }
+
+                let on_ground = item_transitionses
+                    .get(entity_item)
+                    .map(|item_transitions| item_transitions.on_ground);
+                if let (Some(on_ground), Some(sequence_id)) =
+                    (on_ground, sequence_ids.get_mut(entity_item))
+                {
+                    *sequence_id = on_ground;
+                }
+            });
+    }
+}
@@ -0,0 +1,127 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use chase_model::play::{ChaseModeStick, TargetObject};
+use collision_model::play::PickUpEvent;
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    loaded::ItemTransitions,
+    play::{HeldBy, HeldItem},
+};
+use kinematic_model::config::Velocity;
+use sequence_model::loaded::SequenceId;
+
+/// Latches picked up items to the object picking them up.
+///
+/// The item's position is updated to follow the holder's weapon point by the `ItemHeldSystem`.
+#[derive(Debug, Default, new)]
+pub struct ItemPickUpSystem {
+    /// Reader ID for the `PickUpEvent` event channel.
+    #[new(default)]
+    pick_up_event_rid: Option<ReaderId<PickUpEvent>>,
+}
+
+/// `ItemPickUpSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemPickUpSystemData<'s> {
+    /// `PickUpEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub pick_up_ec: Read<'s, EventChannel<PickUpEvent>>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemPickUpSystem {
+    type SystemData = ItemPickUpSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemPickUpSystemData {
+            pick_up_ec,
+            item_transitionses,
+            mut held_bys,
+            mut held_items,
+            mut target_objects,
+            mut chase_mode_sticks,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        pick_up_ec
+            .read(
+                self.pick_up_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for ItemPickUpSystem."),
+            )
+            .for_each(|ev| {
+                let entity_holder = ev.from;
+                let entity_item = ev.to;
+
+                // Only items may be picked up, and each object may only hold one item.
+                let item_transitions =
+                    if let Some(item_transitions) = item_transitionses.get(entity_item) {
+                        *item_transitions
+                    } else {
+                        return;
+                    };
+                if held_bys.contains(entity_item)
+                    || held_items.contains(entity_holder)
+                    || item_transitionses.contains(entity_holder)
+                {
+                    return;
+                }
+
+                held_bys
+                    .insert(entity_item, HeldBy::new(entity_holder))
+                    .expect("Failed to insert `HeldBy` component.");
+                held_items
+                    .insert(entity_holder, HeldItem::new(entity_item))
+                    .expect("Failed to insert `HeldItem` component.");
+                target_objects
+                    .insert(entity_item, TargetObject::new(entity_holder))
+                    .expect("Failed to insert `TargetObject` component.");
+                chase_mode_sticks
+                    .insert(entity_item, ChaseModeStick::new(None))
+                    .expect("Failed to insert `ChaseModeStick` component.");
+
+                if let Some(velocity) = velocities.get_mut(entity_item) {
+                    *velocity = Velocity::default();
+                }
+                if let Some(sequence_id) = sequence_ids.get_mut(entity_item) {
+                    *sequence_id = item_transitions.held;
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.pick_up_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<PickUpEvent>>()
+                .register_reader(),
+        );
+    }
+}
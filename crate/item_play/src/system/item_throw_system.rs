@@ -0,0 +1,137 @@
+use amethyst::{
+    ecs::{Entities, Entity, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use character_model::loaded::CharacterGrabTransitions;
+use chase_model::play::{ChaseModeStick, TargetObject};
+use derivative::Derivative;
+use derive_new::new;
+use item_model::{
+    loaded::ItemTransitions,
+    play::{HeldBy, HeldItem},
+};
+use kinematic_model::config::Velocity;
+use mirrored_model::play::Mirrored;
+use sequence_model::loaded::SequenceId;
+
+/// Horizontal speed of thrown items, in the direction the thrower faces.
+const THROW_VELOCITY_X: f32 = 12.;
+/// Vertical speed of thrown items.
+const THROW_VELOCITY_Y: f32 = 5.;
+
+/// Throws held items when the holder switches to its `Throw` sequence.
+///
+/// The thrown item is released from the holder, and transitions to its `Thrown` sequence.
+#[derive(Debug, Default, new)]
+pub struct ItemThrowSystem;
+
+/// `ItemThrowSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemThrowSystemData<'s> {
+    /// `Entities` resource.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `CharacterGrabTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_grab_transitionses: ReadStorage<'s, CharacterGrabTransitions>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: ReadStorage<'s, ItemTransitions>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `HeldBy` components.
+    #[derivative(Debug = "ignore")]
+    pub held_bys: WriteStorage<'s, HeldBy>,
+    /// `HeldItem` components.
+    #[derivative(Debug = "ignore")]
+    pub held_items: WriteStorage<'s, HeldItem>,
+    /// `TargetObject` components.
+    #[derivative(Debug = "ignore")]
+    pub target_objects: WriteStorage<'s, TargetObject>,
+    /// `ChaseModeStick` components.
+    #[derivative(Debug = "ignore")]
+    pub chase_mode_sticks: WriteStorage<'s, ChaseModeStick>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for ItemThrowSystem {
+    type SystemData = ItemThrowSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ItemThrowSystemData {
+            entities,
+            character_grab_transitionses,
+            item_transitionses,
+            mirroreds,
+            mut held_bys,
+            mut held_items,
+            mut target_objects,
+            mut chase_mode_sticks,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        // Collect thrown items first, as throwing them writes to the `HeldBy` storage.
+        let items_thrown = (&entities, &held_bys)
+            .join()
+            .filter_map(|(entity_item, held_by)| {
+                let entity_holder = held_by.0;
+                let holder_throwing = match (
+                    character_grab_transitionses.get(entity_holder),
+                    sequence_ids.get(entity_holder),
+                ) {
+                    (Some(transitions), Some(sequence_id)) => *sequence_id == transitions.throw,
+                    _ => false,
+                };
+
+                if holder_throwing
+                    && held_items.get(entity_holder) == Some(&HeldItem::new(entity_item))
+                {
+                    Some((entity_item, entity_holder))
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<(Entity, Entity)>>();
+
+        items_thrown
+            .into_iter()
+            .for_each(|(entity_item, entity_holder)| {
+                held_bys.remove(entity_item);
+                held_items.remove(entity_holder);
+                target_objects.remove(entity_item);
+                chase_mode_sticks.remove(entity_item);
+
+                if let Some(velocity) = velocities.get_mut(entity_item) {
+                    let mirrored = mirroreds
+                        .get(entity_holder)
+                        .map(|mirrored| **mirrored)
+                        .unwrap_or(false);
+                    velocity.x = if mirrored {
+                        -THROW_VELOCITY_X
+                    } else {
+                        THROW_VELOCITY_X
+                    };
+                    velocity.y = THROW_VELOCITY_Y;
+                    velocity.z = 0.;
+                }
+
+                let thrown = item_transitionses
+                    .get(entity_item)
+                    .map(|item_transitions| item_transitions.thrown);
+                if let (Some(thrown), Some(sequence_id)) =
+                    (thrown, sequence_ids.get_mut(entity_item))
+                {
+                    *sequence_id = thrown;
+                }
+            });
+    }
+}
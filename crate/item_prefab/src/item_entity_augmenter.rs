@@ -0,0 +1,69 @@
+use amethyst::ecs::Entity;
+use asset_model::loaded::AssetId;
+use collision_model::loaded::HitTransition;
+use item_model::{config::ItemSequenceName, loaded::ItemTransitions};
+use map_model::play::MapBounded;
+use sequence_model::{config::SequenceNameString, loaded::SequenceId};
+
+use crate::{ItemComponentStorages, ItemSpawningResources};
+
+/// Augments an entity with `Item` components.
+#[derive(Debug)]
+pub struct ItemEntityAugmenter;
+
+impl ItemEntityAugmenter {
+    /// Augments an entity with `Item` components.
+    ///
+    /// # Parameters
+    ///
+    /// * `item_spawning_resources`: Resources needed to spawn the item.
+    /// * `item_component_storages`: Item specific `Component` storages.
+    /// * `asset_id`: Asset ID of the item.
+    /// * `entity`: The entity to augment.
+    pub fn augment<'s>(
+        ItemSpawningResources {
+            asset_sequence_id_mappings_item,
+        }: &ItemSpawningResources<'s>,
+        ItemComponentStorages {
+            map_boundeds,
+            hit_transitions,
+            item_transitionses,
+        }: &mut ItemComponentStorages<'s>,
+        asset_id: AssetId,
+        entity: Entity,
+    ) {
+        let sequence_id_mappings = asset_sequence_id_mappings_item
+            .get(asset_id)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Expected `SequenceIdMappings<ItemSequenceName>` to exist for `{:?}`.",
+                    asset_id
+                )
+            });
+        let sequence_id = |item_sequence_name| {
+            sequence_id_mappings
+                .id(&SequenceNameString::Name(item_sequence_name))
+                .copied()
+                .unwrap_or(SequenceId(0))
+        };
+
+        let item_transitions = ItemTransitions {
+            on_ground: sequence_id(ItemSequenceName::OnGround),
+            held: sequence_id(ItemSequenceName::Held),
+            thrown: sequence_id(ItemSequenceName::Thrown),
+        };
+
+        map_boundeds
+            .insert(entity, MapBounded::default())
+            .expect("Failed to insert `MapBounded` component.");
+        hit_transitions
+            .insert(
+                entity,
+                HitTransition::new(sequence_id(ItemSequenceName::Hit)),
+            )
+            .expect("Failed to insert `HitTransition` component.");
+        item_transitionses
+            .insert(entity, item_transitions)
+            .expect("Failed to insert `ItemTransitions` component.");
+    }
+}
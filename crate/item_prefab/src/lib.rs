@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides the prefab types and processing logic for items.
+
+pub use crate::{
+    item_entity_augmenter::ItemEntityAugmenter,
+    system_data::{ItemComponentStorages, ItemSpawningResources},
+};
+
+mod item_entity_augmenter;
+mod system_data;
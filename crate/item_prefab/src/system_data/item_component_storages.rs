@@ -0,0 +1,26 @@
+use amethyst::{
+    ecs::{World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use collision_model::loaded::HitTransition;
+use derivative::Derivative;
+use item_model::loaded::ItemTransitions;
+use map_model::play::MapBounded;
+
+/// Item specific `Component` storages.
+///
+/// These are the storages for the components specific to item objects. See also
+/// `ObjectComponentStorages`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemComponentStorages<'s> {
+    /// `MapBounded` component storage.
+    #[derivative(Debug = "ignore")]
+    pub map_boundeds: WriteStorage<'s, MapBounded>,
+    /// `HitTransition` components.
+    #[derivative(Debug = "ignore")]
+    pub hit_transitions: WriteStorage<'s, HitTransition>,
+    /// `ItemTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub item_transitionses: WriteStorage<'s, ItemTransitions>,
+}
@@ -0,0 +1,16 @@
+use amethyst::{
+    ecs::{Read, World},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use item_model::config::ItemSequenceName;
+use sequence_model::loaded::AssetSequenceIdMappings;
+
+/// Resources used to spawn item entities.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ItemSpawningResources<'s> {
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
+}
@@ -154,10 +154,12 @@ where
                          * `SpriteLoadingBundle`\n\
                          * `CharacterLoadingBundle`\n\
                          * `EnergyLoadingBundle`\n\
+                         * `ItemLoadingBundle`\n\
                          * `BackgroundLoadingBundle`\n\
                          * `MapLoadingBundle`\n\
                          * `amethyst::audio::AudioBundle`\n\
                          * `KinematicLoadingBundle`\n\
+                         * `ChaseLoadingBundle`\n\
                          * `CollisionAudioLoadingBundle`\n\
                          * `UiAudioLoadingBundle`\n\
                          * `UiLoadingBundle`\n\
@@ -7,7 +7,7 @@ use amethyst::{
 use asset_loading::AssetDiscovery;
 use asset_model::{
     config::AssetIndex,
-    loaded::{AssetId, AssetIdMappings, AssetTypeMappings},
+    loaded::{AssetFingerprint, AssetId, AssetIdMappings, AssetTypeMappings},
 };
 use derivative::Derivative;
 use derive_new::new;
@@ -15,7 +15,7 @@ use loading_model::loaded::{AssetLoadStage, LoadStage};
 use log::debug;
 use slotmap::SecondaryMap;
 
-/// Discovers assets and writes to `Option<AssetIndex>`.
+/// Discovers assets and writes to `Option<AssetIndex>` and `AssetFingerprint`.
 #[derive(Debug, Default, new)]
 pub struct AssetDiscoverySystem {
     /// Path to the assets directory.
@@ -29,6 +29,9 @@ pub struct AssetDiscoverySystemData<'s> {
     /// `Option<AssetIndex>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_index: Write<'s, Option<AssetIndex>>,
+    /// `AssetFingerprint` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_fingerprint: Write<'s, AssetFingerprint>,
     /// `AssetIdMappings` resource.
     #[derivative(Debug = "ignore")]
     pub asset_id_mappings: Write<'s, AssetIdMappings>,
@@ -50,6 +53,7 @@ impl<'s> System<'s> for AssetDiscoverySystem {
         &mut self,
         AssetDiscoverySystemData {
             mut asset_index,
+            mut asset_fingerprint,
             mut asset_id_mappings,
             mut asset_type_mappings,
             mut asset_load_stage,
@@ -88,6 +92,7 @@ impl<'s> System<'s> for AssetDiscoverySystem {
                     asset_id_to_path.insert(asset_id, asset_record.path.clone());
                 });
 
+            *asset_fingerprint = AssetFingerprint::from_asset_index(&asset_index_discovered);
             *asset_index = Some(asset_index_discovered);
         }
     }
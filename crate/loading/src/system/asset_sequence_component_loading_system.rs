@@ -2,7 +2,9 @@ use amethyst::ecs::WorldExt;
 use asset_model::{config::AssetType, loaded::AssetId};
 use audio_model::loaded::SourceSequenceHandles;
 use character_model::loaded::CharacterIrsHandles;
+use chase_model::loaded::ChaseSequenceHandles;
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
+use item_model::loaded::WeaponPointSequenceHandles;
 use kinematic_model::loaded::ObjectAccelerationSequenceHandles;
 use loading_model::loaded::LoadStage;
 use loading_spi::{AssetLoadingResources, SequenceComponentLoadingResources};
@@ -90,7 +92,9 @@ impl<'s> AssetPartLoader<'s> for AssetSequenceComponentLoader {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            chase_sequence_assets,
             character_irs_assets,
+            weapon_point_sequence_assets,
             tint_sequence_assets,
             scale_sequence_assets,
             ..
@@ -145,7 +149,9 @@ impl<'s> AssetPartLoader<'s> for AssetSequenceComponentLoader {
             && sequence_component_loaded!(BodySequenceHandles, body_sequence_assets)
             && sequence_component_loaded!(InteractionsSequenceHandles, interactions_sequence_assets)
             && sequence_component_loaded!(SpawnsSequenceHandles, spawns_sequence_assets)
+            && sequence_component_loaded!(ChaseSequenceHandles, chase_sequence_assets)
             && sequence_component_loaded!(CharacterIrsHandles, character_irs_assets)
+            && sequence_component_loaded!(WeaponPointSequenceHandles, weapon_point_sequence_assets)
             && sequence_component_loaded!(TintSequenceHandles, tint_sequence_assets)
             && sequence_component_loaded!(ScaleSequenceHandles, scale_sequence_assets)
     }
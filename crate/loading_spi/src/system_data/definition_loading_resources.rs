@@ -7,6 +7,7 @@ use background_model::{config::BackgroundDefinition, loaded::AssetBackgroundDefi
 use character_model::{config::CharacterDefinition, loaded::AssetCharacterDefinitionHandle};
 use derivative::Derivative;
 use energy_model::{config::EnergyDefinition, loaded::AssetEnergyDefinitionHandle};
+use item_model::{config::ItemDefinition, loaded::AssetItemDefinitionHandle};
 use map_model::{config::MapDefinition, loaded::AssetMapDefinitionHandle};
 use ui_model::{config::UiDefinition, loaded::AssetUiDefinitionHandle};
 
@@ -20,6 +21,9 @@ pub struct DefinitionLoadingResources<'s> {
     /// `EnergyDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub energy_definition_assets: Read<'s, AssetStorage<EnergyDefinition>>,
+    /// `ItemDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub item_definition_assets: Read<'s, AssetStorage<ItemDefinition>>,
     /// `MapDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub map_definition_assets: Read<'s, AssetStorage<MapDefinition>>,
@@ -35,6 +39,9 @@ pub struct DefinitionLoadingResources<'s> {
     /// `AssetEnergyDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_energy_definition_handle: Write<'s, AssetEnergyDefinitionHandle>,
+    /// `AssetItemDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_definition_handle: Write<'s, AssetItemDefinitionHandle>,
     /// `AssetMapDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_map_definition_handle: Write<'s, AssetMapDefinitionHandle>,
@@ -56,6 +63,9 @@ pub struct DefinitionLoadingResourcesRead<'s> {
     /// `EnergyDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub energy_definition_assets: Read<'s, AssetStorage<EnergyDefinition>>,
+    /// `ItemDefinition` assets.
+    #[derivative(Debug = "ignore")]
+    pub item_definition_assets: Read<'s, AssetStorage<ItemDefinition>>,
     /// `MapDefinition` assets.
     #[derivative(Debug = "ignore")]
     pub map_definition_assets: Read<'s, AssetStorage<MapDefinition>>,
@@ -71,6 +81,9 @@ pub struct DefinitionLoadingResourcesRead<'s> {
     /// `AssetEnergyDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_energy_definition_handle: Read<'s, AssetEnergyDefinitionHandle>,
+    /// `AssetItemDefinitionHandle` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_item_definition_handle: Read<'s, AssetItemDefinitionHandle>,
     /// `AssetMapDefinitionHandle` resource.
     #[derivative(Debug = "ignore")]
     pub asset_map_definition_handle: Read<'s, AssetMapDefinitionHandle>,
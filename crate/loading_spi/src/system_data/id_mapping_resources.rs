@@ -5,6 +5,7 @@ use amethyst::{
 use character_model::config::CharacterSequenceName;
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use sequence_model::loaded::AssetSequenceIdMappings;
 use sprite_model::config::SpriteSequenceName;
 
@@ -26,6 +27,9 @@ pub struct IdMappingResources<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Write<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Write<'s, AssetSequenceIdMappings<ItemSequenceName>>,
 }
 
 /// `IdMappingResourcesRead`.
@@ -42,4 +46,7 @@ pub struct IdMappingResourcesRead<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
 }
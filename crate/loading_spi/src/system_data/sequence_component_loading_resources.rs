@@ -8,6 +8,7 @@ use asset_model::{loaded::AssetItemIds, play::AssetWorld};
 use audio_model::loaded::SourceSequence;
 use camera_model::play::CameraZoomDimensions;
 use character_model::loaded::{CharacterInputReactions, CharacterIrs};
+use chase_model::loaded::ChaseSequence;
 use collision_model::{
     config::{Body, Interactions},
     loaded::{BodySequence, InteractionsSequence},
@@ -15,6 +16,7 @@ use collision_model::{
 use derivative::Derivative;
 use game_input_model::{config::PlayerInputConfigs, loaded::PlayerControllers};
 use input_reaction_model::loaded::{InputReaction, InputReactions, InputReactionsSequence};
+use item_model::loaded::WeaponPointSequence;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use map_model::loaded::{AssetMapBounds, AssetMargins};
 use sequence_model::loaded::WaitSequence;
@@ -84,6 +86,9 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `SpawnsSequence` assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `ChaseSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub chase_sequence_assets: Read<'s, AssetStorage<ChaseSequence>>,
 
     /// `InputReactions` assets.
     #[derivative(Debug = "ignore")]
@@ -98,6 +103,9 @@ pub struct SequenceComponentLoadingResources<'s> {
     /// `CharacterIrs` assets.
     #[derivative(Debug = "ignore")]
     pub character_irs_assets: Read<'s, AssetStorage<CharacterIrs>>,
+    /// `WeaponPointSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub weapon_point_sequence_assets: Read<'s, AssetStorage<WeaponPointSequence>>,
 
     /// `TintSequence` assets.
     #[derivative(Debug = "ignore")]
@@ -159,6 +167,9 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `SpawnsSequence` assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `ChaseSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub chase_sequence_assets: Read<'s, AssetStorage<ChaseSequence>>,
 
     /// `CharacterInputReactions` assets.
     #[derivative(Debug = "ignore")]
@@ -166,6 +177,9 @@ pub struct SequenceComponentLoadingResourcesRead<'s> {
     /// `CharacterIrs` assets.
     #[derivative(Debug = "ignore")]
     pub character_irs_assets: Read<'s, AssetStorage<CharacterIrs>>,
+    /// `WeaponPointSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub weapon_point_sequence_assets: Read<'s, AssetStorage<WeaponPointSequence>>,
 
     /// `TintSequence` assets.
     #[derivative(Debug = "ignore")]
@@ -6,9 +6,14 @@ use amethyst::{
 };
 use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder, AutexState};
+use asset_model::{
+    config::AssetType,
+    loaded::{AssetId, AssetTypeMappings},
+};
 use asset_selection_model::play::AssetSelectionEvent;
 use derivative::Derivative;
 use derive_new::new;
+use game_model::play::GameRng;
 use log::debug;
 use map_selection_model::{MapSelection, MapSelectionEntity};
 use state_registry::StateId;
@@ -72,6 +77,20 @@ where
     fn reset_map_selection_state(&self, world: &mut World) {
         world.insert(MapSelectionStatus::Pending);
     }
+
+    /// Resolves a *Random* map selection using the `GameRng`.
+    fn random_selection_resolve(world: &mut World) {
+        let asset_ids = world
+            .read_resource::<AssetTypeMappings>()
+            .iter_ids(&AssetType::Map)
+            .copied()
+            .collect::<Vec<AssetId>>();
+        let mut game_rng = world.write_resource::<GameRng>();
+
+        world
+            .write_resource::<MapSelection>()
+            .random_resolve(&asset_ids, &mut **game_rng);
+    }
 }
 
 impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent> for MapSelectionStateDelegate<'a, 'b, F, S>
@@ -105,6 +124,8 @@ where
             match asset_selection_event {
                 AssetSelectionEvent::Return => Trans::Pop,
                 AssetSelectionEvent::Confirm => {
+                    Self::random_selection_resolve(data.world);
+
                     let map_selection = data.world.read_resource::<MapSelection>();
 
                     // Hack: `AssetSelectionEvent`s are carried through from the previous state
@@ -4,7 +4,7 @@ use amethyst::{
     shrev::{EventChannel, ReaderId},
 };
 use asset_model::{config::AssetType, loaded::AssetTypeMappings};
-use asset_selection_model::play::{AssetSelection, AssetSelectionEvent};
+use asset_selection_model::play::AssetSelectionEvent;
 use derivative::Derivative;
 use derive_new::new;
 use log::warn;
@@ -30,9 +30,6 @@ pub struct MapSelectionSystemData<'s> {
     /// `AssetSelectionEvent` channel.
     #[derivative(Debug = "ignore")]
     pub asset_selection_ec: Read<'s, EventChannel<AssetSelectionEvent>>,
-    /// `AssetTypeMappings` resource.
-    #[derivative(Debug = "ignore")]
-    pub asset_type_mappings: Read<'s, AssetTypeMappings>,
     /// `MapSelection` resource.
     #[derivative(Debug = "ignore")]
     pub map_selection: WriteExpect<'s, MapSelection>,
@@ -46,7 +43,6 @@ impl<'s> System<'s> for MapSelectionSystem {
         MapSelectionSystemData {
             mut map_selection_status,
             asset_selection_ec,
-            asset_type_mappings,
             mut map_selection,
         }: Self::SystemData,
     ) {
@@ -65,19 +61,8 @@ impl<'s> System<'s> for MapSelectionSystem {
                 | AssetSelectionEvent::Select {
                     asset_selection, ..
                 } => {
-                    *map_selection = match asset_selection {
-                        AssetSelection::Random => {
-                            let first_map_id = asset_type_mappings
-                                .iter_ids(&AssetType::Map)
-                                .next()
-                                .copied()
-                                .expect("Expected at least one map to be loaded.");
-
-                            // TODO: implement random.
-                            MapSelection::Random(Some(first_map_id))
-                        }
-                        AssetSelection::Id(asset_id) => MapSelection::Id(asset_id),
-                    };
+                    // *Random* is resolved when the selection is confirmed.
+                    *map_selection = MapSelection::from(asset_selection);
                 }
                 AssetSelectionEvent::Deselect { .. } => {
                     *map_selection_status = MapSelectionStatus::Pending;
@@ -85,7 +70,10 @@ impl<'s> System<'s> for MapSelectionSystem {
                 AssetSelectionEvent::Confirm => {
                     *map_selection_status = MapSelectionStatus::Confirmed;
                 }
-                AssetSelectionEvent::Join { .. } | AssetSelectionEvent::Leave { .. } => {
+                AssetSelectionEvent::Join { .. }
+                | AssetSelectionEvent::Leave { .. }
+                | AssetSelectionEvent::AiSwitch { .. }
+                | AssetSelectionEvent::TeamSwitch { .. } => {
                     warn!("Received `{:?}` in `MapSelectionSystem`.", ev);
                 }
             });
@@ -2,6 +2,7 @@ use amethyst::ecs::{storage::DenseVecStorage, Component};
 use asset_model::loaded::AssetId;
 use asset_selection_model::play::AssetSelection;
 use derivative::Derivative;
+use rand::{seq::SliceRandom, Rng};
 
 /// Selected map ID or random for a particular controller.
 #[derive(Clone, Component, Copy, Debug, Derivative, PartialEq)]
@@ -25,6 +26,21 @@ impl MapSelection {
             MapSelection::Id(asset_id) => Some(asset_id),
         }
     }
+
+    /// Resolves a *Random* selection to one of the given map asset IDs.
+    ///
+    /// # Parameters
+    ///
+    /// * `asset_ids`: Map asset IDs to choose from.
+    /// * `rng`: Random number generator to choose with.
+    pub fn random_resolve<R>(&mut self, asset_ids: &[AssetId], rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        if let MapSelection::Random(..) = self {
+            *self = MapSelection::Random(asset_ids.choose(rng).copied());
+        }
+    }
 }
 
 impl From<AssetSelection> for MapSelection {
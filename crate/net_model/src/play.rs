@@ -1,10 +1,23 @@
 //! Data types used at runtime.
 
 pub use self::{
-    net_data::NetData, net_event_channel::NetEventChannel, net_message_event::NetMessageEvent,
-    net_session_device::NetSessionDevice, net_session_devices::NetSessionDevices,
+    game_input_ack::GameInputAck,
+    game_input_envelope::GameInputEnvelope,
+    game_input_receive_buffer::GameInputReceiveBuffer,
+    game_input_send_buffer::{GameInputSendBuffer, PendingGameInput},
+    game_input_sequence::GameInputSequence,
+    net_data::NetData,
+    net_event_channel::NetEventChannel,
+    net_message_event::NetMessageEvent,
+    net_session_device::NetSessionDevice,
+    net_session_devices::NetSessionDevices,
 };
 
+mod game_input_ack;
+mod game_input_envelope;
+mod game_input_receive_buffer;
+mod game_input_send_buffer;
+mod game_input_sequence;
 mod net_data;
 mod net_event_channel;
 mod net_message_event;
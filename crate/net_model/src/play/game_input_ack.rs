@@ -0,0 +1,14 @@
+use derive_new::new;
+use network_session_model::play::SessionDeviceId;
+use serde::{Deserialize, Serialize};
+
+/// Acknowledges that a `GameInputEnvelope` has been received.
+#[derive(Clone, Copy, Debug, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
+pub struct GameInputAck {
+    /// ID of the device that the input originated from.
+    pub session_device_id: SessionDeviceId,
+    /// Sequence epoch of the received input.
+    pub epoch: u64,
+    /// Sequence number of the received input.
+    pub sequence: u64,
+}
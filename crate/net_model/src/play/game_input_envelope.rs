@@ -0,0 +1,56 @@
+use game_input_model::{config::ControllerId, play::TickedGameInputEvent};
+use network_session_model::play::SessionDeviceId;
+use serde::{Deserialize, Serialize};
+
+use crate::play::GameInputAck;
+
+/// `TickedGameInputEvent` sent over the network, with the information needed to order it.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GameInputEnvelope {
+    /// Version of the envelope format, used to reject envelopes from incompatible clients.
+    pub version: u16,
+    /// ID of the device that the input originated from.
+    pub session_device_id: SessionDeviceId,
+    /// Number of times the originating device has restarted its sequence numbers.
+    pub epoch: u64,
+    /// Sequence number of the input for the originating device, starting from 0.
+    pub sequence: u64,
+    /// ID of the controller that the input came from.
+    pub controller_id: ControllerId,
+    /// The input, stamped with the tick it should be applied on.
+    pub ticked_game_input_event: TickedGameInputEvent,
+}
+
+impl GameInputEnvelope {
+    /// Current version of the envelope format.
+    pub const VERSION: u16 = 1;
+
+    /// Returns a new `GameInputEnvelope` using the current envelope format version.
+    ///
+    /// # Parameters
+    ///
+    /// * `session_device_id`: ID of the device that the input originated from.
+    /// * `epoch`: Number of times the originating device has restarted its sequence numbers.
+    /// * `sequence`: Sequence number of the input for the originating device.
+    /// * `ticked_game_input_event`: The input, stamped with the tick it should be applied on.
+    pub fn new(
+        session_device_id: SessionDeviceId,
+        epoch: u64,
+        sequence: u64,
+        ticked_game_input_event: TickedGameInputEvent,
+    ) -> Self {
+        GameInputEnvelope {
+            version: Self::VERSION,
+            session_device_id,
+            epoch,
+            sequence,
+            controller_id: ticked_game_input_event.game_input_event.controller_id(),
+            ticked_game_input_event,
+        }
+    }
+
+    /// Returns the acknowledgement for this envelope.
+    pub fn ack(&self) -> GameInputAck {
+        GameInputAck::new(self.session_device_id, self.epoch, self.sequence)
+    }
+}
@@ -0,0 +1,187 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::SocketAddr,
+};
+
+use network_session_model::play::SessionDeviceId;
+
+use crate::play::{GameInputEnvelope, NetData};
+
+/// Orders received `GameInputEnvelope`s by sequence number for each originating device.
+///
+/// Envelopes that have already been received are discarded, and envelopes that arrive before
+/// earlier ones are held until the earlier ones arrive.
+///
+/// Devices are tracked by the address the envelope was received from, as well as the device ID in
+/// the envelope. On the session server, each address belongs to a single device, so devices in
+/// different sessions are tracked separately even when they share a device ID. On clients, every
+/// envelope is received from the session server, so the device ID distinguishes the devices.
+///
+/// When a device restarts its sequence in a newer epoch, tracking restarts from sequence 0, and
+/// envelopes from earlier epochs are discarded.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GameInputReceiveBuffer {
+    /// Envelopes received since they were last processed.
+    pub received: Vec<NetData<GameInputEnvelope>>,
+    /// Sequence epoch tracked for each device.
+    pub epochs: HashMap<(SocketAddr, SessionDeviceId), u64>,
+    /// Next sequence number expected from each device.
+    pub next_sequences: HashMap<(SocketAddr, SessionDeviceId), u64>,
+    /// Envelopes received ahead of the next expected sequence number for each device.
+    pub out_of_order:
+        HashMap<(SocketAddr, SessionDeviceId), BTreeMap<u64, NetData<GameInputEnvelope>>>,
+}
+
+impl GameInputReceiveBuffer {
+    /// Maximum number of sequence numbers ahead of the next expected one that is held.
+    ///
+    /// Envelopes further ahead are not accepted, so that the sender sends them again later.
+    pub const OUT_OF_ORDER_LIMIT: u64 = 256;
+
+    /// Queues a received envelope to be processed.
+    pub fn push(&mut self, net_game_input_envelope: NetData<GameInputEnvelope>) {
+        self.received.push(net_game_input_envelope);
+    }
+
+    /// Takes the envelopes received since they were last processed.
+    pub fn take_received(&mut self) -> Vec<NetData<GameInputEnvelope>> {
+        std::mem::take(&mut self.received)
+    }
+
+    /// Returns whether the envelope is within the limit of envelopes held for its device.
+    ///
+    /// Envelopes that have already been received, or are from an earlier epoch, are within the
+    /// limit.
+    pub fn accepts(&self, net_game_input_envelope: &NetData<GameInputEnvelope>) -> bool {
+        let key = Self::key(net_game_input_envelope);
+        let next_sequence = match self.epochs.get(&key) {
+            Some(epoch) if *epoch == net_game_input_envelope.data.epoch => {
+                self.next_sequences.get(&key).copied().unwrap_or(0)
+            }
+            Some(epoch) if *epoch > net_game_input_envelope.data.epoch => return true,
+            _ => 0,
+        };
+
+        net_game_input_envelope.data.sequence < next_sequence + Self::OUT_OF_ORDER_LIMIT
+    }
+
+    /// Returns the envelopes that are ready to be applied after receiving the given envelope.
+    ///
+    /// The returned envelopes are in sequence order. Envelopes that are not [`accepts`]ed are
+    /// discarded.
+    ///
+    /// [`accepts`]: #method.accepts
+    pub fn order(
+        &mut self,
+        net_game_input_envelope: NetData<GameInputEnvelope>,
+    ) -> Vec<NetData<GameInputEnvelope>> {
+        if !self.accepts(&net_game_input_envelope) {
+            return Vec::new();
+        }
+
+        let key = Self::key(&net_game_input_envelope);
+        let epoch = net_game_input_envelope.data.epoch;
+        match self.epochs.get(&key) {
+            Some(epoch_tracked) if epoch < *epoch_tracked => {
+                // From before the device restarted its sequence.
+                return Vec::new();
+            }
+            Some(epoch_tracked) if epoch == *epoch_tracked => {}
+            _ => {
+                self.epochs.insert(key, epoch);
+                self.next_sequences.remove(&key);
+                self.out_of_order.remove(&key);
+            }
+        }
+
+        let sequence = net_game_input_envelope.data.sequence;
+        let next_sequence = self.next_sequences.entry(key).or_insert(0);
+        let out_of_order = self.out_of_order.entry(key).or_insert_with(BTreeMap::new);
+
+        if sequence < *next_sequence {
+            // Already received.
+            return Vec::new();
+        }
+        out_of_order.insert(sequence, net_game_input_envelope);
+
+        let mut ordered = Vec::new();
+        while let Some(net_game_input_envelope) = out_of_order.remove(next_sequence) {
+            ordered.push(net_game_input_envelope);
+            *next_sequence += 1;
+        }
+        ordered
+    }
+
+    /// Stops tracking devices whose envelopes were received from the given address.
+    pub fn remove_socket(&mut self, socket_addr: SocketAddr) {
+        self.epochs
+            .retain(|(socket_addr_device, _), _| *socket_addr_device != socket_addr);
+        self.next_sequences
+            .retain(|(socket_addr_device, _), _| *socket_addr_device != socket_addr);
+        self.out_of_order
+            .retain(|(socket_addr_device, _), _| *socket_addr_device != socket_addr);
+    }
+
+    /// Continues tracking devices from a previous address at their new address.
+    pub fn socket_replace(&mut self, socket_addr_previous: SocketAddr, socket_addr: SocketAddr) {
+        let epochs = std::mem::take(&mut self.epochs);
+        self.epochs = epochs
+            .into_iter()
+            .map(|(key, epoch)| {
+                (
+                    Self::key_replace(key, socket_addr_previous, socket_addr),
+                    epoch,
+                )
+            })
+            .collect();
+
+        let next_sequences = std::mem::take(&mut self.next_sequences);
+        self.next_sequences = next_sequences
+            .into_iter()
+            .map(|(key, next_sequence)| {
+                (
+                    Self::key_replace(key, socket_addr_previous, socket_addr),
+                    next_sequence,
+                )
+            })
+            .collect();
+
+        let out_of_order = std::mem::take(&mut self.out_of_order);
+        self.out_of_order = out_of_order
+            .into_iter()
+            .map(|(key, mut envelopes)| {
+                envelopes
+                    .values_mut()
+                    .filter(|net_game_input_envelope| {
+                        net_game_input_envelope.socket_addr == socket_addr_previous
+                    })
+                    .for_each(|net_game_input_envelope| {
+                        net_game_input_envelope.socket_addr = socket_addr
+                    });
+                (
+                    Self::key_replace(key, socket_addr_previous, socket_addr),
+                    envelopes,
+                )
+            })
+            .collect();
+    }
+
+    fn key(net_game_input_envelope: &NetData<GameInputEnvelope>) -> (SocketAddr, SessionDeviceId) {
+        (
+            net_game_input_envelope.socket_addr,
+            net_game_input_envelope.data.session_device_id,
+        )
+    }
+
+    fn key_replace(
+        (socket_addr_device, session_device_id): (SocketAddr, SessionDeviceId),
+        socket_addr_previous: SocketAddr,
+        socket_addr: SocketAddr,
+    ) -> (SocketAddr, SessionDeviceId) {
+        if socket_addr_device == socket_addr_previous {
+            (socket_addr, session_device_id)
+        } else {
+            (socket_addr_device, session_device_id)
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::net::SocketAddr;
+
+use derive_new::new;
+
+use crate::play::{GameInputAck, GameInputEnvelope};
+
+/// `GameInputEnvelope`s that have been sent, but not yet acknowledged.
+#[derive(Clone, Debug, Default, PartialEq, new)]
+pub struct GameInputSendBuffer {
+    /// Unacknowledged envelopes, in the order they were sent.
+    pub pending: Vec<PendingGameInput>,
+}
+
+/// `GameInputEnvelope` that is waiting to be acknowledged.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct PendingGameInput {
+    /// Address that the envelope was sent to.
+    pub socket_addr: SocketAddr,
+    /// The sent envelope.
+    pub game_input_envelope: GameInputEnvelope,
+    /// Number of ticks since the envelope was last sent.
+    #[new(default)]
+    pub ticks_since_sent: u32,
+}
+
+impl GameInputSendBuffer {
+    /// Number of ticks to wait for an acknowledgement before sending an envelope again.
+    pub const RETRANSMIT_DELAY: u32 = 30;
+
+    /// Tracks an envelope that has been sent to the given address.
+    pub fn push(&mut self, socket_addr: SocketAddr, game_input_envelope: GameInputEnvelope) {
+        self.pending
+            .push(PendingGameInput::new(socket_addr, game_input_envelope));
+    }
+
+    /// Stops tracking the envelope that the acknowledgement is for.
+    pub fn acknowledge(&mut self, socket_addr: SocketAddr, game_input_ack: GameInputAck) {
+        self.pending.retain(|pending_game_input| {
+            pending_game_input.socket_addr != socket_addr
+                || pending_game_input.game_input_envelope.ack() != game_input_ack
+        });
+    }
+
+    /// Stops tracking all envelopes sent to the given address.
+    pub fn remove_socket(&mut self, socket_addr: SocketAddr) {
+        self.pending
+            .retain(|pending_game_input| pending_game_input.socket_addr != socket_addr);
+    }
+
+    /// Redirects all envelopes sent to a device's previous address to its new address.
+    ///
+    /// The envelopes are made due to be sent again on the next tick.
+    pub fn socket_replace(&mut self, socket_addr_previous: SocketAddr, socket_addr: SocketAddr) {
+        self.pending
+            .iter_mut()
+            .filter(|pending_game_input| pending_game_input.socket_addr == socket_addr_previous)
+            .for_each(|pending_game_input| {
+                pending_game_input.socket_addr = socket_addr;
+                pending_game_input.ticks_since_sent = Self::RETRANSMIT_DELAY;
+            });
+    }
+
+    /// Increments the ticks since each envelope was sent, and returns those that are due to be
+    /// sent again.
+    pub fn tick(&mut self) -> Vec<PendingGameInput> {
+        self.pending
+            .iter_mut()
+            .filter_map(|pending_game_input| {
+                pending_game_input.ticks_since_sent += 1;
+                if pending_game_input.ticks_since_sent >= Self::RETRANSMIT_DELAY {
+                    pending_game_input.ticks_since_sent = 0;
+                    Some(*pending_game_input)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+}
@@ -0,0 +1,28 @@
+use derive_new::new;
+
+/// Sequence number to use for the next `GameInputEnvelope` sent by this device.
+///
+/// The sequence restarts from 0 when the session changes. Each restart begins a new epoch, so that
+/// receivers know to restart tracking this device's sequence.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, new)]
+pub struct GameInputSequence {
+    /// Number of times the sequence has restarted.
+    pub epoch: u64,
+    /// Sequence number of the next envelope.
+    pub sequence: u64,
+}
+
+impl GameInputSequence {
+    /// Returns the current sequence number, and increments it.
+    pub fn next(&mut self) -> u64 {
+        let sequence = self.sequence;
+        self.sequence += 1;
+        sequence
+    }
+
+    /// Restarts the sequence from 0 in the next epoch.
+    pub fn restart(&mut self) {
+        self.epoch += 1;
+        self.sequence = 0;
+    }
+}
@@ -1,16 +1,20 @@
 use derive_more::From;
-use game_input_model::GameInputEvent;
 use network_session_model::SessionMessageEvent;
 use serde::{Deserialize, Serialize};
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
+use session_reconnect_model::SessionReconnectEvent;
+
+use crate::play::{GameInputAck, GameInputEnvelope};
 
 /// All variants of messages that can be sent over the network.
 #[derive(Clone, Debug, Deserialize, From, PartialEq, Serialize)]
 pub enum NetMessageEvent {
-    /// `GameInputEvent` messages.
-    GameInputEvent(GameInputEvent),
+    /// `GameInputEvent` messages, wrapped in an envelope used to order them.
+    GameInputEvent(GameInputEnvelope),
+    /// Acknowledgements of received `GameInputEnvelope`s.
+    GameInputAck(GameInputAck),
     /// `SessionHostEvent` messages.
     SessionHostEvent(SessionHostEvent),
     /// `SessionJoinEvent` messages.
@@ -19,4 +23,6 @@ pub enum NetMessageEvent {
     SessionLobbyEvent(SessionLobbyEvent),
     /// `SessionMessageEvent` messages.
     SessionMessageEvent(SessionMessageEvent),
+    /// `SessionReconnectEvent` messages.
+    SessionReconnectEvent(SessionReconnectEvent),
 }
@@ -3,7 +3,10 @@
 //! Provides logic for the network.
 
 pub use crate::system::{
-    NetListenerSystem, NetListenerSystemDesc, NetMessageRequestSystem, NetMessageRequestSystemDesc,
+    GameInputBuffersResetSystem, GameInputBuffersResetSystemData, GameInputBuffersResetSystemDesc,
+    GameInputReceiveSystem, GameInputReceiveSystemData, GameInputRetransmitSystem,
+    GameInputRetransmitSystemData, NetListenerSystem, NetListenerSystemDesc,
+    NetMessageRequestSystem, NetMessageRequestSystemDesc,
 };
 
 mod system;
@@ -1,7 +1,16 @@
 pub use self::{
+    game_input_buffers_reset_system::{
+        GameInputBuffersResetSystem, GameInputBuffersResetSystemData,
+        GameInputBuffersResetSystemDesc,
+    },
+    game_input_receive_system::{GameInputReceiveSystem, GameInputReceiveSystemData},
+    game_input_retransmit_system::{GameInputRetransmitSystem, GameInputRetransmitSystemData},
     net_listener_system::{NetListenerSystem, NetListenerSystemDesc},
     net_message_request_system::{NetMessageRequestSystem, NetMessageRequestSystemDesc},
 };
 
+mod game_input_buffers_reset_system;
+mod game_input_receive_system;
+mod game_input_retransmit_system;
 mod net_listener_system;
 mod net_message_request_system;
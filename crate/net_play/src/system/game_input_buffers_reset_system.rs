@@ -0,0 +1,64 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use net_model::play::{GameInputReceiveBuffer, GameInputSendBuffer, GameInputSequence};
+use network_session_model::SessionStatusEvent;
+
+/// Resets the `GameInputEnvelope` sequence and buffers when the session changes.
+///
+/// The sequence is restarted in a new epoch, as the session server and other devices continue to
+/// track the sequence from the previous epoch.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GameInputBuffersResetSystemDesc))]
+pub struct GameInputBuffersResetSystem {
+    /// Reader ID for the `SessionStatusEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_status_event_rid: ReaderId<SessionStatusEvent>,
+}
+
+/// `GameInputBuffersResetSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameInputBuffersResetSystemData<'s> {
+    /// `SessionStatusEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_status_ec: Read<'s, EventChannel<SessionStatusEvent>>,
+    /// `GameInputSequence` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_sequence: Write<'s, GameInputSequence>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+}
+
+impl<'s> System<'s> for GameInputBuffersResetSystem {
+    type SystemData = GameInputBuffersResetSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameInputBuffersResetSystemData {
+            session_status_ec,
+            mut game_input_sequence,
+            mut game_input_send_buffer,
+            mut game_input_receive_buffer,
+        }: Self::SystemData,
+    ) {
+        let session_status_changed = session_status_ec
+            .read(&mut self.session_status_event_rid)
+            .next()
+            .is_some();
+        if session_status_changed {
+            game_input_sequence.restart();
+            *game_input_send_buffer = GameInputSendBuffer::default();
+            *game_input_receive_buffer = GameInputReceiveBuffer::default();
+        }
+    }
+}
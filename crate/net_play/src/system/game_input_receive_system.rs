@@ -0,0 +1,121 @@
+use std::net::SocketAddr;
+
+use amethyst::{
+    ecs::{System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error, warn};
+use net_model::play::{
+    GameInputAck, GameInputEnvelope, GameInputReceiveBuffer, NetEventChannel, NetMessageEvent,
+};
+
+/// Acknowledges received `GameInputEnvelope`s, and sends them in sequence order to the
+/// `NetEventChannel<GameInputEnvelope>`.
+///
+/// Envelopes too far ahead of the expected sequence are discarded without being acknowledged.
+#[derive(Debug, Default, new)]
+pub struct GameInputReceiveSystem;
+
+/// `GameInputReceiveSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameInputReceiveSystemData<'s> {
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// Net `GameInputEnvelope` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_nec: Write<'s, NetEventChannel<GameInputEnvelope>>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+}
+
+impl GameInputReceiveSystem {
+    fn send_ack(
+        transport_resource: &mut TransportResource,
+        socket_addr: SocketAddr,
+        game_input_ack: GameInputAck,
+    ) {
+        let net_message_event = NetMessageEvent::from(game_input_ack);
+        match bincode::serialize(&net_message_event) {
+            Ok(payload) => {
+                transport_resource.send_with_requirements(
+                    socket_addr,
+                    &payload,
+                    DeliveryRequirement::ReliableOrdered(None),
+                    UrgencyRequirement::OnTick,
+                );
+            }
+            Err(e) => error!(
+                "Failed to serialize `NetMessageEvent::GameInputAck`. Error: `{}`.",
+                e
+            ),
+        }
+    }
+}
+
+impl<'s> System<'s> for GameInputReceiveSystem {
+    type SystemData = GameInputReceiveSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameInputReceiveSystemData {
+            mut game_input_receive_buffer,
+            mut game_input_nec,
+            mut transport_resource,
+        }: Self::SystemData,
+    ) {
+        game_input_receive_buffer
+            .take_received()
+            .into_iter()
+            .filter(|net_game_input_envelope| {
+                let version = net_game_input_envelope.data.version;
+                let version_supported = version == GameInputEnvelope::VERSION;
+                if !version_supported {
+                    warn!(
+                        "Discarding `GameInputEnvelope` from `{}` with version `{}`, \
+                         expected version `{}`.",
+                        net_game_input_envelope.socket_addr,
+                        version,
+                        GameInputEnvelope::VERSION
+                    );
+                }
+                version_supported
+            })
+            .for_each(|net_game_input_envelope| {
+                // Not acknowledged, so that the sender sends it again once earlier envelopes have
+                // arrived.
+                if !game_input_receive_buffer.accepts(&net_game_input_envelope) {
+                    warn!(
+                        "Discarding `GameInputEnvelope` from `{}` with sequence `{}`, too far \
+                         ahead of the expected sequence.",
+                        net_game_input_envelope.socket_addr, net_game_input_envelope.data.sequence
+                    );
+                    return;
+                }
+
+                // Duplicates are acknowledged as well, as the original acknowledgement may have
+                // been lost.
+                Self::send_ack(
+                    &mut transport_resource,
+                    net_game_input_envelope.socket_addr,
+                    net_game_input_envelope.data.ack(),
+                );
+
+                let game_input_envelope = net_game_input_envelope.data;
+                let ordered = game_input_receive_buffer.order(net_game_input_envelope);
+                if ordered.is_empty() {
+                    debug!(
+                        "Holding `GameInputEnvelope` until earlier envelopes arrive, or it is a \
+                         duplicate: `{:?}`.",
+                        game_input_envelope
+                    );
+                }
+                game_input_nec.iter_write(ordered);
+            });
+    }
+}
@@ -0,0 +1,66 @@
+use amethyst::{
+    ecs::{System, World, Write},
+    network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{debug, error};
+use net_model::play::{GameInputSendBuffer, NetMessageEvent, PendingGameInput};
+
+/// Sends `GameInputEnvelope`s again when they have not been acknowledged in time.
+#[derive(Debug, Default, new)]
+pub struct GameInputRetransmitSystem;
+
+/// `GameInputRetransmitSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GameInputRetransmitSystemData<'s> {
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `TransportResource` resource.
+    #[derivative(Debug = "ignore")]
+    pub transport_resource: Write<'s, TransportResource>,
+}
+
+impl<'s> System<'s> for GameInputRetransmitSystem {
+    type SystemData = GameInputRetransmitSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GameInputRetransmitSystemData {
+            mut game_input_send_buffer,
+            mut transport_resource,
+        }: Self::SystemData,
+    ) {
+        game_input_send_buffer.tick().into_iter().for_each(
+            |PendingGameInput {
+                 socket_addr,
+                 game_input_envelope,
+                 ..
+             }| {
+                debug!(
+                    "Retransmitting `GameInputEnvelope` to `{}`: `{:?}`.",
+                    socket_addr, game_input_envelope
+                );
+
+                let net_message_event = NetMessageEvent::from(game_input_envelope);
+                match bincode::serialize(&net_message_event) {
+                    Ok(payload) => {
+                        transport_resource.send_with_requirements(
+                            socket_addr,
+                            &payload,
+                            DeliveryRequirement::ReliableOrdered(None),
+                            UrgencyRequirement::OnTick,
+                        );
+                    }
+                    Err(e) => error!(
+                        "Failed to serialize `NetMessageEvent::GameInputEvent`. Error: `{}`.",
+                        e
+                    ),
+                }
+            },
+        );
+    }
+}
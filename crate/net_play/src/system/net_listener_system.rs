@@ -7,13 +7,15 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::GameInputEvent;
 use log::{debug, error};
-use net_model::play::{NetData, NetEventChannel, NetMessageEvent};
+use net_model::play::{
+    GameInputReceiveBuffer, GameInputSendBuffer, NetData, NetEventChannel, NetMessageEvent,
+};
 use network_session_model::SessionMessageEvent;
 use session_host_model::SessionHostEvent;
 use session_join_model::SessionJoinEvent;
 use session_lobby_model::SessionLobbyEvent;
+use session_reconnect_model::SessionReconnectEvent;
 
 /// Receives `NetMessageEvent`s and sends each variant's data to the corresponding event channel.
 #[derive(Debug, SystemDesc, new)]
@@ -30,9 +32,12 @@ pub struct NetListenerSystemData<'s> {
     /// `NetworkSimulationEvent` channel.
     #[derivative(Debug = "ignore")]
     pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
-    /// Net `GameInputEvent` channel.
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// `GameInputSendBuffer` resource.
     #[derivative(Debug = "ignore")]
-    pub game_input_nec: Write<'s, NetEventChannel<GameInputEvent>>,
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
     /// Net `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_host_nec: Write<'s, NetEventChannel<SessionHostEvent>>,
@@ -45,6 +50,9 @@ pub struct NetListenerSystemData<'s> {
     /// Net `SessionMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub session_message_nec: Write<'s, NetEventChannel<SessionMessageEvent>>,
+    /// Net `SessionReconnectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_nec: Write<'s, NetEventChannel<SessionReconnectEvent>>,
 }
 
 impl<'s> System<'s> for NetListenerSystem {
@@ -54,11 +62,13 @@ impl<'s> System<'s> for NetListenerSystem {
         &mut self,
         NetListenerSystemData {
             network_simulation_ec,
-            mut game_input_nec,
+            mut game_input_receive_buffer,
+            mut game_input_send_buffer,
             mut session_host_nec,
             mut session_join_nec,
             mut session_lobby_nec,
             mut session_message_nec,
+            mut session_reconnect_nec,
         }: Self::SystemData,
     ) {
         network_simulation_ec
@@ -71,9 +81,13 @@ impl<'s> System<'s> for NetListenerSystem {
                         Ok(net_message_event) => {
                             debug!("{:?}", net_message_event);
                             match net_message_event {
-                                NetMessageEvent::GameInputEvent(game_input_event) => {
-                                    game_input_nec
-                                        .single_write(NetData::new(*socket_addr, game_input_event));
+                                NetMessageEvent::GameInputEvent(game_input_envelope) => {
+                                    game_input_receive_buffer
+                                        .push(NetData::new(*socket_addr, game_input_envelope));
+                                }
+                                NetMessageEvent::GameInputAck(game_input_ack) => {
+                                    game_input_send_buffer
+                                        .acknowledge(*socket_addr, game_input_ack);
                                 }
                                 NetMessageEvent::SessionHostEvent(session_host_event) => {
                                     session_host_nec.single_write(NetData::new(
@@ -99,9 +113,19 @@ impl<'s> System<'s> for NetListenerSystem {
                                         session_message_event,
                                     ));
                                 }
+                                NetMessageEvent::SessionReconnectEvent(session_reconnect_event) => {
+                                    session_reconnect_nec.single_write(NetData::new(
+                                        *socket_addr,
+                                        session_reconnect_event,
+                                    ));
+                                }
                             }
                         }
-                        Err(e) => error!("Failed to parse `NetMessageEvent`: `{}`", e),
+                        Err(e) => error!(
+                            "Failed to parse `NetMessageEvent` from `{}`, the sender may be using a \
+                            different protocol version: `{}`",
+                            socket_addr, e
+                        ),
                     }
                 }
                 NetworkSimulationEvent::SendError(io_error, message) => {
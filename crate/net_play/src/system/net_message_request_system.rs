@@ -1,8 +1,6 @@
-use std::net::SocketAddr;
-
 use amethyst::{
     derive::SystemDesc,
-    ecs::{Read, ReadExpect, System, World, Write},
+    ecs::{Read, System, World, Write},
     network::simulation::{DeliveryRequirement, TransportResource, UrgencyRequirement},
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
@@ -10,10 +8,15 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use log::{debug, error};
-use net_model::play::NetMessageEvent;
-use network_session_model::config::SessionServerConfig;
+use net_model::play::{GameInputSendBuffer, NetMessageEvent};
+use network_session_model::play::SessionServerSocketAddr;
 
 /// Sends requests to the session server.
+///
+/// Messages are sent to the `SessionServerSocketAddr`, which is resolved when requesting to host or
+/// join a session.
+///
+/// `GameInputEvent` messages are tracked in the `GameInputSendBuffer` until they are acknowledged.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetMessageRequestSystemDesc))]
 pub struct NetMessageRequestSystem {
@@ -28,12 +31,15 @@ pub struct NetMessageRequestSystemData<'s> {
     /// `NetMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Read<'s, EventChannel<NetMessageEvent>>,
-    /// `SessionServerConfig` resource.
+    /// `SessionServerSocketAddr` resource.
     #[derivative(Debug = "ignore")]
-    pub session_server_config: ReadExpect<'s, SessionServerConfig>,
+    pub session_server_socket_addr: Read<'s, SessionServerSocketAddr>,
     /// `TransportResource` resource.
     #[derivative(Debug = "ignore")]
     pub transport_resource: Write<'s, TransportResource>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
 }
 
 impl<'s> System<'s> for NetMessageRequestSystem {
@@ -43,32 +49,47 @@ impl<'s> System<'s> for NetMessageRequestSystem {
         &mut self,
         NetMessageRequestSystemData {
             net_message_ec,
-            session_server_config,
+            session_server_socket_addr,
             mut transport_resource,
+            mut game_input_send_buffer,
         }: Self::SystemData,
     ) {
-        let server_socket_addr =
-            SocketAddr::new(session_server_config.address, session_server_config.port);
-        net_message_ec
-            .read(&mut self.net_message_event_rid)
-            .for_each(|net_message_event| {
-                match bincode::serialize(net_message_event) {
-                    Ok(payload) => {
-                        debug!("Sending `NetMessageEvent`: `{:?}`.", net_message_event);
-                        // Connect to `server_socket_addr` and send request.
-                        transport_resource.send_with_requirements(
-                            server_socket_addr,
-                            &payload,
-                            // None means it uses a default multiplexed stream.
-                            //
-                            // Suspect if we give it a value, the value will be a "channel" over the
-                            // same socket connection.
-                            DeliveryRequirement::ReliableOrdered(None),
-                            UrgencyRequirement::OnTick,
-                        );
-                    }
-                    Err(e) => error!("Failed to serialize `NetMessageEvent`. Error: `{}`.", e),
-                }
+        let net_message_events = net_message_ec.read(&mut self.net_message_event_rid);
+
+        let server_socket_addr = if let Some(server_socket_addr) = **session_server_socket_addr {
+            server_socket_addr
+        } else {
+            net_message_events.for_each(|net_message_event| {
+                error!(
+                    "Session server address not resolved, dropping `NetMessageEvent`: `{:?}`.",
+                    net_message_event
+                );
             });
+            return;
+        };
+
+        net_message_events.for_each(|net_message_event| {
+            if let NetMessageEvent::GameInputEvent(game_input_envelope) = net_message_event {
+                game_input_send_buffer.push(server_socket_addr, *game_input_envelope);
+            }
+
+            match bincode::serialize(net_message_event) {
+                Ok(payload) => {
+                    debug!("Sending `NetMessageEvent`: `{:?}`.", net_message_event);
+                    // Connect to `server_socket_addr` and send request.
+                    transport_resource.send_with_requirements(
+                        server_socket_addr,
+                        &payload,
+                        // None means it uses a default multiplexed stream.
+                        //
+                        // Suspect if we give it a value, the value will be a "channel" over the
+                        // same socket connection.
+                        DeliveryRequirement::ReliableOrdered(None),
+                        UrgencyRequirement::OnTick,
+                    );
+                }
+                Err(e) => error!("Failed to serialize `NetMessageEvent`. Error: `{}`.", e),
+            }
+        });
     }
 }
@@ -10,8 +10,11 @@ use network_session_model::{
     play::{SessionCondition, SessionStatus},
     SessionMessageEvent,
 };
+use rollback_model::config::SessionSyncMode;
 
 /// Informs the session server all client network input has been sent.
+///
+/// This is only used in lockstep mode, as rollback mode does not wait for the server each tick.
 #[derive(Debug, new)]
 pub struct GameInputTickRequestSystem;
 
@@ -21,6 +24,9 @@ pub struct GameInputTickRequestSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
     /// `SessionCondition` resource.
     #[derivative(Debug = "ignore")]
     pub session_condition: Write<'s, SessionCondition>,
@@ -36,6 +42,7 @@ impl<'s> System<'s> for GameInputTickRequestSystem {
         &mut self,
         GameInputTickRequestSystemData {
             session_status,
+            session_sync_mode,
             mut session_condition,
             mut net_message_ec,
         }: Self::SystemData,
@@ -44,7 +51,10 @@ impl<'s> System<'s> for GameInputTickRequestSystem {
         let session_established = *session_status == SessionStatus::JoinEstablished
             || *session_status == SessionStatus::HostEstablished;
 
-        if session_established && *session_condition == SessionCondition::Ready {
+        if session_established
+            && *session_sync_mode == SessionSyncMode::Lockstep
+            && *session_condition == SessionCondition::Ready
+        {
             net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
                 SessionMessageEvent::GameInputTick,
             ));
@@ -9,11 +9,26 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::{config::ControlBindings, GameInputEvent};
-use net_model::play::NetMessageEvent;
-use network_session_model::play::SessionStatus;
+use game_input_model::{
+    config::ControlBindings,
+    play::{GameTick, TickedGameInputEvent},
+    GameInputEvent,
+};
+use net_model::play::{GameInputEnvelope, GameInputSequence, NetMessageEvent};
+use network_session_model::play::{SessionDeviceId, SessionDeviceType, SessionStatus};
+use rollback_model::{
+    config::{RollbackConfig, SessionSyncMode},
+    play::RollbackStatus,
+};
 
 /// Sends network input to a session server.
+///
+/// Input is wrapped in a `GameInputEnvelope`, stamped with the tick it should be applied on. In
+/// rollback mode, this is delayed by `RollbackConfig::input_delay` ticks, and the input is also
+/// sent to the local `TickedGameInputEvent` channel so that it is applied without waiting for the
+/// server.
+///
+/// Spectators do not send input.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputRequestSystemDesc))]
 pub struct NetworkInputRequestSystem {
@@ -31,9 +46,33 @@ pub struct NetworkInputRequestSystemData<'s> {
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_type: Read<'s, SessionDeviceType>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `RollbackConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_config: Read<'s, RollbackConfig>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `GameInputSequence` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_sequence: Write<'s, GameInputSequence>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+    /// `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_ec: Write<'s, EventChannel<TickedGameInputEvent>>,
 }
 
 impl<'s> System<'s> for NetworkInputRequestSystem {
@@ -44,19 +83,48 @@ impl<'s> System<'s> for NetworkInputRequestSystem {
         NetworkInputRequestSystemData {
             input_ec,
             session_status,
+            session_device_type,
+            session_sync_mode,
+            rollback_config,
+            game_tick,
+            rollback_status,
+            session_device_id,
+            mut game_input_sequence,
             mut net_message_ec,
+            mut ticked_game_input_ec,
         }: Self::SystemData,
     ) {
         let input_events = input_ec.read(&mut self.input_event_rid);
 
         // Guard against sending input events if the application is not in a session.
-        if *session_status == SessionStatus::JoinEstablished
-            || *session_status == SessionStatus::HostEstablished
-        {
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if session_established && *session_device_type == SessionDeviceType::Player {
+            let rollback = *session_sync_mode == SessionSyncMode::Rollback;
+            let tick = if rollback {
+                // While resimulating, the `GameTick` is in the past, so input is stamped relative
+                // to the tick that game play had reached.
+                let present = rollback_status.present().unwrap_or(*game_tick);
+                GameTick(*present + rollback_config.input_delay)
+            } else {
+                *game_tick
+            };
+
             input_events
                 .filter_map(|ev| GameInputEvent::try_from(ev).ok())
+                .map(|ev| TickedGameInputEvent::new(tick, ev))
                 .for_each(|ev| {
-                    net_message_ec.single_write(NetMessageEvent::GameInputEvent(ev));
+                    let game_input_envelope = GameInputEnvelope::new(
+                        *session_device_id,
+                        game_input_sequence.epoch,
+                        game_input_sequence.next(),
+                        ev,
+                    );
+                    net_message_ec
+                        .single_write(NetMessageEvent::GameInputEvent(game_input_envelope));
+                    if rollback {
+                        ticked_game_input_ec.single_write(ev);
+                    }
                 });
         }
     }
@@ -6,32 +6,48 @@ use amethyst::{
 };
 use derivative::Derivative;
 use derive_new::new;
-use game_input_model::GameInputEvent;
+use game_input_model::{play::TickedGameInputEvent, GameInputEvent};
 use log::debug;
-use net_model::play::{NetData, NetEventChannel};
-use network_session_model::play::SessionStatus;
+use net_model::play::{GameInputEnvelope, NetData, NetEventChannel};
+use network_session_model::play::{SessionDeviceId, SessionStatus};
+use rollback_model::config::SessionSyncMode;
 
 /// Writes received `GameInputEvent`s from the net channel to the regular event channel.
+///
+/// The `GameInputEnvelope`s are expected to have been ordered by the `GameInputReceiveSystem`.
+///
+/// In rollback mode, the events are written to the `TickedGameInputEvent` channel instead, so that
+/// they are applied on the tick they were stamped with. Local input is already applied when it is
+/// sent, so events that the session server echoes back to this device are skipped.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(NetworkInputResponseSystemDesc))]
 pub struct NetworkInputResponseSystem {
-    /// Reader ID for the `GameInputEvent` channel.
+    /// Reader ID for the `GameInputEnvelope` channel.
     #[system_desc(event_channel_reader)]
-    game_input_event_rid: ReaderId<NetData<GameInputEvent>>,
+    game_input_envelope_rid: ReaderId<NetData<GameInputEnvelope>>,
 }
 
 #[derive(Derivative, SystemData)]
 #[derivative(Debug)]
 pub struct NetworkInputResponseSystemData<'s> {
-    /// Net `GameInputEvent` channel.
+    /// Net `GameInputEnvelope` channel.
     #[derivative(Debug = "ignore")]
-    pub game_input_nec: Read<'s, NetEventChannel<GameInputEvent>>,
+    pub game_input_nec: Read<'s, NetEventChannel<GameInputEnvelope>>,
     /// `GameInputEvent` channel.
     #[derivative(Debug = "ignore")]
     pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_ec: Write<'s, EventChannel<TickedGameInputEvent>>,
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Read<'s, SessionStatus>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
 }
 
 impl<'s> System<'s> for NetworkInputResponseSystem {
@@ -42,10 +58,13 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
         NetworkInputResponseSystemData {
             game_input_nec,
             mut game_input_ec,
+            mut ticked_game_input_ec,
             session_status,
+            session_sync_mode,
+            session_device_id,
         }: Self::SystemData,
     ) {
-        let game_input_events = game_input_nec.read(&mut self.game_input_event_rid);
+        let game_input_events = game_input_nec.read(&mut self.game_input_envelope_rid);
         let session_status = &*session_status;
 
         if session_status == &SessionStatus::JoinEstablished
@@ -53,16 +72,27 @@ impl<'s> System<'s> for NetworkInputResponseSystem {
         {
             game_input_events.for_each(|ev| {
                 let NetData {
-                    data: game_input_event,
+                    data: game_input_envelope,
                     ..
                 } = ev;
 
                 debug!(
-                    "`NetData<GameInputEvent>` received: {:?}.",
-                    game_input_event
+                    "`NetData<GameInputEnvelope>` received: {:?}.",
+                    game_input_envelope
                 );
 
-                game_input_ec.single_write(*game_input_event);
+                let ticked_game_input_event = &game_input_envelope.ticked_game_input_event;
+
+                match *session_sync_mode {
+                    SessionSyncMode::Lockstep => {
+                        game_input_ec.single_write(ticked_game_input_event.game_input_event)
+                    }
+                    SessionSyncMode::Rollback => {
+                        if game_input_envelope.session_device_id != *session_device_id {
+                            ticked_game_input_ec.single_write(*ticked_game_input_event)
+                        }
+                    }
+                }
             });
         }
     }
@@ -1,23 +1,70 @@
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, ToSocketAddrs};
 
 use serde::{Deserialize, Serialize};
 
+use crate::play::SessionServerResolveError;
+
 /// Configuration needed to connect to the session server.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct SessionServerConfig {
     /// Address of the server.
     ///
-    /// Currently must be an `IpAddr`, in the future we may accept hostnames.
-    pub address: IpAddr,
+    /// This may be a hostname, an IPv4 address, or an IPv6 address. The address may also include a
+    /// port, such as `example.com:1234` or `[::1]:1234`, in which case `port` is ignored.
+    pub address: String,
     /// Port that the server is listening on.
     pub port: u16,
 }
 
+impl SessionServerConfig {
+    /// Resolves the server address to a `SocketAddr`.
+    ///
+    /// Hostnames are looked up using the system resolver, so this may block.
+    pub fn socket_addr_resolve(&self) -> Result<SocketAddr, SessionServerResolveError> {
+        let address = self.address.trim();
+        let resolve_error =
+            |reason: String| SessionServerResolveError::new(self.address.clone(), reason);
+
+        if address.is_empty() {
+            return Err(resolve_error(String::from("Address is empty.")));
+        }
+
+        // IP address with port, e.g. `127.0.0.1:1234` or `[::1]:1234`.
+        if let Ok(socket_addr) = address.parse::<SocketAddr>() {
+            return Ok(socket_addr);
+        }
+
+        // IP address without port, e.g. `127.0.0.1`, `::1`, or `[::1]`.
+        let ip_addr_str = address.trim_start_matches('[').trim_end_matches(']');
+        if let Ok(ip_addr) = ip_addr_str.parse::<IpAddr>() {
+            return Ok(SocketAddr::new(ip_addr, self.port));
+        }
+
+        // Hostname, optionally with port, e.g. `example.com` or `example.com:1234`.
+        let (host, port) = match address.rfind(':') {
+            Some(index) => {
+                let port_str = &address[index + 1..];
+                let port = port_str
+                    .parse::<u16>()
+                    .map_err(|_| resolve_error(format!("Invalid port: `{}`.", port_str)))?;
+                (&address[..index], port)
+            }
+            None => (address, self.port),
+        };
+
+        (host, port)
+            .to_socket_addrs()
+            .map_err(|e| resolve_error(e.to_string()))?
+            .next()
+            .ok_or_else(|| resolve_error(String::from("No addresses found for host.")))
+    }
+}
+
 impl Default for SessionServerConfig {
     fn default() -> Self {
         SessionServerConfig {
-            address: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            address: Ipv4Addr::LOCALHOST.to_string(),
             port: 1234,
         }
     }
@@ -1,21 +1,34 @@
 //! Data types used at runtime.
 
 pub use self::{
-    network_session_model_error::NetworkSessionModelError, session::Session,
-    session_code::SessionCode, session_condition::SessionCondition, session_device::SessionDevice,
+    network_session_model_error::NetworkSessionModelError, protocol_version::ProtocolVersion,
+    session::Session, session_code::SessionCode, session_condition::SessionCondition,
+    session_desync::SessionDesync, session_device::SessionDevice,
     session_device_id::SessionDeviceId, session_device_join::SessionDeviceJoin,
-    session_device_name::SessionDeviceName, session_devices::SessionDevices,
-    session_status::SessionStatus, sessions::Sessions,
+    session_device_name::SessionDeviceName, session_device_type::SessionDeviceType,
+    session_devices::SessionDevices, session_handshake::SessionHandshake,
+    session_reconnect_token::SessionReconnectToken,
+    session_server_resolve_error::SessionServerResolveError,
+    session_server_socket_addr::SessionServerSocketAddr, session_status::SessionStatus,
+    sessions::Sessions, state_checksum::StateChecksum,
 };
 
 mod network_session_model_error;
+mod protocol_version;
 mod session;
 mod session_code;
 mod session_condition;
+mod session_desync;
 mod session_device;
 mod session_device_id;
 mod session_device_join;
 mod session_device_name;
+mod session_device_type;
 mod session_devices;
+mod session_handshake;
+mod session_reconnect_token;
+mod session_server_resolve_error;
+mod session_server_socket_addr;
 mod session_status;
 mod sessions;
+mod state_checksum;
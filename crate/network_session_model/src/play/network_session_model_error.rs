@@ -10,6 +10,8 @@ pub enum NetworkSessionModelError {
     SessionDeviceParseError,
     /// Failed to parse `SessionDevices` from string.
     SessionDevicesParseError,
+    /// Failed to parse a `SessionDeviceType` from string.
+    SessionDeviceTypeParseError,
 }
 
 impl Display for NetworkSessionModelError {
@@ -31,6 +33,12 @@ impl Display for NetworkSessionModelError {
                 \n\
                 Example: `0:az_computer::0:azriel::1:friend_a 1:by_computer::0:byron::1:friend_b`\n"
             ),
+            Self::SessionDeviceTypeParseError => {
+                write!(
+                    f,
+                    "Session device type must be one of: `player`, `spectator`."
+                )
+            }
         }
     }
 }
@@ -0,0 +1,26 @@
+use std::fmt::{self, Display};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Version of the network protocol spoken by a device (`u32` newtype).
+///
+/// Network messages are serialized without any version information, so devices built with
+/// different message types cannot understand each other. This must be incremented whenever
+/// `NetMessageEvent` or any type it contains changes.
+#[derive(
+    Clone, Copy, Debug, Default, Deref, DerefMut, Deserialize, Eq, Hash, PartialEq, Serialize, new,
+)]
+pub struct ProtocolVersion(pub u32);
+
+impl ProtocolVersion {
+    /// Protocol version of this build.
+    pub const CURRENT: ProtocolVersion = ProtocolVersion(1);
+}
+
+impl Display for ProtocolVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -11,6 +11,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, Debug, Default, Deserialize, Hash, PartialEq, Eq, Serialize, new)]
 pub struct SessionCode(pub String);
 
+impl SessionCode {
+    /// Returns a seed for random number generation that is the same on all devices in a session.
+    ///
+    /// This uses the 64 bit FNV-1a hash, which is stable across platforms and compiler versions.
+    pub fn rng_seed(&self) -> u64 {
+        self.0.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
 impl Display for SessionCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", &self.0)
@@ -5,6 +5,53 @@ pub enum SessionCondition {
     PendingGameInputTick,
     /// There is no pending messages for the session to proceed.
     Ready,
+    /// Game play is paused.
+    Paused {
+        /// Whether the `GameInputTick` message is still pending from the server.
+        game_input_tick_pending: bool,
+    },
+}
+
+impl SessionCondition {
+    /// Pauses game play, remembering whether a `GameInputTick` is pending.
+    pub fn pause(&mut self) {
+        if let Self::PendingGameInputTick | Self::Ready = self {
+            *self = Self::Paused {
+                game_input_tick_pending: *self == Self::PendingGameInputTick,
+            };
+        }
+    }
+
+    /// Resumes game play, restoring any pending `GameInputTick`.
+    pub fn resume(&mut self) {
+        if let Self::Paused {
+            game_input_tick_pending,
+        } = *self
+        {
+            *self = if game_input_tick_pending {
+                Self::PendingGameInputTick
+            } else {
+                Self::Ready
+            };
+        }
+    }
+
+    /// Marks that there are no pending messages for the session to proceed.
+    ///
+    /// If game play is paused, it remains paused.
+    pub fn ready(&mut self) {
+        *self = match *self {
+            Self::Paused { .. } => Self::Paused {
+                game_input_tick_pending: false,
+            },
+            Self::PendingGameInputTick | Self::Ready => Self::Ready,
+        };
+    }
+
+    /// Returns whether game play is paused.
+    pub fn is_paused(self) -> bool {
+        matches!(self, Self::Paused { .. })
+    }
 }
 
 impl Default for SessionCondition {
@@ -0,0 +1,24 @@
+use derive_new::new;
+use game_input_model::play::GameTick;
+use serde::{Deserialize, Serialize};
+
+use crate::play::{SessionDeviceId, SessionDeviceName};
+
+/// Message when a device's game play state diverges from the rest of the session.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct SessionDesync {
+    /// Tick that the state diverged at.
+    pub tick: GameTick,
+    /// ID of the device whose state diverged.
+    pub session_device_id: SessionDeviceId,
+    /// Name of the device whose state diverged.
+    pub session_device_name: SessionDeviceName,
+    /// Checksum of the diverging device's state.
+    pub checksum: u64,
+    /// Checksum of the state held by the rest of the session.
+    pub expected_checksum: u64,
+    /// Dump of the diverging device's state, if sent.
+    pub state_dump: Option<String>,
+    /// Dump of the state held by the rest of the session, if sent.
+    pub expected_state_dump: Option<String>,
+}
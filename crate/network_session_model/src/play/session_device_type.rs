@@ -0,0 +1,47 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::play::NetworkSessionModelError;
+
+/// Whether a session device plays in the session, or only watches.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionDeviceType {
+    /// Device has `PlayerControllers` whose input is sent to the session.
+    Player,
+    /// Device receives the session's input, but does not send any.
+    ///
+    /// Spectators are not waited upon when the session progresses.
+    Spectator,
+}
+
+impl Default for SessionDeviceType {
+    fn default() -> Self {
+        SessionDeviceType::Player
+    }
+}
+
+impl FromStr for SessionDeviceType {
+    type Err = NetworkSessionModelError;
+
+    fn from_str(session_device_type_str: &str) -> Result<Self, NetworkSessionModelError> {
+        match session_device_type_str {
+            "player" => Ok(SessionDeviceType::Player),
+            "spectator" => Ok(SessionDeviceType::Spectator),
+            _ => Err(NetworkSessionModelError::SessionDeviceTypeParseError),
+        }
+    }
+}
+
+impl Display for SessionDeviceType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionDeviceType::Player => write!(f, "player"),
+            SessionDeviceType::Spectator => write!(f, "spectator"),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use asset_model::loaded::AssetFingerprint;
+use derive_new::new;
+use rollback_model::config::SessionSyncMode;
+use serde::{Deserialize, Serialize};
+
+use crate::play::ProtocolVersion;
+
+/// Compatibility information sent by a device when hosting or joining a session.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct SessionHandshake {
+    /// Network protocol version of the device.
+    pub protocol_version: ProtocolVersion,
+    /// Fingerprint of the assets on the device.
+    pub asset_fingerprint: AssetFingerprint,
+    /// How game play is kept in sync on the device.
+    pub session_sync_mode: SessionSyncMode,
+}
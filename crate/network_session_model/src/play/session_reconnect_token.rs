@@ -0,0 +1,35 @@
+use std::{
+    fmt::{self, Display},
+    num::ParseIntError,
+    str::FromStr,
+};
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Server generated secret that allows a device to rejoin its session after disconnecting (`u64`
+/// newtype).
+///
+/// The token is sent only to the device it is issued to, so that other clients cannot take over
+/// the device's place in the session.
+#[derive(
+    Clone, Copy, Debug, Default, Deref, DerefMut, Deserialize, Eq, Hash, PartialEq, Serialize, new,
+)]
+pub struct SessionReconnectToken(pub u64);
+
+impl FromStr for SessionReconnectToken {
+    type Err = ParseIntError;
+
+    fn from_str(session_reconnect_token_str: &str) -> Result<Self, ParseIntError> {
+        session_reconnect_token_str
+            .parse::<u64>()
+            .map(SessionReconnectToken)
+    }
+}
+
+impl Display for SessionReconnectToken {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
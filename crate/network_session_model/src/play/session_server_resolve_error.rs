@@ -0,0 +1,26 @@
+use std::fmt::{self, Display, Formatter};
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use structopt::StructOpt;
+
+/// Error when the session server address cannot be resolved to a socket address.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, StructOpt, new)]
+pub struct SessionServerResolveError {
+    /// Session server address that was attempted to be resolved.
+    #[structopt(long)]
+    pub address: String,
+    /// Reason the address could not be resolved.
+    #[structopt(long)]
+    pub reason: String,
+}
+
+impl Display for SessionServerResolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Failed to resolve session server address `{}`: {}",
+            self.address, self.reason
+        )
+    }
+}
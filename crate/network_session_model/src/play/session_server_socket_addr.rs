@@ -0,0 +1,11 @@
+use std::net::SocketAddr;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+/// Resolved socket address of the session server (`Option<SocketAddr>` newtype).
+///
+/// This is resolved from the `SessionServerConfig` when requesting to host or join a session, and
+/// when reconnecting to a session.
+#[derive(Clone, Copy, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct SessionServerSocketAddr(pub Option<SocketAddr>);
@@ -0,0 +1,14 @@
+use derive_new::new;
+use game_input_model::play::GameTick;
+use serde::{Deserialize, Serialize};
+
+/// Checksum of a device's game play state at the start of a tick.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+pub struct StateChecksum {
+    /// Tick that the state was recorded at.
+    pub tick: GameTick,
+    /// Checksum of the game play state.
+    pub checksum: u64,
+    /// Human readable dump of the game play state, if requested.
+    pub state_dump: Option<String>,
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::play::SessionDeviceJoin;
+use crate::play::{SessionDesync, SessionDeviceId, SessionDeviceJoin, StateChecksum};
 
 /// Session message events.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -14,4 +14,40 @@ pub enum SessionMessageEvent {
     GameInputTick,
     /// An additional device joined the session.
     SessionDeviceJoin(SessionDeviceJoin),
+    /// A device lost its connection to the session server, and may reconnect.
+    ///
+    /// The session server sends this to the remaining devices in the session.
+    SessionDeviceDisconnect(SessionDeviceId),
+    /// A device that lost its connection has rejoined the session.
+    ///
+    /// The session server sends this to the other devices in the session.
+    SessionDeviceReconnect(SessionDeviceId),
+    /// Checksum of a device's game play state, sent by game clients to the session server.
+    StateChecksum(StateChecksum),
+    /// A device's game play state diverged from the rest of the session.
+    ///
+    /// The session server sends this to all devices in the session.
+    SessionDesync(SessionDesync),
+    /// A device requested to restart the round, sent by game clients to the session server.
+    GamePlayRestartRequest,
+    /// Notification to restart the round.
+    ///
+    /// The session server sends this to all devices in the session.
+    GamePlayRestartNotify,
+    /// A device requested to pause the round, sent by game clients to the session server.
+    GamePlayPauseRequest,
+    /// Notification to pause the round.
+    ///
+    /// The session server sends this to all devices in the session.
+    GamePlayPauseNotify,
+    /// A device requested to resume the round, sent by game clients to the session server.
+    GamePlayResumeRequest,
+    /// Notification to resume the round.
+    ///
+    /// The session server sends this to all devices in the session.
+    GamePlayResumeNotify,
+    /// The device is not part of any session.
+    ///
+    /// The session server sends this to a device that sends game input without being in a session.
+    SessionNotFound,
 }
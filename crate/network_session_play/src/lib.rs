@@ -5,9 +5,10 @@
 pub use crate::{
     session_code_generator::SessionCodeGenerator,
     system::{
-        SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
-        SessionMessageResponseSystem, SessionMessageResponseSystemDesc,
-        SessionStatusNotifierSystem,
+        GamePlayPauseRequestSystem, GamePlayPauseRequestSystemDesc, GamePlayRestartRequestSystem,
+        GamePlayRestartRequestSystemDesc, SessionInputResourcesSyncSystem,
+        SessionInputResourcesSyncSystemDesc, SessionMessageResponseSystem,
+        SessionMessageResponseSystemDesc, SessionStatusNotifierSystem,
     },
 };
 
@@ -1,4 +1,8 @@
 pub use self::{
+    game_play_pause_request_system::{GamePlayPauseRequestSystem, GamePlayPauseRequestSystemDesc},
+    game_play_restart_request_system::{
+        GamePlayRestartRequestSystem, GamePlayRestartRequestSystemDesc,
+    },
     session_input_resources_sync_system::{
         SessionInputResourcesSyncSystem, SessionInputResourcesSyncSystemDesc,
     },
@@ -8,6 +12,8 @@ pub use self::{
     session_status_notifier_system::SessionStatusNotifierSystem,
 };
 
+mod game_play_pause_request_system;
+mod game_play_restart_request_system;
 mod session_input_resources_sync_system;
 mod session_message_response_system;
 mod session_status_notifier_system;
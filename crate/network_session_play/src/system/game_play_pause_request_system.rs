@@ -0,0 +1,68 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayEvent;
+use net_model::play::NetMessageEvent;
+use network_session_model::{play::SessionStatus, SessionMessageEvent};
+
+/// Sends requests to the session server to pause or resume the round.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamePlayPauseRequestSystemDesc))]
+pub struct GamePlayPauseRequestSystem {
+    /// Reader ID for the `GamePlayEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_play_event_rid: ReaderId<GamePlayEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayPauseRequestSystemData<'s> {
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Read<'s, EventChannel<GamePlayEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `NetworkMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for GamePlayPauseRequestSystem {
+    type SystemData = GamePlayPauseRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayPauseRequestSystemData {
+            game_play_ec,
+            session_status,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let game_play_events = game_play_ec.read(&mut self.game_play_event_rid);
+
+        // Only request the session server to pause or resume when in a session.
+        if *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished
+        {
+            // Only the last pause or resume request is sent if multiple are received.
+            let session_message_event = game_play_events
+                .filter_map(|ev| match ev {
+                    GamePlayEvent::Pause => Some(SessionMessageEvent::GamePlayPauseRequest),
+                    GamePlayEvent::Resume => Some(SessionMessageEvent::GamePlayResumeRequest),
+                    _ => None,
+                })
+                .last();
+
+            if let Some(session_message_event) = session_message_event {
+                net_message_ec
+                    .single_write(NetMessageEvent::SessionMessageEvent(session_message_event));
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_play_model::GamePlayEvent;
+use net_model::play::NetMessageEvent;
+use network_session_model::{play::SessionStatus, SessionMessageEvent};
+
+/// Sends requests to the session server to restart the round.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(GamePlayRestartRequestSystemDesc))]
+pub struct GamePlayRestartRequestSystem {
+    /// Reader ID for the `GamePlayEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_play_event_rid: ReaderId<GamePlayEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct GamePlayRestartRequestSystemData<'s> {
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Read<'s, EventChannel<GamePlayEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `NetworkMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for GamePlayRestartRequestSystem {
+    type SystemData = GamePlayRestartRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        GamePlayRestartRequestSystemData {
+            game_play_ec,
+            session_status,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let mut game_play_events = game_play_ec.read(&mut self.game_play_event_rid);
+
+        // Only request the session server to restart when in a session.
+        if *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished
+        {
+            // Only send one restart request if multiple are received.
+            let restart_requested = game_play_events.any(|ev| *ev == GamePlayEvent::Restart);
+
+            if restart_requested {
+                net_message_ec.single_write(NetMessageEvent::SessionMessageEvent(
+                    SessionMessageEvent::GamePlayRestartRequest,
+                ));
+            }
+        }
+    }
+}
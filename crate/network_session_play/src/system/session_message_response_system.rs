@@ -2,15 +2,16 @@ use amethyst::{
     derive::SystemDesc,
     ecs::{Read, System, World, Write},
     shred::{ResourceId, SystemData},
-    shrev::ReaderId,
+    shrev::{EventChannel, ReaderId},
 };
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::loaded::PlayerControllers;
-use log::debug;
+use game_play_model::GamePlayEvent;
+use log::{debug, error, info, warn};
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::{
-    play::{SessionCondition, SessionDeviceJoin, SessionDevices, SessionStatus},
+    play::{SessionCondition, SessionDesync, SessionDeviceJoin, SessionDevices, SessionStatus},
     SessionMessageEvent,
 };
 
@@ -41,6 +42,9 @@ pub struct SessionMessageResponseSystemData<'s> {
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Write<'s, PlayerControllers>,
+    /// `GamePlayEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_play_ec: Write<'s, EventChannel<GamePlayEvent>>,
 }
 
 impl<'s> System<'s> for SessionMessageResponseSystem {
@@ -54,6 +58,7 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
             mut session_condition,
             mut session_devices,
             mut player_controllers,
+            mut game_play_ec,
         }: Self::SystemData,
     ) {
         let session_message_events = session_message_nec.read(&mut self.session_message_event_rid);
@@ -69,7 +74,7 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
                     SessionMessageEvent::GameInputTick => {
                         // Have received all `GameInputEvent`s from the session server.
                         debug!("Session `GameInputTick` received.");
-                        *session_condition = SessionCondition::Ready;
+                        session_condition.ready();
                     }
                     SessionMessageEvent::SessionDeviceJoin(session_device_join) => {
                         let SessionDeviceJoin {
@@ -82,11 +87,70 @@ impl<'s> System<'s> for SessionMessageResponseSystem {
                         session_devices.push(session_device.clone());
                         *player_controllers = player_controllers_received.clone();
                     }
+                    SessionMessageEvent::SessionDeviceDisconnect(session_device_id) => {
+                        warn!(
+                            "Session device [{}] disconnected, waiting for it to reconnect.",
+                            session_device_id
+                        );
+                    }
+                    SessionMessageEvent::SessionDeviceReconnect(session_device_id) => {
+                        info!("Session device [{}] reconnected.", session_device_id);
+                    }
+                    SessionMessageEvent::StateChecksum(_)
+                    | SessionMessageEvent::GamePlayRestartRequest
+                    | SessionMessageEvent::GamePlayPauseRequest
+                    | SessionMessageEvent::GamePlayResumeRequest => {
+                        // Only sent from game clients to the session server.
+                    }
+                    SessionMessageEvent::GamePlayRestartNotify => {
+                        debug!("Game play restart notification received.");
+                        game_play_ec.single_write(GamePlayEvent::RestartNotify);
+                    }
+                    SessionMessageEvent::GamePlayPauseNotify => {
+                        debug!("Game play pause notification received.");
+                        game_play_ec.single_write(GamePlayEvent::PauseNotify);
+                    }
+                    SessionMessageEvent::GamePlayResumeNotify => {
+                        debug!("Game play resume notification received.");
+                        game_play_ec.single_write(GamePlayEvent::ResumeNotify);
+                    }
+                    SessionMessageEvent::SessionNotFound => {
+                        warn!("Session server does not have a session for this device.");
+                    }
+                    SessionMessageEvent::SessionDesync(session_desync) => {
+                        let SessionDesync {
+                            tick,
+                            session_device_id,
+                            session_device_name,
+                            checksum,
+                            expected_checksum,
+                            state_dump,
+                            expected_state_dump,
+                        } = session_desync;
+
+                        error!(
+                            "Game play state desynced at tick `{}`: `{}` [{}] has checksum `{}`, \
+                             expected `{}`.",
+                            tick,
+                            session_device_name,
+                            session_device_id,
+                            checksum,
+                            expected_checksum
+                        );
+                        if let (Some(state_dump), Some(expected_state_dump)) =
+                            (state_dump, expected_state_dump)
+                        {
+                            error!(
+                                "State of `{}`:\n{}\n\nExpected state:\n{}",
+                                session_device_name, state_dump, expected_state_dump
+                            );
+                        }
+                    }
                 }
             });
         } else {
-            // Unfreeze the other systems if not in a network game.
-            *session_condition = SessionCondition::Ready;
+            // Unfreeze the other systems if not in a network game, unless game play is paused.
+            session_condition.ready();
         }
     }
 }
@@ -7,6 +7,10 @@ use character_model::{
 };
 use energy_model::config::{EnergySequence, EnergySequenceName};
 use input_reaction_loading::{IrsLoader, IrsLoaderParams};
+use item_model::{
+    config::{ItemSequence, ItemSequenceName, WeaponPoint},
+    loaded::{WeaponPointSequence, WeaponPointSequenceHandle, WeaponPointSequenceHandles},
+};
 use kinematic_model::{
     config::{PositionInit, VelocityInit},
     play::PositionZAsY,
@@ -36,14 +40,17 @@ impl ObjectAscl {
                 DefinitionLoadingResourcesRead {
                     character_definition_assets,
                     energy_definition_assets,
+                    item_definition_assets,
                     asset_character_definition_handle,
                     asset_energy_definition_handle,
+                    asset_item_definition_handle,
                     ..
                 },
             id_mapping_resources_read:
                 IdMappingResourcesRead {
                     asset_sequence_id_mappings_character,
                     asset_sequence_id_mappings_energy,
+                    asset_sequence_id_mappings_item,
                     ..
                 },
             texture_loading_resources_read:
@@ -64,8 +71,10 @@ impl ObjectAscl {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            chase_sequence_assets,
             character_input_reactions_assets,
             character_irs_assets,
+            weapon_point_sequence_assets,
             ..
         }: &mut SequenceComponentLoadingResources<'_>,
         asset_id: AssetId,
@@ -93,6 +102,7 @@ impl ObjectAscl {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -101,6 +111,7 @@ impl ObjectAscl {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            chase_sequence_assets,
             body_assets,
             interactions_assets,
             spawns_assets,
@@ -163,7 +174,34 @@ impl ObjectAscl {
                     CharacterIrsHandles::new(character_irs_handles)
                 };
 
-                item_entity_builder = item_entity_builder.with(character_irs_handles);
+                let weapon_point_sequence_handles = {
+                    let weapon_point_sequence_handles = character_definition
+                        .object_definition
+                        .sequences
+                        .values()
+                        .map(|sequence| {
+                            let weapon_point_sequence = WeaponPointSequence::new(
+                                sequence
+                                    .object_sequence
+                                    .sequence
+                                    .frames
+                                    .iter()
+                                    .map(|frame| frame.weapon_point)
+                                    .collect::<Vec<WeaponPoint>>(),
+                            );
+                            loader.load_from_data(
+                                weapon_point_sequence,
+                                (),
+                                weapon_point_sequence_assets,
+                            )
+                        })
+                        .collect::<Vec<WeaponPointSequenceHandle>>();
+                    WeaponPointSequenceHandles::new(weapon_point_sequence_handles)
+                };
+
+                item_entity_builder = item_entity_builder
+                    .with(character_irs_handles)
+                    .with(weapon_point_sequence_handles);
 
                 let object = ObjectLoader::load::<CharacterSequence>(
                     object_loader_params,
@@ -206,6 +244,40 @@ impl ObjectAscl {
 
                 (sequence_id_init, object)
             }
+            ObjectType::Item => {
+                let item_definition = asset_item_definition_handle
+                    .get(asset_id)
+                    .and_then(|item_definition_handle| {
+                        item_definition_assets.get(item_definition_handle)
+                    })
+                    .expect("Expected `ItemDefinition` to be loaded.");
+
+                let sequence_id_mappings = asset_sequence_id_mappings_item
+                    .get(asset_id)
+                    .expect("Expected `SequenceIdMapping` to be loaded.");
+                let sequence_id_init = {
+                    let sequence_name_default = ItemSequenceName::default();
+                    sequence_id_mappings
+                        .id_by_name(sequence_name_default)
+                        .copied()
+                        .unwrap_or_else(|| {
+                            warn!(
+                                "`{}` sequence ID not found for asset: `{}`. \
+                                 Falling back to first declared sequence.",
+                                sequence_name_default, asset_slug
+                            );
+
+                            SequenceId::new(0)
+                        })
+                };
+
+                let object = ObjectLoader::load::<ItemSequence>(
+                    object_loader_params,
+                    &item_definition.object_definition,
+                );
+
+                (sequence_id_init, object)
+            }
             ObjectType::TestObject => panic!("`TestObject` loading is not supported."),
         };
         let Object {
@@ -217,6 +289,7 @@ impl ObjectAscl {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            chase_sequence_handles,
         } = object;
 
         let item_id = {
@@ -235,6 +308,7 @@ impl ObjectAscl {
                 .with(body_sequence_handles)
                 .with(interactions_sequence_handles)
                 .with(spawns_sequence_handles)
+                .with(chase_sequence_handles)
                 .build();
             ItemId::new(item_entity)
         };
@@ -5,6 +5,7 @@ use asset_model::config::AssetType;
 use audio_loading::AudioLoader;
 use audio_model::loaded::{SourceHandleOpt, SourceSequence, SourceSequenceHandles};
 use character_model::config::CharacterSequenceName;
+use chase_model::loaded::{ChaseOpt, ChaseSequence, ChaseSequenceHandles};
 use collision_model::{
     config::{Body, Interactions},
     loaded::{
@@ -12,6 +13,7 @@ use collision_model::{
     },
 };
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use kinematic_model::{
     config::{ObjectAcceleration, Position, Velocity},
     loaded::{ObjectAccelerationSequence, ObjectAccelerationSequenceHandles},
@@ -53,6 +55,7 @@ impl ObjectLoader {
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -61,6 +64,7 @@ impl ObjectLoader {
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            chase_sequence_assets,
             sprite_sheet_handles,
             body_assets,
             interactions_assets,
@@ -117,6 +121,7 @@ impl ObjectLoader {
             BodySequenceHandles::default(),
             InteractionsSequenceHandles::default(),
             SpawnsSequenceHandles::default(),
+            ChaseSequenceHandles::default(),
         );
         let (
             wait_sequence_handles,
@@ -126,6 +131,7 @@ impl ObjectLoader {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            chase_sequence_handles,
         ) = object_definition.sequences.values().fold(
             sequences_handles,
             |(
@@ -136,6 +142,7 @@ impl ObjectLoader {
                 mut body_sequence_handles,
                 mut interactions_sequence_handles,
                 mut spawns_sequence_handles,
+                mut chase_sequence_handles,
             ),
              sequence| {
                 let object_sequence = sequence.object_sequence();
@@ -176,6 +183,14 @@ impl ObjectLoader {
                         })
                         .collect::<Vec<ObjectAcceleration>>(),
                 );
+                let chase_sequence = ChaseSequence::new(
+                    object_sequence
+                        .sequence
+                        .frames
+                        .iter()
+                        .map(|frame| ChaseOpt::new(frame.object_frame().chase))
+                        .collect::<Vec<ChaseOpt>>(),
+                );
                 let sprite_render_sequence = SpriteRenderSequence::new(
                     object_sequence
                         .sequence
@@ -290,6 +305,26 @@ impl ObjectLoader {
                                                             .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
                                                 }
                                             }
+                                            ObjectType::Item => {
+                                                let spawn_sequence_id_mappings = asset_sequence_id_mappings_item.get(spawn_asset_id)
+                                                    .unwrap_or_else(|| panic!("`SequenceIdMappings<Item>` not found for `{}`.", spawn_asset_slug));
+
+                                                if let Some(sequence_string) = spawn_config.sequence.as_ref() {
+                                                    let sequence_name_string = SequenceNameString::from_str(sequence_string).expect("Expected `SequenceNameString::from_str` to succeed.");
+                                                    spawn_sequence_id_mappings.id(&sequence_name_string).copied().unwrap_or_else(|| {
+                                                        let message = format!("Sequence ID not found for string: `{}` in `{}`. Falling back to default.", sequence_string, spawn_asset_slug);
+                                                        error!("{}", message);
+
+                                                        let sequence_default = ItemSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                    })
+                                                } else {
+                                                    let sequence_default = ItemSequenceName::default();
+                                                        spawn_sequence_id_mappings.id(&SequenceNameString::from(sequence_default)).copied()
+                                                            .unwrap_or_else(|| panic!("`{}` sequence not found for `{}`", sequence_default, spawn_asset_slug))
+                                                }
+                                            }
                                             ObjectType::TestObject => {
                                                 panic!("Spawning `TestObject`s is not supported.")
                                             }
@@ -332,6 +367,8 @@ impl ObjectLoader {
                     loader.load_from_data(interactions_sequence, (), interactions_sequence_assets);
                 let spawns_sequence_handle =
                     loader.load_from_data(spawns_sequence, (), spawns_sequence_assets);
+                let chase_sequence_handle =
+                    loader.load_from_data(chase_sequence, (), chase_sequence_assets);
 
                 wait_sequence_handles.push(wait_sequence_handle);
                 source_sequence_handles.push(source_sequence_handle);
@@ -340,6 +377,7 @@ impl ObjectLoader {
                 body_sequence_handles.push(body_sequence_handle);
                 interactions_sequence_handles.push(interactions_sequence_handle);
                 spawns_sequence_handles.push(spawns_sequence_handle);
+                chase_sequence_handles.push(chase_sequence_handle);
 
                 (
                     wait_sequence_handles,
@@ -349,6 +387,7 @@ impl ObjectLoader {
                     body_sequence_handles,
                     interactions_sequence_handles,
                     spawns_sequence_handles,
+                    chase_sequence_handles,
                 )
             },
         );
@@ -361,6 +400,7 @@ impl ObjectLoader {
             body_sequence_handles,
             interactions_sequence_handles,
             spawns_sequence_handles,
+            chase_sequence_handles,
             SequenceEndTransitions::new(sequence_end_transitions),
         )
     }
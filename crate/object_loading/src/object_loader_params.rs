@@ -6,12 +6,14 @@ use amethyst::{
 use asset_model::loaded::{AssetIdMappings, AssetTypeMappings};
 use audio_model::loaded::SourceSequence;
 use character_model::config::CharacterSequenceName;
+use chase_model::loaded::ChaseSequence;
 use collision_model::{
     config::{Body, Interactions},
     loaded::{BodySequence, InteractionsSequence},
 };
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
@@ -38,6 +40,9 @@ pub struct ObjectLoaderParams<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: &'s AssetSequenceIdMappings<EnergySequenceName>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: &'s AssetSequenceIdMappings<ItemSequenceName>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: &'s AssetStorage<WaitSequence>,
@@ -62,6 +67,9 @@ pub struct ObjectLoaderParams<'s> {
     /// `SpawnsSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: &'s AssetStorage<SpawnsSequence>,
+    /// `ChaseSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub chase_sequence_assets: &'s AssetStorage<ChaseSequence>,
     /// `Body` assets.
     #[derivative(Debug = "ignore")]
     pub body_assets: &'s AssetStorage<Body>,
@@ -90,6 +98,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             ref asset_type_mappings,
             ref asset_sequence_id_mappings_character,
             ref asset_sequence_id_mappings_energy,
+            ref asset_sequence_id_mappings_item,
             ref wait_sequence_assets,
             ref source_assets,
             ref source_sequence_assets,
@@ -98,6 +107,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             ref body_sequence_assets,
             ref interactions_sequence_assets,
             ref spawns_sequence_assets,
+            ref chase_sequence_assets,
             ref body_assets,
             ref interactions_assets,
             ref spawns_assets,
@@ -109,6 +119,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             asset_type_mappings,
             asset_sequence_id_mappings_character,
             asset_sequence_id_mappings_energy,
+            asset_sequence_id_mappings_item,
             wait_sequence_assets,
             source_assets,
             source_sequence_assets,
@@ -117,6 +128,7 @@ impl<'s> From<(&'s ObjectLoaderSystemData<'s>, &'s [SpriteSheetHandle])>
             body_sequence_assets,
             interactions_sequence_assets,
             spawns_sequence_assets,
+            chase_sequence_assets,
             body_assets,
             interactions_assets,
             spawns_assets,
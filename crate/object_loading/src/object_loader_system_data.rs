@@ -7,12 +7,14 @@ use amethyst::{
 use asset_model::loaded::{AssetIdMappings, AssetTypeMappings};
 use audio_model::loaded::SourceSequence;
 use character_model::config::CharacterSequenceName;
+use chase_model::loaded::ChaseSequence;
 use collision_model::{
     config::{Body, Interactions},
     loaded::{BodySequence, InteractionsSequence},
 };
 use derivative::Derivative;
 use energy_model::config::EnergySequenceName;
+use item_model::config::ItemSequenceName;
 use kinematic_model::loaded::ObjectAccelerationSequence;
 use sequence_model::loaded::{AssetSequenceIdMappings, WaitSequence};
 use spawn_model::loaded::{Spawns, SpawnsSequence};
@@ -38,6 +40,9 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `AssetSequenceIdMappings<EnergySequenceName>` resource.
     #[derivative(Debug = "ignore")]
     pub asset_sequence_id_mappings_energy: Read<'s, AssetSequenceIdMappings<EnergySequenceName>>,
+    /// `AssetSequenceIdMappings<ItemSequenceName>` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_sequence_id_mappings_item: Read<'s, AssetSequenceIdMappings<ItemSequenceName>>,
     /// `WaitSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub wait_sequence_assets: Read<'s, AssetStorage<WaitSequence>>,
@@ -62,6 +67,9 @@ pub struct ObjectLoaderSystemData<'s> {
     /// `SpawnsSequence`s assets.
     #[derivative(Debug = "ignore")]
     pub spawns_sequence_assets: Read<'s, AssetStorage<SpawnsSequence>>,
+    /// `ChaseSequence`s assets.
+    #[derivative(Debug = "ignore")]
+    pub chase_sequence_assets: Read<'s, AssetStorage<ChaseSequence>>,
     /// `Body` assets.
     #[derivative(Debug = "ignore")]
     pub body_assets: Read<'s, AssetStorage<Body>>,
@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use chase_model::config::Chase;
 use collision_model::config::{Body, Interactions};
 use derive_new::new;
 use kinematic_model::config::ObjectAcceleration;
@@ -17,6 +18,7 @@ use sprite_model::config::SpriteRef;
 /// * **Effects:** Sound(s) to play.
 /// * **Spawning:** Spawning additional object(s).
 /// * **Weapon:** Where an active weapon should be.
+/// * **Chase:** How the object moves relative to its target object.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize, new)]
 #[serde(default, deny_unknown_fields)]
 pub struct ObjectFrame {
@@ -34,6 +36,9 @@ pub struct ObjectFrame {
     pub interactions: Interactions,
     /// Objects to spawn.
     pub spawns: Spawns,
+    /// How the object chases its target object.
+    #[new(default)]
+    pub chase: Option<Chase>,
 }
 
 impl AsRef<Wait> for ObjectFrame {
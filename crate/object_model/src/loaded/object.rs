@@ -1,4 +1,5 @@
 use audio_model::loaded::SourceSequenceHandles;
+use chase_model::loaded::ChaseSequenceHandles;
 use collision_model::loaded::{BodySequenceHandles, InteractionsSequenceHandles};
 use derivative::Derivative;
 use derive_new::new;
@@ -26,6 +27,8 @@ pub struct Object {
     pub interactions_sequence_handles: InteractionsSequenceHandles,
     /// Handles to `SpawnsSequence`s that this object uses.
     pub spawns_sequence_handles: SpawnsSequenceHandles,
+    /// Handles to `ChaseSequence`s that this object uses.
+    pub chase_sequence_handles: ChaseSequenceHandles,
     /// Sequence transition when a sequence ends.
     pub sequence_end_transitions: SequenceEndTransitions,
 }
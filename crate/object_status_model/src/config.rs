@@ -1,5 +1,13 @@
 //! Contains the types that represent the configuration on disk.
 
-pub use self::stun_points::{StunPoints, STUN_POINTS_DEFAULT};
+pub use self::{
+    defense_limit::DefenseLimit,
+    defense_points::{DefensePoints, DEFENSE_POINTS_DEFAULT},
+    stun_points::{StunPoints, STUN_POINTS_DEFAULT},
+    stun_recovery::StunRecovery,
+};
 
+mod defense_limit;
+mod defense_points;
 mod stun_points;
+mod stun_recovery;
@@ -0,0 +1,18 @@
+use amethyst::ecs::{storage::VecStorage, Component};
+use derivative::Derivative;
+use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
+use numeric_newtype_derive::numeric_newtype;
+use serde::{Deserialize, Serialize};
+
+/// Default defense points to inflict on a blocked hit.
+pub const DEFENSE_POINTS_DEFAULT: u32 = 20;
+
+/// Defense points of an object.
+///
+/// These accumulate when an object blocks hits, and the object's guard is broken when they reach
+/// its `DefenseLimit`.
+#[numeric_newtype]
+#[derive(Component, Debug, Derivative, Deserialize, Hash, Serialize)]
+#[derivative(Default)]
+#[storage(VecStorage)]
+pub struct DefensePoints(#[derivative(Default(value = "DEFENSE_POINTS_DEFAULT"))] pub u32);
@@ -2,6 +2,6 @@
 
 //! Provides logic used during game play.
 
-pub use crate::system::StunPointsReductionSystem;
+pub use crate::system::{DefensePointsReductionSystem, StunPointsReductionSystem};
 
 mod system;
@@ -1,3 +1,7 @@
-pub use self::stun_points_reduction_system::StunPointsReductionSystem;
+pub use self::{
+    defense_points_reduction_system::DefensePointsReductionSystem,
+    stun_points_reduction_system::StunPointsReductionSystem,
+};
 
+mod defense_points_reduction_system;
 mod stun_points_reduction_system;
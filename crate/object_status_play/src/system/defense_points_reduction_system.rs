@@ -0,0 +1,21 @@
+use amethyst::ecs::{Join, System, WriteStorage};
+use derive_new::new;
+use object_status_model::config::DefensePoints;
+
+/// Decrements `DefensePoints`.
+#[derive(Debug, Default, new)]
+pub struct DefensePointsReductionSystem;
+
+type DefensePointsReductionSystemData<'s> = WriteStorage<'s, DefensePoints>;
+
+impl<'s> System<'s> for DefensePointsReductionSystem {
+    type SystemData = DefensePointsReductionSystemData<'s>;
+
+    fn run(&mut self, mut defense_pointses: Self::SystemData) {
+        (&mut defense_pointses).join().for_each(|defense_points| {
+            if *defense_points > 0 {
+                *defense_points -= 1;
+            }
+        });
+    } // kcov-ignore
+}
@@ -1,21 +1,29 @@
-use amethyst::ecs::{Join, System, WriteStorage};
+use amethyst::ecs::{Join, ReadStorage, System, WriteStorage};
 use derive_new::new;
-use object_status_model::config::StunPoints;
+use object_status_model::config::{StunPoints, StunRecovery};
 
 /// Decrements `StunPoints`.
+///
+/// Objects recover their `StunRecovery` in stun points each tick, or 1 if they have none.
 #[derive(Debug, Default, new)]
 pub struct StunPointsReductionSystem;
 
-type StunPointsReductionSystemData<'s> = WriteStorage<'s, StunPoints>;
+type StunPointsReductionSystemData<'s> =
+    (ReadStorage<'s, StunRecovery>, WriteStorage<'s, StunPoints>);
 
 impl<'s> System<'s> for StunPointsReductionSystem {
     type SystemData = StunPointsReductionSystemData<'s>;
 
-    fn run(&mut self, mut stun_pointses: Self::SystemData) {
-        (&mut stun_pointses).join().for_each(|stun_points| {
-            if *stun_points > 0 {
-                *stun_points -= 1;
-            }
-        });
+    fn run(&mut self, (stun_recoveries, mut stun_pointses): Self::SystemData) {
+        (&mut stun_pointses, stun_recoveries.maybe())
+            .join()
+            .for_each(|(stun_points, stun_recovery)| {
+                let stun_recovery = stun_recovery.copied().unwrap_or_default();
+                if stun_points.0 > stun_recovery.0 {
+                    *stun_points -= stun_recovery.0;
+                } else {
+                    *stun_points = StunPoints::new(0);
+                }
+            });
     } // kcov-ignore
 }
@@ -18,6 +18,9 @@ pub enum ObjectType {
     /// Energy / aura / spark effects.
     #[evt(skip)]
     Energy,
+    /// Weapons and other objects that may be picked up.
+    #[evt(skip)]
+    Item,
     /// Used in tests.
     #[evt(skip)]
     TestObject,
@@ -0,0 +1,6 @@
+//! User defined configuration types for replays.
+
+pub use self::{replay::Replay, replay_character_selection::ReplayCharacterSelection};
+
+mod replay;
+mod replay_character_selection;
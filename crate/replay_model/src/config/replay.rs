@@ -0,0 +1,30 @@
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::play::TickedGameInputEvent;
+use game_rules_model::config::GameRules;
+use serde::{Deserialize, Serialize};
+
+use crate::config::ReplayCharacterSelection;
+
+/// Recorded match that may be played back.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct Replay {
+    /// Seed for random number generation used in the match.
+    pub rng_seed: u64,
+    /// Characters selected for each controller.
+    pub character_selections: Vec<ReplayCharacterSelection>,
+    /// Slug of the selected map.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub map_selection: AssetSlug,
+    /// Rules of the match.
+    #[new(default)]
+    #[serde(default)]
+    pub game_rules: GameRules,
+    /// Game input events sent during the match, in tick order.
+    #[serde(default)]
+    pub game_input_events: Vec<TickedGameInputEvent>,
+}
@@ -0,0 +1,28 @@
+use ai_model::config::AiDifficulty;
+use asset_model::config::AssetSlug;
+use derive_new::new;
+use game_input_model::config::ControllerId;
+use serde::{Deserialize, Serialize};
+use team_model::play::TeamCounter;
+
+/// Character selected for a controller in a recorded match.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct ReplayCharacterSelection {
+    /// ID of the controller that selected the character.
+    pub controller_id: ControllerId,
+    /// Slug of the selected character.
+    #[serde(
+        serialize_with = "AssetSlug::serialize_str",
+        deserialize_with = "AssetSlug::deserialize_str"
+    )]
+    pub asset_slug: AssetSlug,
+    /// Difficulty of the computer controlling the character, `None` if controlled by a player.
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ai_difficulty: Option<AiDifficulty>,
+    /// Numbered team selected for the character, `None` if on an independent team.
+    #[new(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team_counter: Option<TeamCounter>,
+}
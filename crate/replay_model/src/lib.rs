@@ -0,0 +1,9 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to record and play back matches.
+//!
+//! A replay stores the inputs for each tick of a match, along with the selections and random seed
+//! used to begin it, so that the match can be simulated again.
+
+pub mod config;
+pub mod play;
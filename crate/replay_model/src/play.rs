@@ -0,0 +1,9 @@
+//! Data types used at runtime.
+
+pub use self::{
+    replay_mode::ReplayMode, replay_playback::ReplayPlayback, replay_recording::ReplayRecording,
+};
+
+mod replay_mode;
+mod replay_playback;
+mod replay_recording;
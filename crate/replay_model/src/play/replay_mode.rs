@@ -0,0 +1,19 @@
+use std::path::PathBuf;
+
+use derivative::Derivative;
+
+/// Whether matches are being recorded or played back.
+#[derive(Clone, Debug, Derivative, PartialEq)]
+#[derivative(Default)]
+pub enum ReplayMode {
+    /// Matches are neither recorded nor played back.
+    #[derivative(Default)]
+    None,
+    /// Matches are recorded to a replay file.
+    Record {
+        /// Path to write the replay file to.
+        path: PathBuf,
+    },
+    /// Game input is read from a replay instead of live input.
+    Playback,
+}
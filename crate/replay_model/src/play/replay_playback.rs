@@ -0,0 +1,36 @@
+use std::collections::BTreeMap;
+
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+use game_input_model::{
+    play::{GameTick, TickedGameInputEvent},
+    GameInputEvent,
+};
+
+/// Recorded `GameInputEvent`s to play back, grouped by tick.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct ReplayPlayback(pub BTreeMap<GameTick, Vec<GameInputEvent>>);
+
+impl ReplayPlayback {
+    /// Returns the `GameInputEvent`s recorded for the given tick.
+    pub fn events(&self, tick: GameTick) -> &[GameInputEvent] {
+        self.0.get(&tick).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+impl From<Vec<TickedGameInputEvent>> for ReplayPlayback {
+    fn from(ticked_game_input_events: Vec<TickedGameInputEvent>) -> Self {
+        let events = ticked_game_input_events.into_iter().fold(
+            BTreeMap::<GameTick, Vec<GameInputEvent>>::new(),
+            |mut events, ticked_game_input_event| {
+                events
+                    .entry(ticked_game_input_event.tick)
+                    .or_default()
+                    .push(ticked_game_input_event.game_input_event);
+                events
+            },
+        );
+
+        ReplayPlayback(events)
+    }
+}
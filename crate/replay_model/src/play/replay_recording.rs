@@ -0,0 +1,8 @@
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::config::Replay;
+
+/// Replay of the match currently being recorded, if any.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq, new)]
+pub struct ReplayRecording(pub Option<Replay>);
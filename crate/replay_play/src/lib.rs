@@ -0,0 +1,14 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to record matches to replay files, and to play them back.
+
+pub use crate::{
+    replay_playback_state::ReplayPlaybackState,
+    system::{
+        ReplayPlaybackSystem, ReplayPlaybackSystemData, ReplayRecordSystem, ReplayRecordSystemData,
+        ReplayRecordSystemDesc,
+    },
+};
+
+mod replay_playback_state;
+mod system;
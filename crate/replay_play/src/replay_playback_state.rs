@@ -0,0 +1,142 @@
+use std::{collections::HashMap, fmt::Debug, marker::PhantomData};
+
+use amethyst::prelude::*;
+use application_event::AppEvent;
+use application_state::AutexState;
+use asset_model::loaded::AssetIdMappings;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_model::play::{GameRng, RngSeed};
+use log::error;
+use map_selection_model::MapSelection;
+use replay_model::{config::Replay, play::ReplayPlayback};
+
+/// `State` that sets up the selections from a replay, then switches to the next state.
+///
+/// This should be switched to after assets have been loaded, as the replay's asset slugs are
+/// resolved to asset IDs.
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct ReplayPlaybackState<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    /// Replay to play back.
+    replay: Replay,
+    /// The `State` that follows this one.
+    #[derivative(Debug(bound = "F: Debug"))]
+    next_state_fn: F,
+    /// Whether the replay selections were set up successfully.
+    #[new(default)]
+    replay_ready: bool,
+    /// Lifetime tracker.
+    #[derivative(Debug = "ignore")]
+    #[new(default)]
+    phantom_data: PhantomData<dyn AutexState<'a, 'b>>,
+}
+
+impl<'a, 'b, F, S> ReplayPlaybackState<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    /// Inserts the selections, random seed, and recorded input from the replay into the world.
+    ///
+    /// Returns `false` if any of the replay's assets are not loaded.
+    fn replay_setup(&self, world: &mut World) -> bool {
+        let selections = {
+            let asset_id_mappings = world.read_resource::<AssetIdMappings>();
+
+            let character_selections = self
+                .replay
+                .character_selections
+                .iter()
+                .map(|character_selection| {
+                    let asset_slug = &character_selection.asset_slug;
+                    asset_id_mappings
+                        .id(asset_slug)
+                        .copied()
+                        .map(|asset_id| (character_selection.controller_id, asset_id))
+                        .ok_or(asset_slug)
+                })
+                .collect::<Result<HashMap<_, _>, _>>();
+            let map_selection = asset_id_mappings
+                .id(&self.replay.map_selection)
+                .copied()
+                .ok_or(&self.replay.map_selection);
+
+            character_selections.and_then(|character_selections| {
+                map_selection.map(|map_asset_id| (character_selections, map_asset_id))
+            })
+        };
+
+        match selections {
+            Ok((character_selections, map_asset_id)) => {
+                let mut character_selections = CharacterSelections::new(character_selections);
+                character_selections.ai_difficulties = self
+                    .replay
+                    .character_selections
+                    .iter()
+                    .filter_map(|character_selection| {
+                        character_selection
+                            .ai_difficulty
+                            .map(|ai_difficulty| (character_selection.controller_id, ai_difficulty))
+                    })
+                    .collect::<HashMap<_, _>>();
+                character_selections.team_counters = self
+                    .replay
+                    .character_selections
+                    .iter()
+                    .filter_map(|character_selection| {
+                        character_selection
+                            .team_counter
+                            .map(|team_counter| (character_selection.controller_id, team_counter))
+                    })
+                    .collect::<HashMap<_, _>>();
+
+                world.insert(character_selections);
+                world.insert(MapSelection::Id(map_asset_id));
+                world.insert(self.replay.game_rules);
+                let rng_seed = RngSeed::new(self.replay.rng_seed);
+                world.insert(rng_seed);
+                world.insert(GameRng::new(rng_seed));
+                world.insert(ReplayPlayback::from(self.replay.game_input_events.clone()));
+
+                true
+            }
+            Err(asset_slug) => {
+                error!(
+                    "Unable to play back replay: asset `{}` is not loaded.",
+                    asset_slug
+                );
+
+                false
+            }
+        }
+    }
+}
+
+impl<'a, 'b, F, S> State<GameData<'a, 'b>, AppEvent> for ReplayPlaybackState<'a, 'b, F, S>
+where
+    F: Fn() -> Box<S>,
+    S: AutexState<'a, 'b> + 'static,
+{
+    fn on_start(&mut self, data: StateData<'_, GameData<'_, '_>>) {
+        self.replay_ready = self.replay_setup(data.world);
+    }
+
+    fn update(
+        &mut self,
+        data: StateData<'_, GameData<'_, '_>>,
+    ) -> Trans<GameData<'a, 'b>, AppEvent> {
+        data.data.update(&data.world);
+
+        if self.replay_ready {
+            Trans::Switch((self.next_state_fn)())
+        } else {
+            Trans::Quit
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub use self::{
+    replay_playback_system::{ReplayPlaybackSystem, ReplayPlaybackSystemData},
+    replay_record_system::{ReplayRecordSystem, ReplayRecordSystemData, ReplayRecordSystemDesc},
+};
+
+mod replay_playback_system;
+mod replay_record_system;
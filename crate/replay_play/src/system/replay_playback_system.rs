@@ -0,0 +1,67 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::EventChannel,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{play::GameTick, GameInputEvent};
+use replay_model::play::{ReplayMode, ReplayPlayback};
+use state_registry::StateId;
+
+/// Sends the recorded `GameInputEvent`s for the current tick when playing back a replay.
+#[derive(Debug, Default, new)]
+pub struct ReplayPlaybackSystem {
+    /// Tick whose `GameInputEvent`s were most recently sent.
+    #[new(default)]
+    tick_applied: Option<GameTick>,
+}
+
+/// `ReplayPlaybackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayPlaybackSystemData<'s> {
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `ReplayPlayback` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_playback: Read<'s, ReplayPlayback>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+}
+
+impl<'s> System<'s> for ReplayPlaybackSystem {
+    type SystemData = ReplayPlaybackSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayPlaybackSystemData {
+            replay_mode,
+            state_id,
+            game_tick,
+            replay_playback,
+            mut game_input_ec,
+        }: Self::SystemData,
+    ) {
+        if *replay_mode != ReplayMode::Playback || *state_id != StateId::GamePlay {
+            self.tick_applied = None;
+            return;
+        }
+
+        let game_tick = *game_tick;
+        if self.tick_applied == Some(game_tick) {
+            return;
+        }
+        self.tick_applied = Some(game_tick);
+
+        game_input_ec.iter_write(replay_playback.events(game_tick).iter().copied());
+    }
+}
@@ -0,0 +1,209 @@
+use std::{fs, path::Path};
+
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use asset_model::loaded::AssetIdMappings;
+use character_selection_model::CharacterSelections;
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    play::{GameTick, TickedGameInputEvent},
+    GameInputEvent,
+};
+use game_model::play::RngSeed;
+use game_play_model::GamePlayStatus;
+use game_rules_model::config::GameRules;
+use log::{error, info};
+use map_selection_model::MapSelection;
+use replay_model::{
+    config::{Replay, ReplayCharacterSelection},
+    play::{ReplayMode, ReplayRecording},
+};
+use rollback_model::play::RollbackStatus;
+use state_registry::StateId;
+
+/// Records `GameInputEvent`s during game play, and writes them to a replay file when the game ends.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(ReplayRecordSystemDesc))]
+pub struct ReplayRecordSystem {
+    /// Reader ID for the `GameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    game_input_event_rid: ReaderId<GameInputEvent>,
+}
+
+/// `ReplayRecordSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ReplayRecordSystemData<'s> {
+    /// `ReplayMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_mode: Read<'s, ReplayMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GamePlayStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_play_status: Read<'s, GamePlayStatus>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `RngSeed` resource.
+    #[derivative(Debug = "ignore")]
+    pub rng_seed: Read<'s, RngSeed>,
+    /// `AssetIdMappings` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_id_mappings: Read<'s, AssetIdMappings>,
+    /// `CharacterSelections` resource.
+    #[derivative(Debug = "ignore")]
+    pub character_selections: Read<'s, CharacterSelections>,
+    /// `MapSelection` resource.
+    #[derivative(Debug = "ignore")]
+    pub map_selection: Read<'s, MapSelection>,
+    /// `GameRules` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rules: Read<'s, GameRules>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Read<'s, EventChannel<GameInputEvent>>,
+    /// `ReplayRecording` resource.
+    #[derivative(Debug = "ignore")]
+    pub replay_recording: Write<'s, ReplayRecording>,
+}
+
+impl ReplayRecordSystem {
+    /// Returns a new `Replay` for the current selections, if they are all known.
+    fn replay_begin(
+        rng_seed: RngSeed,
+        asset_id_mappings: &AssetIdMappings,
+        character_selections: &CharacterSelections,
+        map_selection: MapSelection,
+        game_rules: GameRules,
+    ) -> Option<Replay> {
+        let mut character_selections = character_selections
+            .selections
+            .iter()
+            .map(|(controller_id, asset_id)| {
+                asset_id_mappings
+                    .slug(*asset_id)
+                    .cloned()
+                    .map(|asset_slug| {
+                        let mut replay_character_selection =
+                            ReplayCharacterSelection::new(*controller_id, asset_slug);
+                        replay_character_selection.ai_difficulty = character_selections
+                            .ai_difficulties
+                            .get(controller_id)
+                            .copied();
+                        replay_character_selection.team_counter = character_selections
+                            .team_counters
+                            .get(controller_id)
+                            .copied();
+                        replay_character_selection
+                    })
+            })
+            .collect::<Option<Vec<ReplayCharacterSelection>>>()?;
+        character_selections.sort_by_key(|character_selection| character_selection.controller_id);
+
+        let map_selection = map_selection
+            .asset_id()
+            .and_then(|asset_id| asset_id_mappings.slug(asset_id))
+            .cloned()?;
+
+        let mut replay = Replay::new(*rng_seed, character_selections, map_selection, Vec::new());
+        replay.game_rules = game_rules;
+        Some(replay)
+    }
+
+    /// Writes the replay to the given path.
+    fn replay_write(path: &Path, replay: &Replay) {
+        let result = serde_yaml::to_string(replay)
+            .map_err(|e| e.to_string())
+            .and_then(|replay_yaml| fs::write(path, replay_yaml).map_err(|e| e.to_string()));
+
+        match result {
+            Ok(()) => info!("Replay written to `{}`.", path.display()),
+            Err(e) => error!("Failed to write replay to `{}`: {}", path.display(), e),
+        }
+    }
+}
+
+impl<'s> System<'s> for ReplayRecordSystem {
+    type SystemData = ReplayRecordSystemData<'s>;
+
+    fn run(
+        &mut self,
+        ReplayRecordSystemData {
+            replay_mode,
+            state_id,
+            game_play_status,
+            game_tick,
+            rollback_status,
+            rng_seed,
+            asset_id_mappings,
+            character_selections,
+            map_selection,
+            game_rules,
+            game_input_ec,
+            mut replay_recording,
+        }: Self::SystemData,
+    ) {
+        let game_input_events = game_input_ec.read(&mut self.game_input_event_rid);
+
+        let path = if let ReplayMode::Record { path } = &*replay_mode {
+            path
+        } else {
+            return;
+        };
+
+        // The match is still in progress while the pause menu is open.
+        let game_playing = matches!(*state_id, StateId::GamePlay | StateId::GamePlayPause)
+            && *game_play_status != GamePlayStatus::Ended;
+        if !game_playing {
+            if let Some(replay) = replay_recording.take() {
+                Self::replay_write(path, &replay);
+            }
+            return;
+        }
+
+        // Input while paused navigates the pause menu, and is not played back.
+        if *state_id == StateId::GamePlayPause {
+            return;
+        }
+
+        if replay_recording.is_none() {
+            **replay_recording = Self::replay_begin(
+                *rng_seed,
+                &asset_id_mappings,
+                &character_selections,
+                *map_selection,
+                *game_rules,
+            );
+
+            if replay_recording.is_none() {
+                error!("Unable to record replay: selected assets do not have slugs.");
+                return;
+            }
+        }
+
+        if let Some(replay) = replay_recording.as_mut() {
+            // Events from the rolled back ticks are sent again while resimulating.
+            if let RollbackStatus::Restoring { tick, .. } = *rollback_status {
+                replay
+                    .game_input_events
+                    .retain(|ticked_game_input_event| ticked_game_input_event.tick < tick);
+            }
+
+            let tick = *game_tick;
+            replay.game_input_events.extend(
+                game_input_events
+                    .map(|game_input_event| TickedGameInputEvent::new(tick, *game_input_event)),
+            );
+        }
+    }
+}
@@ -0,0 +1,6 @@
+//! User defined configuration types.
+
+pub use self::{rollback_config::RollbackConfig, session_sync_mode::SessionSyncMode};
+
+mod rollback_config;
+mod session_sync_mode;
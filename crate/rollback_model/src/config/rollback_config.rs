@@ -0,0 +1,33 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for rollback synchronization.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct RollbackConfig {
+    /// Number of ticks that local input is delayed by before it is applied.
+    ///
+    /// A larger delay gives remote input more time to arrive, which reduces the number of
+    /// rollbacks, at the cost of local responsiveness.
+    pub input_delay: u64,
+    /// Maximum number of ticks that game state may be rolled back by.
+    ///
+    /// Input that arrives for a tick older than this is discarded.
+    pub rollback_window: u64,
+}
+
+impl RollbackConfig {
+    /// Default number of ticks that local input is delayed by.
+    pub const INPUT_DELAY_DEFAULT: u64 = 2;
+    /// Default maximum number of ticks that game state may be rolled back by.
+    pub const ROLLBACK_WINDOW_DEFAULT: u64 = 8;
+}
+
+impl Default for RollbackConfig {
+    fn default() -> Self {
+        RollbackConfig {
+            input_delay: Self::INPUT_DELAY_DEFAULT,
+            rollback_window: Self::ROLLBACK_WINDOW_DEFAULT,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString};
+
+/// How game play is kept in sync between devices in a network session.
+///
+/// All devices in a session must use the same mode. The session server rejects devices that join
+/// with a different mode to the session host.
+#[derive(
+    Clone, Copy, Debug, Derivative, Deserialize, Display, EnumString, PartialEq, Eq, Serialize,
+)]
+#[derivative(Default)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SessionSyncMode {
+    /// Each tick waits for the session server to confirm all input has been sent.
+    #[derivative(Default)]
+    Lockstep,
+    /// Input is applied immediately, and game state is rolled back when late input arrives.
+    Rollback,
+}
@@ -0,0 +1,11 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used for rollback synchronization of network sessions.
+//!
+//! In rollback mode, each device simulates game play immediately using the input it has received,
+//! and stores a snapshot of game state every tick. When input for a past tick arrives from a remote
+//! device, the game state is restored to the snapshot of that tick, and the ticks up to the present
+//! are simulated again with the corrected input.
+
+pub mod config;
+pub mod play;
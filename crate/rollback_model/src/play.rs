@@ -0,0 +1,11 @@
+//! Data types used at runtime.
+
+pub use self::{
+    component_snapshots::ComponentSnapshots, input_history::InputHistory,
+    resource_snapshots::ResourceSnapshots, rollback_status::RollbackStatus,
+};
+
+mod component_snapshots;
+mod input_history;
+mod resource_snapshots;
+mod rollback_status;
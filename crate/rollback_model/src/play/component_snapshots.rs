@@ -0,0 +1,48 @@
+use std::collections::VecDeque;
+
+use amethyst::ecs::Entity;
+use derivative::Derivative;
+use game_input_model::play::GameTick;
+
+/// Values of a component for each entity, recorded at the beginning of recent ticks.
+///
+/// # Type Parameters
+///
+/// * `C`: Component type whose values are recorded.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default(bound = ""))]
+pub struct ComponentSnapshots<C> {
+    /// Snapshots ordered from oldest to newest.
+    pub snapshots: VecDeque<(GameTick, Vec<(Entity, C)>)>,
+}
+
+impl<C> ComponentSnapshots<C> {
+    /// Records the component values for the given tick.
+    ///
+    /// Snapshots for the given tick and later are discarded, as they are superseded by this one.
+    /// Only the most recent `capacity` snapshots are kept.
+    pub fn record(&mut self, tick: GameTick, components: Vec<(Entity, C)>, capacity: usize) {
+        while self
+            .snapshots
+            .back()
+            .map(|(snapshot_tick, _)| *snapshot_tick >= tick)
+            .unwrap_or(false)
+        {
+            self.snapshots.pop_back();
+        }
+
+        self.snapshots.push_back((tick, components));
+
+        while self.snapshots.len() > capacity {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Returns the component values recorded for the given tick.
+    pub fn get(&self, tick: GameTick) -> Option<&[(Entity, C)]> {
+        self.snapshots
+            .iter()
+            .find(|(snapshot_tick, _)| *snapshot_tick == tick)
+            .map(|(_, components)| components.as_slice())
+    }
+}
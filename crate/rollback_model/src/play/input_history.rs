@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+
+use derive_deref::{Deref, DerefMut};
+use game_input_model::{
+    play::{GameTick, TickedGameInputEvent},
+    GameInputEvent,
+};
+
+/// `GameInputEvent`s received for each tick, from both local and remote devices.
+#[derive(Clone, Debug, Default, Deref, DerefMut, PartialEq)]
+pub struct InputHistory(pub BTreeMap<GameTick, Vec<GameInputEvent>>);
+
+impl InputHistory {
+    /// Records a `TickedGameInputEvent`.
+    ///
+    /// Every event is recorded, including events identical to ones already recorded for the same
+    /// tick, as they may be separate presses. Duplicate deliveries are expected to have been
+    /// discarded by envelope sequence number before reaching the history.
+    pub fn insert(&mut self, ticked_game_input_event: TickedGameInputEvent) {
+        let TickedGameInputEvent {
+            tick,
+            game_input_event,
+        } = ticked_game_input_event;
+
+        self.0
+            .entry(tick)
+            .or_insert_with(Vec::new)
+            .push(game_input_event);
+    }
+
+    /// Returns the `GameInputEvent`s recorded for the given tick.
+    pub fn events(&self, tick: GameTick) -> &[GameInputEvent] {
+        self.0.get(&tick).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Removes the events recorded for ticks before the given tick.
+    pub fn prune_before(&mut self, tick: GameTick) {
+        self.0 = self.0.split_off(&tick);
+    }
+}
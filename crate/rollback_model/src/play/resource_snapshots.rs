@@ -0,0 +1,47 @@
+use std::collections::VecDeque;
+
+use derivative::Derivative;
+use game_input_model::play::GameTick;
+
+/// Values of a resource, recorded at the beginning of recent ticks.
+///
+/// # Type Parameters
+///
+/// * `R`: Resource type whose values are recorded.
+#[derive(Clone, Debug, Derivative)]
+#[derivative(Default(bound = ""))]
+pub struct ResourceSnapshots<R> {
+    /// Snapshots ordered from oldest to newest.
+    pub snapshots: VecDeque<(GameTick, R)>,
+}
+
+impl<R> ResourceSnapshots<R> {
+    /// Records the resource value for the given tick.
+    ///
+    /// Snapshots for the given tick and later are discarded, as they are superseded by this one.
+    /// Only the most recent `capacity` snapshots are kept.
+    pub fn record(&mut self, tick: GameTick, resource: R, capacity: usize) {
+        while self
+            .snapshots
+            .back()
+            .map(|(snapshot_tick, _)| *snapshot_tick >= tick)
+            .unwrap_or(false)
+        {
+            self.snapshots.pop_back();
+        }
+
+        self.snapshots.push_back((tick, resource));
+
+        while self.snapshots.len() > capacity {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Returns the resource value recorded for the given tick.
+    pub fn get(&self, tick: GameTick) -> Option<&R> {
+        self.snapshots
+            .iter()
+            .find(|(snapshot_tick, _)| *snapshot_tick == tick)
+            .map(|(_, resource)| resource)
+    }
+}
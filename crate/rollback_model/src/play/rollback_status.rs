@@ -0,0 +1,39 @@
+use derivative::Derivative;
+use game_input_model::play::GameTick;
+
+/// Whether game state is being rolled back.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum RollbackStatus {
+    /// Game play is proceeding normally.
+    #[derivative(Default)]
+    None,
+    /// Game state is to be restored to the snapshot for the given tick.
+    Restoring {
+        /// Tick to restore game state to.
+        tick: GameTick,
+        /// Tick that game play had reached before the rollback.
+        present: GameTick,
+    },
+    /// Past ticks are being simulated again.
+    Resimulating {
+        /// Tick that game play had reached before the rollback.
+        present: GameTick,
+    },
+}
+
+impl RollbackStatus {
+    /// Returns the tick that game play had reached before the rollback, if rolling back.
+    pub fn present(self) -> Option<GameTick> {
+        match self {
+            RollbackStatus::None => None,
+            RollbackStatus::Restoring { present, .. }
+            | RollbackStatus::Resimulating { present } => Some(present),
+        }
+    }
+
+    /// Returns whether past ticks are being simulated again.
+    pub fn is_resimulating(self) -> bool {
+        matches!(self, RollbackStatus::Resimulating { .. })
+    }
+}
@@ -0,0 +1,16 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to roll back and resimulate game play when late network input arrives.
+
+pub use crate::{
+    rollback_play_bundle::RollbackPlayBundle,
+    system::{
+        ComponentRollbackSystem, ComponentRollbackSystemData, ResourceRollbackSystem,
+        ResourceRollbackSystemData, RollbackInputSystem, RollbackInputSystemData,
+        RollbackInputSystemDesc, RollbackStatusUpdateSystem, RollbackStatusUpdateSystemData,
+        SpawnRollbackSystem, SpawnRollbackSystemData,
+    },
+};
+
+mod rollback_play_bundle;
+mod system;
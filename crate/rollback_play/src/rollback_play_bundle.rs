@@ -0,0 +1,139 @@
+use std::any;
+
+use ai_model::play::AiDecisionClock;
+use amethyst::{
+    core::{bundle::SystemBundle, SystemDesc},
+    ecs::{DispatcherBuilder, World},
+    Error,
+};
+use charge_model::play::{ChargeStatus, ChargeTrackerClock};
+use chase_model::{
+    loaded::ChaseOpt,
+    play::{ChaseModeStick, TargetObject},
+};
+use collision_model::play::{GrabbedBy, Grabbing, HitRepeatTrackers};
+use derive_new::new;
+use game_input_model::play::ControllerInput;
+use game_rules_model::play::{RespawnClock, Stocks, SurvivalWave};
+use game_stats_model::play::{PlayerPointsPrev, PlayerStats};
+use item_model::play::{HeldBy, HeldItem};
+use kinematic_model::config::{Position, Velocity};
+use mirrored_model::play::Mirrored;
+use object_model::play::{Grounding, HealthPoints, SkillPoints};
+use object_status_model::config::{DefensePoints, StunPoints};
+use sequence_model::{
+    loaded::SequenceId,
+    play::{FrameFreezeClock, FrameIndexClock, FrameWaitClock, SequenceStatus},
+};
+use status_effect_model::play::ActiveStatusEffects;
+use team_model::play::Team;
+
+use crate::{
+    ComponentRollbackSystem, ResourceRollbackSystem, RollbackInputSystem, RollbackInputSystemDesc,
+    SpawnRollbackSystem,
+};
+
+/// Adds the systems that record and restore game state for rollback.
+///
+/// These systems must run before the game play systems, and the `RollbackStatusUpdateSystem` must
+/// be added after them.
+#[derive(Debug, new)]
+pub struct RollbackPlayBundle {
+    /// System names that the `RollbackInputSystem` should depend on.
+    ///
+    /// This should include the systems that write `TickedGameInputEvent`s.
+    #[new(default)]
+    input_dependencies: Vec<&'static str>,
+}
+
+impl RollbackPlayBundle {
+    /// Specifies system names that the `RollbackInputSystem` should depend on.
+    pub fn with_input_dependencies(mut self, input_dependencies: &[&'static str]) -> Self {
+        self.input_dependencies = input_dependencies.to_vec();
+        self
+    }
+}
+
+impl<'a, 'b> SystemBundle<'a, 'b> for RollbackPlayBundle {
+    fn build(
+        self,
+        world: &mut World,
+        builder: &mut DispatcherBuilder<'a, 'b>,
+    ) -> Result<(), Error> {
+        builder.add(
+            <RollbackInputSystemDesc as SystemDesc<'a, 'b, RollbackInputSystem>>::build(
+                RollbackInputSystemDesc::default(),
+                world,
+            ),
+            any::type_name::<RollbackInputSystem>(),
+            &self.input_dependencies,
+        ); // kcov-ignore
+        builder.add(
+            SpawnRollbackSystem::new(),
+            any::type_name::<SpawnRollbackSystem>(),
+            &[any::type_name::<RollbackInputSystem>()],
+        ); // kcov-ignore
+
+        macro_rules! component_rollback_system {
+            ($component:ty) => {
+                let component_rollback_system =
+                    ComponentRollbackSystem::<$component>::new(stringify!($component));
+                let component_rollback_system_name = component_rollback_system.system_name();
+                builder.add(
+                    component_rollback_system,
+                    &component_rollback_system_name,
+                    &[any::type_name::<SpawnRollbackSystem>()],
+                ); // kcov-ignore
+            };
+        }
+
+        component_rollback_system!(Position<f32>);
+        component_rollback_system!(Velocity<f32>);
+        component_rollback_system!(Mirrored);
+        component_rollback_system!(Grounding);
+        component_rollback_system!(SequenceId);
+        component_rollback_system!(SequenceStatus);
+        component_rollback_system!(FrameIndexClock);
+        component_rollback_system!(FrameWaitClock);
+        component_rollback_system!(FrameFreezeClock);
+        component_rollback_system!(HealthPoints);
+        component_rollback_system!(SkillPoints);
+        component_rollback_system!(StunPoints);
+        component_rollback_system!(DefensePoints);
+        component_rollback_system!(ChargeStatus);
+        component_rollback_system!(ChargeTrackerClock);
+        component_rollback_system!(HitRepeatTrackers);
+        component_rollback_system!(ControllerInput);
+        component_rollback_system!(PlayerStats);
+        component_rollback_system!(PlayerPointsPrev);
+        component_rollback_system!(Stocks);
+        component_rollback_system!(RespawnClock);
+        component_rollback_system!(Grabbing);
+        component_rollback_system!(GrabbedBy);
+        component_rollback_system!(TargetObject);
+        component_rollback_system!(ChaseModeStick);
+        component_rollback_system!(ChaseOpt);
+        component_rollback_system!(HeldBy);
+        component_rollback_system!(HeldItem);
+        component_rollback_system!(ActiveStatusEffects);
+        component_rollback_system!(Team);
+        component_rollback_system!(AiDecisionClock);
+
+        macro_rules! resource_rollback_system {
+            ($resource:ty) => {
+                let resource_rollback_system =
+                    ResourceRollbackSystem::<$resource>::new(stringify!($resource));
+                let resource_rollback_system_name = resource_rollback_system.system_name();
+                builder.add(
+                    resource_rollback_system,
+                    &resource_rollback_system_name,
+                    &[any::type_name::<SpawnRollbackSystem>()],
+                ); // kcov-ignore
+            };
+        }
+
+        resource_rollback_system!(SurvivalWave);
+
+        Ok(())
+    }
+}
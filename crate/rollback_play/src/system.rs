@@ -0,0 +1,15 @@
+pub use self::{
+    component_rollback_system::{ComponentRollbackSystem, ComponentRollbackSystemData},
+    resource_rollback_system::{ResourceRollbackSystem, ResourceRollbackSystemData},
+    rollback_input_system::{
+        RollbackInputSystem, RollbackInputSystemData, RollbackInputSystemDesc,
+    },
+    rollback_status_update_system::{RollbackStatusUpdateSystem, RollbackStatusUpdateSystemData},
+    spawn_rollback_system::{SpawnRollbackSystem, SpawnRollbackSystemData},
+};
+
+mod component_rollback_system;
+mod resource_rollback_system;
+mod rollback_input_system;
+mod rollback_status_update_system;
+mod spawn_rollback_system;
@@ -0,0 +1,130 @@
+use std::{any, marker::PhantomData};
+
+use amethyst::{
+    ecs::{Component, Entities, Join, Read, System, World, Write, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::GameTick;
+use rollback_model::{
+    config::{RollbackConfig, SessionSyncMode},
+    play::{ComponentSnapshots, RollbackStatus},
+};
+use state_registry::StateId;
+
+/// Records snapshots of a component each tick, and restores them when rolling back.
+///
+/// This must run before any system that updates `C` during game play.
+///
+/// # Limitations
+///
+/// Entity deletion is not tracked. When restoring, components are only restored onto entities that
+/// are still alive, so an entity that is deleted within the rollback window is not brought back,
+/// and its snapshotted components are discarded. Entities spawned after the restored tick are
+/// deleted by the `SpawnRollbackSystem`.
+#[derive(Clone, Debug, Default, new)]
+pub struct ComponentRollbackSystem<C>
+where
+    C: Component + Clone + Send + Sync,
+{
+    /// Stringified name of the `Component` tracked by this system.
+    component_name: &'static str,
+    /// Component tracked by this system.
+    component: PhantomData<C>,
+}
+
+/// `ComponentRollbackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ComponentRollbackSystemData<'s, C>
+where
+    C: Component + Clone + Send + Sync,
+{
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `RollbackConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_config: Read<'s, RollbackConfig>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `C` components.
+    #[derivative(Debug = "ignore")]
+    pub components: WriteStorage<'s, C>,
+    /// `ComponentSnapshots<C>` resource.
+    #[derivative(Debug = "ignore")]
+    pub component_snapshots: Write<'s, ComponentSnapshots<C>>,
+}
+
+impl<C> ComponentRollbackSystem<C>
+where
+    C: Component + Clone + Send + Sync,
+{
+    /// Returns a String representing this system's name.
+    pub fn system_name(&self) -> String {
+        format!("{}<{}>", any::type_name::<Self>(), self.component_name)
+    }
+}
+
+impl<'s, C> System<'s> for ComponentRollbackSystem<C>
+where
+    C: Component + Clone + Send + Sync,
+{
+    type SystemData = ComponentRollbackSystemData<'s, C>;
+
+    fn run(
+        &mut self,
+        ComponentRollbackSystemData {
+            entities,
+            session_sync_mode,
+            state_id,
+            rollback_config,
+            game_tick,
+            rollback_status,
+            mut components,
+            mut component_snapshots,
+        }: Self::SystemData,
+    ) {
+        if *session_sync_mode != SessionSyncMode::Rollback || *state_id != StateId::GamePlay {
+            return;
+        }
+
+        if let RollbackStatus::Restoring { tick, .. } = *rollback_status {
+            if let Some(snapshot) = component_snapshots.get(tick) {
+                components.clear();
+                snapshot
+                    .iter()
+                    .filter(|(entity, _)| entities.is_alive(*entity))
+                    .for_each(|(entity, component)| {
+                        components
+                            .insert(*entity, component.clone())
+                            .unwrap_or_else(|_| {
+                                // kcov-ignore-start
+                                panic!("Failed to insert `{}` component.", self.component_name)
+                                // kcov-ignore-end
+                            }); // kcov-ignore
+                    });
+            }
+        } else {
+            let snapshot = (&*entities, &components)
+                .join()
+                .map(|(entity, component)| (entity, component.clone()))
+                .collect::<Vec<_>>();
+
+            // One more than the window, so that the oldest tick within the window can be restored.
+            let capacity = rollback_config.rollback_window as usize + 1;
+            component_snapshots.record(*game_tick, snapshot, capacity);
+        }
+    }
+}
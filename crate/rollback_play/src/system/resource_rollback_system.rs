@@ -0,0 +1,102 @@
+use std::{any, marker::PhantomData};
+
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::GameTick;
+use rollback_model::{
+    config::{RollbackConfig, SessionSyncMode},
+    play::{ResourceSnapshots, RollbackStatus},
+};
+use state_registry::StateId;
+
+/// Records snapshots of a resource each tick, and restores them when rolling back.
+///
+/// This must run before any system that updates `R` during game play.
+#[derive(Clone, Debug, Default, new)]
+pub struct ResourceRollbackSystem<R>
+where
+    R: Clone + Default + Send + Sync + 'static,
+{
+    /// Stringified name of the resource tracked by this system.
+    resource_name: &'static str,
+    /// Resource tracked by this system.
+    resource: PhantomData<R>,
+}
+
+/// `ResourceRollbackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct ResourceRollbackSystemData<'s, R>
+where
+    R: Clone + Default + Send + Sync + 'static,
+{
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `RollbackConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_config: Read<'s, RollbackConfig>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `R` resource.
+    #[derivative(Debug = "ignore")]
+    pub resource: Write<'s, R>,
+    /// `ResourceSnapshots<R>` resource.
+    #[derivative(Debug = "ignore")]
+    pub resource_snapshots: Write<'s, ResourceSnapshots<R>>,
+}
+
+impl<R> ResourceRollbackSystem<R>
+where
+    R: Clone + Default + Send + Sync + 'static,
+{
+    /// Returns a String representing this system's name.
+    pub fn system_name(&self) -> String {
+        format!("{}<{}>", any::type_name::<Self>(), self.resource_name)
+    }
+}
+
+impl<'s, R> System<'s> for ResourceRollbackSystem<R>
+where
+    R: Clone + Default + Send + Sync + 'static,
+{
+    type SystemData = ResourceRollbackSystemData<'s, R>;
+
+    fn run(
+        &mut self,
+        ResourceRollbackSystemData {
+            session_sync_mode,
+            state_id,
+            rollback_config,
+            game_tick,
+            rollback_status,
+            mut resource,
+            mut resource_snapshots,
+        }: Self::SystemData,
+    ) {
+        if *session_sync_mode != SessionSyncMode::Rollback || *state_id != StateId::GamePlay {
+            return;
+        }
+
+        if let RollbackStatus::Restoring { tick, .. } = *rollback_status {
+            if let Some(snapshot) = resource_snapshots.get(tick) {
+                *resource = snapshot.clone();
+            }
+        } else {
+            // One more than the window, so that the oldest tick within the window can be restored.
+            let capacity = rollback_config.rollback_window as usize + 1;
+            resource_snapshots.record(*game_tick, resource.clone(), capacity);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{
+    play::{GameTick, TickedGameInputEvent},
+    GameInputEvent,
+};
+use log::debug;
+use network_session_model::play::SessionStatus;
+use rollback_model::{
+    config::{RollbackConfig, SessionSyncMode},
+    play::{InputHistory, RollbackStatus},
+};
+use state_registry::StateId;
+
+/// Records `TickedGameInputEvent`s, and sends the `GameInputEvent`s for the current tick.
+///
+/// When an event is received for a tick that has already been simulated, this begins a rollback
+/// to that tick. Events for ticks older than the `RollbackConfig::rollback_window` are discarded.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(RollbackInputSystemDesc))]
+pub struct RollbackInputSystem {
+    /// Reader ID for the `TickedGameInputEvent` channel.
+    #[system_desc(event_channel_reader)]
+    ticked_game_input_event_rid: ReaderId<TickedGameInputEvent>,
+    /// Tick whose `GameInputEvent`s were most recently sent.
+    #[new(default)]
+    #[system_desc(skip)]
+    tick_applied: Option<GameTick>,
+    /// Earliest tick of input received for the match while it was paused.
+    #[new(default)]
+    #[system_desc(skip)]
+    tick_paused_rollback: Option<GameTick>,
+}
+
+/// `RollbackInputSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct RollbackInputSystemData<'s> {
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `RollbackConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_config: Read<'s, RollbackConfig>,
+    /// `TickedGameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub ticked_game_input_ec: Read<'s, EventChannel<TickedGameInputEvent>>,
+    /// `GameInputEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub game_input_ec: Write<'s, EventChannel<GameInputEvent>>,
+    /// `InputHistory` resource.
+    #[derivative(Debug = "ignore")]
+    pub input_history: Write<'s, InputHistory>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Write<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Write<'s, RollbackStatus>,
+}
+
+impl<'s> System<'s> for RollbackInputSystem {
+    type SystemData = RollbackInputSystemData<'s>;
+
+    fn run(
+        &mut self,
+        RollbackInputSystemData {
+            session_sync_mode,
+            session_status,
+            state_id,
+            rollback_config,
+            ticked_game_input_ec,
+            mut game_input_ec,
+            mut input_history,
+            mut game_tick,
+            mut rollback_status,
+        }: Self::SystemData,
+    ) {
+        let ticked_game_input_events =
+            ticked_game_input_ec.read(&mut self.ticked_game_input_event_rid);
+
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if !session_established || *session_sync_mode != SessionSyncMode::Rollback {
+            return;
+        }
+
+        // While paused, input for ticks that were already simulated is recorded, and rolled back
+        // when the match resumes. Other input navigates the pause menu.
+        if *state_id == StateId::GamePlayPause {
+            let current_tick = *game_tick;
+            let tick_paused_rollback = &mut self.tick_paused_rollback;
+            ticked_game_input_events.for_each(|ticked_game_input_event| {
+                if ticked_game_input_event.tick < current_tick {
+                    input_history.insert(*ticked_game_input_event);
+                    *tick_paused_rollback = Some(
+                        tick_paused_rollback
+                            .map(|tick| tick.min(ticked_game_input_event.tick))
+                            .unwrap_or(ticked_game_input_event.tick),
+                    );
+                } else {
+                    game_input_ec.single_write(ticked_game_input_event.game_input_event);
+                }
+            });
+            return;
+        }
+
+        // Outside of game play, input is applied as soon as it is received.
+        if *state_id != StateId::GamePlay {
+            self.tick_paused_rollback = None;
+            ticked_game_input_events.for_each(|ticked_game_input_event| {
+                game_input_ec.single_write(ticked_game_input_event.game_input_event);
+            });
+            return;
+        }
+
+        let current_tick = *game_tick;
+        let current_tick_applied = self.tick_applied == Some(current_tick);
+        let present = rollback_status.present().unwrap_or(current_tick);
+        let earliest_tick = GameTick(present.saturating_sub(rollback_config.rollback_window));
+
+        let rollback_tick = ticked_game_input_events
+            .copied()
+            .filter(|ticked_game_input_event| {
+                // Input for ticks older than the window can no longer be applied.
+                let within_window = ticked_game_input_event.tick >= earliest_tick;
+                if !within_window {
+                    debug!(
+                        "Discarding input for tick `{}`, which is outside the rollback window.",
+                        ticked_game_input_event.tick
+                    );
+                }
+                within_window
+            })
+            .map(|ticked_game_input_event| {
+                input_history.insert(ticked_game_input_event);
+                ticked_game_input_event.tick
+            })
+            .filter(|tick| *tick < current_tick || (*tick == current_tick && current_tick_applied))
+            .chain(self.tick_paused_rollback.take())
+            .filter(|tick| *tick >= earliest_tick)
+            .min();
+
+        if let Some(rollback_tick) = rollback_tick {
+            debug!(
+                "Rolling back from tick `{}` to tick `{}`.",
+                present, rollback_tick
+            );
+
+            *rollback_status = RollbackStatus::Restoring {
+                tick: rollback_tick,
+                present,
+            };
+            *game_tick = rollback_tick;
+        }
+
+        if rollback_tick.is_some() || !current_tick_applied {
+            input_history
+                .events(*game_tick)
+                .iter()
+                .copied()
+                .for_each(|game_input_event| game_input_ec.single_write(game_input_event));
+
+            self.tick_applied = Some(*game_tick);
+        }
+
+        input_history.prune_before(earliest_tick);
+    }
+}
@@ -0,0 +1,49 @@
+use amethyst::{
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::play::GameTick;
+use rollback_model::play::RollbackStatus;
+
+/// Updates the `RollbackStatus` after each tick, ending the rollback once the present is reached.
+///
+/// The `GamePlayState` runs the dispatcher again while resimulating, so the present is reached
+/// within the same frame.
+///
+/// This must run after the `GameTick` has been incremented.
+#[derive(Debug, Default, new)]
+pub struct RollbackStatusUpdateSystem;
+
+/// `RollbackStatusUpdateSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct RollbackStatusUpdateSystemData<'s> {
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Write<'s, RollbackStatus>,
+}
+
+impl<'s> System<'s> for RollbackStatusUpdateSystem {
+    type SystemData = RollbackStatusUpdateSystemData<'s>;
+
+    fn run(
+        &mut self,
+        RollbackStatusUpdateSystemData {
+            game_tick,
+            mut rollback_status,
+        }: Self::SystemData,
+    ) {
+        if let Some(present) = rollback_status.present() {
+            *rollback_status = if *game_tick >= present {
+                RollbackStatus::None
+            } else {
+                RollbackStatus::Resimulating { present }
+            };
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use amethyst::{
+    ecs::{Entities, Join, Read, ReadStorage, System, World},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Position;
+use log::error;
+use rollback_model::{
+    config::SessionSyncMode,
+    play::{ComponentSnapshots, RollbackStatus},
+};
+use state_registry::StateId;
+
+/// Deletes objects that were spawned after the tick that game play is rolled back to.
+///
+/// Objects that existed at that tick are identified by their `Position` snapshot, so this must run
+/// before the `ComponentRollbackSystem<Position<f32>>`.
+#[derive(Debug, Default, new)]
+pub struct SpawnRollbackSystem;
+
+/// `SpawnRollbackSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SpawnRollbackSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Read<'s, RollbackStatus>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `ComponentSnapshots<Position<f32>>` resource.
+    #[derivative(Debug = "ignore")]
+    pub position_snapshots: Read<'s, ComponentSnapshots<Position<f32>>>,
+}
+
+impl<'s> System<'s> for SpawnRollbackSystem {
+    type SystemData = SpawnRollbackSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SpawnRollbackSystemData {
+            entities,
+            session_sync_mode,
+            state_id,
+            rollback_status,
+            positions,
+            position_snapshots,
+        }: Self::SystemData,
+    ) {
+        if *session_sync_mode != SessionSyncMode::Rollback || *state_id != StateId::GamePlay {
+            return;
+        }
+
+        if let RollbackStatus::Restoring { tick, .. } = *rollback_status {
+            if let Some(snapshot) = position_snapshots.get(tick) {
+                (&*entities, &positions)
+                    .join()
+                    .filter(|(entity, _)| {
+                        !snapshot
+                            .iter()
+                            .any(|(snapshot_entity, _)| snapshot_entity == entity)
+                    })
+                    .for_each(|(entity, _)| {
+                        if let Err(e) = entities.delete(entity) {
+                            error!("Failed to delete spawned entity: `{}`.", e);
+                        }
+                    });
+            }
+        }
+    }
+}
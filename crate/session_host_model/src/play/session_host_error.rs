@@ -0,0 +1,26 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumString;
+
+/// Error when attempting to host a session.
+#[derive(Clone, Copy, Debug, Deserialize, EnumString, PartialEq, Serialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum SessionHostError {
+    /// The session server is hosting the maximum number of sessions.
+    ServerFull,
+    /// The device's address is temporarily banned for sending too many requests.
+    Banned,
+    /// The device's protocol version differs from the session server's.
+    VersionMismatch,
+}
+
+impl Display for SessionHostError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::ServerFull => write!(f, "Session server is full."),
+            Self::Banned => write!(f, "Too many requests, please try again later."),
+            Self::VersionMismatch => write!(f, "Game version does not match the server."),
+        }
+    }
+}
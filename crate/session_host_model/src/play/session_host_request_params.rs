@@ -1,6 +1,6 @@
 use derive_new::new;
 use game_input_model::loaded::PlayerControllers;
-use network_session_model::play::SessionDeviceName;
+use network_session_model::play::{SessionDeviceName, SessionHandshake};
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -13,4 +13,11 @@ pub struct SessionHostRequestParams {
     /// Player controllers from this session device.
     #[structopt(long)]
     pub player_controllers: PlayerControllers,
+    /// Protocol version and asset fingerprint of the device.
+    ///
+    /// This is filled in by the request system when the request is sent.
+    #[structopt(skip)]
+    #[new(default)]
+    #[serde(default)]
+    pub session_handshake: SessionHandshake,
 }
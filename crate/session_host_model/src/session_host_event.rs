@@ -1,3 +1,4 @@
+use network_session_model::play::SessionServerResolveError;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -11,7 +12,7 @@ use crate::play::{SessionAcceptResponse, SessionHostRequestParams, SessionReject
 ///
 /// * `session_host session_host_request --device-name azriel --player-controllers "0:azriel 1:friend_a"`
 /// * `session_host host_cancel`
-/// * `session_host session_accept --session-code abcd --session-devices "1:az_comp::0:azriel::1:friend_a" --session-device_id 1`
+/// * `session_host session_accept --session-code abcd --session-devices "1:az_comp::0:azriel::1:friend_a" --session-device_id 1 --session-reconnect-token 1234`
 /// * `session_host back`
 ///
 /// **Note:** The `session_accept` subcommand is designed to be received from the server, so sending
@@ -28,6 +29,8 @@ pub enum SessionHostEvent {
     SessionAccept(SessionAcceptResponse),
     /// Server rejected the client's request.
     SessionReject(SessionRejectResponse),
+    /// Session server address could not be resolved, so the request was not sent.
+    SessionServerResolveFailed(SessionServerResolveError),
     /// Return to the previous menu.
     Back,
 }
@@ -4,7 +4,8 @@
 
 pub use crate::system::{
     SessionHostRequestSystem, SessionHostRequestSystemDesc, SessionHostResponseSystem,
-    SessionHostResponseSystemDesc,
+    SessionHostResponseSystemDesc, SessionHostStatusDisplaySystem,
+    SessionHostStatusDisplaySystemDesc,
 };
 
 mod system;
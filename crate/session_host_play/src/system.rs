@@ -1,7 +1,11 @@
 pub use self::{
     session_host_request_system::{SessionHostRequestSystem, SessionHostRequestSystemDesc},
     session_host_response_system::{SessionHostResponseSystem, SessionHostResponseSystemDesc},
+    session_host_status_display_system::{
+        SessionHostStatusDisplaySystem, SessionHostStatusDisplaySystemDesc,
+    },
 };
 
 mod session_host_request_system;
 mod session_host_response_system;
+mod session_host_status_display_system;
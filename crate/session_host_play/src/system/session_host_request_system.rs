@@ -4,13 +4,28 @@ use amethyst::{
     shred::{ResourceId, SystemData},
     shrev::{EventChannel, ReaderId},
 };
+use asset_model::loaded::AssetFingerprint;
 use derivative::Derivative;
 use derive_new::new;
+use log::error;
 use net_model::play::NetMessageEvent;
-use network_session_model::play::SessionStatus;
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{
+        ProtocolVersion, SessionDeviceType, SessionHandshake, SessionServerSocketAddr,
+        SessionStatus,
+    },
+};
+use rollback_model::config::SessionSyncMode;
 use session_host_model::SessionHostEvent;
 
 /// Sends requests to a game server to host a session.
+///
+/// The session server address is resolved before the request is sent. If it cannot be resolved,
+/// a `SessionHostEvent::SessionServerResolveFailed` event is sent instead.
+///
+/// The protocol version, asset fingerprint, and session sync mode of this device are sent with the
+/// request, so that the server can reject incompatible devices.
 #[derive(Debug, SystemDesc, new)]
 #[system_desc(name(SessionHostRequestSystemDesc))]
 pub struct SessionHostRequestSystem {
@@ -24,10 +39,25 @@ pub struct SessionHostRequestSystem {
 pub struct SessionHostRequestSystemData<'s> {
     /// `SessionHostEvent` channel.
     #[derivative(Debug = "ignore")]
-    pub session_host_ec: Read<'s, EventChannel<SessionHostEvent>>,
+    pub session_host_ec: Write<'s, EventChannel<SessionHostEvent>>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: Read<'s, SessionServerConfig>,
+    /// `AssetFingerprint` resource.
+    #[derivative(Debug = "ignore")]
+    pub asset_fingerprint: Read<'s, AssetFingerprint>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `SessionServerSocketAddr` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_socket_addr: Write<'s, SessionServerSocketAddr>,
     /// `SessionStatus` resource.
     #[derivative(Debug = "ignore")]
     pub session_status: Write<'s, SessionStatus>,
+    /// `SessionDeviceType` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_type: Write<'s, SessionDeviceType>,
     /// `NetworkMessageEvent` channel.
     #[derivative(Debug = "ignore")]
     pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
@@ -39,8 +69,13 @@ impl<'s> System<'s> for SessionHostRequestSystem {
     fn run(
         &mut self,
         SessionHostRequestSystemData {
-            session_host_ec,
+            mut session_host_ec,
+            session_server_config,
+            asset_fingerprint,
+            session_sync_mode,
+            mut session_server_socket_addr,
             mut session_status,
+            mut session_device_type,
             mut net_message_ec,
         }: Self::SystemData,
     ) {
@@ -54,18 +89,38 @@ impl<'s> System<'s> for SessionHostRequestSystem {
         // Only process one session host event if multiple are received.
         let session_host_request_params = session_host_events.find_map(|ev| {
             if let SessionHostEvent::SessionHostRequest(session_host_request_params) = ev {
-                Some(session_host_request_params)
+                Some(session_host_request_params.clone())
             } else {
                 None
             }
         });
 
-        if let Some(session_host_request_params) = session_host_request_params {
+        if let Some(mut session_host_request_params) = session_host_request_params {
+            // Resolve the address each time, as the address it resolves to may change.
+            match session_server_config.socket_addr_resolve() {
+                Ok(socket_addr) => **session_server_socket_addr = Some(socket_addr),
+                Err(session_server_resolve_error) => {
+                    error!("{}", session_server_resolve_error);
+                    session_host_ec.single_write(SessionHostEvent::SessionServerResolveFailed(
+                        session_server_resolve_error,
+                    ));
+                    return;
+                }
+            }
+
+            session_host_request_params.session_handshake = SessionHandshake::new(
+                ProtocolVersion::CURRENT,
+                *asset_fingerprint,
+                *session_sync_mode,
+            );
+
             net_message_ec.single_write(NetMessageEvent::SessionHostEvent(
                 SessionHostEvent::SessionHostRequest(session_host_request_params.clone()),
             ));
 
             *session_status = SessionStatus::HostRequested;
+            // The host always plays in the session.
+            *session_device_type = SessionDeviceType::Player;
         }
     }
 }
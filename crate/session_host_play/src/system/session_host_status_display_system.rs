@@ -0,0 +1,140 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use session_host_model::{play::SessionHostStatusEntity, SessionHostEntity, SessionHostEvent};
+
+const FONT_COLOUR_ERROR: [f32; 4] = [1., 0.5, 0.5, 1.];
+const FONT_SIZE_STATUS: f32 = 20.;
+const LABEL_WIDTH: f32 = 600.;
+const LABEL_HEIGHT: f32 = 30.;
+
+/// Displays why a session host request failed.
+///
+/// The message is removed when another request is sent, or when leaving the host menu.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionHostStatusDisplaySystemDesc))]
+pub struct SessionHostStatusDisplaySystem {
+    /// Reader ID for the `SessionHostEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_host_event_rid: ReaderId<SessionHostEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionHostStatusDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionHostEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_host_ec: Read<'s, EventChannel<SessionHostEvent>>,
+    /// `SessionHostEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_host_entities: WriteStorage<'s, SessionHostEntity>,
+    /// `SessionHostStatusEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_host_status_entities: WriteStorage<'s, SessionHostStatusEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl SessionHostStatusDisplaySystem {
+    /// Deletes existing entities used to display the session host status.
+    fn delete_existing(
+        entities: &Entities<'_>,
+        session_host_status_entities: &WriteStorage<'_, SessionHostStatusEntity>,
+    ) {
+        (entities, session_host_status_entities)
+            .join()
+            .for_each(|(entity, _)| {
+                entities
+                    .delete(entity)
+                    .expect("Failed to delete `SessionHostStatusEntity` entity.");
+            });
+    }
+
+    /// Returns the message to display for a session host event, if any.
+    fn status_text(session_host_event: &SessionHostEvent) -> Option<String> {
+        match session_host_event {
+            SessionHostEvent::SessionReject(session_reject_response) => Some(format!(
+                "Failed to host session: {}",
+                session_reject_response.session_host_error
+            )),
+            SessionHostEvent::SessionServerResolveFailed(session_server_resolve_error) => {
+                Some(format!("{}", session_server_resolve_error))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionHostStatusDisplaySystem {
+    type SystemData = SessionHostStatusDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionHostStatusDisplaySystemData {
+            entities,
+            session_host_ec,
+            mut session_host_entities,
+            mut session_host_status_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        session_host_ec
+            .read(&mut self.session_host_event_rid)
+            .for_each(|session_host_event| {
+                Self::delete_existing(&entities, &session_host_status_entities);
+
+                if let Some(status_text) = Self::status_text(session_host_event) {
+                    let font = theme
+                        .fonts
+                        .get(&FontVariant::Regular)
+                        .expect("Failed to get regular font handle.");
+
+                    let ui_transform = UiTransform::new(
+                        String::from("session_host_status_text"),
+                        Anchor::BottomMiddle,
+                        Anchor::BottomMiddle,
+                        0.,
+                        LABEL_HEIGHT,
+                        1.,
+                        LABEL_WIDTH,
+                        LABEL_HEIGHT,
+                    );
+                    let ui_text = UiText::new(
+                        font.clone(),
+                        status_text,
+                        FONT_COLOUR_ERROR,
+                        FONT_SIZE_STATUS,
+                    );
+
+                    entities
+                        .build_entity()
+                        .with(SessionHostEntity, &mut session_host_entities)
+                        .with(SessionHostStatusEntity, &mut session_host_status_entities)
+                        .with(ui_transform, &mut ui_transforms)
+                        .with(ui_text, &mut ui_texts)
+                        .build();
+                }
+            });
+    }
+}
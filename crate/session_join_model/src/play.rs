@@ -3,10 +3,12 @@
 pub use self::{
     session_accept_response::SessionAcceptResponse, session_join_error::SessionJoinError,
     session_join_request_params::SessionJoinRequestParams,
+    session_join_status_entity::SessionJoinStatusEntity,
     session_reject_response::SessionRejectResponse,
 };
 
 mod session_accept_response;
 mod session_join_error;
 mod session_join_request_params;
+mod session_join_status_entity;
 mod session_reject_response;
@@ -1,6 +1,6 @@
 use derive_new::new;
 use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
-use network_session_model::play::{Session, SessionDeviceId};
+use network_session_model::play::{Session, SessionDeviceId, SessionReconnectToken};
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -18,6 +18,9 @@ pub struct SessionAcceptResponse {
     /// ID that the server generated for the session joiner.
     #[structopt(long)]
     pub session_device_id: SessionDeviceId,
+    /// Token to present when reconnecting to the session after a disconnect.
+    #[structopt(long)]
+    pub session_reconnect_token: SessionReconnectToken,
     /// All player controllers.
     #[structopt(long)]
     pub player_controllers: PlayerControllers,
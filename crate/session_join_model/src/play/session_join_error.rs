@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumString;
 
@@ -7,4 +9,32 @@ use strum_macros::EnumString;
 pub enum SessionJoinError {
     /// The session code does not exist on the server.
     SessionCodeNotFound,
+    /// The session code expired because its lobby was idle for too long.
+    SessionCodeExpired,
+    /// The session has the maximum number of devices.
+    SessionFull,
+    /// The device's address is temporarily banned for sending too many requests.
+    Banned,
+    /// The device's protocol version differs from the session server's.
+    VersionMismatch,
+    /// The device's assets differ from the session host's.
+    AssetMismatch,
+    /// The device's session sync mode differs from the session host's.
+    SyncModeMismatch,
+}
+
+impl Display for SessionJoinError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SessionCodeNotFound => write!(f, "Session code not found."),
+            Self::SessionCodeExpired => write!(f, "Session code has expired."),
+            Self::SessionFull => write!(f, "Session is full."),
+            Self::Banned => write!(f, "Too many requests, please try again later."),
+            Self::VersionMismatch => write!(f, "Game version does not match the server."),
+            Self::AssetMismatch => write!(f, "Game assets differ from the session host's."),
+            Self::SyncModeMismatch => {
+                write!(f, "Network sync mode differs from the session host's.")
+            }
+        }
+    }
 }
@@ -1,6 +1,8 @@
 use derive_new::new;
 use game_input_model::loaded::PlayerControllers;
-use network_session_model::play::{SessionCode, SessionDeviceName};
+use network_session_model::play::{
+    SessionCode, SessionDeviceName, SessionDeviceType, SessionHandshake,
+};
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -14,6 +16,19 @@ pub struct SessionJoinRequestParams {
     #[structopt(long = "device-name")]
     pub session_device_name: SessionDeviceName,
     /// Player controllers from this session device.
-    #[structopt(long)]
+    ///
+    /// This should be empty when joining as a spectator.
+    #[structopt(long, default_value = "")]
     pub player_controllers: PlayerControllers,
+    /// Whether the device joins to play or to spectate.
+    #[structopt(long, default_value = "player")]
+    #[serde(default)]
+    pub session_device_type: SessionDeviceType,
+    /// Protocol version and asset fingerprint of the device.
+    ///
+    /// This is filled in by the request system when the request is sent.
+    #[structopt(skip)]
+    #[new(default)]
+    #[serde(default)]
+    pub session_handshake: SessionHandshake,
 }
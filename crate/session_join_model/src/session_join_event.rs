@@ -1,3 +1,4 @@
+use network_session_model::play::SessionServerResolveError;
 use serde::{Deserialize, Serialize};
 use structopt_derive::StructOpt;
 
@@ -10,8 +11,9 @@ use crate::play::{SessionAcceptResponse, SessionJoinRequestParams, SessionReject
 /// When read in as a command, the command string should look like the following:
 ///
 /// * `session_join session_join_request --session-code abcd --device-name azriel --player-controllers "0:azriel 1:friend_a`
+/// * `session_join session_join_request --session-code abcd --device-name carlo --session-device-type spectator`
 /// * `session_join join_cancel`
-/// * `session_join session_accept --session-code abcd --session-devices "0:azriel::0:azriel::1:friend_a 1:byron::0:friend_b 2:carlo::0:friend_c" --session-device-id 2`
+/// * `session_join session_accept --session-code abcd --session-devices "0:azriel::0:azriel::1:friend_a 1:byron::0:friend_b 2:carlo::0:friend_c" --session-device-id 2 --session-reconnect-token 1234`
 /// * `session_join back`
 ///
 /// **Note:** The `session_accept` subcommand is designed to be received from the server, so sending
@@ -28,6 +30,8 @@ pub enum SessionJoinEvent {
     SessionAccept(SessionAcceptResponse),
     /// Server rejected the client's request.
     SessionReject(SessionRejectResponse),
+    /// Session server address could not be resolved, so the request was not sent.
+    SessionServerResolveFailed(SessionServerResolveError),
     /// Return to the previous menu.
     Back,
 }
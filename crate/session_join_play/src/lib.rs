@@ -4,7 +4,8 @@
 
 pub use crate::system::{
     SessionJoinRequestSystem, SessionJoinRequestSystemDesc, SessionJoinResponseSystem,
-    SessionJoinResponseSystemDesc,
+    SessionJoinResponseSystemDesc, SessionJoinStatusDisplaySystem,
+    SessionJoinStatusDisplaySystemDesc,
 };
 
 mod system;
@@ -1,7 +1,11 @@
 pub use self::{
     session_join_request_system::{SessionJoinRequestSystem, SessionJoinRequestSystemDesc},
     session_join_response_system::{SessionJoinResponseSystem, SessionJoinResponseSystemDesc},
+    session_join_status_display_system::{
+        SessionJoinStatusDisplaySystem, SessionJoinStatusDisplaySystemDesc,
+    },
 };
 
 mod session_join_request_system;
 mod session_join_response_system;
+mod session_join_status_display_system;
@@ -7,10 +7,11 @@ use amethyst::{
 use derivative::Derivative;
 use derive_new::new;
 use game_input_model::{loaded::PlayerControllers, play::ControllerIdOffset};
+use game_model::play::{GameRng, RngSeed};
 use log::debug;
 use net_model::play::{NetData, NetEventChannel};
 use network_session_model::play::{
-    Session, SessionCode, SessionDeviceId, SessionDevices, SessionStatus,
+    Session, SessionCode, SessionDeviceId, SessionDevices, SessionReconnectToken, SessionStatus,
 };
 use session_join_model::{play::SessionAcceptResponse, SessionJoinEvent};
 
@@ -38,6 +39,9 @@ pub struct SessionJoinResponseSystemData<'s> {
     /// `SessionDeviceId` resource.
     #[derivative(Debug = "ignore")]
     pub session_device_id: Write<'s, SessionDeviceId>,
+    /// `SessionReconnectToken` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token: Write<'s, SessionReconnectToken>,
     /// `SessionDevices` resource.
     #[derivative(Debug = "ignore")]
     pub session_devices: Write<'s, SessionDevices>,
@@ -47,6 +51,12 @@ pub struct SessionJoinResponseSystemData<'s> {
     /// `PlayerControllers` resource.
     #[derivative(Debug = "ignore")]
     pub player_controllers: Write<'s, PlayerControllers>,
+    /// `RngSeed` resource.
+    #[derivative(Debug = "ignore")]
+    pub rng_seed: Write<'s, RngSeed>,
+    /// `GameRng` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_rng: Write<'s, GameRng>,
     /// `ControllerIdOffset` resource.
     #[derivative(Debug = "ignore")]
     pub controller_id_offset: Write<'s, ControllerIdOffset>,
@@ -62,9 +72,12 @@ impl<'s> System<'s> for SessionJoinResponseSystem {
             mut session_join_ec,
             mut session_code,
             mut session_device_id,
+            mut session_reconnect_token,
             mut session_devices,
             mut session_status,
             mut player_controllers,
+            mut rng_seed,
+            mut game_rng,
             mut controller_id_offset,
         }: Self::SystemData,
     ) {
@@ -93,13 +106,17 @@ impl<'s> System<'s> for SessionJoinResponseSystem {
                                         session_devices: session_devices_received,
                                     },
                                 session_device_id: session_device_id_received,
+                                session_reconnect_token: session_reconnect_token_received,
                                 player_controllers: player_controllers_received,
                                 controller_id_offset: controller_id_offset_received,
                             } = session_accept_response.clone();
 
                             // Write to resources.
                             *session_code = session_code_received;
+                            *rng_seed = RngSeed::new(session_code.rng_seed());
+                            *game_rng = GameRng::new(*rng_seed);
                             *session_device_id = session_device_id_received;
+                            *session_reconnect_token = session_reconnect_token_received;
                             *session_devices = session_devices_received;
                             session_status_new = Some(SessionStatus::JoinEstablished);
                             *player_controllers = player_controllers_received;
@@ -0,0 +1,140 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Entities, Join, Read, ReadExpect, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+    ui::{Anchor, UiText, UiTransform},
+};
+use application_ui::{FontVariant, Theme};
+use derivative::Derivative;
+use derive_new::new;
+use session_join_model::{play::SessionJoinStatusEntity, SessionJoinEntity, SessionJoinEvent};
+
+const FONT_COLOUR_ERROR: [f32; 4] = [1., 0.5, 0.5, 1.];
+const FONT_SIZE_STATUS: f32 = 20.;
+const LABEL_WIDTH: f32 = 600.;
+const LABEL_HEIGHT: f32 = 30.;
+
+/// Displays why a session join request failed.
+///
+/// The message is removed when another request is sent, or when leaving the join menu.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionJoinStatusDisplaySystemDesc))]
+pub struct SessionJoinStatusDisplaySystem {
+    /// Reader ID for the `SessionJoinEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_join_event_rid: ReaderId<SessionJoinEvent>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionJoinStatusDisplaySystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `SessionJoinEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_join_ec: Read<'s, EventChannel<SessionJoinEvent>>,
+    /// `SessionJoinEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_join_entities: WriteStorage<'s, SessionJoinEntity>,
+    /// `SessionJoinStatusEntity` components.
+    #[derivative(Debug = "ignore")]
+    pub session_join_status_entities: WriteStorage<'s, SessionJoinStatusEntity>,
+
+    // Resources needed to display text.
+    /// `Theme` resource.
+    #[derivative(Debug = "ignore")]
+    pub theme: ReadExpect<'s, Theme>,
+    /// `UiTransform` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_transforms: WriteStorage<'s, UiTransform>,
+    /// `UiText` components.
+    #[derivative(Debug = "ignore")]
+    pub ui_texts: WriteStorage<'s, UiText>,
+}
+
+impl SessionJoinStatusDisplaySystem {
+    /// Deletes existing entities used to display the session join status.
+    fn delete_existing(
+        entities: &Entities<'_>,
+        session_join_status_entities: &WriteStorage<'_, SessionJoinStatusEntity>,
+    ) {
+        (entities, session_join_status_entities)
+            .join()
+            .for_each(|(entity, _)| {
+                entities
+                    .delete(entity)
+                    .expect("Failed to delete `SessionJoinStatusEntity` entity.");
+            });
+    }
+
+    /// Returns the message to display for a session join event, if any.
+    fn status_text(session_join_event: &SessionJoinEvent) -> Option<String> {
+        match session_join_event {
+            SessionJoinEvent::SessionReject(session_reject_response) => Some(format!(
+                "Failed to join `{}`: {}",
+                session_reject_response.session_code, session_reject_response.session_join_error
+            )),
+            SessionJoinEvent::SessionServerResolveFailed(session_server_resolve_error) => {
+                Some(format!("{}", session_server_resolve_error))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'s> System<'s> for SessionJoinStatusDisplaySystem {
+    type SystemData = SessionJoinStatusDisplaySystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionJoinStatusDisplaySystemData {
+            entities,
+            session_join_ec,
+            mut session_join_entities,
+            mut session_join_status_entities,
+            theme,
+            mut ui_transforms,
+            mut ui_texts,
+        }: Self::SystemData,
+    ) {
+        session_join_ec
+            .read(&mut self.session_join_event_rid)
+            .for_each(|session_join_event| {
+                Self::delete_existing(&entities, &session_join_status_entities);
+
+                if let Some(status_text) = Self::status_text(session_join_event) {
+                    let font = theme
+                        .fonts
+                        .get(&FontVariant::Regular)
+                        .expect("Failed to get regular font handle.");
+
+                    let ui_transform = UiTransform::new(
+                        String::from("session_join_status_text"),
+                        Anchor::BottomMiddle,
+                        Anchor::BottomMiddle,
+                        0.,
+                        LABEL_HEIGHT,
+                        1.,
+                        LABEL_WIDTH,
+                        LABEL_HEIGHT,
+                    );
+                    let ui_text = UiText::new(
+                        font.clone(),
+                        status_text,
+                        FONT_COLOUR_ERROR,
+                        FONT_SIZE_STATUS,
+                    );
+
+                    entities
+                        .build_entity()
+                        .with(SessionJoinEntity, &mut session_join_entities)
+                        .with(SessionJoinStatusEntity, &mut session_join_status_entities)
+                        .with(ui_transform, &mut ui_transforms)
+                        .with(ui_text, &mut ui_texts)
+                        .build();
+                }
+            });
+    }
+}
@@ -5,11 +5,13 @@ use application_event::AppEvent;
 use application_state::{AppState, AppStateBuilder};
 use character_selection::{
     CharacterSelectionStateBuilder, CharacterSelectionStateDelegate, CharacterSelectionSystem,
+    CswTeamTintSystem,
 };
 use derivative::Derivative;
 use derive_new::new;
 use game_loading::GameLoadingState;
 use game_play::GamePlayState;
+use game_rules_model::config::GameRules;
 use log::debug;
 use map_selection::{MapSelectionBundle, MapSelectionStateBuilder, MapSelectionStateDelegate};
 use session_lobby_model::{SessionLobbyEntity, SessionLobbyEvent};
@@ -64,6 +66,11 @@ impl SessionLobbyStateDelegate {
             any::type_name::<CharacterSelectionSystem>(),
             &[],
         )
+        .with_system(
+            CswTeamTintSystem::new(),
+            any::type_name::<CswTeamTintSystem>(),
+            &[any::type_name::<CharacterSelectionSystem>()],
+        )
         .build();
 
         Box::new(state)
@@ -81,13 +88,17 @@ impl State<GameData<'static, 'static>, AppEvent> for SessionLobbyStateDelegate {
 
     fn handle_event(
         &mut self,
-        _data: StateData<'_, GameData<'static, 'static>>,
+        data: StateData<'_, GameData<'static, 'static>>,
         event: AppEvent,
     ) -> Trans<GameData<'static, 'static>, AppEvent> {
         if let AppEvent::SessionLobby(session_lobby_event) = event {
             debug!("Received session_lobby_event: {:?}", session_lobby_event);
             match session_lobby_event {
                 SessionLobbyEvent::SessionStartNotify => {
+                    // Game rules are not synchronised across the session, so network rounds use
+                    // the default rules.
+                    data.world.insert(GameRules::default());
+
                     let character_selection_state = Self::character_selection_state();
                     Trans::Switch(character_selection_state)
                 }
@@ -0,0 +1,5 @@
+//! User defined configuration types for session reconnection.
+
+pub use self::session_reconnect_config::SessionReconnectConfig;
+
+mod session_reconnect_config;
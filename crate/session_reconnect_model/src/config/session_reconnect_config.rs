@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for how long disconnected devices may take to reconnect.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize, new)]
+#[serde(deny_unknown_fields)]
+pub struct SessionReconnectConfig {
+    /// Duration that a disconnected device's place in a session is held for.
+    ///
+    /// When this elapses without the device reconnecting, the session is closed.
+    pub grace_period: Duration,
+}
+
+impl SessionReconnectConfig {
+    /// Default duration that a disconnected device's place in a session is held for.
+    pub const GRACE_PERIOD_DEFAULT: Duration = Duration::from_secs(30);
+}
+
+impl Default for SessionReconnectConfig {
+    fn default() -> Self {
+        SessionReconnectConfig {
+            grace_period: Self::GRACE_PERIOD_DEFAULT,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Types used to reconnect to a session after the connection to the session server drops.
+//!
+//! When a device is accepted into a session, the session server issues it a
+//! `SessionReconnectToken`. If the device's connection drops, the session server holds its place in
+//! the session for a grace period, during which the device may present the token from a new
+//! connection to take its place back.
+
+pub use crate::session_reconnect_event::SessionReconnectEvent;
+
+pub mod config;
+pub mod play;
+
+mod session_reconnect_event;
@@ -0,0 +1,15 @@
+//! Data types used at runtime.
+
+pub use self::{
+    session_reconnect_accept_response::SessionReconnectAcceptResponse,
+    session_reconnect_error::SessionReconnectError,
+    session_reconnect_reject_response::SessionReconnectRejectResponse,
+    session_reconnect_request_params::SessionReconnectRequestParams,
+    session_reconnect_status::SessionReconnectStatus,
+};
+
+mod session_reconnect_accept_response;
+mod session_reconnect_error;
+mod session_reconnect_reject_response;
+mod session_reconnect_request_params;
+mod session_reconnect_status;
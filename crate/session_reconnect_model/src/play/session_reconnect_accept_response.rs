@@ -0,0 +1,19 @@
+use derive_new::new;
+use game_input_model::{loaded::PlayerControllers, play::GameTick};
+use network_session_model::play::{Session, SessionDeviceId};
+use serde::{Deserialize, Serialize};
+
+/// Response when a session reconnect request is accepted.
+///
+/// Input that was sent while the device was disconnected is sent again after this response.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionReconnectAcceptResponse {
+    /// Session information.
+    pub session: Session,
+    /// ID of the reconnected device.
+    pub session_device_id: SessionDeviceId,
+    /// All player controllers.
+    pub player_controllers: PlayerControllers,
+    /// Latest tick of input that the session server has relayed for the session.
+    pub game_tick: GameTick,
+}
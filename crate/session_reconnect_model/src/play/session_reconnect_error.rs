@@ -0,0 +1,26 @@
+use std::fmt::{self, Display};
+
+use serde::{Deserialize, Serialize};
+
+/// Error when attempting to reconnect to a session.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+pub enum SessionReconnectError {
+    /// The session code does not exist on the server.
+    ///
+    /// This happens when the grace period elapsed before the device reconnected.
+    SessionCodeNotFound,
+    /// The device is not, or is no longer, part of the session.
+    SessionDeviceNotFound,
+    /// The reconnect token does not match the one issued to the device.
+    SessionReconnectTokenInvalid,
+}
+
+impl Display for SessionReconnectError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SessionCodeNotFound => write!(f, "Session no longer exists."),
+            Self::SessionDeviceNotFound => write!(f, "Device is no longer part of the session."),
+            Self::SessionReconnectTokenInvalid => write!(f, "Reconnect token is invalid."),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use derive_new::new;
+use network_session_model::play::SessionCode;
+use serde::{Deserialize, Serialize};
+
+use crate::play::SessionReconnectError;
+
+/// Response when a session reconnect request is rejected.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionReconnectRejectResponse {
+    /// Code of the session.
+    pub session_code: SessionCode,
+    /// Session reconnect rejection reason.
+    pub session_reconnect_error: SessionReconnectError,
+}
@@ -0,0 +1,14 @@
+use derive_new::new;
+use network_session_model::play::{SessionCode, SessionDeviceId, SessionReconnectToken};
+use serde::{Deserialize, Serialize};
+
+/// Parameters to request to reconnect to a session.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, new)]
+pub struct SessionReconnectRequestParams {
+    /// Code of the session to reconnect to.
+    pub session_code: SessionCode,
+    /// ID of the device in the session.
+    pub session_device_id: SessionDeviceId,
+    /// Token issued to the device when it was accepted into the session.
+    pub session_reconnect_token: SessionReconnectToken,
+}
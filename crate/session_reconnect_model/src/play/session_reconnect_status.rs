@@ -0,0 +1,12 @@
+use derivative::Derivative;
+
+/// Whether this client is reconnecting to its session.
+#[derive(Clone, Copy, Debug, Derivative, PartialEq, Eq)]
+#[derivative(Default)]
+pub enum SessionReconnectStatus {
+    /// Connection to the session server is not known to have dropped.
+    #[derivative(Default)]
+    None,
+    /// Connection dropped, and reconnect requests are being sent.
+    Reconnecting,
+}
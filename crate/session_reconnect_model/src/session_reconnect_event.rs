@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::play::{
+    SessionReconnectAcceptResponse, SessionReconnectRejectResponse, SessionReconnectRequestParams,
+};
+
+/// Session reconnection events.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields, rename_all = "snake_case")]
+pub enum SessionReconnectEvent {
+    /// Client requests to take its place back in a session.
+    SessionReconnectRequest(SessionReconnectRequestParams),
+    /// Server accepted the client's request.
+    SessionReconnectAccept(SessionReconnectAcceptResponse),
+    /// Server rejected the client's request.
+    SessionReconnectReject(SessionReconnectRejectResponse),
+}
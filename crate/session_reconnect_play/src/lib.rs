@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic to reconnect to a network session after the connection drops.
+
+pub use crate::system::{
+    SessionReconnectRequestSystem, SessionReconnectRequestSystemDesc,
+    SessionReconnectResponseSystem, SessionReconnectResponseSystemDesc,
+};
+
+mod system;
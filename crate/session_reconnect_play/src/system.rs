@@ -0,0 +1,11 @@
+pub use self::{
+    session_reconnect_request_system::{
+        SessionReconnectRequestSystem, SessionReconnectRequestSystemDesc,
+    },
+    session_reconnect_response_system::{
+        SessionReconnectResponseSystem, SessionReconnectResponseSystemDesc,
+    },
+};
+
+mod session_reconnect_request_system;
+mod session_reconnect_response_system;
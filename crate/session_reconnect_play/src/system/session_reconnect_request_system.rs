@@ -0,0 +1,159 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    network::simulation::NetworkSimulationEvent,
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use derivative::Derivative;
+use derive_new::new;
+use log::{error, warn};
+use net_model::play::{GameInputReceiveBuffer, GameInputSendBuffer, NetMessageEvent};
+use network_session_model::{
+    config::SessionServerConfig,
+    play::{
+        SessionCode, SessionDeviceId, SessionReconnectToken, SessionServerSocketAddr, SessionStatus,
+    },
+};
+use session_reconnect_model::{
+    play::{SessionReconnectRequestParams, SessionReconnectStatus},
+    SessionReconnectEvent,
+};
+
+/// Number of ticks to wait before resending a reconnect request.
+const RECONNECT_RETRY_DELAY: u32 = 60;
+
+/// Sends requests to the session server to reconnect to the session when the connection drops.
+///
+/// The session server address is resolved again before each request, in case it has changed.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionReconnectRequestSystemDesc))]
+pub struct SessionReconnectRequestSystem {
+    /// Reader ID for the `NetworkSimulationEvent` channel.
+    #[system_desc(event_channel_reader)]
+    network_simulation_event_rid: ReaderId<NetworkSimulationEvent>,
+    /// Number of ticks since the last reconnect request was sent.
+    #[new(default)]
+    #[system_desc(skip)]
+    ticks_since_request: u32,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionReconnectRequestSystemData<'s> {
+    /// `NetworkSimulationEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub network_simulation_ec: Read<'s, EventChannel<NetworkSimulationEvent>>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Read<'s, SessionStatus>,
+    /// `SessionServerConfig` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_config: Read<'s, SessionServerConfig>,
+    /// `SessionCode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code: Read<'s, SessionCode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Read<'s, SessionDeviceId>,
+    /// `SessionReconnectToken` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_token: Read<'s, SessionReconnectToken>,
+    /// `SessionReconnectStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_status: Write<'s, SessionReconnectStatus>,
+    /// `SessionServerSocketAddr` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_server_socket_addr: Write<'s, SessionServerSocketAddr>,
+    /// `GameInputSendBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_send_buffer: Write<'s, GameInputSendBuffer>,
+    /// `GameInputReceiveBuffer` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_input_receive_buffer: Write<'s, GameInputReceiveBuffer>,
+    /// `NetworkMessageEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub net_message_ec: Write<'s, EventChannel<NetMessageEvent>>,
+}
+
+impl<'s> System<'s> for SessionReconnectRequestSystem {
+    type SystemData = SessionReconnectRequestSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionReconnectRequestSystemData {
+            network_simulation_ec,
+            session_status,
+            session_server_config,
+            session_code,
+            session_device_id,
+            session_reconnect_token,
+            mut session_reconnect_status,
+            mut session_server_socket_addr,
+            mut game_input_send_buffer,
+            mut game_input_receive_buffer,
+            mut net_message_ec,
+        }: Self::SystemData,
+    ) {
+        let disconnected = network_simulation_ec
+            .read(&mut self.network_simulation_event_rid)
+            .any(|ev| matches!(ev, NetworkSimulationEvent::Disconnect(_)));
+
+        let session_established = *session_status == SessionStatus::JoinEstablished
+            || *session_status == SessionStatus::HostEstablished;
+        if !session_established {
+            *session_reconnect_status = SessionReconnectStatus::None;
+            return;
+        }
+
+        if disconnected && *session_reconnect_status == SessionReconnectStatus::None {
+            warn!(
+                "Disconnected from session `{}`, reconnecting.",
+                *session_code
+            );
+
+            *session_reconnect_status = SessionReconnectStatus::Reconnecting;
+            self.ticks_since_request = RECONNECT_RETRY_DELAY;
+        }
+
+        if *session_reconnect_status == SessionReconnectStatus::Reconnecting {
+            if self.ticks_since_request >= RECONNECT_RETRY_DELAY {
+                self.ticks_since_request = 0;
+
+                match session_server_config.socket_addr_resolve() {
+                    Ok(socket_addr) => {
+                        // Unacknowledged input is resent to the new address, and input from
+                        // the new address continues from the previous address' sequence.
+                        if let Some(socket_addr_previous) = **session_server_socket_addr {
+                            if socket_addr_previous != socket_addr {
+                                game_input_send_buffer
+                                    .socket_replace(socket_addr_previous, socket_addr);
+                                game_input_receive_buffer
+                                    .socket_replace(socket_addr_previous, socket_addr);
+                            }
+                        }
+                        **session_server_socket_addr = Some(socket_addr);
+                    }
+                    Err(session_server_resolve_error) => {
+                        // Try again after the retry delay.
+                        error!("{}", session_server_resolve_error);
+                        return;
+                    }
+                }
+
+                let session_reconnect_request_params = SessionReconnectRequestParams::new(
+                    session_code.clone(),
+                    *session_device_id,
+                    *session_reconnect_token,
+                );
+                net_message_ec.single_write(NetMessageEvent::SessionReconnectEvent(
+                    SessionReconnectEvent::SessionReconnectRequest(
+                        session_reconnect_request_params,
+                    ),
+                ));
+            } else {
+                self.ticks_since_request += 1;
+            }
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use amethyst::{
+    derive::SystemDesc,
+    ecs::{Read, System, World, Write},
+    shred::{ResourceId, SystemData},
+    shrev::ReaderId,
+};
+use derivative::Derivative;
+use derive_new::new;
+use game_input_model::{loaded::PlayerControllers, play::GameTick};
+use log::{debug, error, info};
+use net_model::play::{NetData, NetEventChannel};
+use network_session_model::play::{
+    Session, SessionCode, SessionCondition, SessionDeviceId, SessionDevices, SessionStatus,
+};
+use rollback_model::{config::SessionSyncMode, play::RollbackStatus};
+use session_reconnect_model::{
+    play::{SessionReconnectAcceptResponse, SessionReconnectStatus},
+    SessionReconnectEvent,
+};
+use state_registry::StateId;
+
+/// Restores the session resources when reconnected to a session.
+///
+/// In rollback mode, game play is fast forwarded to the session's current tick.
+#[derive(Debug, SystemDesc, new)]
+#[system_desc(name(SessionReconnectResponseSystemDesc))]
+pub struct SessionReconnectResponseSystem {
+    /// Reader ID for the `SessionReconnectEvent` channel.
+    #[system_desc(event_channel_reader)]
+    session_reconnect_event_rid: ReaderId<NetData<SessionReconnectEvent>>,
+}
+
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct SessionReconnectResponseSystemData<'s> {
+    /// `SessionReconnectEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_nec: Read<'s, NetEventChannel<SessionReconnectEvent>>,
+    /// `SessionSyncMode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_sync_mode: Read<'s, SessionSyncMode>,
+    /// `StateId` resource.
+    #[derivative(Debug = "ignore")]
+    pub state_id: Read<'s, StateId>,
+    /// `GameTick` resource.
+    #[derivative(Debug = "ignore")]
+    pub game_tick: Read<'s, GameTick>,
+    /// `SessionReconnectStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_reconnect_status: Write<'s, SessionReconnectStatus>,
+    /// `SessionCode` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_code: Write<'s, SessionCode>,
+    /// `SessionDeviceId` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_device_id: Write<'s, SessionDeviceId>,
+    /// `SessionDevices` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_devices: Write<'s, SessionDevices>,
+    /// `SessionStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_status: Write<'s, SessionStatus>,
+    /// `SessionCondition` resource.
+    #[derivative(Debug = "ignore")]
+    pub session_condition: Write<'s, SessionCondition>,
+    /// `PlayerControllers` resource.
+    #[derivative(Debug = "ignore")]
+    pub player_controllers: Write<'s, PlayerControllers>,
+    /// `RollbackStatus` resource.
+    #[derivative(Debug = "ignore")]
+    pub rollback_status: Write<'s, RollbackStatus>,
+}
+
+impl<'s> System<'s> for SessionReconnectResponseSystem {
+    type SystemData = SessionReconnectResponseSystemData<'s>;
+
+    fn run(
+        &mut self,
+        SessionReconnectResponseSystemData {
+            session_reconnect_nec,
+            session_sync_mode,
+            state_id,
+            game_tick,
+            mut session_reconnect_status,
+            mut session_code,
+            mut session_device_id,
+            mut session_devices,
+            mut session_status,
+            mut session_condition,
+            mut player_controllers,
+            mut rollback_status,
+        }: Self::SystemData,
+    ) {
+        let session_reconnect_events =
+            session_reconnect_nec.read(&mut self.session_reconnect_event_rid);
+
+        if *session_reconnect_status != SessionReconnectStatus::Reconnecting {
+            return;
+        }
+
+        session_reconnect_events.for_each(|ev| match ev {
+            NetData {
+                data: SessionReconnectEvent::SessionReconnectAccept(session_reconnect_accept_response),
+                ..
+            } if session_reconnect_accept_response.session.session_code == *session_code => {
+                debug!("Session reconnected: {:?}", session_reconnect_accept_response);
+
+                let SessionReconnectAcceptResponse {
+                    session:
+                        Session {
+                            session_code: session_code_received,
+                            session_devices: session_devices_received,
+                        },
+                    session_device_id: session_device_id_received,
+                    player_controllers: player_controllers_received,
+                    game_tick: game_tick_received,
+                } = session_reconnect_accept_response.clone();
+
+                info!("Reconnected to session `{}`.", session_code_received);
+
+                // Write to resources.
+                *session_code = session_code_received;
+                *session_device_id = session_device_id_received;
+                *session_devices = session_devices_received;
+                *player_controllers = player_controllers_received;
+                *session_reconnect_status = SessionReconnectStatus::None;
+
+                // Lockstep mode resends `GameInputTick` when ready.
+                session_condition.ready();
+
+                // Rollback mode simulates the ticks missed while disconnected. These are all
+                // simulated within the next frame by the `GamePlayState`.
+                if *session_sync_mode == SessionSyncMode::Rollback
+                    && *state_id == StateId::GamePlay
+                    && *rollback_status == RollbackStatus::None
+                    && *game_tick < game_tick_received
+                {
+                    *rollback_status = RollbackStatus::Resimulating {
+                        present: game_tick_received,
+                    };
+                }
+            }
+            NetData {
+                data: SessionReconnectEvent::SessionReconnectReject(session_reconnect_reject_response),
+                ..
+            } if session_reconnect_reject_response.session_code == *session_code => {
+                error!(
+                    "Failed to reconnect to session `{}`: {}",
+                    session_reconnect_reject_response.session_code,
+                    session_reconnect_reject_response.session_reconnect_error
+                );
+
+                *session_reconnect_status = SessionReconnectStatus::None;
+                *session_status = SessionStatus::None;
+            }
+            _ => {}
+        });
+    }
+}
@@ -2,6 +2,7 @@ use amethyst::ecs::Entity;
 use asset_model::config::AssetType;
 use character_prefab::CharacterEntityAugmenter;
 use energy_prefab::EnergyEntityAugmenter;
+use item_prefab::ItemEntityAugmenter;
 use log::{debug, error};
 use object_type::ObjectType;
 use spawn_model::{loaded::Spawn, play::SpawnEvent};
@@ -27,7 +28,10 @@ impl GameObjectSpawner {
             item_ids,
             character_spawning_resources,
             character_component_storages,
+            energy_spawning_resources,
             energy_component_storages,
+            item_spawning_resources,
+            item_component_storages,
             spawn_ec,
         }: &mut SpawnGameObjectResources<'_>,
         entity_parent: Entity,
@@ -76,7 +80,20 @@ impl GameObjectSpawner {
                 );
             }
             AssetType::Object(ObjectType::Energy) => {
-                EnergyEntityAugmenter::augment(entity_spawned, energy_component_storages);
+                EnergyEntityAugmenter::augment(
+                    energy_spawning_resources,
+                    energy_component_storages,
+                    asset_id,
+                    entity_spawned,
+                );
+            }
+            AssetType::Object(ObjectType::Item) => {
+                ItemEntityAugmenter::augment(
+                    item_spawning_resources,
+                    item_component_storages,
+                    asset_id,
+                    entity_spawned,
+                );
             }
             _ => {
                 let asset_slug = asset_id_mappings
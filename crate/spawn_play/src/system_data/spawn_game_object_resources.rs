@@ -6,7 +6,8 @@ use amethyst::{
 use asset_model::loaded::{AssetId, AssetIdMappings, AssetItemIds, AssetTypeMappings, ItemId};
 use character_prefab::{CharacterComponentStorages, CharacterSpawningResources};
 use derivative::Derivative;
-use energy_prefab::EnergyComponentStorages;
+use energy_prefab::{EnergyComponentStorages, EnergySpawningResources};
+use item_prefab::{ItemComponentStorages, ItemSpawningResources};
 use spawn_model::play::SpawnEvent;
 
 /// `SpawnGameObjectResources`.
@@ -37,9 +38,18 @@ pub struct SpawnGameObjectResources<'s> {
     /// `CharacterComponentStorages`.
     #[derivative(Debug = "ignore")]
     pub character_component_storages: CharacterComponentStorages<'s>,
+    /// `EnergySpawningResources`.
+    #[derivative(Debug = "ignore")]
+    pub energy_spawning_resources: EnergySpawningResources<'s>,
     /// `EnergyComponentStorages`.
     #[derivative(Debug = "ignore")]
     pub energy_component_storages: EnergyComponentStorages<'s>,
+    /// `ItemSpawningResources`.
+    #[derivative(Debug = "ignore")]
+    pub item_spawning_resources: ItemSpawningResources<'s>,
+    /// `ItemComponentStorages`.
+    #[derivative(Debug = "ignore")]
+    pub item_component_storages: ItemComponentStorages<'s>,
     /// `SpawnEvent` channel.
     #[derivative(Debug = "ignore")]
     pub spawn_ec: Write<'s, EventChannel<SpawnEvent>>,
@@ -2,6 +2,7 @@
 
 //! Provides logic for sprite components at runtime.
 
-pub use crate::system::SpriteScaleUpdateSystem;
+pub use crate::{sequence_tint::SequenceTint, system::SpriteScaleUpdateSystem};
 
+mod sequence_tint;
 mod system;
@@ -0,0 +1,44 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{Entity, ReadStorage},
+    renderer::{palette::Srgba, resources::Tint},
+};
+use sequence_model::play::FrameIndexClock;
+use sprite_model::loaded::TintSequence;
+
+/// Looks up the `Tint` of an entity's current sequence frame.
+#[derive(Debug)]
+pub struct SequenceTint;
+
+impl SequenceTint {
+    /// Returns the tint of the entity's current sequence frame, defaulting to white.
+    ///
+    /// # Parameters
+    ///
+    /// * `tint_sequence_handles`: `Handle<TintSequence>` components.
+    /// * `tint_sequence_assets`: `TintSequence` assets.
+    /// * `frame_index_clocks`: `FrameIndexClock` components.
+    /// * `entity`: Entity whose tint to look up.
+    pub fn tint(
+        tint_sequence_handles: &ReadStorage<'_, Handle<TintSequence>>,
+        tint_sequence_assets: &AssetStorage<TintSequence>,
+        frame_index_clocks: &ReadStorage<'_, FrameIndexClock>,
+        entity: Entity,
+    ) -> Tint {
+        tint_sequence_handles
+            .get(entity)
+            .and_then(|tint_sequence_handle| tint_sequence_assets.get(tint_sequence_handle))
+            .and_then(|tint_sequence| {
+                let frame_index = frame_index_clocks
+                    .get(entity)
+                    .map(|frame_index_clock| (*frame_index_clock).value)
+                    .unwrap_or(0);
+
+                tint_sequence
+                    .get(frame_index)
+                    .or_else(|| tint_sequence.last())
+                    .copied()
+            })
+            .unwrap_or_else(|| Tint(Srgba::new(1., 1., 1., 1.)))
+    }
+}
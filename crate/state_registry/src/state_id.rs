@@ -34,6 +34,12 @@ pub enum StateId {
     GameLoading,
     /// `GamePlayState` ID.
     GamePlay,
+    /// `GamePlayState` ID while the round is paused.
+    GamePlayPause,
+    /// `GamePlayStatsState` ID.
+    GamePlayStats,
+    /// `GameRulesSelectionState` ID.
+    GameRulesSelection,
     /// `LoadingState` ID.
     Loading,
     /// `MapSelectionState` ID.
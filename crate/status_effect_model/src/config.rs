@@ -0,0 +1,6 @@
+//! Contains the types that represent the configuration on disk.
+
+pub use self::{status_effect::StatusEffect, status_effect_kind::StatusEffectKind};
+
+mod status_effect;
+mod status_effect_kind;
@@ -0,0 +1,22 @@
+use derivative::Derivative;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::config::StatusEffectKind;
+
+/// Timed effect applied to an object when hit.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Hash, Serialize, new)]
+#[derivative(Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatusEffect {
+    /// Type of status effect.
+    pub kind: StatusEffectKind,
+    /// Number of ticks the effect lasts.
+    #[derivative(Default(value = "60"))]
+    pub duration: u32,
+    /// Amount of health points (HP) to subtract every `interval` ticks.
+    pub hp_damage: u32,
+    /// Number of ticks between each `hp_damage` subtraction.
+    #[derivative(Default(value = "10"))]
+    pub interval: u32,
+}
@@ -0,0 +1,18 @@
+use derivative::Derivative;
+use serde::{Deserialize, Serialize};
+
+/// Types of status effects.
+#[derive(Clone, Copy, Debug, Derivative, Deserialize, PartialEq, Eq, Hash, Serialize)]
+#[derivative(Default)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusEffectKind {
+    /// Inflicts damage over time.
+    #[derivative(Default)]
+    Burn,
+    /// Prevents movement, and holds the object in its `frozen` sequence.
+    Freeze,
+    /// Inflicts damage over time.
+    Poison,
+    /// Reduces movement speed.
+    Slow,
+}
@@ -0,0 +1,5 @@
+//! Contains the types that represent processed configuration.
+
+pub use self::status_effect_transitions::StatusEffectTransitions;
+
+mod status_effect_transitions;
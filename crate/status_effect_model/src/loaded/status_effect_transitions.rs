@@ -0,0 +1,14 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_new::new;
+use sequence_model::loaded::SequenceId;
+
+/// Sequences to transition to while a status effect is active.
+///
+/// This is used by the status effect systems to override object sequences, similar to
+/// `CharacterHitTransitions`.
+#[derive(Clone, Component, Copy, Debug, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct StatusEffectTransitions {
+    /// Sequence ID to transition to while frozen.
+    pub frozen: SequenceId,
+}
@@ -0,0 +1,8 @@
+//! Types used during game play.
+
+pub use self::{
+    active_status_effect::ActiveStatusEffect, active_status_effects::ActiveStatusEffects,
+};
+
+mod active_status_effect;
+mod active_status_effects;
@@ -0,0 +1,25 @@
+use derive_new::new;
+use logic_clock::LogicClock;
+
+use crate::config::StatusEffect;
+
+/// Status effect that is currently applied to an object.
+#[derive(Clone, Copy, Debug, PartialEq, new)]
+pub struct ActiveStatusEffect {
+    /// The status effect configuration.
+    pub status_effect: StatusEffect,
+    /// Ticks until the effect ends.
+    pub duration_clock: LogicClock,
+    /// Ticks until the next `hp_damage` subtraction.
+    pub interval_clock: LogicClock,
+}
+
+impl From<StatusEffect> for ActiveStatusEffect {
+    fn from(status_effect: StatusEffect) -> Self {
+        ActiveStatusEffect::new(
+            status_effect,
+            LogicClock::new(status_effect.duration as usize),
+            LogicClock::new(status_effect.interval as usize),
+        )
+    }
+}
@@ -0,0 +1,36 @@
+use amethyst::ecs::{storage::DenseVecStorage, Component};
+use derive_deref::{Deref, DerefMut};
+use derive_new::new;
+
+use crate::{
+    config::{StatusEffect, StatusEffectKind},
+    play::ActiveStatusEffect,
+};
+
+/// Status effects that are currently applied to an object.
+#[derive(Clone, Component, Debug, Default, Deref, DerefMut, PartialEq, new)]
+#[storage(DenseVecStorage)]
+pub struct ActiveStatusEffects(pub Vec<ActiveStatusEffect>);
+
+impl ActiveStatusEffects {
+    /// Applies a status effect, replacing any active effect of the same kind.
+    pub fn apply(&mut self, status_effect: StatusEffect) {
+        let active_status_effect = ActiveStatusEffect::from(status_effect);
+        if let Some(existing) = self
+            .0
+            .iter_mut()
+            .find(|existing| existing.status_effect.kind == status_effect.kind)
+        {
+            *existing = active_status_effect;
+        } else {
+            self.0.push(active_status_effect);
+        }
+    }
+
+    /// Returns whether an effect of the given kind is active.
+    pub fn contains(&self, kind: StatusEffectKind) -> bool {
+        self.0
+            .iter()
+            .any(|active_status_effect| active_status_effect.status_effect.kind == kind)
+    }
+}
@@ -0,0 +1,10 @@
+#![deny(missing_debug_implementations, missing_docs)] // kcov-ignore
+
+//! Provides logic for status effects used during game play.
+
+pub use crate::system::{
+    StatusEffectApplySystem, StatusEffectApplySystemData, StatusEffectTickSystem,
+    StatusEffectTickSystemData, StatusEffectTintSystem, StatusEffectTintSystemData,
+};
+
+mod system;
@@ -0,0 +1,9 @@
+pub use self::{
+    status_effect_apply_system::{StatusEffectApplySystem, StatusEffectApplySystemData},
+    status_effect_tick_system::{StatusEffectTickSystem, StatusEffectTickSystemData},
+    status_effect_tint_system::{StatusEffectTintSystem, StatusEffectTintSystemData},
+};
+
+mod status_effect_apply_system;
+mod status_effect_tick_system;
+mod status_effect_tint_system;
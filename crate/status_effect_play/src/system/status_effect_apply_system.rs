@@ -0,0 +1,143 @@
+use amethyst::{
+    ecs::{Read, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+    shrev::{EventChannel, ReaderId},
+};
+use character_model::loaded::{CharacterDefendTransitions, HitDirectionTransitions};
+use collision_model::{
+    config::{Hit, Interaction, InteractionKind},
+    play::HitEvent,
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Position;
+use mirrored_model::play::Mirrored;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+use status_effect_model::play::ActiveStatusEffects;
+
+/// Applies status effects from `Hit` interactions to the hit object.
+///
+/// Only objects with `HealthPoints` are affected, and hits that are blocked do not apply status
+/// effects. Applying an effect that is already active restarts its duration.
+///
+/// This must run before the `CharacterHitEffectSystem`, which transitions the hit character out of
+/// the sequence it blocked the hit in.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectApplySystem {
+    /// Reader ID for the `HitEvent` event channel.
+    #[new(default)]
+    hit_event_rid: Option<ReaderId<HitEvent>>,
+}
+
+/// `StatusEffectApplySystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectApplySystemData<'s> {
+    /// `HitEvent` channel.
+    #[derivative(Debug = "ignore")]
+    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: ReadStorage<'s, HealthPoints>,
+    /// `Position<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub positions: ReadStorage<'s, Position<f32>>,
+    /// `Mirrored` components.
+    #[derivative(Debug = "ignore")]
+    pub mirroreds: ReadStorage<'s, Mirrored>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: ReadStorage<'s, SequenceId>,
+    /// `CharacterDefendTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub character_defend_transitionses: ReadStorage<'s, CharacterDefendTransitions>,
+    /// `ActiveStatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub active_status_effectses: WriteStorage<'s, ActiveStatusEffects>,
+}
+
+impl<'s> System<'s> for StatusEffectApplySystem {
+    type SystemData = StatusEffectApplySystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectApplySystemData {
+            hit_ec,
+            health_pointses,
+            positions,
+            mirroreds,
+            sequence_ids,
+            character_defend_transitionses,
+            mut active_status_effectses,
+        }: Self::SystemData,
+    ) {
+        hit_ec
+            .read(
+                self.hit_event_rid
+                    .as_mut()
+                    .expect("Expected reader ID to exist for StatusEffectApplySystem."),
+            )
+            .for_each(|ev| {
+                if let Interaction {
+                    kind:
+                        InteractionKind::Hit(Hit {
+                            status_effect: Some(status_effect),
+                            ..
+                        }),
+                    ..
+                } = ev.interaction
+                {
+                    if !health_pointses.contains(ev.to) {
+                        return;
+                    }
+
+                    let blocked = match (
+                        character_defend_transitionses.get(ev.to),
+                        sequence_ids.get(ev.to),
+                    ) {
+                        (Some(character_defend_transitions), Some(sequence_id)) => {
+                            let mirrored_from = mirroreds
+                                .get(ev.from)
+                                .map(|mirrored| **mirrored)
+                                .unwrap_or(false);
+                            let mirrored_to = mirroreds
+                                .get(ev.to)
+                                .map(|mirrored| **mirrored)
+                                .unwrap_or(false);
+                            let from_front = HitDirectionTransitions::from_front(
+                                positions.get(ev.from),
+                                positions.get(ev.to),
+                                mirrored_from,
+                                mirrored_to,
+                            );
+                            character_defend_transitions.blocks(*sequence_id, from_front)
+                        }
+                        _ => false,
+                    };
+                    if blocked {
+                        return;
+                    }
+
+                    if let Some(active_status_effects) = active_status_effectses.get_mut(ev.to) {
+                        active_status_effects.apply(status_effect);
+                    } else {
+                        let mut active_status_effects = ActiveStatusEffects::default();
+                        active_status_effects.apply(status_effect);
+                        active_status_effectses
+                            .insert(ev.to, active_status_effects)
+                            .expect("Failed to insert `ActiveStatusEffects` component.");
+                    }
+                }
+            });
+    }
+
+    fn setup(&mut self, world: &mut World) {
+        Self::SystemData::setup(world);
+        self.hit_event_rid = Some(
+            world
+                .fetch_mut::<EventChannel<HitEvent>>()
+                .register_reader(),
+        );
+    }
+}
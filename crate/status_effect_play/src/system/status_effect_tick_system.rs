@@ -0,0 +1,142 @@
+use amethyst::{
+    ecs::{Entities, Join, ReadStorage, System, World, WriteStorage},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use kinematic_model::config::Velocity;
+use object_model::play::HealthPoints;
+use sequence_model::loaded::SequenceId;
+use status_effect_model::{
+    config::StatusEffectKind, loaded::StatusEffectTransitions, play::ActiveStatusEffects,
+};
+
+/// Proportion of horizontal velocity retained each tick while slowed.
+const SLOW_VELOCITY_MULTIPLIER: f32 = 0.5;
+
+/// Ticks active status effects, and applies their effects.
+///
+/// * `Burn` and `Poison` subtract `HealthPoints` every `interval` ticks.
+/// * `Freeze` stops horizontal movement, and holds the object in its `frozen` sequence while it has
+///   `HealthPoints` remaining.
+/// * `Slow` reduces horizontal velocity on every tick while it is active.
+///
+/// Effects are removed when their duration ends.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectTickSystem;
+
+/// `StatusEffectTickSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectTickSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `StatusEffectTransitions` components.
+    #[derivative(Debug = "ignore")]
+    pub status_effect_transitionses: ReadStorage<'s, StatusEffectTransitions>,
+    /// `ActiveStatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub active_status_effectses: WriteStorage<'s, ActiveStatusEffects>,
+    /// `HealthPoints` components.
+    #[derivative(Debug = "ignore")]
+    pub health_pointses: WriteStorage<'s, HealthPoints>,
+    /// `Velocity<f32>` components.
+    #[derivative(Debug = "ignore")]
+    pub velocities: WriteStorage<'s, Velocity<f32>>,
+    /// `SequenceId` components.
+    #[derivative(Debug = "ignore")]
+    pub sequence_ids: WriteStorage<'s, SequenceId>,
+}
+
+impl<'s> System<'s> for StatusEffectTickSystem {
+    type SystemData = StatusEffectTickSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectTickSystemData {
+            entities,
+            status_effect_transitionses,
+            mut active_status_effectses,
+            mut health_pointses,
+            mut velocities,
+            mut sequence_ids,
+        }: Self::SystemData,
+    ) {
+        let mut entities_expired = Vec::new();
+
+        (
+            &entities,
+            &mut active_status_effectses,
+            (&mut health_pointses).maybe(),
+            (&mut velocities).maybe(),
+            (&mut sequence_ids).maybe(),
+            status_effect_transitionses.maybe(),
+        )
+            .join()
+            .for_each(
+                |(
+                    entity,
+                    active_status_effects,
+                    mut health_points,
+                    velocity,
+                    sequence_id,
+                    status_effect_transitions,
+                )| {
+                    active_status_effects
+                        .iter_mut()
+                        .for_each(|active_status_effect| {
+                            active_status_effect.duration_clock.tick();
+                            active_status_effect.interval_clock.tick();
+
+                            if active_status_effect.interval_clock.is_complete() {
+                                active_status_effect.interval_clock.reset();
+
+                                if let Some(health_points) = health_points.as_mut() {
+                                    let hp_damage = active_status_effect.status_effect.hp_damage;
+                                    if health_points.0 < hp_damage {
+                                        **health_points = HealthPoints(0);
+                                    } else {
+                                        (**health_points) -= hp_damage;
+                                    }
+                                }
+                            }
+                        });
+
+                    let frozen = active_status_effects.contains(StatusEffectKind::Freeze);
+                    let slowed = active_status_effects.contains(StatusEffectKind::Slow);
+                    if let Some(velocity) = velocity {
+                        if frozen {
+                            velocity.x = 0.;
+                            velocity.z = 0.;
+                        } else if slowed {
+                            velocity.x *= SLOW_VELOCITY_MULTIPLIER;
+                            velocity.z *= SLOW_VELOCITY_MULTIPLIER;
+                        }
+                    }
+
+                    let alive = health_points
+                        .map(|health_points| health_points.0 > 0)
+                        .unwrap_or(true);
+                    if let (true, true, Some(sequence_id), Some(status_effect_transitions)) =
+                        (frozen, alive, sequence_id, status_effect_transitions)
+                    {
+                        if *sequence_id != status_effect_transitions.frozen {
+                            *sequence_id = status_effect_transitions.frozen;
+                        }
+                    }
+
+                    active_status_effects.retain(|active_status_effect| {
+                        !active_status_effect.duration_clock.is_complete()
+                    });
+                    if active_status_effects.is_empty() {
+                        entities_expired.push(entity);
+                    }
+                },
+            );
+
+        entities_expired.into_iter().for_each(|entity| {
+            active_status_effectses.remove(entity);
+        });
+    }
+}
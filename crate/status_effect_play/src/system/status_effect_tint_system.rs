@@ -0,0 +1,123 @@
+use amethyst::{
+    assets::{AssetStorage, Handle},
+    ecs::{BitSet, Entities, Join, Read, ReadStorage, System, World, WriteStorage},
+    renderer::{palette::Srgba, resources::Tint},
+    shred::{ResourceId, SystemData},
+};
+use derivative::Derivative;
+use derive_new::new;
+use sequence_model::play::FrameIndexClock;
+use sprite_model::loaded::TintSequence;
+use sprite_play::SequenceTint;
+use status_effect_model::{config::StatusEffectKind, play::ActiveStatusEffects};
+
+/// Tints objects based on their most recently applied status effect.
+///
+/// The status effect colour is blended with the object's sequence tint, and when an object no
+/// longer has any active status effects, its `Tint` is restored to the sequence tint.
+///
+/// This should run after the `FrameComponentUpdateSystem<TintSequence>`, so that the status effect
+/// tint overrides the sequence tint.
+#[derive(Debug, Default, new)]
+pub struct StatusEffectTintSystem {
+    /// Entities that were tinted in the previous tick.
+    #[new(default)]
+    entities_tinted: BitSet,
+}
+
+/// `StatusEffectTintSystemData`.
+#[derive(Derivative, SystemData)]
+#[derivative(Debug)]
+pub struct StatusEffectTintSystemData<'s> {
+    /// `Entities`.
+    #[derivative(Debug = "ignore")]
+    pub entities: Entities<'s>,
+    /// `ActiveStatusEffects` components.
+    #[derivative(Debug = "ignore")]
+    pub active_status_effectses: ReadStorage<'s, ActiveStatusEffects>,
+    /// `Handle<TintSequence>` components.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_handles: ReadStorage<'s, Handle<TintSequence>>,
+    /// `TintSequence` assets.
+    #[derivative(Debug = "ignore")]
+    pub tint_sequence_assets: Read<'s, AssetStorage<TintSequence>>,
+    /// `FrameIndexClock` components.
+    #[derivative(Debug = "ignore")]
+    pub frame_index_clocks: ReadStorage<'s, FrameIndexClock>,
+    /// `Tint` components.
+    #[derivative(Debug = "ignore")]
+    pub tints: WriteStorage<'s, Tint>,
+}
+
+impl StatusEffectTintSystem {
+    /// Returns the tint for a status effect.
+    fn tint(status_effect_kind: StatusEffectKind) -> Tint {
+        let (r, g, b) = match status_effect_kind {
+            StatusEffectKind::Burn => (1., 0.55, 0.4),
+            StatusEffectKind::Freeze => (0.55, 0.75, 1.),
+            StatusEffectKind::Poison => (0.6, 1., 0.55),
+            StatusEffectKind::Slow => (0.75, 0.7, 0.9),
+        };
+        Tint(Srgba::new(r, g, b, 1.))
+    }
+
+    /// Returns the channel-wise product of the sequence tint and status effect tint.
+    fn blend(Tint(sequence_tint): Tint, Tint(status_effect_tint): Tint) -> Tint {
+        Tint(Srgba::new(
+            sequence_tint.red * status_effect_tint.red,
+            sequence_tint.green * status_effect_tint.green,
+            sequence_tint.blue * status_effect_tint.blue,
+            sequence_tint.alpha * status_effect_tint.alpha,
+        ))
+    }
+}
+
+impl<'s> System<'s> for StatusEffectTintSystem {
+    type SystemData = StatusEffectTintSystemData<'s>;
+
+    fn run(
+        &mut self,
+        StatusEffectTintSystemData {
+            entities,
+            active_status_effectses,
+            tint_sequence_handles,
+            tint_sequence_assets,
+            frame_index_clocks,
+            mut tints,
+        }: Self::SystemData,
+    ) {
+        let mut entities_tinted = BitSet::new();
+
+        (&entities, &active_status_effectses, &mut tints)
+            .join()
+            .for_each(|(entity, active_status_effects, tint)| {
+                if let Some(active_status_effect) = active_status_effects.last() {
+                    let sequence_tint = SequenceTint::tint(
+                        &tint_sequence_handles,
+                        &tint_sequence_assets,
+                        &frame_index_clocks,
+                        entity,
+                    );
+                    *tint = Self::blend(
+                        sequence_tint,
+                        Self::tint(active_status_effect.status_effect.kind),
+                    );
+                    entities_tinted.add(entity.id());
+                }
+            });
+
+        (&entities, &self.entities_tinted, &mut tints)
+            .join()
+            .filter(|(entity, _, _)| !entities_tinted.contains(entity.id()))
+            .for_each(|(entity, _, tint)| {
+                *tint = SequenceTint::tint(
+                    &tint_sequence_handles,
+                    &tint_sequence_assets,
+                    &frame_index_clocks,
+                    entity,
+                );
+            });
+
+        self.entities_tinted = entities_tinted;
+    }
+}
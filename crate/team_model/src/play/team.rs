@@ -1,6 +1,6 @@
 use amethyst::ecs::{storage::VecStorage, Component};
 
-use crate::play::{IndependentCounter, TeamCounter};
+use crate::play::{IndependentCounter, TeamColour, TeamCounter};
 
 /// Represents the in-game grouping of player teams.
 #[derive(Clone, Component, Copy, Debug, Hash, PartialEq, Eq, PartialOrd)]
@@ -17,3 +17,13 @@ pub enum Team {
     /// consistently.
     Number(TeamCounter),
 }
+
+impl Team {
+    /// Returns the colour of this team, `None` for `Independent` teams.
+    pub fn colour(self) -> Option<TeamColour> {
+        match self {
+            Team::Independent(..) => None,
+            Team::Number(team_counter) => Some(TeamColour::from(team_counter)),
+        }
+    }
+}
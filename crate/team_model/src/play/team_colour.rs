@@ -0,0 +1,42 @@
+use derive_more::Display;
+
+use crate::play::TeamCounter;
+
+/// Colour used to render a numbered team.
+#[derive(Clone, Copy, Debug, Display, Hash, PartialEq, Eq)]
+pub enum TeamColour {
+    /// Red team.
+    Red,
+    /// Blue team.
+    Blue,
+    /// Green team.
+    Green,
+    /// Yellow team.
+    Yellow,
+}
+
+impl TeamColour {
+    /// Number of distinct team colours.
+    pub const COUNT: u32 = 4;
+
+    /// Returns the RGBA values of this colour.
+    pub fn rgba(self) -> [f32; 4] {
+        match self {
+            TeamColour::Red => [0.9, 0.15, 0.15, 1.],
+            TeamColour::Blue => [0.2, 0.4, 1., 1.],
+            TeamColour::Green => [0.15, 0.8, 0.2, 1.],
+            TeamColour::Yellow => [0.95, 0.85, 0.1, 1.],
+        }
+    }
+}
+
+impl From<TeamCounter> for TeamColour {
+    fn from(team_counter: TeamCounter) -> Self {
+        match team_counter.0 % Self::COUNT {
+            0 => TeamColour::Red,
+            1 => TeamColour::Blue,
+            2 => TeamColour::Green,
+            _ => TeamColour::Yellow,
+        }
+    }
+}
@@ -2,7 +2,22 @@ use derive_more::{Add, AddAssign, Display, From, Sub, SubAssign};
 use numeric_newtype_derive::numeric_newtype;
 use serde::{Deserialize, Serialize};
 
+use crate::play::TeamColour;
+
 /// Newtype for counting numbered teams.
 #[numeric_newtype]
 #[derive(Debug, Default, Deserialize, Hash, Serialize)]
 pub struct TeamCounter(pub u32);
+
+impl TeamCounter {
+    /// Returns the team to switch to when cycling through numbered teams.
+    ///
+    /// `None` represents an `Independent` team.
+    pub fn next(team_counter: Option<TeamCounter>) -> Option<TeamCounter> {
+        match team_counter {
+            None => Some(TeamCounter(0)),
+            Some(TeamCounter(n)) if n + 1 < TeamColour::COUNT => Some(TeamCounter(n + 1)),
+            Some(_) => None,
+        }
+    }
+}
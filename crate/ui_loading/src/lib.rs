@@ -4,7 +4,7 @@
 
 pub use self::ui_ascl::{
     UiAscl, UiAsclCharacterSelection, UiAsclComponents, UiAsclControlSettings, UiAsclForm,
-    UiAsclMapSelection, UiAsclMenu, UiAsclSessionLobby,
+    UiAsclGamePlayStats, UiAsclMapSelection, UiAsclMenu, UiAsclSessionLobby,
 };
 pub use crate::ui_loading_bundle::UiLoadingBundle;
 
@@ -39,6 +39,7 @@ use ui_model::config::{UiDefinition, UiType};
 pub use self::{
     ui_ascl_character_selection::UiAsclCharacterSelection, ui_ascl_components::UiAsclComponents,
     ui_ascl_control_settings::UiAsclControlSettings, ui_ascl_form::UiAsclForm,
+    ui_ascl_game_play_stats::UiAsclGamePlayStats,
     ui_ascl_map_selection::UiAsclMapSelection, ui_ascl_menu::UiAsclMenu,
     ui_ascl_session_lobby::UiAsclSessionLobby,
 };
@@ -47,6 +48,7 @@ mod ui_ascl_character_selection;
 mod ui_ascl_components;
 mod ui_ascl_control_settings;
 mod ui_ascl_form;
+mod ui_ascl_game_play_stats;
 mod ui_ascl_map_selection;
 mod ui_ascl_menu;
 mod ui_ascl_session_lobby;
@@ -389,6 +391,22 @@ impl UiAscl {
                         map_selection_ui,
                     );
                 }
+                UiType::GamePlayStats(game_play_stats_ui) => {
+                    UiAsclMenu::load(
+                        &mut sequence_component_loading_resources.asset_world,
+                        asset_slug,
+                        sequence_id_mappings,
+                        &ui_ascl_components,
+                        &mut item_ids_all,
+                        &sequence_component_loading_resources.player_controllers,
+                        &game_play_stats_ui.menu,
+                    );
+                    UiAsclGamePlayStats::load(
+                        &mut sequence_component_loading_resources.asset_world,
+                        &mut item_ids_all,
+                        game_play_stats_ui,
+                    );
+                }
                 UiType::SessionLobby(session_lobby_ui) => {
                     UiAsclSessionLobby::load(
                         &mut sequence_component_loading_resources.asset_world,
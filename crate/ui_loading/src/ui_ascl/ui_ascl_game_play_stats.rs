@@ -0,0 +1,67 @@
+use amethyst::ecs::{Builder, WorldExt};
+use asset_model::{loaded::ItemId, play::AssetWorld};
+use game_play_stats_ui_model::{
+    config::{GamePlayStatsUi, PlayerStatsWidgetTemplate},
+    loaded::PlayerStatsWidget,
+};
+
+/// Loads asset items for a `GamePlayStatsUi`.
+///
+/// The menu items are loaded separately by the `UiAsclMenu`.
+#[derive(Debug)]
+pub struct UiAsclGamePlayStats;
+
+impl UiAsclGamePlayStats {
+    /// Loads asset items for a `GamePlayStatsUi`.
+    pub fn load(
+        asset_world: &mut AssetWorld,
+        item_ids_all: &mut Vec<ItemId>,
+        game_play_stats_ui: &GamePlayStatsUi,
+    ) {
+        let item_id_player_stats_widget =
+            Self::load_item_entity_player_stats_widget(asset_world, game_play_stats_ui);
+
+        item_ids_all.push(item_id_player_stats_widget);
+    }
+
+    fn load_item_entity_player_stats_widget(
+        asset_world: &mut AssetWorld,
+        game_play_stats_ui: &GamePlayStatsUi,
+    ) -> ItemId {
+        let game_play_stats_ui_model::config::PlayerStatsWidget {
+            position: position_init,
+            player_stats_widget_template:
+                PlayerStatsWidgetTemplate {
+                    dimensions,
+                    player_name,
+                    player_stats,
+                },
+        } = game_play_stats_ui.player_stats.clone();
+
+        let item_id_player_name = ItemId::new(
+            asset_world
+                .create_entity()
+                .with(player_name.position)
+                .with(player_name)
+                .build(),
+        );
+        let item_id_player_stats = ItemId::new(
+            asset_world
+                .create_entity()
+                .with(player_stats.position)
+                .with(player_stats)
+                .build(),
+        );
+
+        let item_entity_player_stats_widget = asset_world
+            .create_entity()
+            .with(position_init)
+            .with(dimensions)
+            .with(PlayerStatsWidget::new(
+                item_id_player_name,
+                item_id_player_stats,
+            ))
+            .build();
+        ItemId::new(item_entity_player_stats_widget)
+    }
+}
@@ -1,6 +1,7 @@
 use application_menu::MenuIndex;
 use character_selection_ui_model::config::CharacterSelectionUi;
 use control_settings_model::config::ControlSettings;
+use game_play_stats_ui_model::config::GamePlayStatsUi;
 use map_selection_ui_model::config::MapSelectionUi;
 use serde::{Deserialize, Serialize};
 use session_lobby_ui_model::config::SessionLobbyUi;
@@ -15,6 +16,8 @@ pub enum UiType {
     CharacterSelection(CharacterSelectionUi),
     /// Control Settings UI.
     ControlSettings(ControlSettings),
+    /// Game play statistics UI.
+    GamePlayStats(Box<GamePlayStatsUi>),
     /// Generic menu UI.
     Menu(UiMenuItems<MenuIndex>),
     /// Generic form UI.
@@ -0,0 +1,2 @@
+mod ai_behaviours;
+mod ai_difficulty;
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use serde_yaml;
+
+    use ai_model::config::{AiBehaviour, AiBehaviours, AiDifficulty};
+    use character_model::config::CharacterSequenceName;
+    use charge_model::config::ChargePoints;
+    use object_model::play::HealthPoints;
+    use sequence_model::config::SequenceNameString;
+
+    const AI_BEHAVIOURS_EMPTY: &str = "{}";
+    const AI_BEHAVIOURS_HARD_SPECIFIED: &str = r#"---
+hard:
+  decision_interval: 2
+  attack_distance: 70.0
+  z_tolerance: 5.0
+  defend_against: ["dash_attack"]
+  retreat_hp: 20
+  special_charge: 50
+"#;
+
+    #[test]
+    fn deserializes_default_ai_behaviours_when_empty() {
+        let ai_behaviours = serde_yaml::from_str::<AiBehaviours>(AI_BEHAVIOURS_EMPTY)
+            .expect("Failed to deserialize `AiBehaviours`.");
+
+        assert_eq!(AiBehaviours::default(), ai_behaviours);
+    }
+
+    #[test]
+    fn deserializes_specified_ai_behaviour() {
+        let ai_behaviours = serde_yaml::from_str::<AiBehaviours>(AI_BEHAVIOURS_HARD_SPECIFIED)
+            .expect("Failed to deserialize `AiBehaviours`.");
+
+        assert_eq!(
+            &AiBehaviour::new(
+                2,
+                70.,
+                5.,
+                vec![SequenceNameString::Name(CharacterSequenceName::DashAttack)],
+                HealthPoints::new(20),
+                ChargePoints::new(50),
+            ),
+            ai_behaviours.behaviour(AiDifficulty::Hard)
+        );
+        assert_eq!(
+            &AiBehaviour::default(),
+            ai_behaviours.behaviour(AiDifficulty::Normal)
+        );
+    }
+}
@@ -0,0 +1,18 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::config::AiDifficulty;
+
+    #[test]
+    fn next_cycles_through_difficulties_and_back_to_player() {
+        assert_eq!(Some(AiDifficulty::Easy), AiDifficulty::next(None));
+        assert_eq!(
+            Some(AiDifficulty::Normal),
+            AiDifficulty::next(Some(AiDifficulty::Easy))
+        );
+        assert_eq!(
+            Some(AiDifficulty::Hard),
+            AiDifficulty::next(Some(AiDifficulty::Normal))
+        );
+        assert_eq!(None, AiDifficulty::next(Some(AiDifficulty::Hard)));
+    }
+}
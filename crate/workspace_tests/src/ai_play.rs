@@ -0,0 +1,2 @@
+mod behaviour_ai_controller;
+mod system;
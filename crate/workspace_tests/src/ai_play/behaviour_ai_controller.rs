@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::{
+        config::AiBehaviour,
+        play::{AiEnemyObservation, AiObservation},
+        AiController,
+    };
+    use character_model::config::CharacterSequenceName;
+    use charge_model::config::ChargePoints;
+    use game_input_model::play::ControllerInput;
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use sequence_model::config::SequenceNameString;
+
+    use ai_play::BehaviourAiController;
+
+    #[test]
+    fn no_input_when_no_enemy() {
+        let ai_observation = observation(Mirrored::new(false), 100, 0, None);
+
+        assert_eq!(
+            ControllerInput::default(),
+            BehaviourAiController::controller_input(&ai_observation, &AiBehaviour::default())
+        );
+    }
+
+    #[test]
+    fn moves_toward_enemy_when_out_of_range() {
+        let ai_observation = observation(
+            Mirrored::new(false),
+            100,
+            0,
+            Some(AiEnemyObservation::new(Position::new(-100., 0., 30.), None)),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                x_axis_value: -1.,
+                z_axis_value: 1.,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &AiBehaviour::default())
+        );
+    }
+
+    #[test]
+    fn turns_to_face_enemy_when_in_range() {
+        let ai_observation = observation(
+            Mirrored::new(false),
+            100,
+            0,
+            Some(AiEnemyObservation::new(Position::new(-30., 0., 0.), None)),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                x_axis_value: -1.,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &AiBehaviour::default())
+        );
+    }
+
+    #[test]
+    fn attacks_when_facing_enemy_in_range() {
+        let ai_observation = observation(
+            Mirrored::new(true),
+            100,
+            0,
+            Some(AiEnemyObservation::new(Position::new(-30., 0., 5.), None)),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                attack: true,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &AiBehaviour::default())
+        );
+    }
+
+    #[test]
+    fn defends_when_enemy_is_in_defend_against_sequence() {
+        let ai_observation = observation(
+            Mirrored::new(false),
+            100,
+            0,
+            Some(AiEnemyObservation::new(
+                Position::new(30., 0., 0.),
+                Some(SequenceNameString::Name(CharacterSequenceName::DashAttack)),
+            )),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                defend: true,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &AiBehaviour::default())
+        );
+    }
+
+    #[test]
+    fn uses_special_when_charge_reaches_special_charge() {
+        let ai_behaviour = AiBehaviour {
+            special_charge: ChargePoints::new(50),
+            ..Default::default()
+        };
+        let ai_observation = observation(
+            Mirrored::new(false),
+            100,
+            50,
+            Some(AiEnemyObservation::new(Position::new(30., 0., 0.), None)),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                special: true,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &ai_behaviour)
+        );
+    }
+
+    #[test]
+    fn retreats_when_health_points_at_or_below_retreat_hp() {
+        let ai_behaviour = AiBehaviour {
+            retreat_hp: HealthPoints::new(20),
+            ..Default::default()
+        };
+        let ai_observation = observation(
+            Mirrored::new(false),
+            20,
+            0,
+            Some(AiEnemyObservation::new(Position::new(30., 0., 0.), None)),
+        );
+
+        assert_eq!(
+            ControllerInput {
+                x_axis_value: -1.,
+                ..Default::default()
+            },
+            BehaviourAiController::controller_input(&ai_observation, &ai_behaviour)
+        );
+    }
+
+    fn observation(
+        mirrored: Mirrored,
+        health_points: u32,
+        charge_points: u32,
+        enemy: Option<AiEnemyObservation>,
+    ) -> AiObservation {
+        AiObservation::new(
+            Position::new(0., 0., 0.),
+            mirrored,
+            HealthPoints::new(health_points),
+            ChargePoints::new(charge_points),
+            enemy,
+        )
+    }
+}
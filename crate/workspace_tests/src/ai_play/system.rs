@@ -0,0 +1 @@
+mod ai_controller_system;
@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::{
+        config::AiDifficulty,
+        play::{AiControlled, AiDecisionClock},
+    };
+    use amethyst::{
+        ecs::{Builder, Entity, EntityBuilder, Join, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{Axis, ControlAction, ControllerId},
+        play::{AxisMoveEventData, ControlActionEventData, ControlInputEvent, ControllerInput},
+    };
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use team_model::play::{IndependentCounter, Team};
+
+    use ai_play::{AiControllerSystem, BehaviourAiController};
+
+    #[test]
+    fn sends_no_events_before_decision_clock_completes() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                ai_decision_clock_value: 0,
+                controller_input: ControllerInput::default(),
+                enemy_x: 100.,
+            },
+            |_entity| vec![],
+        )
+    }
+
+    #[test]
+    fn sends_axis_moved_toward_enemy_when_decision_clock_completes() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                ai_decision_clock_value: 9,
+                controller_input: ControllerInput::default(),
+                enemy_x: 100.,
+            },
+            |entity| {
+                vec![ControlInputEvent::AxisMoved(AxisMoveEventData {
+                    controller_id: CONTROLLER_ID,
+                    entity,
+                    axis: Axis::X,
+                    value: 1.,
+                })]
+            },
+        )
+    }
+
+    #[test]
+    fn presses_attack_when_enemy_in_range() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                ai_decision_clock_value: 9,
+                controller_input: ControllerInput::default(),
+                enemy_x: 30.,
+            },
+            |entity| {
+                vec![ControlInputEvent::ControlActionPress(
+                    ControlActionEventData {
+                        controller_id: CONTROLLER_ID,
+                        entity,
+                        control_action: ControlAction::Attack,
+                    },
+                )]
+            },
+        )
+    }
+
+    #[test]
+    fn sends_no_events_when_attack_already_held() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                ai_decision_clock_value: 9,
+                controller_input: ControllerInput {
+                    attack: true,
+                    ..Default::default()
+                },
+                enemy_x: 30.,
+            },
+            |_entity| vec![],
+        )
+    }
+
+    #[test]
+    fn releases_attack_when_enemy_out_of_range() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                ai_decision_clock_value: 9,
+                controller_input: ControllerInput {
+                    attack: true,
+                    ..Default::default()
+                },
+                enemy_x: 100.,
+            },
+            |entity| {
+                vec![
+                    ControlInputEvent::AxisMoved(AxisMoveEventData {
+                        controller_id: CONTROLLER_ID,
+                        entity,
+                        axis: Axis::X,
+                        value: 1.,
+                    }),
+                    ControlInputEvent::ControlActionRelease(ControlActionEventData {
+                        controller_id: CONTROLLER_ID,
+                        entity,
+                        control_action: ControlAction::Attack,
+                    }),
+                ]
+            },
+        )
+    }
+
+    const CONTROLLER_ID: ControllerId = 1;
+
+    fn run_test(
+        SetupParams {
+            ai_decision_clock_value,
+            controller_input,
+            enemy_x,
+        }: SetupParams,
+        expected_events_fn: fn(Entity) -> Vec<ControlInputEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(AiControllerSystem::<BehaviourAiController>::new(), "", &[])
+            .with_effect(move |world| {
+                let reader_id = world
+                    .write_resource::<EventChannel<ControlInputEvent>>()
+                    .register_reader(); // kcov-ignore
+                world.insert(reader_id);
+
+                create_character(world, Team::Independent(IndependentCounter::new(0)), 0.)
+                    .with(AiControlled::new(CONTROLLER_ID, AiDifficulty::Normal))
+                    .with(AiDecisionClock::new_with_value(10, ai_decision_clock_value))
+                    .with(controller_input)
+                    .build();
+                create_character(
+                    world,
+                    Team::Independent(IndependentCounter::new(1)),
+                    enemy_x,
+                )
+                .build();
+            })
+            .with_assertion(move |world| {
+                let entity = {
+                    let ai_controlleds = world.read_storage::<AiControlled>();
+                    let entities = world.entities();
+                    (&*entities, &ai_controlleds)
+                        .join()
+                        .map(|(entity, _)| entity)
+                        .next()
+                        .expect("Expected entity with `AiControlled` component to exist.")
+                };
+                let control_input_events = {
+                    let control_input_ec = world.read_resource::<EventChannel<ControlInputEvent>>();
+                    let mut reader_id = world.write_resource::<ReaderId<ControlInputEvent>>();
+                    control_input_ec
+                        .read(&mut reader_id)
+                        .copied()
+                        .collect::<Vec<ControlInputEvent>>()
+                };
+
+                assert_eq!(expected_events_fn(entity), control_input_events);
+            })
+            .run()
+    }
+
+    fn create_character(world: &mut World, team: Team, x: f32) -> EntityBuilder {
+        world
+            .create_entity()
+            .with(team)
+            .with(HealthPoints::new(100))
+            .with(Position::<f32>::new(x, 0., 0.))
+            .with(Mirrored::new(false))
+    }
+
+    struct SetupParams {
+        ai_decision_clock_value: usize,
+        controller_input: ControllerInput,
+        enemy_x: f32,
+    }
+}
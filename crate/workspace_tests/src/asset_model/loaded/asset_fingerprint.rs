@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, path::PathBuf, str::FromStr};
+
+    use asset_model::{
+        config::{AssetIndex, AssetRecord, AssetSlug, AssetType},
+        loaded::AssetFingerprint,
+    };
+    use object_type::ObjectType;
+
+    #[test]
+    fn fingerprint_does_not_depend_on_discovery_order() {
+        let asset_index_0 = asset_index(vec![
+            (AssetType::Object(ObjectType::Character), "test/char_0"),
+            (AssetType::Object(ObjectType::Character), "test/char_1"),
+            (AssetType::Map, "test/map_0"),
+        ]);
+        let asset_index_1 = asset_index(vec![
+            (AssetType::Map, "test/map_0"),
+            (AssetType::Object(ObjectType::Character), "test/char_1"),
+            (AssetType::Object(ObjectType::Character), "test/char_0"),
+        ]);
+
+        assert_eq!(
+            AssetFingerprint::from_asset_index(&asset_index_0),
+            AssetFingerprint::from_asset_index(&asset_index_1)
+        );
+    }
+
+    #[test]
+    fn fingerprint_differs_when_assets_differ() {
+        let asset_index_0 = asset_index(vec![
+            (AssetType::Object(ObjectType::Character), "test/char_0"),
+            (AssetType::Map, "test/map_0"),
+        ]);
+        let asset_index_1 = asset_index(vec![
+            (AssetType::Object(ObjectType::Character), "test/char_0"),
+            (AssetType::Map, "test/map_1"),
+        ]);
+
+        assert_ne!(
+            AssetFingerprint::from_asset_index(&asset_index_0),
+            AssetFingerprint::from_asset_index(&asset_index_1)
+        );
+    }
+
+    fn asset_index(asset_type_and_slugs: Vec<(AssetType, &str)>) -> AssetIndex {
+        let asset_records = asset_type_and_slugs.into_iter().fold(
+            HashMap::<AssetType, Vec<AssetRecord>>::new(),
+            |mut asset_records, (asset_type, asset_slug)| {
+                let asset_slug =
+                    AssetSlug::from_str(asset_slug).expect("Expected asset slug to be valid.");
+                let asset_record = AssetRecord::new(asset_slug, PathBuf::from(""));
+                asset_records
+                    .entry(asset_type)
+                    .or_default()
+                    .push(asset_record);
+                asset_records
+            },
+        );
+
+        AssetIndex::new(asset_records)
+    }
+}
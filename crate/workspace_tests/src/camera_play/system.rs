@@ -1,2 +1,3 @@
+mod camera_free_system;
 mod camera_tracking_system;
 mod camera_velocity_system;
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use std::any;
+
+    use amethyst::{
+        ecs::{Entity, WorldExt},
+        input::InputEvent,
+        shrev::EventChannel,
+        window::ScreenDimensions,
+        Error,
+    };
+    use amethyst_test::{AmethystApplication, HIDPI, SCREEN_HEIGHT, SCREEN_WIDTH};
+    use camera_model::play::{CameraMode, CameraTargetCoordinates};
+    use game_input_model::config::{
+        Axis, ControlAction, ControlBindings, PlayerActionControl, PlayerAxisControl,
+    };
+    use network_session_model::play::SessionDeviceType;
+
+    use camera_play::{CameraCreator, CameraFreeSystem, CameraFreeSystemDesc};
+
+    #[test]
+    fn moves_camera_target_with_axis_input_in_free_mode() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_device_type: SessionDeviceType::Spectator,
+                camera_mode: CameraMode::Free,
+                input_events: vec![InputEvent::AxisMoved {
+                    axis: PlayerAxisControl::new(0, Axis::X),
+                    value: 1.,
+                }],
+            },
+            ExpectedParams {
+                camera_mode: CameraMode::Free,
+                camera_target_moved: true,
+            },
+        )
+    }
+
+    #[test]
+    fn toggles_camera_mode_when_spectator_presses_special() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_device_type: SessionDeviceType::Spectator,
+                camera_mode: CameraMode::Tracking,
+                input_events: vec![InputEvent::ActionPressed(PlayerActionControl::new(
+                    0,
+                    ControlAction::Special,
+                ))],
+            },
+            ExpectedParams {
+                camera_mode: CameraMode::Free,
+                camera_target_moved: false,
+            },
+        )
+    }
+
+    #[test]
+    fn uses_tracking_mode_for_players() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_device_type: SessionDeviceType::Player,
+                camera_mode: CameraMode::Free,
+                input_events: vec![
+                    InputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Special)),
+                    InputEvent::AxisMoved {
+                        axis: PlayerAxisControl::new(0, Axis::X),
+                        value: 1.,
+                    },
+                ],
+            },
+            ExpectedParams {
+                camera_mode: CameraMode::Tracking,
+                camera_target_moved: false,
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_device_type,
+            camera_mode: camera_mode_setup,
+            input_events,
+        }: SetupParams,
+        ExpectedParams {
+            camera_mode: camera_mode_expected,
+            camera_target_moved,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_resource(ScreenDimensions::new(SCREEN_WIDTH, SCREEN_HEIGHT, HIDPI))
+            .with_resource(session_device_type)
+            .with_resource(camera_mode_setup)
+            .with_system_desc(
+                CameraFreeSystemDesc::default(),
+                any::type_name::<CameraFreeSystem>(),
+                &[],
+            )
+            .with_effect(|world| {
+                let camera_entity = CameraCreator::create_in_world(world);
+                let camera_target_coordinates = world
+                    .read_storage::<CameraTargetCoordinates>()
+                    .get(camera_entity)
+                    .copied()
+                    .expect("Expected entity to have `CameraTargetCoordinates` component.");
+                world.insert(camera_entity);
+                world.insert(camera_target_coordinates);
+            })
+            .with_effect(move |world| {
+                world
+                    .write_resource::<EventChannel<InputEvent<ControlBindings>>>()
+                    .iter_write(input_events);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let camera_target_coordinates_initial =
+                    *world.read_resource::<CameraTargetCoordinates>();
+                let camera_target_coordinates = world
+                    .read_storage::<CameraTargetCoordinates>()
+                    .get(entity)
+                    .copied()
+                    .expect("Expected entity to have `CameraTargetCoordinates` component.");
+                let camera_mode = *world.read_resource::<CameraMode>();
+
+                assert_eq!(camera_mode_expected, camera_mode);
+                assert_eq!(
+                    camera_target_moved,
+                    camera_target_coordinates.x > camera_target_coordinates_initial.x
+                );
+                assert_eq!(
+                    camera_target_coordinates_initial.y,
+                    camera_target_coordinates.y
+                );
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        session_device_type: SessionDeviceType,
+        camera_mode: CameraMode,
+        input_events: Vec<InputEvent<ControlBindings>>,
+    }
+
+    struct ExpectedParams {
+        camera_mode: CameraMode,
+        camera_target_moved: bool,
+    }
+}
@@ -1,3 +1,4 @@
+mod character_hit_reactions;
 mod character_irr_part;
 mod character_sequence;
 
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use object_status_model::config::{StunPoints, StunRecovery};
+    use sequence_model::config::SequenceNameString;
+    use serde_yaml;
+
+    use character_model::config::{
+        CharacterHitReactions, CharacterSequenceName, HitDirectionSequences, StunThreshold,
+    };
+
+    const HIT_REACTIONS_YAML: &str = "\
+stun_recovery: 2
+stun_thresholds:
+  - { limit: 30, front: 'flinch_0', back: 'flinch_1' }
+  - { limit: 90, front: 'custom_dazed' }
+falling: { front: 'fall_forward_ascend' }
+";
+
+    #[test]
+    fn deserialize_character_hit_reactions() {
+        let hit_reactions = serde_yaml::from_str::<CharacterHitReactions>(HIT_REACTIONS_YAML)
+            .expect("Failed to deserialize `CharacterHitReactions`.");
+
+        let expected = CharacterHitReactions::new(
+            StunRecovery::new(2),
+            vec![
+                StunThreshold::new(
+                    StunPoints::new(30),
+                    SequenceNameString::Name(CharacterSequenceName::Flinch0),
+                    Some(SequenceNameString::Name(CharacterSequenceName::Flinch1)),
+                ),
+                StunThreshold::new(
+                    StunPoints::new(90),
+                    SequenceNameString::String(String::from("custom_dazed")),
+                    None,
+                ),
+            ],
+            HitDirectionSequences::new(
+                SequenceNameString::Name(CharacterSequenceName::FallForwardAscend),
+                None,
+            ),
+        );
+        assert_eq!(expected, hit_reactions);
+    }
+
+    #[test]
+    fn default_uses_existing_stun_thresholds() {
+        let hit_reactions = serde_yaml::from_str::<CharacterHitReactions>("{}")
+            .expect("Failed to deserialize `CharacterHitReactions`.");
+
+        let limits = hit_reactions
+            .stun_thresholds
+            .iter()
+            .map(|stun_threshold| stun_threshold.limit)
+            .collect::<Vec<StunPoints>>();
+        assert_eq!(
+            vec![
+                StunPoints::new(40),
+                StunPoints::new(80),
+                StunPoints::new(120)
+            ],
+            limits
+        );
+        assert_eq!(StunRecovery::new(1), hit_reactions.stun_recovery);
+    }
+}
@@ -4,7 +4,7 @@ mod test {
 
     use amethyst::{
         assets::{AssetStorage, Loader, Processor},
-        ecs::{Builder, Read, ReadExpect, World, WorldExt, Write},
+        ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt, Write},
         shred::SystemData,
         Error,
     };
@@ -15,7 +15,7 @@ mod test {
     };
     use character_model::{
         config::{CharacterDefinition, CharacterSequenceName},
-        loaded::AssetCharacterDefinitionHandle,
+        loaded::{AssetCharacterDefinitionHandle, CharacterHitTransitions},
         play::RunCounter,
     };
     use charge_model::{
@@ -25,7 +25,7 @@ mod test {
     use game_input_model::play::ControllerInput;
     use map_model::play::MapBounded;
     use object_model::{config::Mass, play::HealthPoints};
-    use object_status_model::config::StunPoints;
+    use object_status_model::config::{DefenseLimit, DefensePoints, StunPoints};
     use sequence_model::loaded::{AssetSequenceIdMappings, SequenceIdMappings};
 
     use character_prefab::{
@@ -34,26 +34,14 @@ mod test {
 
     #[test]
     fn augments_entity_with_character_components() -> Result<(), Error> {
-        let assertion = |world: &mut World| {
-            let entity = world.create_entity().build();
-            {
-                let asset_id = *world.read_resource::<AssetId>();
-                let (character_spawning_resources, mut character_component_storages) = world
-                    .system_data::<(
-                        CharacterSpawningResources<'_>,
-                        CharacterComponentStorages<'_>,
-                    )>();
-                CharacterEntityAugmenter::augment(
-                    &character_spawning_resources,
-                    &mut character_component_storages,
-                    asset_id,
-                    entity,
-                );
-            }
+        run_test(CharacterDefinition::default(), |world| {
+            let entity = augment(world);
 
             assert!(world.read_storage::<ControllerInput>().contains(entity));
             assert!(world.read_storage::<HealthPoints>().contains(entity));
             assert!(world.read_storage::<StunPoints>().contains(entity));
+            assert!(world.read_storage::<DefensePoints>().contains(entity));
+            assert!(world.read_storage::<DefenseLimit>().contains(entity));
             assert!(world.read_storage::<RunCounter>().contains(entity));
             assert!(world.read_storage::<Mass>().contains(entity));
             assert!(world.read_storage::<MapBounded>().contains(entity));
@@ -62,8 +50,41 @@ mod test {
             assert!(world.read_storage::<ChargeDelay>().contains(entity));
             assert!(world.read_storage::<ChargeUseMode>().contains(entity));
             assert!(world.read_storage::<ChargeRetention>().contains(entity));
-        };
+        })
+    }
+
+    #[test]
+    fn sorts_stun_transitions_by_limit() -> Result<(), Error> {
+        let mut character_definition = CharacterDefinition::default();
+        character_definition.hit_reactions.stun_thresholds.reverse();
+
+        run_test(character_definition, |world| {
+            let entity = augment(world);
 
+            let character_hit_transitionses = world.read_storage::<CharacterHitTransitions>();
+            let character_hit_transitions = character_hit_transitionses
+                .get(entity)
+                .expect("Expected `CharacterHitTransitions` component to exist.");
+            let limits = character_hit_transitions
+                .stun_transitions
+                .iter()
+                .map(|stun_transition| stun_transition.limit)
+                .collect::<Vec<StunPoints>>();
+            assert_eq!(
+                vec![
+                    StunPoints::new(40),
+                    StunPoints::new(80),
+                    StunPoints::new(120)
+                ],
+                limits
+            );
+        })
+    }
+
+    fn run_test(
+        character_definition: CharacterDefinition,
+        assertion: fn(&mut World),
+    ) -> Result<(), Error> {
         AmethystApplication::blank()
             .with_system(Processor::<CharacterDefinition>::new(), "", &[])
             .with_setup(|world| {
@@ -71,7 +92,7 @@ mod test {
                 <CharacterSpawningResources as SystemData>::setup(world);
                 <CharacterComponentStorages as SystemData>::setup(world);
             })
-            .with_effect(|world| {
+            .with_effect(move |world| {
                 let asset_id = {
                     let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
                     let asset_slug =
@@ -92,7 +113,7 @@ mod test {
                         Read<'_, AssetStorage<CharacterDefinition>>,
                     )>();
 
-                    let character_definition = CharacterDefinition::default();
+                    let character_definition = character_definition.clone();
 
                     let sequence_id_mappings = SequenceIdMappings::from_iter(
                         character_definition.object_definition.sequences.keys(),
@@ -112,4 +133,22 @@ mod test {
             .with_assertion(assertion)
             .run()
     }
+
+    fn augment(world: &mut World) -> Entity {
+        let entity = world.create_entity().build();
+        let asset_id = *world.read_resource::<AssetId>();
+        let (character_spawning_resources, mut character_component_storages) = world
+            .system_data::<(
+                CharacterSpawningResources<'_>,
+                CharacterComponentStorages<'_>,
+            )>();
+        CharacterEntityAugmenter::augment(
+            &character_spawning_resources,
+            &mut character_component_storages,
+            asset_id,
+            entity,
+        );
+
+        entity
+    }
 }
@@ -2,6 +2,7 @@
 mod tests {
     use std::{any, str::FromStr};
 
+    use ai_model::config::AiDifficulty;
     use amethyst::{
         core::TransformBundle,
         ecs::{World, WorldExt},
@@ -20,6 +21,7 @@ mod tests {
     use character_selection_model::CharacterSelections;
     use game_input_model::config::ControlBindings;
     use object_type::ObjectType;
+    use team_model::play::{TeamColour, TeamCounter};
 
     use character_selection::CharacterSelectionSystem;
 
@@ -73,6 +75,94 @@ mod tests {
         )
     }
 
+    #[test]
+    fn inserts_random_selection_on_select_random_event() -> Result<(), Error> {
+        run_random_test(
+            false,
+            AssetSelectionEvent::Select {
+                entity: None,
+                controller_id: 123,
+                asset_selection: AssetSelection::Random,
+            },
+            true,
+        )
+    }
+
+    #[test]
+    fn removes_random_selection_on_deselect_event() -> Result<(), Error> {
+        run_random_test(
+            true,
+            AssetSelectionEvent::Deselect {
+                entity: None,
+                controller_id: 123,
+            },
+            false,
+        )
+    }
+
+    #[test]
+    fn inserts_ai_difficulty_on_ai_switch_event() -> Result<(), Error> {
+        run_ai_switch_test(None, Some(AiDifficulty::Easy))
+    }
+
+    #[test]
+    fn cycles_ai_difficulty_on_ai_switch_event() -> Result<(), Error> {
+        run_ai_switch_test(Some(AiDifficulty::Easy), Some(AiDifficulty::Normal))
+    }
+
+    #[test]
+    fn removes_ai_difficulty_on_ai_switch_event_from_hard() -> Result<(), Error> {
+        run_ai_switch_test(Some(AiDifficulty::Hard), None)
+    }
+
+    #[test]
+    fn inserts_team_counter_on_team_switch_event() -> Result<(), Error> {
+        run_team_switch_test(
+            None,
+            AssetSelectionEvent::TeamSwitch {
+                entity: None,
+                controller_id: 123,
+            },
+            Some(TeamCounter::new(0)),
+        )
+    }
+
+    #[test]
+    fn cycles_team_counter_on_team_switch_event() -> Result<(), Error> {
+        run_team_switch_test(
+            Some(TeamCounter::new(0)),
+            AssetSelectionEvent::TeamSwitch {
+                entity: None,
+                controller_id: 123,
+            },
+            Some(TeamCounter::new(1)),
+        )
+    }
+
+    #[test]
+    fn removes_team_counter_on_team_switch_event_from_last_team() -> Result<(), Error> {
+        run_team_switch_test(
+            Some(TeamCounter::new(TeamColour::COUNT - 1)),
+            AssetSelectionEvent::TeamSwitch {
+                entity: None,
+                controller_id: 123,
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn removes_team_counter_on_leave_event() -> Result<(), Error> {
+        run_team_switch_test(
+            Some(TeamCounter::new(1)),
+            AssetSelectionEvent::Leave {
+                entity: None,
+                controller_id: 123,
+            },
+            None,
+        )
+    }
+
     fn run_test(
         SetupParams {
             with_character_selection_initial,
@@ -135,6 +225,108 @@ mod tests {
             .run_winit_loop()
     }
 
+    fn run_random_test(
+        random_selection_initial: bool,
+        asset_selection_event: AssetSelectionEvent,
+        random_selection_expected: bool,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                if random_selection_initial {
+                    world
+                        .write_resource::<CharacterSelections>()
+                        .random_selections
+                        .insert(123);
+                }
+
+                send_event(world, asset_selection_event);
+            })
+            .with_assertion(move |world| {
+                let character_selections = world.read_resource::<CharacterSelections>();
+
+                assert_eq!(
+                    random_selection_expected,
+                    character_selections.random_selections.contains(&123)
+                );
+                assert_eq!(None, character_selections.selections.get(&123));
+            })
+            .run()
+    }
+
+    fn run_ai_switch_test(
+        ai_difficulty_initial: Option<AiDifficulty>,
+        ai_difficulty_expected: Option<AiDifficulty>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                if let Some(ai_difficulty_initial) = ai_difficulty_initial {
+                    world
+                        .write_resource::<CharacterSelections>()
+                        .ai_difficulties
+                        .insert(123, ai_difficulty_initial);
+                }
+
+                send_event(
+                    world,
+                    AssetSelectionEvent::AiSwitch {
+                        entity: None,
+                        controller_id: 123,
+                    },
+                );
+            })
+            .with_assertion(move |world| {
+                let character_selections = world.read_resource::<CharacterSelections>();
+
+                assert_eq!(
+                    ai_difficulty_expected,
+                    character_selections.ai_difficulties.get(&123).copied()
+                );
+            })
+            .run()
+    }
+
+    fn run_team_switch_test(
+        team_counter_initial: Option<TeamCounter>,
+        asset_selection_event: AssetSelectionEvent,
+        team_counter_expected: Option<TeamCounter>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(
+                CharacterSelectionSystem::new(),
+                any::type_name::<CharacterSelectionSystem>(),
+                &[],
+            ) // kcov-ignore
+            .with_effect(move |world| {
+                if let Some(team_counter_initial) = team_counter_initial {
+                    world
+                        .write_resource::<CharacterSelections>()
+                        .team_counters
+                        .insert(123, team_counter_initial);
+                }
+
+                send_event(world, asset_selection_event);
+            })
+            .with_assertion(move |world| {
+                let character_selections = world.read_resource::<CharacterSelections>();
+
+                assert_eq!(
+                    team_counter_expected,
+                    character_selections.team_counters.get(&123).copied()
+                );
+            })
+            .run()
+    }
+
     fn send_event(world: &mut World, event: AssetSelectionEvent) {
         world
             .write_resource::<EventChannel<AssetSelectionEvent>>()
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetId, AssetIdMappings},
+    };
+    use game_model::play::{GameRng, RngSeed};
+
+    use character_selection_model::CharacterSelections;
+
+    #[test]
+    fn random_resolve_selects_character_for_each_random_selection() {
+        let asset_ids = asset_ids();
+        let mut character_selections = CharacterSelections::default();
+        character_selections.selections.insert(0, asset_ids[0]);
+        character_selections.random_selections.insert(1);
+        character_selections.random_selections.insert(2);
+
+        let mut game_rng = GameRng::new(RngSeed::new(123));
+        character_selections.random_resolve(&asset_ids, &mut *game_rng);
+
+        assert!(character_selections.random_selections.is_empty());
+        assert_eq!(Some(&asset_ids[0]), character_selections.selections.get(&0));
+        [1, 2].iter().for_each(|controller_id| {
+            let asset_id = character_selections.selections.get(controller_id);
+            assert!(
+                asset_id.map_or(false, |asset_id| asset_ids.contains(asset_id)),
+                "Expected controller {} to have a character selected.",
+                controller_id
+            );
+        });
+    }
+
+    #[test]
+    fn random_resolve_is_reproducible_with_same_seed() {
+        let asset_ids = asset_ids();
+        let resolve = || {
+            let mut character_selections = CharacterSelections::default();
+            (0..4).for_each(|controller_id| {
+                character_selections.random_selections.insert(controller_id);
+            });
+
+            let mut game_rng = GameRng::new(RngSeed::new(456));
+            character_selections.random_resolve(&asset_ids, &mut *game_rng);
+            character_selections.selections
+        };
+
+        assert_eq!(resolve(), resolve());
+    }
+
+    #[test]
+    fn random_resolve_leaves_selection_empty_when_no_characters() {
+        let mut character_selections = CharacterSelections::default();
+        character_selections.random_selections.insert(0);
+
+        let mut game_rng = GameRng::new(RngSeed::new(123));
+        character_selections.random_resolve(&[], &mut *game_rng);
+
+        assert!(character_selections.random_selections.is_empty());
+        assert!(character_selections.selections.is_empty());
+    }
+
+    fn asset_ids() -> Vec<AssetId> {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        ["test/char_0", "test/char_1", "test/char_2"]
+            .iter()
+            .map(|slug| {
+                let asset_slug =
+                    AssetSlug::from_str(slug).expect("Expected asset slug to be valid.");
+                asset_id_mappings.insert(asset_slug)
+            })
+            .collect::<Vec<AssetId>>()
+    }
+}
@@ -0,0 +1 @@
+mod chase_loading_bundle;
@@ -0,0 +1,19 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{assets::AssetStorage, ecs::WorldExt, Error};
+    use amethyst_test::AmethystApplication;
+    use chase_model::loaded::ChaseSequence;
+
+    use chase_loading::ChaseLoadingBundle;
+
+    #[test]
+    fn bundle_build_adds_chase_sequence_processor() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_bundle(ChaseLoadingBundle::new())
+            .with_assertion(|world| {
+                // Next line will panic if the Processors aren't added
+                world.read_resource::<AssetStorage<ChaseSequence>>();
+            })
+            .run()
+    }
+}
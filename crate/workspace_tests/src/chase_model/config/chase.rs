@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use serde_yaml;
+
+    use chase_model::config::{Chase, ChaseMode};
+
+    const CHASE_EMPTY: &str = "{}";
+    const CHASE_ALL_SPECIFIED: &str = r#"---
+mode: "orbit"
+speed: 3.5
+turn_rate: 0.25
+radius: 40.0
+"#;
+
+    #[test]
+    fn deserializes_default_chase_when_empty() {
+        let chase =
+            serde_yaml::from_str::<Chase>(CHASE_EMPTY).expect("Failed to deserialize `Chase`.");
+
+        assert_eq!(Chase::new(ChaseMode::Seek, 5., 0.1, 80.), chase);
+    }
+
+    #[test]
+    fn deserializes_all_specified_chase() {
+        let chase = serde_yaml::from_str::<Chase>(CHASE_ALL_SPECIFIED)
+            .expect("Failed to deserialize `Chase`.");
+
+        assert_eq!(Chase::new(ChaseMode::Orbit, 3.5, 0.25, 40.), chase);
+    }
+}
@@ -1 +1,3 @@
+mod chase_system;
+mod chase_target_acquisition_system;
 mod stick_to_target_object_system;
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use approx::assert_relative_eq;
+    use chase_model::{
+        config::{Chase, ChaseMode},
+        loaded::ChaseOpt,
+        play::TargetObject,
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+
+    use chase_play::ChaseSystem;
+
+    #[test]
+    fn seek_sets_velocity_toward_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase: Chase::new(ChaseMode::Seek, 5., 0.1, 80.),
+                velocity: Velocity::default(),
+                target_position: Position::new(10., 0., 0.),
+            },
+            |velocity, _position, mirrored| {
+                assert_relative_eq!(5., velocity[0]);
+                assert_relative_eq!(0., velocity[2]);
+                assert_eq!(Mirrored::new(false), mirrored);
+            },
+        )
+    }
+
+    #[test]
+    fn seek_turns_toward_target_by_at_most_turn_rate() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase: Chase::new(ChaseMode::Seek, 5., 0.1, 80.),
+                velocity: Velocity::new(5., 0., 0.),
+                target_position: Position::new(0., 0., 10.),
+            },
+            |velocity, _position, _mirrored| {
+                assert_relative_eq!(5. * 0.1f32.cos(), velocity[0]);
+                assert_relative_eq!(5. * 0.1f32.sin(), velocity[2]);
+            },
+        )
+    }
+
+    #[test]
+    fn flee_sets_velocity_away_from_target() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase: Chase::new(ChaseMode::Flee, 5., 0.1, 80.),
+                velocity: Velocity::default(),
+                target_position: Position::new(10., 0., 0.),
+            },
+            |velocity, _position, mirrored| {
+                assert_relative_eq!(-5., velocity[0]);
+                assert_relative_eq!(0., velocity[2], epsilon = 1e-5);
+                assert_eq!(Mirrored::new(true), mirrored);
+            },
+        )
+    }
+
+    #[test]
+    fn orbit_sets_velocity_tangential_to_target_at_radius() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase: Chase::new(ChaseMode::Orbit, 5., 0.1, 80.),
+                velocity: Velocity::default(),
+                target_position: Position::new(80., 0., 0.),
+            },
+            |velocity, _position, _mirrored| {
+                assert_relative_eq!(5. * FRAC_PI_2.cos(), velocity[0]);
+                assert_relative_eq!(5., velocity[2]);
+            },
+        )
+    }
+
+    #[test]
+    fn stick_copies_target_position() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                chase: Chase::new(ChaseMode::Stick, 5., 0.1, 80.),
+                velocity: Velocity::default(),
+                target_position: Position::new(10., 20., 30.),
+            },
+            |velocity, position, _mirrored| {
+                assert_eq!(Velocity::default(), velocity);
+                assert_eq!(Position::new(10., 20., 30.), position);
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            chase,
+            velocity,
+            target_position,
+        }: SetupParams,
+        assertion_fn: fn(Velocity<f32>, Position<f32>, Mirrored),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ChaseSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let target = world.create_entity().with(target_position).build();
+                let chaser = world
+                    .create_entity()
+                    .with(ChaseOpt::new(Some(chase)))
+                    .with(TargetObject::new(target))
+                    .with(Position::<f32>::default())
+                    .with(velocity)
+                    .with(Mirrored::default())
+                    .build();
+
+                world.insert(chaser);
+            })
+            .with_assertion(move |world| {
+                let chaser = *world.read_resource::<Entity>();
+                let velocity = *world
+                    .read_storage::<Velocity<f32>>()
+                    .get(chaser)
+                    .expect("Expected chaser to have `Velocity<f32>` component.");
+                let position = *world
+                    .read_storage::<Position<f32>>()
+                    .get(chaser)
+                    .expect("Expected chaser to have `Position<f32>` component.");
+                let mirrored = *world
+                    .read_storage::<Mirrored>()
+                    .get(chaser)
+                    .expect("Expected chaser to have `Mirrored` component.");
+
+                assertion_fn(velocity, position, mirrored);
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        chase: Chase,
+        velocity: Velocity<f32>,
+        target_position: Position<f32>,
+    }
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::{config::Chase, loaded::ChaseOpt, play::TargetObject};
+    use kinematic_model::config::Position;
+    use object_model::play::HealthPoints;
+    use spawn_model::play::SpawnParent;
+    use team_model::play::{IndependentCounter, Team, TeamCounter};
+
+    use chase_play::ChaseTargetAcquisitionSystem;
+
+    #[test]
+    fn uses_spawn_parent_target_object() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ChaseTargetAcquisitionSystem::new(), "", &[])
+            .with_effect(|world| {
+                let parent_target = create_object(world, Team::Number(TeamCounter::new(1)), 50.);
+                create_object(world, Team::Number(TeamCounter::new(1)), 10.);
+                let parent = world
+                    .create_entity()
+                    .with(TargetObject::new(parent_target))
+                    .build();
+                let chaser = create_chaser(world, Some(SpawnParent::new(parent)));
+
+                world.insert(Entities {
+                    chaser,
+                    expected: Some(parent_target),
+                });
+            })
+            .with_assertion(assert_target_object)
+            .run()
+    }
+
+    #[test]
+    fn acquires_nearest_living_enemy_when_no_spawn_parent_target() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ChaseTargetAcquisitionSystem::new(), "", &[])
+            .with_effect(|world| {
+                // Same team.
+                create_object(world, Team::Number(TeamCounter::new(0)), 1.);
+                // Dead enemy.
+                let dead_enemy = create_object(world, Team::Number(TeamCounter::new(1)), 2.);
+                world
+                    .write_storage::<HealthPoints>()
+                    .insert(dead_enemy, HealthPoints::new(0))
+                    .expect("Failed to insert `HealthPoints` component.");
+                let nearest_enemy = create_object(world, Team::Number(TeamCounter::new(1)), 10.);
+                create_object(world, Team::Independent(IndependentCounter::new(0)), 20.);
+                let chaser = create_chaser(world, None);
+
+                world.insert(Entities {
+                    chaser,
+                    expected: Some(nearest_enemy),
+                });
+            })
+            .with_assertion(assert_target_object)
+            .run()
+    }
+
+    #[test]
+    fn does_not_insert_target_object_when_no_enemy() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ChaseTargetAcquisitionSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_object(world, Team::Number(TeamCounter::new(0)), 10.);
+                let chaser = create_chaser(world, None);
+
+                world.insert(Entities {
+                    chaser,
+                    expected: None,
+                });
+            })
+            .with_assertion(assert_target_object)
+            .run()
+    }
+
+    fn create_object(world: &mut World, team: Team, x: f32) -> Entity {
+        world
+            .create_entity()
+            .with(team)
+            .with(HealthPoints::new(100))
+            .with(Position::<f32>::new(x, 0., 0.))
+            .build()
+    }
+
+    fn create_chaser(world: &mut World, spawn_parent: Option<SpawnParent>) -> Entity {
+        let mut entity_builder = world
+            .create_entity()
+            .with(ChaseOpt::new(Some(Chase::default())))
+            .with(Team::Number(TeamCounter::new(0)))
+            .with(Position::<f32>::default());
+
+        if let Some(spawn_parent) = spawn_parent {
+            entity_builder = entity_builder.with(spawn_parent);
+        }
+
+        entity_builder.build()
+    }
+
+    fn assert_target_object(world: &mut World) {
+        let Entities { chaser, expected } = *world.read_resource::<Entities>();
+
+        assert_eq!(
+            expected.map(TargetObject::new),
+            world.read_storage::<TargetObject>().get(chaser).copied()
+        );
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    struct Entities {
+        chaser: Entity,
+        expected: Option<Entity>,
+    }
+}
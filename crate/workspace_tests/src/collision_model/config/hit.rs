@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod test {
     use kinematic_model::config::Acceleration;
-    use object_status_model::config::StunPoints;
+    use object_status_model::config::{DefensePoints, StunPoints};
     use serde_yaml;
 
     use collision_model::config::{Hit, HitLimit, HitRepeatDelay};
@@ -13,6 +13,8 @@ hp_damage: 3
 sp_damage: 4
 stun: 5
 acceleration: { x: -1, y: 2 }
+chip_damage: 6
+guard_damage: 7
 "#;
 
     #[test]
@@ -27,6 +29,8 @@ acceleration: { x: -1, y: 2 }
             4,
             StunPoints::new(5),
             Acceleration::new(-1, 2, 0),
+            6,
+            DefensePoints::new(7),
         );
 
         assert_eq!(expected, hit_deserialized);
@@ -1,13 +1,13 @@
 #[cfg(test)]
 mod tests {
     use kinematic_model::config::Acceleration;
-    use object_status_model::config::StunPoints;
+    use object_status_model::config::{DefensePoints, StunPoints};
     use serde::Deserialize;
     use serde_yaml;
     use shape_model::Volume;
 
     use collision_model::config::{
-        Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind, Interactions,
+        Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind, InteractionTag, Interactions,
     };
 
     const ITR_PHYSICAL_ALL_SPECIFIED: &str = r#"---
@@ -19,8 +19,11 @@ interactions:
       sp_damage: 50
       stun: 33
       acceleration: { x: -1, y: 2 }
+      chip_damage: 4
+      guard_damage: 25
     bounds: [{ sphere: { x: 1, y: 1, r: 1 } }]
     multiple: true
+    tags: ["counter"]
 "#;
     const ITR_PHYSICAL_MINIMUM_SPECIFIED: &str = r#"---
 interactions:
@@ -44,6 +47,9 @@ interactions:
                 sp_damage: 50,
                 stun: StunPoints::new(33),
                 acceleration: Acceleration::new(-1, 2, 0),
+                chip_damage: 4,
+                guard_damage: DefensePoints::new(25),
+                status_effect: None,
             }),
             bounds: vec![Volume::Sphere {
                 x: 1,
@@ -52,6 +58,7 @@ interactions:
                 r: 1,
             }],
             multiple: true,
+            tags: vec![InteractionTag::new(String::from("counter"))],
         }];
         assert_eq!(Interactions::new(interactions), frame.interactions);
     }
@@ -70,6 +77,7 @@ interactions:
             }],
             kind: Default::default(),
             multiple: Default::default(),
+            tags: Default::default(),
         }]; // kcov-ignore
         assert_eq!(Interactions::new(interactions), frame.interactions);
     }
@@ -91,6 +99,7 @@ interactions:
                 ..Default::default()
             }),
             multiple: Default::default(),
+            tags: Default::default(),
         }]; // kcov-ignore
         assert_eq!(Interactions::new(interactions), frame.interactions);
     }
@@ -1,4 +1,5 @@
 mod contact_detection_system;
+mod grab_detection_system;
 mod hit_detection_system;
 mod hit_effect_system;
 mod hit_repeat_trackers_augment_system;
@@ -10,6 +10,7 @@ mod tests {
         config::{Hit, HitLimit, HitRepeatDelay, Interaction, InteractionKind},
         play::{CollisionEvent, ContactEvent},
     };
+    use game_rules_model::config::GameRules;
     use pretty_assertions::assert_eq;
     use shape_model::Volume;
     use spawn_model::play::SpawnParent;
@@ -64,6 +65,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn inserts_contact_event_when_entities_on_same_team_with_friendly_fire() -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::NoSpawnParent,
+            TeamsVariant::SameTeamFriendlyFire,
+            |entity_from, entity_to| vec![contact_event(entity_from, entity_to)],
+        )
+    }
+
+    #[test]
+    fn does_not_insert_contact_event_when_hitter_entity_is_spawn_parent_with_friendly_fire(
+    ) -> Result<(), Error> {
+        run_test(
+            SpawnParentVariant::HitterEntityIsSpawnParent,
+            TeamsVariant::SameTeamFriendlyFire,
+            |_, _| vec![],
+        )
+    }
+
     fn run_test(
         spawn_parent_variant: SpawnParentVariant,
         teams_variant: TeamsVariant,
@@ -93,7 +113,7 @@ mod tests {
                 }
 
                 match teams_variant {
-                    TeamsVariant::SameTeam => {
+                    TeamsVariant::SameTeam | TeamsVariant::SameTeamFriendlyFire => {
                         let mut teams = world.write_storage::<Team>();
                         teams
                             .insert(entity_from, Team::Independent(IndependentCounter::new(0)))
@@ -114,6 +134,13 @@ mod tests {
                     TeamsVariant::NoTeam => {}
                 }
 
+                if teams_variant == TeamsVariant::SameTeamFriendlyFire {
+                    world.insert(GameRules {
+                        friendly_fire: true,
+                        ..Default::default()
+                    });
+                }
+
                 send_event(world, collision_event(entity_from, entity_to));
 
                 world.insert((entity_from, entity_to));
@@ -203,6 +230,7 @@ mod tests {
     #[derive(Clone, Copy, Debug, PartialEq)]
     enum TeamsVariant {
         SameTeam,
+        SameTeamFriendlyFire,
         DifferentTeam,
         NoTeam,
     }
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Grab, Hit, Interaction, InteractionKind, Throw},
+        play::{ContactEvent, GrabEvent, GrabHoldClock, GrabbedBy, Grabbing},
+    };
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+
+    use collision_play::GrabDetectionSystem;
+
+    #[test]
+    fn inserts_grab_event_for_grab_interaction() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabDetectionSystem::new(), "", &[])
+            .with_effect(setup_event_reader)
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().build();
+
+                send_events(world, vec![contact_event(entity_from, entity_to)]);
+
+                world.insert((entity_from, entity_to));
+            })
+            .with_assertion(|world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+                assert_events(world, vec![grab_event(entity_from, entity_to)]);
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_insert_grab_event_for_hit_interaction() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabDetectionSystem::new(), "", &[])
+            .with_effect(setup_event_reader)
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().build();
+
+                let interaction =
+                    Interaction::new(InteractionKind::Hit(Hit::default()), vec![], true);
+                send_events(
+                    world,
+                    vec![ContactEvent::new(
+                        entity_from,
+                        entity_to,
+                        interaction,
+                        body(),
+                    )],
+                );
+            })
+            .with_assertion(|world| assert_events(world, vec![]))
+            .run()
+    }
+
+    #[test]
+    fn does_not_insert_grab_event_when_target_is_already_grabbed() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabDetectionSystem::new(), "", &[])
+            .with_effect(setup_event_reader)
+            .with_effect(|world| {
+                let entity_other = world.create_entity().build();
+                let entity_from = world.create_entity().build();
+                let entity_to = world
+                    .create_entity()
+                    .with(GrabbedBy::new(entity_other))
+                    .build();
+
+                send_events(world, vec![contact_event(entity_from, entity_to)]);
+            })
+            .with_assertion(|world| assert_events(world, vec![]))
+            .run()
+    }
+
+    #[test]
+    fn does_not_insert_grab_event_when_grabber_is_already_grabbing() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabDetectionSystem::new(), "", &[])
+            .with_effect(setup_event_reader)
+            .with_effect(|world| {
+                let entity_other = world.create_entity().build();
+                let entity_from = world
+                    .create_entity()
+                    .with(Grabbing::new(
+                        entity_other,
+                        GrabHoldClock::new(10),
+                        Throw::default(),
+                    ))
+                    .build();
+                let entity_to = world.create_entity().build();
+
+                send_events(world, vec![contact_event(entity_from, entity_to)]);
+            })
+            .with_assertion(|world| assert_events(world, vec![]))
+            .run()
+    }
+
+    #[test]
+    fn inserts_one_grab_event_per_grabber_per_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(GrabDetectionSystem::new(), "", &[])
+            .with_effect(setup_event_reader)
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to_0 = world.create_entity().build();
+                let entity_to_1 = world.create_entity().build();
+
+                send_events(
+                    world,
+                    vec![
+                        contact_event(entity_from, entity_to_0),
+                        contact_event(entity_from, entity_to_1),
+                    ],
+                );
+
+                world.insert((entity_from, entity_to_0));
+            })
+            .with_assertion(|world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+                assert_events(world, vec![grab_event(entity_from, entity_to)]);
+            })
+            .run()
+    }
+
+    fn setup_event_reader(world: &mut World) {
+        let grab_event_rid = world
+            .write_resource::<EventChannel<GrabEvent>>()
+            .register_reader(); // kcov-ignore
+
+        world.insert(grab_event_rid);
+    }
+
+    fn send_events(world: &mut World, events: Vec<ContactEvent>) {
+        let mut ec = world.write_resource::<EventChannel<ContactEvent>>();
+        ec.iter_write(events)
+    } // kcov-ignore
+
+    fn contact_event(entity_from: Entity, entity_to: Entity) -> ContactEvent {
+        ContactEvent::new(entity_from, entity_to, interaction(), body())
+    }
+
+    fn grab_event(entity_from: Entity, entity_to: Entity) -> GrabEvent {
+        GrabEvent::new(entity_from, entity_to, interaction(), body())
+    }
+
+    fn interaction() -> Interaction {
+        Interaction::new(InteractionKind::Grab(Grab::default()), vec![], true)
+    }
+
+    fn body() -> Volume {
+        Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        }
+    }
+
+    fn assert_events(world: &mut World, grab_events_expected: Vec<GrabEvent>) {
+        let grab_ec = world.read_resource::<EventChannel<GrabEvent>>();
+        let mut grab_event_rid = world.write_resource::<ReaderId<GrabEvent>>();
+        let grab_events = grab_ec
+            .read(&mut grab_event_rid)
+            .collect::<Vec<&GrabEvent>>();
+
+        let grab_events_expected = grab_events_expected.iter().collect::<Vec<&GrabEvent>>();
+        assert_eq!(grab_events_expected, grab_events);
+    }
+}
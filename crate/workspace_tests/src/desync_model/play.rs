@@ -0,0 +1,2 @@
+mod object_states;
+mod pending_state_checksums;
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::play::GameTick;
+
+    use desync_model::play::{ObjectState, ObjectStates};
+
+    #[test]
+    fn checksum_does_not_depend_on_object_order() {
+        let object_states_0 = ObjectStates::new(vec![object_state(1.), object_state(2.)]);
+        let object_states_1 = ObjectStates::new(vec![object_state(2.), object_state(1.)]);
+
+        assert_eq!(
+            object_states_0.state_checksum(GameTick(1), false),
+            object_states_1.state_checksum(GameTick(1), false)
+        );
+    }
+
+    #[test]
+    fn checksum_differs_when_object_state_differs() {
+        let object_states_0 = ObjectStates::new(vec![object_state(1.)]);
+        let object_states_1 = ObjectStates::new(vec![object_state(2.)]);
+
+        assert_ne!(
+            object_states_0.state_checksum(GameTick(1), false).checksum,
+            object_states_1.state_checksum(GameTick(1), false).checksum
+        );
+    }
+
+    #[test]
+    fn includes_state_dump_when_requested() {
+        let object_states = ObjectStates::new(vec![object_state(2.), object_state(1.)]);
+
+        let state_checksum = object_states.state_checksum(GameTick(1), true);
+
+        assert_eq!(
+            Some(String::from(
+                "position: (1, 0, 0), hp: Some(100), sp: None, sequence_id: 3, frame_index: Some(2)\n\
+                 position: (2, 0, 0), hp: Some(100), sp: None, sequence_id: 3, frame_index: Some(2)"
+            )),
+            state_checksum.state_dump
+        );
+    }
+
+    fn object_state(x: f32) -> ObjectState {
+        ObjectState::new([x, 0., 0.], Some(100), None, 3, Some(2))
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::play::GameTick;
+    use network_session_model::play::StateChecksum;
+
+    use desync_model::play::PendingStateChecksums;
+
+    #[test]
+    fn insert_replaces_checksum_for_same_tick() {
+        let mut pending_state_checksums = PendingStateChecksums::default();
+
+        pending_state_checksums.insert(StateChecksum::new(GameTick(1), 123, None));
+        pending_state_checksums.insert(StateChecksum::new(GameTick(1), 456, None));
+
+        assert_eq!(
+            vec![StateChecksum::new(GameTick(1), 456, None)],
+            pending_state_checksums.take_confirmed(GameTick(1))
+        );
+    }
+
+    #[test]
+    fn take_confirmed_returns_checksums_up_to_and_including_tick() {
+        let mut pending_state_checksums = PendingStateChecksums::default();
+        pending_state_checksums.insert(StateChecksum::new(GameTick(1), 1, None));
+        pending_state_checksums.insert(StateChecksum::new(GameTick(2), 2, None));
+        pending_state_checksums.insert(StateChecksum::new(GameTick(3), 3, None));
+
+        assert_eq!(
+            vec![
+                StateChecksum::new(GameTick(1), 1, None),
+                StateChecksum::new(GameTick(2), 2, None),
+            ],
+            pending_state_checksums.take_confirmed(GameTick(2))
+        );
+        assert_eq!(
+            vec![StateChecksum::new(GameTick(3), 3, None)],
+            pending_state_checksums.take_confirmed(GameTick(3))
+        );
+    }
+}
@@ -0,0 +1 @@
+mod state_checksum_system;
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use desync_model::play::{ObjectStates, PendingStateChecksums};
+    use game_input_model::play::GameTick;
+    use net_model::play::NetMessageEvent;
+    use network_session_model::{play::SessionStatus, SessionMessageEvent};
+    use rollback_model::config::SessionSyncMode;
+    use state_registry::StateId;
+
+    use desync_play::StateChecksumSystem;
+
+    #[test]
+    fn sends_state_checksum_on_checksum_interval_when_lockstep() -> Result<(), Error> {
+        let state_checksum = ObjectStates::default().state_checksum(GameTick(60), false);
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                game_tick: GameTick(60),
+            },
+            ExpectedParams {
+                net_message_events: vec![NetMessageEvent::SessionMessageEvent(
+                    SessionMessageEvent::StateChecksum(state_checksum),
+                )],
+                pending_ticks: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_state_checksum_between_checksum_intervals() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                game_tick: GameTick(61),
+            },
+            ExpectedParams {
+                net_message_events: vec![],
+                pending_ticks: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn holds_state_checksum_within_rollback_window() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_sync_mode: SessionSyncMode::Rollback,
+                game_tick: GameTick(60),
+            },
+            ExpectedParams {
+                net_message_events: vec![],
+                pending_ticks: vec![GameTick(60)],
+            },
+        )
+    }
+
+    #[test]
+    fn does_nothing_when_session_not_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                game_tick: GameTick(60),
+            },
+            ExpectedParams {
+                net_message_events: vec![],
+                pending_ticks: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn keeps_pending_state_checksums_while_paused() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<NetMessageEvent>> as SystemData>::setup)
+            .with_system(StateChecksumSystem::new(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(StateId::GamePlay)
+            .with_resource(GameTick(60))
+            .with_assertion(|world| assert_pending_ticks(world, vec![GameTick(60)]))
+            .with_effect(|world| world.insert(StateId::GamePlayPause))
+            .with_assertion(|world| assert_pending_ticks(world, vec![GameTick(60)]))
+            .run()
+    }
+
+    fn run_test(
+        SetupParams {
+            session_status,
+            session_sync_mode,
+            game_tick,
+        }: SetupParams,
+        ExpectedParams {
+            net_message_events: net_message_events_expected,
+            pending_ticks: pending_ticks_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<NetMessageEvent>> as SystemData>::setup)
+            .with_setup(setup_net_message_event_reader)
+            .with_system(StateChecksumSystem::new(), "", &[])
+            .with_resource(session_status)
+            .with_resource(session_sync_mode)
+            .with_resource(StateId::GamePlay)
+            .with_resource(game_tick)
+            .with_assertion(move |world| {
+                let (mut net_message_event_rid, net_message_ec, pending_state_checksums) = world
+                    .system_data::<(
+                        WriteExpect<'_, ReaderId<NetMessageEvent>>,
+                        Read<'_, EventChannel<NetMessageEvent>>,
+                        Read<'_, PendingStateChecksums>,
+                    )>();
+                let net_message_events = net_message_ec
+                    .read(&mut *net_message_event_rid)
+                    .cloned()
+                    .collect::<Vec<NetMessageEvent>>();
+                let pending_ticks = pending_state_checksums
+                    .keys()
+                    .copied()
+                    .collect::<Vec<GameTick>>();
+
+                assert_eq!(
+                    (net_message_events_expected, pending_ticks_expected),
+                    (net_message_events, pending_ticks)
+                );
+            })
+            .run()
+    }
+
+    fn assert_pending_ticks(world: &mut World, pending_ticks_expected: Vec<GameTick>) {
+        let pending_ticks = world
+            .read_resource::<PendingStateChecksums>()
+            .keys()
+            .copied()
+            .collect::<Vec<GameTick>>();
+
+        assert_eq!(pending_ticks_expected, pending_ticks);
+    }
+
+    fn setup_net_message_event_reader(world: &mut World) {
+        let net_message_event_rid = world
+            .write_resource::<EventChannel<NetMessageEvent>>()
+            .register_reader();
+        world.insert(net_message_event_rid);
+    }
+
+    struct SetupParams {
+        session_status: SessionStatus,
+        session_sync_mode: SessionSyncMode,
+        game_tick: GameTick,
+    }
+
+    struct ExpectedParams {
+        net_message_events: Vec<NetMessageEvent>,
+        pending_ticks: Vec<GameTick>,
+    }
+}
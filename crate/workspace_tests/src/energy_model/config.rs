@@ -1,4 +1,5 @@
 mod energy_sequence;
+mod energy_transitions;
 
 #[cfg(test)]
 mod test {
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use collision_model::config::InteractionTag;
+    use sequence_model::config::SequenceNameString;
+    use serde_yaml;
+
+    use energy_model::config::{
+        EnergySequenceName, EnergyTaggedTransition, EnergyTransition, EnergyTransitions,
+    };
+
+    const TRANSITIONS_EMPTY: &str = "{}";
+    const TRANSITIONS_ALL_SPECIFIED: &str = r#"---
+hitting: "hover"
+hit: "split"
+map_boundary: { next: "hit", reflect: true }
+tagged_hits:
+  - { tag: "counter", next: "hitting", reflect: true }
+"#;
+
+    #[test]
+    fn deserializes_default_transitions_when_empty() {
+        let energy_transitions = serde_yaml::from_str::<EnergyTransitions>(TRANSITIONS_EMPTY)
+            .expect("Failed to deserialize `EnergyTransitions`.");
+
+        let expected = EnergyTransitions::new(
+            SequenceNameString::Name(EnergySequenceName::Hitting),
+            SequenceNameString::Name(EnergySequenceName::Hit),
+            None,
+            Vec::new(),
+        );
+        assert_eq!(expected, energy_transitions);
+    }
+
+    #[test]
+    fn deserializes_all_specified_transitions() {
+        let energy_transitions =
+            serde_yaml::from_str::<EnergyTransitions>(TRANSITIONS_ALL_SPECIFIED)
+                .expect("Failed to deserialize `EnergyTransitions`.");
+
+        let expected = EnergyTransitions::new(
+            SequenceNameString::Name(EnergySequenceName::Hover),
+            SequenceNameString::String(String::from("split")),
+            Some(EnergyTransition::new(
+                SequenceNameString::Name(EnergySequenceName::Hit),
+                true,
+            )),
+            vec![EnergyTaggedTransition::new(
+                InteractionTag::new(String::from("counter")),
+                SequenceNameString::Name(EnergySequenceName::Hitting),
+                true,
+            )],
+        );
+        assert_eq!(expected, energy_transitions);
+    }
+}
@@ -0,0 +1,2 @@
+mod energy_boundary_transition_system;
+mod energy_tagged_hit_transition_system;
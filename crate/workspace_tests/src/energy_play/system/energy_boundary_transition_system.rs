@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use energy_model::loaded::EnergyBoundaryTransition;
+    use enumflags2::BitFlags;
+    use kinematic_model::config::Velocity;
+    use map_model::play::{BoundaryFace, MapBoundaryEvent, MapBoundaryEventData};
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+
+    use energy_play::EnergyBoundaryTransitionSystem;
+
+    #[test]
+    fn transitions_and_reflects_energy_on_boundary_exit() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                reflect: true,
+                boundary_event_fn: MapBoundaryEvent::Exit,
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(3),
+                mirrored: Mirrored::new(true),
+                velocity: Velocity::new(-2., 3., 4.),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_without_reflecting_when_reflect_is_false() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                reflect: false,
+                boundary_event_fn: MapBoundaryEvent::Exit,
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(3),
+                mirrored: Mirrored::new(false),
+                velocity: Velocity::new(2., 3., 4.),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_transition_on_boundary_enter() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                reflect: true,
+                boundary_event_fn: MapBoundaryEvent::Enter,
+            },
+            ExpectedParams {
+                sequence_id: SequenceId::new(0),
+                mirrored: Mirrored::new(false),
+                velocity: Velocity::new(2., 3., 4.),
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            reflect,
+            boundary_event_fn,
+        }: SetupParams,
+        ExpectedParams {
+            sequence_id: sequence_id_expected,
+            mirrored: mirrored_expected,
+            velocity: velocity_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(EnergyBoundaryTransitionSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let entity = world
+                    .create_entity()
+                    .with(EnergyBoundaryTransition::new(SequenceId::new(3), reflect))
+                    .with(Mirrored::new(false))
+                    .with(Velocity::new(2., 3., 4.))
+                    .with(SequenceId::new(0))
+                    .build();
+
+                let boundary_faces = BitFlags::from(BoundaryFace::Right);
+                send_event(
+                    world,
+                    boundary_event_fn(MapBoundaryEventData::new(entity, boundary_faces)),
+                );
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+
+                let sequence_ids = world.read_storage::<SequenceId>();
+                let mirroreds = world.read_storage::<Mirrored>();
+                let velocities = world.read_storage::<Velocity<f32>>();
+
+                assert_eq!(Some(&sequence_id_expected), sequence_ids.get(entity));
+                assert_eq!(Some(&mirrored_expected), mirroreds.get(entity));
+                assert_eq!(Some(&velocity_expected), velocities.get(entity));
+            })
+            .run()
+    }
+
+    fn send_event(world: &mut World, map_boundary_event: MapBoundaryEvent) {
+        let mut ec = world.write_resource::<EventChannel<MapBoundaryEvent>>();
+        ec.single_write(map_boundary_event);
+    } // kcov-ignore
+
+    struct SetupParams {
+        reflect: bool,
+        boundary_event_fn: fn(MapBoundaryEventData) -> MapBoundaryEvent,
+    }
+
+    struct ExpectedParams {
+        sequence_id: SequenceId,
+        mirrored: Mirrored,
+        velocity: Velocity<f32>,
+    }
+}
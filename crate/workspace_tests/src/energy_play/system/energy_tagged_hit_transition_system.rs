@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Interaction, InteractionKind, InteractionTag},
+        play::HitEvent,
+    };
+    use energy_model::loaded::{EnergyTaggedHitTransition, EnergyTaggedHitTransitions};
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+    use team_model::play::{IndependentCounter, Team};
+
+    use energy_play::EnergyTaggedHitTransitionSystem;
+
+    #[test]
+    fn reflects_energy_when_hit_by_tagged_interaction() -> Result<(), Error> {
+        run_test(
+            "counter",
+            ExpectedParams {
+                sequence_id: SequenceId::new(2),
+                mirrored: Mirrored::new(true),
+                velocity: Velocity::new(-5., 1., 0.),
+                team: Team::Independent(IndependentCounter::new(1)),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_transition_when_interaction_tag_does_not_match() -> Result<(), Error> {
+        run_test(
+            "other",
+            ExpectedParams {
+                sequence_id: SequenceId::new(0),
+                mirrored: Mirrored::new(false),
+                velocity: Velocity::new(5., 1., 0.),
+                team: Team::Independent(IndependentCounter::new(0)),
+            },
+        )
+    }
+
+    fn run_test(
+        interaction_tag: &'static str,
+        ExpectedParams {
+            sequence_id: sequence_id_expected,
+            mirrored: mirrored_expected,
+            velocity: velocity_expected,
+            team: team_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(EnergyTaggedHitTransitionSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(Team::Independent(IndependentCounter::new(1)))
+                    .build();
+                let entity_to = world
+                    .create_entity()
+                    .with(EnergyTaggedHitTransitions::new(vec![
+                        EnergyTaggedHitTransition::new(
+                            InteractionTag::new(String::from("counter")),
+                            SequenceId::new(2),
+                            true,
+                        ),
+                    ]))
+                    .with(Team::Independent(IndependentCounter::new(0)))
+                    .with(Mirrored::new(false))
+                    .with(Velocity::new(5., 1., 0.))
+                    .with(SequenceId::new(0))
+                    .build();
+
+                send_event(world, entity_from, entity_to, interaction_tag);
+
+                world.insert(entity_to);
+            })
+            .with_assertion(move |world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                let sequence_ids = world.read_storage::<SequenceId>();
+                let mirroreds = world.read_storage::<Mirrored>();
+                let velocities = world.read_storage::<Velocity<f32>>();
+                let teams = world.read_storage::<Team>();
+
+                assert_eq!(Some(&sequence_id_expected), sequence_ids.get(entity_to));
+                assert_eq!(Some(&mirrored_expected), mirroreds.get(entity_to));
+                assert_eq!(Some(&velocity_expected), velocities.get(entity_to));
+                assert_eq!(Some(&team_expected), teams.get(entity_to));
+            })
+            .run()
+    }
+
+    fn send_event(
+        world: &mut World,
+        entity_from: Entity,
+        entity_to: Entity,
+        interaction_tag: &'static str,
+    ) {
+        let mut interaction =
+            Interaction::new(InteractionKind::Hit(Default::default()), vec![], true);
+        interaction
+            .tags
+            .push(InteractionTag::new(String::from(interaction_tag)));
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<HitEvent>>();
+        ec.single_write(HitEvent::new(entity_from, entity_to, interaction, body));
+    } // kcov-ignore
+
+    struct ExpectedParams {
+        sequence_id: SequenceId,
+        mirrored: Mirrored,
+        velocity: Velocity<f32>,
+        team: Team,
+    }
+}
@@ -1,39 +1,171 @@
 #[cfg(test)]
 mod tests {
+    use std::{iter::FromIterator, str::FromStr};
+
     use amethyst::{
-        core::TransformBundle,
-        ecs::{Builder, World, WorldExt},
-        renderer::{types::DefaultBackend, RenderEmptyBundle},
+        assets::{AssetStorage, Loader, Processor},
+        ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt, Write},
         shred::SystemData,
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use collision_model::loaded::{HitTransition, HittingTransition};
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetId, AssetIdMappings},
+    };
+    use collision_model::{
+        config::InteractionTag,
+        loaded::{HitTransition, HittingTransition},
+    };
+    use energy_model::{
+        config::{
+            EnergyDefinition, EnergySequenceName, EnergyTaggedTransition, EnergyTransition,
+            EnergyTransitions,
+        },
+        loaded::{
+            AssetEnergyDefinitionHandle, EnergyBoundaryTransition, EnergyTaggedHitTransition,
+            EnergyTaggedHitTransitions,
+        },
+    };
     use map_model::play::MapUnboundedDelete;
+    use object_model::config::ObjectDefinition;
+    use sequence_model::{
+        config::SequenceNameString,
+        loaded::{AssetSequenceIdMappings, SequenceId, SequenceIdMappings},
+    };
 
-    use energy_prefab::{EnergyComponentStorages, EnergyEntityAugmenter};
+    use energy_prefab::{EnergyComponentStorages, EnergyEntityAugmenter, EnergySpawningResources};
 
     #[test]
     fn augments_entity_with_energy_components() -> Result<(), Error> {
-        let assertion = |world: &mut World| {
-            let entity = world.create_entity().build();
-            {
-                let mut energy_component_storages = EnergyComponentStorages::fetch(&world);
-                EnergyEntityAugmenter::augment(entity, &mut energy_component_storages);
-            }
-
+        run_test(EnergyTransitions::default(), |world, entity| {
             assert!(world.read_storage::<MapUnboundedDelete>().contains(entity));
-            assert!(world.read_storage::<HitTransition>().contains(entity));
-            assert!(world.read_storage::<HittingTransition>().contains(entity));
+            assert_eq!(
+                Some(&HitTransition::new(SequenceId::new(1))),
+                world.read_storage::<HitTransition>().get(entity)
+            );
+            assert_eq!(
+                Some(&HittingTransition::new(SequenceId::new(2))),
+                world.read_storage::<HittingTransition>().get(entity)
+            );
+            assert!(!world
+                .read_storage::<EnergyBoundaryTransition>()
+                .contains(entity));
+            assert!(!world
+                .read_storage::<EnergyTaggedHitTransitions>()
+                .contains(entity));
+        })
+    }
+
+    #[test]
+    fn augments_entity_with_boundary_and_tagged_hit_transitions() -> Result<(), Error> {
+        let energy_transitions = EnergyTransitions {
+            map_boundary: Some(EnergyTransition::new(
+                SequenceNameString::Name(EnergySequenceName::Hit),
+                true,
+            )),
+            tagged_hits: vec![
+                EnergyTaggedTransition::new(
+                    tag(),
+                    SequenceNameString::Name(EnergySequenceName::Hitting),
+                    true,
+                ),
+                EnergyTaggedTransition::new(
+                    InteractionTag::new(String::from("missing")),
+                    SequenceNameString::String(String::from("missing")),
+                    false,
+                ),
+            ],
+            ..Default::default()
         };
 
+        run_test(energy_transitions, |world, entity| {
+            assert_eq!(
+                Some(&EnergyBoundaryTransition::new(SequenceId::new(1), true)),
+                world.read_storage::<EnergyBoundaryTransition>().get(entity)
+            );
+            assert_eq!(
+                Some(&EnergyTaggedHitTransitions::new(vec![
+                    EnergyTaggedHitTransition::new(tag(), SequenceId::new(2), true)
+                ])),
+                world
+                    .read_storage::<EnergyTaggedHitTransitions>()
+                    .get(entity)
+            );
+        })
+    }
+
+    fn run_test(
+        energy_transitions: EnergyTransitions,
+        assertion_fn: fn(&mut World, Entity),
+    ) -> Result<(), Error> {
         AmethystApplication::blank()
-            .with_bundle(TransformBundle::new())
-            .with_bundle_event_fn(|event_loop| RenderEmptyBundle::<DefaultBackend>::new(event_loop))
-            .with_effect(|world| {
+            .with_system(Processor::<EnergyDefinition>::new(), "", &[])
+            .with_setup(|world| {
+                <Read<'_, AssetIdMappings> as SystemData>::setup(world);
+                <EnergySpawningResources as SystemData>::setup(world);
                 <EnergyComponentStorages as SystemData>::setup(world);
             })
-            .with_assertion(assertion)
-            .run_winit_loop()
+            .with_effect(move |world| {
+                let asset_id = {
+                    let mut asset_id_mappings = world.write_resource::<AssetIdMappings>();
+                    let asset_slug = AssetSlug::from_str("test/energy")
+                        .expect("Expected asset slug to be valid.");
+                    asset_id_mappings.insert(asset_slug)
+                };
+
+                {
+                    let (
+                        loader,
+                        mut asset_sequence_id_mappings_energy,
+                        mut asset_energy_definition_handle,
+                        energy_definition_assets,
+                    ) = world.system_data::<(
+                        ReadExpect<'_, Loader>,
+                        Write<'_, AssetSequenceIdMappings<EnergySequenceName>>,
+                        Write<'_, AssetEnergyDefinitionHandle>,
+                        Read<'_, AssetStorage<EnergyDefinition>>,
+                    )>();
+
+                    let sequence_names = vec![
+                        SequenceNameString::Name(EnergySequenceName::Hover),
+                        SequenceNameString::Name(EnergySequenceName::Hit),
+                        SequenceNameString::Name(EnergySequenceName::Hitting),
+                    ];
+                    let sequence_id_mappings = SequenceIdMappings::from_iter(sequence_names.iter());
+                    asset_sequence_id_mappings_energy.insert(asset_id, sequence_id_mappings);
+
+                    let energy_definition = EnergyDefinition {
+                        object_definition: ObjectDefinition::default(),
+                        transitions: energy_transitions.clone(),
+                    };
+                    let energy_definition_handle =
+                        loader.load_from_data(energy_definition, (), &*energy_definition_assets);
+                    asset_energy_definition_handle.insert(asset_id, energy_definition_handle);
+                }
+
+                world.insert(asset_id);
+            })
+            .with_assertion(move |world| {
+                let entity = world.create_entity().build();
+                {
+                    let asset_id = *world.read_resource::<AssetId>();
+                    let energy_spawning_resources = EnergySpawningResources::fetch(&world);
+                    let mut energy_component_storages = EnergyComponentStorages::fetch(&world);
+                    EnergyEntityAugmenter::augment(
+                        &energy_spawning_resources,
+                        &mut energy_component_storages,
+                        asset_id,
+                        entity,
+                    );
+                }
+
+                assertion_fn(world, entity);
+            })
+            .run()
+    }
+
+    fn tag() -> InteractionTag {
+        InteractionTag::new(String::from("counter"))
     }
 }
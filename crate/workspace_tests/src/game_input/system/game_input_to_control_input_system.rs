@@ -17,12 +17,14 @@ mod tests {
         GameInputEvent,
     };
     use hamcrest::prelude::*;
+    use network_session_model::play::SessionCondition;
 
     use game_input::{GameInputToControlInputSystem, GameInputToControlInputSystemDesc};
 
     #[test]
     fn sends_control_input_events_for_key_presses() -> Result<(), Error> {
         run_test(
+            SessionCondition::Ready,
             vec![
                 GameInputEvent::AxisMoved {
                     axis: PlayerAxisControl {
@@ -68,6 +70,7 @@ mod tests {
     #[test]
     fn sends_control_input_events_for_key_releases() -> Result<(), Error> {
         run_test(
+            SessionCondition::Ready,
             vec![
                 GameInputEvent::AxisMoved {
                     axis: PlayerAxisControl {
@@ -143,7 +146,29 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sends_control_input_events_only_to_shared_input_controlled_entities_when_paused(
+    ) -> Result<(), Error> {
+        run_test(
+            SessionCondition::Paused {
+                game_input_tick_pending: false,
+            },
+            vec![GameInputEvent::ActionPressed(PlayerActionControl {
+                player: 0,
+                action: ControlAction::Jump,
+            })],
+            |_input_controlled_entity, shared_input_controlled_entity| {
+                vec![ControlInputEvent::ControlActionPress(ControlActionEventData {
+                    controller_id: 0,
+                    entity: shared_input_controlled_entity,
+                    control_action: ControlAction::Jump,
+                })]
+            },
+        )
+    }
+
     fn run_test<F>(
+        session_condition: SessionCondition,
         mut game_input_events: Vec<GameInputEvent>,
         expected_control_input_events: F,
     ) -> Result<(), Error>
@@ -156,6 +181,7 @@ mod tests {
                 any::type_name::<GameInputToControlInputSystem>(),
                 &[],
             ) // kcov-ignore
+            .with_resource(session_condition)
             .with_effect(move |world| {
                 let reader_id = world
                     .write_resource::<EventChannel<ControlInputEvent>>()
@@ -24,6 +24,8 @@ mod tests {
         GameInputEvent,
     };
     use hamcrest::prelude::*;
+    use replay_model::play::ReplayMode;
+    use state_registry::StateId;
 
     use game_input::{InputToGameInputSystem, InputToGameInputSystemDesc};
 
@@ -34,6 +36,7 @@ mod tests {
     #[test]
     fn sends_input_events_for_key_presses() -> Result<(), Error> {
         run_test(
+            ReplayMode::None,
             vec![key_press(AXIS_POSITIVE), key_press(ACTION_JUMP)],
             vec![
                 GameInputEvent::AxisMoved {
@@ -54,6 +57,7 @@ mod tests {
     #[test]
     fn sends_input_events_for_key_releases() -> Result<(), Error> {
         run_test(
+            ReplayMode::None,
             vec![
                 key_press(AXIS_POSITIVE),
                 key_release(AXIS_POSITIVE),
@@ -87,7 +91,17 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_send_input_events_during_replay_playback() -> Result<(), Error> {
+        run_test(
+            ReplayMode::Playback,
+            vec![key_press(AXIS_POSITIVE), key_press(ACTION_JUMP)],
+            vec![],
+        )
+    }
+
     fn run_test(
+        replay_mode: ReplayMode,
         key_events: Vec<Event<'static, ()>>,
         input_events_expected: Vec<GameInputEvent>,
     ) -> Result<(), Error> {
@@ -96,6 +110,8 @@ mod tests {
 
         AmethystApplication::ui_base::<ControlBindings>()
             .with_resource(player_input_configs)
+            .with_resource(replay_mode)
+            .with_resource(StateId::GamePlay)
             .with_system_desc(
                 InputToGameInputSystemDesc::default(),
                 any::type_name::<InputToGameInputSystem>(),
@@ -15,8 +15,9 @@ mod tests {
     use character_selection_model::CharacterSelections;
     use game_input_model::play::InputControlled;
     use game_model::play::GameEntities;
+    use game_rules_model::config::GameRules;
     use object_type::ObjectType;
-    use team_model::play::{IndependentCounter, Team};
+    use team_model::play::{IndependentCounter, Team, TeamCounter};
 
     use game_loading::{
         CharacterAugmentStatus, CharacterSelectionSpawningSystem, GameLoadingStatus,
@@ -129,6 +130,80 @@ mod tests {
         )
     }
 
+    #[test]
+    fn spawns_characters_on_selected_teams() -> Result<(), Error> {
+        run_test(
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
+                world.insert(game_loading_status);
+
+                let asset_id = first_character_asset_id(world);
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, asset_id);
+                character_selections.selections.insert(1, asset_id);
+                character_selections
+                    .team_counters
+                    .insert(0, TeamCounter::new(2));
+                world.insert(character_selections);
+            },
+            |world| {
+                let (input_controlleds, teams) = world.system_data::<TestSystemData<'_>>();
+                let mut components = (&input_controlleds, &teams)
+                    .join()
+                    .map(|(input_controlled, team)| (input_controlled.controller_id, *team))
+                    .collect::<Vec<_>>();
+                components.sort_by_key(|(controller_id, _)| *controller_id);
+
+                assert_eq!(
+                    vec![
+                        (0, Team::Number(TeamCounter::new(2))),
+                        (1, Team::Independent(IndependentCounter::new(0))),
+                    ],
+                    components
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn spawns_characters_on_single_team_regardless_of_selected_teams() -> Result<(), Error> {
+        run_test(
+            |world| {
+                let mut game_loading_status = GameLoadingStatus::new();
+                game_loading_status.character_augment_status = CharacterAugmentStatus::Prefab;
+                world.insert(game_loading_status);
+                world.insert(GameRules::survival());
+
+                let asset_id = first_character_asset_id(world);
+
+                let mut character_selections = CharacterSelections::default();
+                character_selections.selections.insert(0, asset_id);
+                character_selections.selections.insert(1, asset_id);
+                character_selections
+                    .team_counters
+                    .insert(0, TeamCounter::new(2));
+                world.insert(character_selections);
+            },
+            |world| {
+                let (input_controlleds, teams) = world.system_data::<TestSystemData<'_>>();
+                let components = (&input_controlleds, &teams)
+                    .join()
+                    .map(|(_, team)| *team)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    vec![
+                        Team::Number(TeamCounter::new(0)),
+                        Team::Number(TeamCounter::new(0)),
+                    ],
+                    components
+                );
+            },
+        )
+    }
+
     fn run_test(setup_fn: fn(&mut World), assertion_fn: fn(&mut World)) -> Result<(), Error> {
         AutexousiousApplication::config_base()
             .with_effect(<CharacterSelectionSpawningSystem as System>::SystemData::setup)
@@ -1,4 +1,7 @@
+mod character_grab_effect_system;
+mod character_grab_release_system;
 mod character_hit_effect_system;
+mod character_respawn_system;
 mod character_sequence_update_system;
 mod game_play_end_detection_system;
 mod game_play_end_transition_delay_system;
@@ -7,4 +10,6 @@ mod game_play_removal_augment_system;
 mod grounding_friction_system;
 mod object_kinematics_update_system;
 mod object_transform_update_system;
+mod player_stats_update_system;
 mod sequence;
+mod team_tint_system;
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterGrabTransitions;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_model::{
+        config::{Grab, GrabHoldDuration, Interaction, InteractionKind, Throw},
+        play::{GrabEvent, GrabHoldClock, GrabbedBy, Grabbing},
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use game_play::CharacterGrabEffectSystem;
+
+    #[test]
+    fn latches_grabbed_character_to_grabbing_character() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(character_grab_transitions())
+                    .with(Mirrored::new(true))
+                    .with(SequenceId::new(0))
+                    .build();
+                let entity_to = world
+                    .create_entity()
+                    .with(character_grab_transitions())
+                    .with(Mirrored::new(true))
+                    .with(Velocity::<f32>::new(3., 4., 5.))
+                    .with(SequenceId::new(0))
+                    .build();
+
+                send_event(world, entity_from, entity_to);
+
+                world.insert((entity_from, entity_to));
+            })
+            .with_assertion(|world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&Grabbing::new(
+                        entity_to,
+                        GrabHoldClock::new(10),
+                        Throw::default()
+                    )),
+                    world.read_storage::<Grabbing>().get(entity_from)
+                );
+                assert_eq!(
+                    Some(&GrabbedBy::new(entity_from)),
+                    world.read_storage::<GrabbedBy>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&TargetObject::new(entity_from)),
+                    world.read_storage::<TargetObject>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&ChaseModeStick::new(Some(Position::<f32>::new(
+                        -20., 0., 5.
+                    )))),
+                    world.read_storage::<ChaseModeStick>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&Mirrored::new(false)),
+                    world.read_storage::<Mirrored>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&Velocity::<f32>::default()),
+                    world.read_storage::<Velocity<f32>>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(entity_from)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(3)),
+                    world.read_storage::<SequenceId>().get(entity_to)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_grab_object_without_grab_transitions() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabEffectSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world
+                    .create_entity()
+                    .with(character_grab_transitions())
+                    .with(SequenceId::new(0))
+                    .build();
+                let entity_to = world.create_entity().with(SequenceId::new(0)).build();
+
+                send_event(world, entity_from, entity_to);
+
+                world.insert((entity_from, entity_to));
+            })
+            .with_assertion(|world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(!world.read_storage::<Grabbing>().contains(entity_from));
+                assert!(!world.read_storage::<GrabbedBy>().contains(entity_to));
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(entity_from)
+                );
+            })
+            .run()
+    }
+
+    fn character_grab_transitions() -> CharacterGrabTransitions {
+        CharacterGrabTransitions::new(
+            SequenceId::new(1),
+            SequenceId::new(2),
+            SequenceId::new(3),
+            SequenceId::new(4),
+            SequenceId::new(5),
+        )
+    }
+
+    fn send_event(world: &mut World, entity_from: Entity, entity_to: Entity) {
+        let grab = Grab::new(
+            GrabHoldDuration::new(10),
+            Position::<i32>::new(20, 0, 5),
+            Throw::default(),
+        );
+        let interaction = Interaction::new(InteractionKind::Grab(grab), vec![], true);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<GrabEvent>>();
+        ec.single_write(GrabEvent::new(entity_from, entity_to, interaction, body));
+    } // kcov-ignore
+}
@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterGrabTransitions;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_model::{
+        config::Throw,
+        play::{GrabHoldClock, GrabbedBy, Grabbing},
+    };
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use object_status_model::config::StunPoints;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+
+    use game_play::CharacterGrabReleaseSystem;
+
+    #[test]
+    fn keeps_holding_grabbed_character_before_hold_duration_ends() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id_grabber: SequenceId::new(1),
+                sequence_id_grabbed: SequenceId::new(3),
+                hold_duration: 10,
+            },
+            |world, entity_grabber, entity_grabbed| {
+                assert!(world.read_storage::<Grabbing>().contains(entity_grabber));
+                assert_attached(world, entity_grabbed, true);
+                assert_sequence_ids(
+                    world,
+                    (entity_grabber, entity_grabbed),
+                    (SequenceId::new(1), SequenceId::new(3)),
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn releases_grabbed_character_when_hold_duration_ends() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id_grabber: SequenceId::new(1),
+                sequence_id_grabbed: SequenceId::new(3),
+                hold_duration: 1,
+            },
+            |world, entity_grabber, entity_grabbed| {
+                assert!(!world.read_storage::<Grabbing>().contains(entity_grabber));
+                assert_attached(world, entity_grabbed, false);
+                assert_sequence_ids(
+                    world,
+                    (entity_grabber, entity_grabbed),
+                    (SequenceId::new(5), SequenceId::new(5)),
+                );
+                assert_eq!(
+                    Some(&HealthPoints::new(100)),
+                    world.read_storage::<HealthPoints>().get(entity_grabbed)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn releases_grabbed_character_when_hit() -> Result<(), Error> {
+        // Grabbed character has transitioned to a hit sequence.
+        run_test(
+            SetupParams {
+                sequence_id_grabber: SequenceId::new(1),
+                sequence_id_grabbed: SequenceId::new(6),
+                hold_duration: 10,
+            },
+            |world, entity_grabber, entity_grabbed| {
+                assert!(!world.read_storage::<Grabbing>().contains(entity_grabber));
+                assert_attached(world, entity_grabbed, false);
+                assert_sequence_ids(
+                    world,
+                    (entity_grabber, entity_grabbed),
+                    (SequenceId::new(5), SequenceId::new(6)),
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn throws_grabbed_character_with_throw_velocity() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                sequence_id_grabber: SequenceId::new(2),
+                sequence_id_grabbed: SequenceId::new(3),
+                hold_duration: 10,
+            },
+            |world, entity_grabber, entity_grabbed| {
+                assert!(!world.read_storage::<Grabbing>().contains(entity_grabber));
+                assert_attached(world, entity_grabbed, false);
+                assert_sequence_ids(
+                    world,
+                    (entity_grabber, entity_grabbed),
+                    (SequenceId::new(2), SequenceId::new(4)),
+                );
+                assert_eq!(
+                    Some(&HealthPoints::new(90)),
+                    world.read_storage::<HealthPoints>().get(entity_grabbed)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(20)),
+                    world.read_storage::<StunPoints>().get(entity_grabbed)
+                );
+                // Grabbing character is mirrored, so the throw is to the left.
+                assert_eq!(
+                    Some(&Velocity::<f32>::new(-5., 6., 7.)),
+                    world.read_storage::<Velocity<f32>>().get(entity_grabbed)
+                );
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            sequence_id_grabber,
+            sequence_id_grabbed,
+            hold_duration,
+        }: SetupParams,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterGrabReleaseSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let entity_grabber = world
+                    .create_entity()
+                    .with(character_grab_transitions())
+                    .with(Mirrored::new(true))
+                    .with(sequence_id_grabber)
+                    .build();
+                let entity_grabbed = world
+                    .create_entity()
+                    .with(character_grab_transitions())
+                    .with(Mirrored::new(false))
+                    .with(HealthPoints::new(100))
+                    .with(StunPoints::new(0))
+                    .with(Velocity::<f32>::default())
+                    .with(sequence_id_grabbed)
+                    .with(GrabbedBy::new(entity_grabber))
+                    .with(TargetObject::new(entity_grabber))
+                    .with(ChaseModeStick::new(None))
+                    .build();
+
+                world
+                    .write_storage::<Grabbing>()
+                    .insert(
+                        entity_grabber,
+                        Grabbing::new(
+                            entity_grabbed,
+                            GrabHoldClock::new(hold_duration),
+                            Throw::new(10, StunPoints::new(20), Velocity::<i32>::new(5, 6, 7)),
+                        ),
+                    )
+                    .expect("Failed to insert `Grabbing` component.");
+
+                world.insert((entity_grabber, entity_grabbed));
+            })
+            .with_assertion(move |world| {
+                let (entity_grabber, entity_grabbed) = *world.read_resource::<(Entity, Entity)>();
+
+                assertion_fn(world, entity_grabber, entity_grabbed);
+            })
+            .run()
+    }
+
+    fn character_grab_transitions() -> CharacterGrabTransitions {
+        CharacterGrabTransitions::new(
+            SequenceId::new(1),
+            SequenceId::new(2),
+            SequenceId::new(3),
+            SequenceId::new(4),
+            SequenceId::new(5),
+        )
+    }
+
+    fn assert_attached(world: &mut World, entity_grabbed: Entity, attached: bool) {
+        assert_eq!(
+            attached,
+            world.read_storage::<GrabbedBy>().contains(entity_grabbed)
+        );
+        assert_eq!(
+            attached,
+            world
+                .read_storage::<TargetObject>()
+                .contains(entity_grabbed)
+        );
+        assert_eq!(
+            attached,
+            world
+                .read_storage::<ChaseModeStick>()
+                .contains(entity_grabbed)
+        );
+    }
+
+    fn assert_sequence_ids(
+        world: &mut World,
+        (entity_grabber, entity_grabbed): (Entity, Entity),
+        (sequence_id_grabber, sequence_id_grabbed): (SequenceId, SequenceId),
+    ) {
+        let sequence_ids = world.read_storage::<SequenceId>();
+        assert_eq!(Some(&sequence_id_grabber), sequence_ids.get(entity_grabber));
+        assert_eq!(Some(&sequence_id_grabbed), sequence_ids.get(entity_grabbed));
+    }
+
+    struct SetupParams {
+        sequence_id_grabber: SequenceId,
+        sequence_id_grabbed: SequenceId,
+        hold_duration: usize,
+    }
+}
@@ -1,157 +1,417 @@
-use amethyst::{
-    ecs::{Read, ReadStorage, System, World, WriteStorage},
-    shred::{ResourceId, SystemData},
-    shrev::{EventChannel, ReaderId},
-};
-use character_model::loaded::CharacterHitTransitions;
-use collision_model::{
-    config::{Hit, Interaction, InteractionKind},
-    play::HitEvent,
-};
-use derivative::Derivative;
-use derive_new::new;
-use kinematic_model::config::Velocity;
-use mirrored_model::play::Mirrored;
-use object_model::play::HealthPoints;
-use object_status_model::config::StunPoints;
-use sequence_model::loaded::SequenceId;
-
-const STUN_THRESHOLD_LOW: StunPoints = StunPoints(40);
-const STUN_THRESHOLD_MID: StunPoints = StunPoints(80);
-const STUN_THRESHOLD_HIGH: StunPoints = StunPoints(120);
-
-/// Determines collision effects for characters.
-#[derive(Debug, Default, new)]
-pub struct CharacterHitEffectSystem {
-    /// Reader ID for the `HitEvent` event channel.
-    #[new(default)]
-    hit_event_rid: Option<ReaderId<HitEvent>>,
-}
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::{
+        CharacterDefendTransitions, CharacterHitTransitions, HitDirectionTransitions,
+        StunTransition,
+    };
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use object_status_model::config::{DefenseLimit, DefensePoints, StunPoints};
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
 
-#[derive(Derivative, SystemData)]
-#[derivative(Debug)]
-pub struct CharacterHitEffectSystemData<'s> {
-    /// `HitEvent` channel.
-    #[derivative(Debug = "ignore")]
-    pub hit_ec: Read<'s, EventChannel<HitEvent>>,
-    /// `Mirrored` components.
-    #[derivative(Debug = "ignore")]
-    pub mirroreds: ReadStorage<'s, Mirrored>,
-    /// `CharacterHitTransitions` components.
-    #[derivative(Debug = "ignore")]
-    pub character_hit_transitionses: ReadStorage<'s, CharacterHitTransitions>,
-    /// `HealthPoints` components.
-    #[derivative(Debug = "ignore")]
-    pub health_pointses: WriteStorage<'s, HealthPoints>,
-    /// `StunPoints` components.
-    #[derivative(Debug = "ignore")]
-    pub stun_pointses: WriteStorage<'s, StunPoints>,
-    /// `Velocity<f32>` components.
-    #[derivative(Debug = "ignore")]
-    pub velocities: WriteStorage<'s, Velocity<f32>>,
-    /// `SequenceId` components.
-    #[derivative(Debug = "ignore")]
-    pub sequence_ids: WriteStorage<'s, SequenceId>,
-}
+    use game_play::CharacterHitEffectSystem;
+
+    #[test]
+    fn transitions_to_stun_threshold_front_sequence_when_hit_from_front() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 0,
+                mirrored_to: true,
+                position_xs: None,
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 5,
+                sequence_id: SequenceId::new(1),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_to_stun_threshold_back_sequence_when_hit_from_back() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 0,
+                mirrored_to: false,
+                position_xs: None,
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 5,
+                sequence_id: SequenceId::new(2),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_to_next_stun_threshold_when_stun_points_exceed_limit() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 15,
+                mirrored_to: true,
+                position_xs: None,
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 20,
+                sequence_id: SequenceId::new(3),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_to_falling_when_stun_points_exceed_all_limits() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 40,
+                mirrored_to: false,
+                position_xs: None,
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 45,
+                sequence_id: SequenceId::new(6),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_to_falling_when_health_points_reach_zero() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 5,
+                stun_points: 0,
+                mirrored_to: true,
+                position_xs: None,
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 0,
+                stun_points: 5,
+                sequence_id: SequenceId::new(5),
+            },
+        )
+    }
 
-impl<'s> System<'s> for CharacterHitEffectSystem {
-    type SystemData = CharacterHitEffectSystemData<'s>;
-
-    fn run(
-        &mut self,
-        CharacterHitEffectSystemData {
-            hit_ec,
-            mirroreds,
-            character_hit_transitionses,
-            mut health_pointses,
-            mut stun_pointses,
-            mut velocities,
-            mut sequence_ids,
-        }: Self::SystemData,
-    ) {
-        // Read from channel
-        hit_ec
-            .read(
-                self.hit_event_rid
-                    .as_mut()
-                    .expect("Expected reader ID to exist for CharacterHitEffectSystem."),
-            )
-            .for_each(|ev| {
-                let mirrored = mirroreds
-                    .get(ev.from)
-                    .map(|mirrored| **mirrored)
-                    .unwrap_or(false);
-
-                let character_hit_transitions = character_hit_transitionses.get(ev.to);
-                let health_points = health_pointses.get_mut(ev.to);
-                let stun_points = stun_pointses.get_mut(ev.to);
-                let velocity = velocities.get_mut(ev.to);
-                let sequence_id = sequence_ids.get_mut(ev.to);
-
-                if let (
-                    Some(character_hit_transitions),
-                    Some(health_points),
-                    Some(stun_points),
-                    Some(velocity),
-                    Some(sequence_id),
-                ) = (
-                    character_hit_transitions,
-                    health_points,
-                    stun_points,
-                    velocity,
-                    sequence_id,
-                ) {
-                    // TODO: Split this system with health check system.
-                    let Interaction {
-                        kind:
-                            InteractionKind::Hit(Hit {
-                                hp_damage,
-                                stun,
-                                acceleration,
-                                ..
-                            }),
-                        ..
-                    } = ev.interaction;
-                    if health_points.0 < hp_damage {
-                        *health_points = HealthPoints(0);
-                    } else {
-                        (*health_points) -= hp_damage;
-                    }
-
-                    *stun_points += stun;
-
-                    if mirrored {
-                        velocity.x -= (*acceleration).x as f32;
-                    } else {
-                        velocity.x += (*acceleration).x as f32;
-                    }
-                    velocity.y += (*acceleration).y as f32;
-                    velocity.z += (*acceleration).z as f32;
-
-                    let next_sequence_id = if *health_points == 0 {
-                        character_hit_transitions.falling
-                    } else if *stun_points < STUN_THRESHOLD_LOW {
-                        character_hit_transitions.low_stun
-                    } else if *stun_points < STUN_THRESHOLD_MID {
-                        character_hit_transitions.mid_stun
-                    } else if *stun_points < STUN_THRESHOLD_HIGH {
-                        character_hit_transitions.high_stun
-                    } else {
-                        character_hit_transitions.falling
-                    };
-
-                    // Set sequence id
-                    *sequence_id = next_sequence_id;
+    #[test]
+    fn transitions_to_back_sequence_when_attacker_is_behind() -> Result<(), Error> {
+        // Attacker faces the character's back, even though they face opposite directions.
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 0,
+                mirrored_to: true,
+                position_xs: Some((10., 0.)),
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 5,
+                sequence_id: SequenceId::new(2),
+            },
+        )
+    }
+
+    #[test]
+    fn transitions_to_front_sequence_when_attacker_is_in_front() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                health_points: 100,
+                stun_points: 0,
+                mirrored_to: false,
+                position_xs: Some((10., 0.)),
+            },
+            hit(10, 5),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 5,
+                sequence_id: SequenceId::new(1),
+            },
+        )
+    }
+
+    #[test]
+    fn blocks_hit_from_front_when_defending() -> Result<(), Error> {
+        run_defend_test(
+            DefendSetupParams {
+                health_points: 100,
+                defense_points: 0,
+                mirrored_to: true,
+            },
+            defend_hit(10, 5, 2, 10),
+            ExpectedParams {
+                health_points: 98,
+                stun_points: 0,
+                sequence_id: SequenceId::new(8),
+            },
+            DefensePoints::new(10),
+        )
+    }
+
+    #[test]
+    fn takes_full_damage_when_hit_from_back_while_defending() -> Result<(), Error> {
+        run_defend_test(
+            DefendSetupParams {
+                health_points: 100,
+                defense_points: 0,
+                mirrored_to: false,
+            },
+            defend_hit(10, 5, 2, 10),
+            ExpectedParams {
+                health_points: 90,
+                stun_points: 5,
+                sequence_id: SequenceId::new(2),
+            },
+            DefensePoints::new(0),
+        )
+    }
+
+    #[test]
+    fn transitions_to_guard_break_when_defense_points_reach_limit() -> Result<(), Error> {
+        run_defend_test(
+            DefendSetupParams {
+                health_points: 100,
+                defense_points: 25,
+                mirrored_to: true,
+            },
+            defend_hit(10, 5, 2, 10),
+            ExpectedParams {
+                health_points: 98,
+                stun_points: 5,
+                sequence_id: SequenceId::new(9),
+            },
+            DefensePoints::new(0),
+        )
+    }
+
+    #[test]
+    fn transitions_to_falling_when_chip_damage_reduces_health_points_to_zero() -> Result<(), Error>
+    {
+        run_defend_test(
+            DefendSetupParams {
+                health_points: 1,
+                defense_points: 0,
+                mirrored_to: true,
+            },
+            defend_hit(10, 5, 2, 10),
+            ExpectedParams {
+                health_points: 0,
+                stun_points: 0,
+                sequence_id: SequenceId::new(5),
+            },
+            DefensePoints::new(10),
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            health_points: health_points_setup,
+            stun_points: stun_points_setup,
+            mirrored_to,
+            position_xs,
+        }: SetupParams,
+        hit: Hit,
+        ExpectedParams {
+            health_points: health_points_expected,
+            stun_points: stun_points_expected,
+            sequence_id: sequence_id_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterHitEffectSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let mut entity_from_builder = world.create_entity().with(Mirrored::new(false));
+                if let Some((position_x_from, _)) = position_xs {
+                    entity_from_builder =
+                        entity_from_builder.with(Position::<f32>::new(position_x_from, 0., 0.));
+                }
+                let entity_from = entity_from_builder.build();
+
+                let mut entity_to_builder = world
+                    .create_entity()
+                    .with(character_hit_transitions())
+                    .with(HealthPoints::new(health_points_setup))
+                    .with(StunPoints::new(stun_points_setup))
+                    .with(Velocity::<f32>::default())
+                    .with(SequenceId::new(0))
+                    .with(Mirrored::new(mirrored_to));
+                if let Some((_, position_x_to)) = position_xs {
+                    entity_to_builder =
+                        entity_to_builder.with(Position::<f32>::new(position_x_to, 0., 0.));
                 }
-            });
+                let entity_to = entity_to_builder.build();
+
+                send_event(world, entity_from, entity_to, hit);
+
+                world.insert(entity_to);
+            })
+            .with_assertion(move |world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&HealthPoints::new(health_points_expected)),
+                    world.read_storage::<HealthPoints>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(stun_points_expected)),
+                    world.read_storage::<StunPoints>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&sequence_id_expected),
+                    world.read_storage::<SequenceId>().get(entity_to)
+                );
+            })
+            .run()
+    }
+
+    fn run_defend_test(
+        DefendSetupParams {
+            health_points: health_points_setup,
+            defense_points: defense_points_setup,
+            mirrored_to,
+        }: DefendSetupParams,
+        hit: Hit,
+        ExpectedParams {
+            health_points: health_points_expected,
+            stun_points: stun_points_expected,
+            sequence_id: sequence_id_expected,
+        }: ExpectedParams,
+        defense_points_expected: DefensePoints,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterHitEffectSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let entity_from = world.create_entity().with(Mirrored::new(false)).build();
+                let entity_to = world
+                    .create_entity()
+                    .with(character_hit_transitions())
+                    .with(CharacterDefendTransitions::new(
+                        SequenceId::new(7),
+                        SequenceId::new(8),
+                        SequenceId::new(9),
+                    ))
+                    .with(HealthPoints::new(health_points_setup))
+                    .with(StunPoints::new(0))
+                    .with(DefensePoints::new(defense_points_setup))
+                    .with(DefenseLimit::new(30))
+                    .with(Velocity::<f32>::default())
+                    .with(SequenceId::new(7))
+                    .with(Mirrored::new(mirrored_to))
+                    .build();
+
+                send_event(world, entity_from, entity_to, hit);
+
+                world.insert(entity_to);
+            })
+            .with_assertion(move |world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&HealthPoints::new(health_points_expected)),
+                    world.read_storage::<HealthPoints>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&StunPoints::new(stun_points_expected)),
+                    world.read_storage::<StunPoints>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&defense_points_expected),
+                    world.read_storage::<DefensePoints>().get(entity_to)
+                );
+                assert_eq!(
+                    Some(&sequence_id_expected),
+                    world.read_storage::<SequenceId>().get(entity_to)
+                );
+            })
+            .run()
+    }
+
+    fn character_hit_transitions() -> CharacterHitTransitions {
+        CharacterHitTransitions::new(
+            vec![
+                StunTransition::new(
+                    StunPoints::new(10),
+                    HitDirectionTransitions::new(SequenceId::new(1), SequenceId::new(2)),
+                ),
+                StunTransition::new(
+                    StunPoints::new(30),
+                    HitDirectionTransitions::new(SequenceId::new(3), SequenceId::new(4)),
+                ),
+            ],
+            HitDirectionTransitions::new(SequenceId::new(5), SequenceId::new(6)),
+        )
+    }
+
+    fn hit(hp_damage: u32, stun: u32) -> Hit {
+        Hit {
+            hp_damage,
+            stun: StunPoints::new(stun),
+            ..Default::default()
+        }
+    }
+
+    fn defend_hit(hp_damage: u32, stun: u32, chip_damage: u32, guard_damage: u32) -> Hit {
+        Hit {
+            hp_damage,
+            stun: StunPoints::new(stun),
+            chip_damage,
+            guard_damage: DefensePoints::new(guard_damage),
+            ..Default::default()
+        }
+    }
+
+    fn send_event(world: &mut World, entity_from: Entity, entity_to: Entity, hit: Hit) {
+        let interaction = Interaction::new(InteractionKind::Hit(hit), vec![], true);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<HitEvent>>();
+        ec.single_write(HitEvent::new(entity_from, entity_to, interaction, body));
+    } // kcov-ignore
+
+    struct SetupParams {
+        health_points: u32,
+        stun_points: u32,
+        mirrored_to: bool,
+        /// X positions of the attacker and the character.
+        position_xs: Option<(f32, f32)>,
+    }
+
+    struct DefendSetupParams {
+        health_points: u32,
+        defense_points: u32,
+        mirrored_to: bool,
     }
 
-    fn setup(&mut self, world: &mut World) {
-        Self::SystemData::setup(world);
-        self.hit_event_rid = Some(
-            world
-                .fetch_mut::<EventChannel<HitEvent>>()
-                .register_reader(),
-        );
+    struct ExpectedParams {
+        health_points: u32,
+        stun_points: u32,
+        sequence_id: SequenceId,
     }
 }
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::GamePlayStatus;
+    use game_rules_model::{
+        config::GameRules,
+        play::{RespawnClock, Stocks},
+    };
+    use kinematic_model::config::{Position, Velocity};
+    use object_model::play::HealthPoints;
+
+    use game_play::CharacterRespawnSystem;
+
+    #[test]
+    fn knocked_out_character_loses_stock_and_waits_to_respawn() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(2),
+                respawn_clock: None,
+            },
+            ExpectedParams {
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(1),
+                respawn_clock: Some(RespawnClock::new_with_value(RESPAWN_DELAY, 1)),
+            },
+        )
+    }
+
+    #[test]
+    fn knocked_out_character_without_stocks_remaining_does_not_respawn() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(1),
+                respawn_clock: None,
+            },
+            ExpectedParams {
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(0),
+                respawn_clock: None,
+            },
+        )
+    }
+
+    #[test]
+    fn respawns_character_when_respawn_clock_completes() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(1),
+                respawn_clock: Some(RespawnClock::new_with_value(
+                    RESPAWN_DELAY,
+                    RESPAWN_DELAY - 1,
+                )),
+            },
+            ExpectedParams {
+                health_points: HealthPoints::default(),
+                stocks: Stocks::new(1),
+                respawn_clock: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_change_alive_character() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                health_points: HealthPoints::new(50),
+                stocks: Stocks::new(2),
+                respawn_clock: None,
+            },
+            ExpectedParams {
+                health_points: HealthPoints::new(50),
+                stocks: Stocks::new(2),
+                respawn_clock: None,
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_respawn_when_game_play_is_not_playing() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Ended,
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(2),
+                respawn_clock: None,
+            },
+            ExpectedParams {
+                health_points: HealthPoints::new(0),
+                stocks: Stocks::new(2),
+                respawn_clock: None,
+            },
+        )
+    }
+
+    /// Respawn delay of `GameRules::default()`.
+    const RESPAWN_DELAY: usize = 120;
+
+    fn run_test(
+        SetupParams {
+            game_play_status,
+            health_points,
+            stocks,
+            respawn_clock,
+        }: SetupParams,
+        ExpectedParams {
+            health_points: health_points_expected,
+            stocks: stocks_expected,
+            respawn_clock: respawn_clock_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(CharacterRespawnSystem::new(), "", &[])
+            .with_effect(move |world| {
+                world.insert(game_play_status);
+                world.insert(GameRules::default());
+
+                let mut entity_builder = world
+                    .create_entity()
+                    .with(health_points)
+                    .with(stocks)
+                    .with(Position::<f32>::new(10., 20., 30.))
+                    .with(Velocity::<f32>::new(1., 2., 3.));
+                if let Some(respawn_clock) = respawn_clock {
+                    entity_builder = entity_builder.with(respawn_clock);
+                }
+                let entity = entity_builder.build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let health_pointses = world.read_storage::<HealthPoints>();
+                let stockses = world.read_storage::<Stocks>();
+                let respawn_clocks = world.read_storage::<RespawnClock>();
+
+                assert_eq!(Some(&health_points_expected), health_pointses.get(entity));
+                assert_eq!(Some(&stocks_expected), stockses.get(entity));
+                assert_eq!(respawn_clock_expected.as_ref(), respawn_clocks.get(entity));
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        game_play_status: GamePlayStatus,
+        health_points: HealthPoints,
+        stocks: Stocks,
+        respawn_clock: Option<RespawnClock>,
+    }
+
+    struct ExpectedParams {
+        health_points: HealthPoints,
+        stocks: Stocks,
+        respawn_clock: Option<RespawnClock>,
+    }
+}
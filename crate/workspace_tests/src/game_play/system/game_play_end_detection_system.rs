@@ -7,7 +7,12 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
+    use game_input_model::play::GameTick;
     use game_play_model::{GamePlayEvent, GamePlayStatus};
+    use game_rules_model::{
+        config::GameRules,
+        play::{RespawnClock, Stocks, SurvivalWave},
+    };
     use game_stats_model::play::{WinOutcome, WinStatus};
     use object_model::play::HealthPoints;
     use std::any;
@@ -15,6 +20,11 @@ mod tests {
 
     use game_play::{GamePlayEndDetectionSystem, GamePlayEndDetectionSystemData};
 
+    /// Time limit of `GameRules::timed_stock()`.
+    const TIME_LIMIT: u64 = 3 * 60 * 60;
+    /// Wave count of `GameRules::survival()`.
+    const WAVE_COUNT: u32 = 5;
+
     #[test]
     fn does_not_send_game_play_end_event_when_game_play_is_not_playing() -> Result<(), Error> {
         run_test(
@@ -30,6 +40,7 @@ mod tests {
                         liveness: Liveness::Alive,
                     },
                 ],
+                ..Default::default()
             },
             ExpectedParams {
                 game_play_status: GamePlayStatus::Playing,
@@ -55,6 +66,7 @@ mod tests {
                         liveness: Liveness::Dead,
                     },
                 ],
+                ..Default::default()
             },
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
@@ -85,6 +97,7 @@ mod tests {
                         liveness: Liveness::Dead,
                     },
                 ],
+                ..Default::default()
             },
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
@@ -109,6 +122,7 @@ mod tests {
                         liveness: Liveness::Dead,
                     },
                 ],
+                ..Default::default()
             },
             ExpectedParams {
                 game_play_status: GamePlayStatus::Ended,
@@ -134,6 +148,7 @@ mod tests {
                         liveness: Liveness::Alive,
                     },
                 ],
+                ..Default::default()
             },
             ExpectedParams {
                 game_play_status: GamePlayStatus::Playing,
@@ -143,10 +158,254 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_send_game_play_end_event_when_knocked_out_team_is_respawning() -> Result<(), Error>
+    {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(0)),
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Respawning { stocks: 1 },
+                    },
+                ],
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![],
+                win_status: WinStatus::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_game_play_end_event_before_time_limit() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(0)),
+                        liveness: Liveness::Stocked {
+                            health_points: 100,
+                            stocks: 2,
+                        },
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Stocked {
+                            health_points: 100,
+                            stocks: 1,
+                        },
+                    },
+                ],
+                game_rules: GameRules::timed_stock(),
+                game_tick: GameTick::new(99),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![],
+                win_status: WinStatus::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_decision_to_team_with_most_stocks_when_timed_out() -> Result<(), Error> {
+        let winning_team = Team::Independent(IndependentCounter::new(0));
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: winning_team,
+                        liveness: Liveness::Stocked {
+                            health_points: 10,
+                            stocks: 2,
+                        },
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Stocked {
+                            health_points: 100,
+                            stocks: 1,
+                        },
+                    },
+                ],
+                game_rules: GameRules::timed_stock(),
+                game_tick: GameTick::new(TIME_LIMIT),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Decision { winning_team }),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_decision_to_team_with_most_health_points_when_stocks_tied() -> Result<(), Error> {
+        let winning_team = Team::Independent(IndependentCounter::new(1));
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(0)),
+                        liveness: Liveness::Stocked {
+                            health_points: 10,
+                            stocks: 2,
+                        },
+                    },
+                    ObjectStatus {
+                        team: winning_team,
+                        liveness: Liveness::Stocked {
+                            health_points: 20,
+                            stocks: 2,
+                        },
+                    },
+                ],
+                game_rules: GameRules::timed_stock(),
+                game_tick: GameTick::new(TIME_LIMIT),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Decision { winning_team }),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_draw_when_timed_out_and_teams_tied() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(0)),
+                        liveness: Liveness::Stocked {
+                            health_points: 50,
+                            stocks: 2,
+                        },
+                    },
+                    ObjectStatus {
+                        team: Team::Independent(IndependentCounter::new(1)),
+                        liveness: Liveness::Stocked {
+                            health_points: 50,
+                            stocks: 2,
+                        },
+                    },
+                ],
+                game_rules: GameRules::timed_stock(),
+                game_tick: GameTick::new(TIME_LIMIT),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Draw),
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_game_play_end_event_when_survival_waves_remain() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_PLAYERS,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_WAVE,
+                        liveness: Liveness::Dead,
+                    },
+                ],
+                game_rules: GameRules::survival(),
+                survival_wave: SurvivalWave::new(2),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Playing,
+                game_play_events: vec![],
+                win_status: WinStatus::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_survived_when_last_survival_wave_defeated() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_PLAYERS,
+                        liveness: Liveness::Alive,
+                    },
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_WAVE,
+                        liveness: Liveness::Dead,
+                    },
+                ],
+                game_rules: GameRules::survival(),
+                survival_wave: SurvivalWave::new(WAVE_COUNT),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Survived { waves: WAVE_COUNT }),
+            },
+        )
+    }
+
+    #[test]
+    fn sends_overwhelmed_when_players_knocked_out_in_survival() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_play_status: GamePlayStatus::Playing,
+                objects: vec![
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_PLAYERS,
+                        liveness: Liveness::Dead,
+                    },
+                    ObjectStatus {
+                        team: SurvivalWave::TEAM_WAVE,
+                        liveness: Liveness::Alive,
+                    },
+                ],
+                game_rules: GameRules::survival(),
+                survival_wave: SurvivalWave::new(3),
+                ..Default::default()
+            },
+            ExpectedParams {
+                game_play_status: GamePlayStatus::Ended,
+                game_play_events: vec![GamePlayEvent::End],
+                win_status: WinStatus::new(WinOutcome::Overwhelmed { wave: 3 }),
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             game_play_status: game_play_status_setup,
             objects,
+            game_rules,
+            game_tick,
+            survival_wave,
         }: SetupParams,
         ExpectedParams {
             game_play_status: game_play_status_expected,
@@ -156,18 +415,31 @@ mod tests {
     ) -> Result<(), Error> {
         AmethystApplication::blank()
             .with_resource(game_play_status_setup)
+            .with_resource(game_rules)
+            .with_resource(game_tick)
+            .with_resource(survival_wave)
             .with_setup(GamePlayEndDetectionSystemData::setup)
             .with_setup(register_event_reader)
             .with_effect(move |world| {
                 objects.into_iter().for_each(|object_status| {
                     let ObjectStatus { liveness, team } = object_status;
 
-                    let health_points = match liveness {
-                        Liveness::Alive => HealthPoints(100),
-                        Liveness::Dead => HealthPoints(0),
-                    };
-
-                    world.create_entity().with(team).with(health_points).build();
+                    let entity_builder = world.create_entity().with(team);
+                    match liveness {
+                        Liveness::Alive => entity_builder.with(HealthPoints(100)),
+                        Liveness::Dead => entity_builder.with(HealthPoints(0)),
+                        Liveness::Respawning { stocks } => entity_builder
+                            .with(HealthPoints(0))
+                            .with(Stocks(stocks))
+                            .with(RespawnClock::new(10)),
+                        Liveness::Stocked {
+                            health_points,
+                            stocks,
+                        } => entity_builder
+                            .with(HealthPoints(health_points))
+                            .with(Stocks(stocks)),
+                    }
+                    .build();
                 });
             })
             .with_system_single(
@@ -206,9 +478,13 @@ mod tests {
         assert_eq!(game_play_events_expected, game_play_events_actual);
     }
 
+    #[derive(Default)]
     struct SetupParams {
         game_play_status: GamePlayStatus,
         objects: Vec<ObjectStatus>,
+        game_rules: GameRules,
+        game_tick: GameTick,
+        survival_wave: SurvivalWave,
     }
 
     struct ExpectedParams {
@@ -225,5 +501,7 @@ mod tests {
     enum Liveness {
         Alive,
         Dead,
+        Respawning { stocks: u32 },
+        Stocked { health_points: u32, stocks: u32 },
     }
 }
@@ -0,0 +1,217 @@
+#[cfg(test)]
+mod tests {
+    use ai_model::play::AiControlled;
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use game_input_model::play::InputControlled;
+    use game_play_model::GamePlayStatus;
+    use game_rules_model::play::SurvivalWaveCharacter;
+    use game_stats_model::play::{PlayerPointsPrev, PlayerStats};
+    use object_model::play::{HealthPoints, SkillPoints};
+    use pretty_assertions::assert_eq;
+    use shape_model::Volume;
+
+    use game_play::PlayerStatsUpdateSystem;
+
+    #[test]
+    fn records_damage_dealt_and_taken_when_player_is_hit() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            80,
+            PlayerStats {
+                damage_dealt: 20,
+                hits_landed: 1,
+                combo_current: 1,
+                combo_max: 1,
+                combo_ticks_remaining: PlayerStats::COMBO_WINDOW_TICKS,
+                ticks_alive: 2,
+                ..Default::default()
+            },
+            PlayerStats {
+                damage_taken: 20,
+                ticks_alive: 2,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn records_knock_out_when_player_health_points_reach_zero() -> Result<(), Error> {
+        run_test(
+            GamePlayStatus::Playing,
+            0,
+            PlayerStats {
+                damage_dealt: 100,
+                hits_landed: 1,
+                combo_current: 1,
+                combo_max: 1,
+                combo_ticks_remaining: PlayerStats::COMBO_WINDOW_TICKS,
+                kos: 1,
+                ticks_alive: 2,
+                ..Default::default()
+            },
+            PlayerStats {
+                damage_taken: 100,
+                ticks_alive: 1,
+                ..Default::default()
+            },
+        )
+    }
+
+    #[test]
+    fn records_damage_taken_from_player_points_prev() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(PlayerStatsUpdateSystem::new(), "", &[])
+            .with_effect(|world| {
+                world.insert(GamePlayStatus::Playing);
+                let entity = world
+                    .create_entity()
+                    .with(InputControlled::new(0))
+                    .with(HealthPoints::new(70))
+                    .with(SkillPoints::new(40))
+                    .with(PlayerPointsPrev::new(
+                        HealthPoints::new(100),
+                        SkillPoints::new(50),
+                    ))
+                    .build();
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&PlayerStats {
+                        damage_taken: 30,
+                        sp_used: 10,
+                        ticks_alive: 1,
+                        ..Default::default()
+                    }),
+                    world.read_storage::<PlayerStats>().get(entity)
+                );
+                assert_eq!(
+                    Some(&PlayerPointsPrev::new(
+                        HealthPoints::new(70),
+                        SkillPoints::new(40)
+                    )),
+                    world.read_storage::<PlayerPointsPrev>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_record_stats_when_game_play_is_not_playing() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(PlayerStatsUpdateSystem::new(), "", &[])
+            .with_effect(|world| {
+                world.insert(GamePlayStatus::Paused);
+                let entity = world
+                    .create_entity()
+                    .with(InputControlled::new(0))
+                    .with(HealthPoints::new(100))
+                    .build();
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert!(world.read_storage::<PlayerStats>().get(entity).is_none());
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_record_stats_for_survival_wave_characters() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(PlayerStatsUpdateSystem::new(), "", &[])
+            .with_effect(|world| {
+                world.insert(GamePlayStatus::Playing);
+                let entity = world
+                    .create_entity()
+                    .with(AiControlled::new(0, Default::default()))
+                    .with(SurvivalWaveCharacter)
+                    .with(HealthPoints::new(100))
+                    .build();
+                world.insert(entity);
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert!(world.read_storage::<PlayerStats>().get(entity).is_none());
+            })
+            .run()
+    }
+
+    fn run_test(
+        game_play_status: GamePlayStatus,
+        health_points_to: u32,
+        player_stats_from_expected: PlayerStats,
+        player_stats_to_expected: PlayerStats,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(PlayerStatsUpdateSystem::new(), "", &[])
+            .with_effect(move |world| {
+                world.insert(game_play_status);
+
+                let entity_from = world
+                    .create_entity()
+                    .with(InputControlled::new(0))
+                    .with(HealthPoints::new(100))
+                    .with(SkillPoints::new(0))
+                    .build();
+                let entity_to = world
+                    .create_entity()
+                    .with(AiControlled::new(1, Default::default()))
+                    .with(HealthPoints::new(100))
+                    .with(SkillPoints::new(0))
+                    .build();
+
+                world.insert((entity_from, entity_to));
+            })
+            .with_effect(move |world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+
+                world
+                    .write_storage::<HealthPoints>()
+                    .insert(entity_to, HealthPoints::new(health_points_to))
+                    .expect("Failed to insert `HealthPoints` component.");
+
+                send_event(world, entity_from, entity_to);
+            })
+            .with_assertion(move |world| {
+                let (entity_from, entity_to) = *world.read_resource::<(Entity, Entity)>();
+                let player_statses = world.read_storage::<PlayerStats>();
+
+                assert_eq!(
+                    Some(&player_stats_from_expected),
+                    player_statses.get(entity_from)
+                );
+                assert_eq!(
+                    Some(&player_stats_to_expected),
+                    player_statses.get(entity_to)
+                );
+            })
+            .run()
+    }
+
+    fn send_event(world: &mut World, entity_from: Entity, entity_to: Entity) {
+        let hit = Hit::default();
+        let interaction = Interaction::new(InteractionKind::Hit(hit), vec![], true);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<HitEvent>>();
+        ec.single_write(HitEvent::new(entity_from, entity_to, interaction, body));
+    } // kcov-ignore
+}
@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::{AssetStorage, Loader},
+        ecs::{Builder, Entity, Read, ReadExpect, World, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AutexousiousApplication;
+    use approx::assert_relative_eq;
+    use sequence_model::play::FrameIndexClock;
+    use sprite_model::loaded::TintSequence;
+    use status_effect_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::ActiveStatusEffects,
+    };
+    use team_model::play::{IndependentCounter, Team, TeamCounter};
+
+    use game_play::TeamTintSystem;
+
+    #[test]
+    fn tints_object_on_numbered_team() -> Result<(), Error> {
+        run_test(Team::Number(TeamCounter::new(0)), false, false)
+    }
+
+    #[test]
+    fn does_not_tint_object_on_independent_team() -> Result<(), Error> {
+        run_test(Team::Independent(IndependentCounter::new(0)), false, true)
+    }
+
+    #[test]
+    fn does_not_tint_object_with_active_status_effect() -> Result<(), Error> {
+        run_test(Team::Number(TeamCounter::new(0)), true, true)
+    }
+
+    #[test]
+    fn blends_team_colour_with_sequence_tint_without_compounding() -> Result<(), Error> {
+        AutexousiousApplication::config_base()
+            .with_system(TeamTintSystem::new(), "", &[])
+            .with_effect(|world| {
+                let tint_sequence_handle = {
+                    let (loader, tint_sequence_assets) = world.system_data::<(
+                        ReadExpect<'_, Loader>,
+                        Read<'_, AssetStorage<TintSequence>>,
+                    )>();
+
+                    let tint_sequence = TintSequence::new(vec![Tint(Srgba::new(0.5, 1., 1., 1.))]);
+                    loader.load_from_data(tint_sequence, (), &tint_sequence_assets)
+                };
+
+                let entity = world
+                    .create_entity()
+                    .with(Team::Number(TeamCounter::new(0)))
+                    .with(tint_sequence_handle)
+                    .with(FrameIndexClock::new_with_value(1, 0))
+                    .with(Tint(Srgba::new(0.5, 1., 1., 1.)))
+                    .build();
+
+                world.insert(entity);
+            })
+            // Allow the `TintSequence` to be processed.
+            .with_effect(|_| {})
+            .with_assertion(|world| assert_tint(world, [0.4825, 0.7025, 0.7025, 1.]))
+            .with_assertion(|world| assert_tint(world, [0.4825, 0.7025, 0.7025, 1.]))
+            .run_winit_loop()
+    }
+
+    fn run_test(
+        team: Team,
+        with_status_effect: bool,
+        tint_white_expected: bool,
+    ) -> Result<(), Error> {
+        let tint_white = Tint(Srgba::new(1., 1., 1., 1.));
+
+        AmethystApplication::blank()
+            .with_system(TeamTintSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let mut active_status_effects = ActiveStatusEffects::default();
+                if with_status_effect {
+                    active_status_effects.apply(StatusEffect::new(
+                        StatusEffectKind::Freeze,
+                        10,
+                        0,
+                        2,
+                    ));
+                }
+
+                let entity = world
+                    .create_entity()
+                    .with(team)
+                    .with(active_status_effects)
+                    .with(tint_white)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let tints = world.read_storage::<Tint>();
+                let tint = tints.get(entity).expect("Expected `Tint` to exist.");
+
+                assert_eq!(tint_white_expected, &tint_white == tint);
+            })
+            .run()
+    }
+
+    fn assert_tint(world: &mut World, [r, g, b, a]: [f32; 4]) {
+        let entity = *world.read_resource::<Entity>();
+        let tints = world.read_storage::<Tint>();
+        let Tint(tint) = *tints.get(entity).expect("Expected `Tint` to exist.");
+
+        assert_relative_eq!(r, tint.red, epsilon = 1e-5);
+        assert_relative_eq!(g, tint.green, epsilon = 1e-5);
+        assert_relative_eq!(b, tint.blue, epsilon = 1e-5);
+        assert_relative_eq!(a, tint.alpha, epsilon = 1e-5);
+    }
+}
@@ -0,0 +1,2 @@
+mod game_play_stats_event_stdin_mapper;
+mod game_play_stats_stdio_bundle;
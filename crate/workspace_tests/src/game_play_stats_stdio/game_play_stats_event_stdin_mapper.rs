@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use game_play_stats_model::{GamePlayStatsEventArgs, GamePlayStatsIndex};
+    use menu_model::MenuEvent;
+    use stdio_spi::StdinMapper;
+
+    use game_play_stats_stdio::GamePlayStatsEventStdinMapper;
+
+    #[test]
+    fn maps_select_event() {
+        let args = GamePlayStatsEventArgs::Select {
+            index: GamePlayStatsIndex::Rematch,
+        };
+
+        let result = GamePlayStatsEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            MenuEvent::Select(GamePlayStatsIndex::Rematch),
+            result.unwrap()
+        )
+    }
+
+    #[test]
+    fn maps_close_event() {
+        let args = GamePlayStatsEventArgs::Close;
+
+        let result = GamePlayStatsEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(MenuEvent::Close, result.unwrap())
+    }
+}
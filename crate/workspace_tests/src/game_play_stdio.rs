@@ -1 +1,2 @@
 mod game_play_event_stdin_mapper;
+mod game_play_pause_event_stdin_mapper;
@@ -21,8 +21,11 @@ mod tests {
 
     test_mapping!(maps_return_event, Return);
     test_mapping!(maps_restart_event, Restart);
+    test_mapping!(maps_restart_notify_event, RestartNotify);
     test_mapping!(maps_pause_event, Pause);
+    test_mapping!(maps_pause_notify_event, PauseNotify);
     test_mapping!(maps_resume_event, Resume);
+    test_mapping!(maps_resume_notify_event, ResumeNotify);
     test_mapping!(maps_end_event, End);
     test_mapping!(maps_end_stats_event, EndStats);
 }
@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use game_play_model::{GamePlayPauseEventArgs, GamePlayPauseIndex};
+    use menu_model::MenuEvent;
+    use stdio_spi::StdinMapper;
+
+    use game_play_stdio::GamePlayPauseEventStdinMapper;
+
+    #[test]
+    fn maps_select_event() {
+        let args = GamePlayPauseEventArgs::Select {
+            index: GamePlayPauseIndex::Resume,
+        };
+
+        let result = GamePlayPauseEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            MenuEvent::Select(GamePlayPauseIndex::Resume),
+            result.unwrap()
+        )
+    }
+
+    #[test]
+    fn maps_close_event() {
+        let args = GamePlayPauseEventArgs::Close;
+
+        let result = GamePlayPauseEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(MenuEvent::Close, result.unwrap())
+    }
+}
@@ -0,0 +1,51 @@
+#[cfg(test)]
+mod tests {
+    use game_rules_model::config::{GameRules, TeamAssignment, WinCondition};
+
+    #[test]
+    fn default_rules_are_single_stock_last_team_standing() {
+        let game_rules = GameRules::default();
+
+        assert_eq!(None, game_rules.time_limit);
+        assert_eq!(1, game_rules.stocks);
+        assert_eq!(TeamAssignment::Independent, game_rules.team_assignment);
+        assert!(!game_rules.friendly_fire);
+        assert_eq!(WinCondition::LastTeamStanding, game_rules.win_condition);
+    }
+
+    #[test]
+    fn deserializes_missing_fields_as_default() {
+        let game_rules = serde_yaml::from_str::<GameRules>("stocks: 3")
+            .expect("Failed to deserialize `GameRules`.");
+
+        let expected = GameRules {
+            stocks: 3,
+            ..Default::default()
+        };
+        assert_eq!(expected, game_rules);
+    }
+
+    #[test]
+    fn deserializes_win_condition_and_team_assignment() {
+        let game_rules = serde_yaml::from_str::<GameRules>(
+            "---
+time_limit: 600
+team_assignment:
+  round_robin:
+    team_count: 3
+win_condition:
+  survival_waves:
+    wave_count: 4
+",
+        )
+        .expect("Failed to deserialize `GameRules`.");
+
+        let expected = GameRules {
+            time_limit: Some(600),
+            team_assignment: TeamAssignment::RoundRobin { team_count: 3 },
+            win_condition: WinCondition::SurvivalWaves { wave_count: 4 },
+            ..Default::default()
+        };
+        assert_eq!(expected, game_rules);
+    }
+}
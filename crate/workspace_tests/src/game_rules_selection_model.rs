@@ -0,0 +1 @@
+mod game_rules_index;
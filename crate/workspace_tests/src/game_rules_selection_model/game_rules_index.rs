@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use game_rules_model::config::{GameRules, TeamAssignment, WinCondition};
+
+    use game_rules_selection_model::GameRulesIndex;
+
+    #[test]
+    fn classic_rules_are_default_rules() {
+        assert_eq!(GameRules::default(), GameRulesIndex::Classic.game_rules());
+    }
+
+    #[test]
+    fn timed_stock_rules_have_time_limit_and_stocks() {
+        let game_rules = GameRulesIndex::TimedStock.game_rules();
+
+        assert!(game_rules.time_limit.is_some());
+        assert!(game_rules.stocks > 1);
+    }
+
+    #[test]
+    fn survival_rules_use_survival_waves() {
+        let game_rules = GameRulesIndex::Survival.game_rules();
+
+        assert_eq!(TeamAssignment::Single, game_rules.team_assignment);
+        assert!(matches!(
+            game_rules.win_condition,
+            WinCondition::SurvivalWaves { .. }
+        ));
+    }
+
+    #[test]
+    fn team_battle_rules_assign_two_teams() {
+        let game_rules = GameRulesIndex::TeamBattle.game_rules();
+
+        assert_eq!(
+            TeamAssignment::RoundRobin { team_count: 2 },
+            game_rules.team_assignment
+        );
+    }
+}
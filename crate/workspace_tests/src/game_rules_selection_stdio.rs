@@ -0,0 +1,2 @@
+mod game_rules_selection_event_stdin_mapper;
+mod game_rules_selection_stdio_bundle;
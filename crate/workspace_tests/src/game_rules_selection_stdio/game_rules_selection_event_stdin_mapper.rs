@@ -0,0 +1,33 @@
+#[cfg(test)]
+mod tests {
+    use game_rules_selection_model::{GameRulesIndex, GameRulesSelectionEventArgs};
+    use menu_model::MenuEvent;
+    use stdio_spi::StdinMapper;
+
+    use game_rules_selection_stdio::GameRulesSelectionEventStdinMapper;
+
+    #[test]
+    fn maps_select_event() {
+        let args = GameRulesSelectionEventArgs::Select {
+            index: GameRulesIndex::TimedStock,
+        };
+
+        let result = GameRulesSelectionEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(
+            MenuEvent::Select(GameRulesIndex::TimedStock),
+            result.unwrap()
+        )
+    }
+
+    #[test]
+    fn maps_close_event() {
+        let args = GameRulesSelectionEventArgs::Close;
+
+        let result = GameRulesSelectionEventStdinMapper::map(&(), args);
+
+        assert!(result.is_ok());
+        assert_eq!(MenuEvent::Close, result.unwrap())
+    }
+}
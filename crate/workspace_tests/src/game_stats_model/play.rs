@@ -0,0 +1 @@
+mod player_stats;
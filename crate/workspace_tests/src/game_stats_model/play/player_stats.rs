@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use game_stats_model::play::PlayerStats;
+
+    #[test]
+    fn hit_land_starts_combo() {
+        let mut player_stats = PlayerStats::default();
+
+        player_stats.hit_land();
+
+        assert_eq!(
+            PlayerStats {
+                hits_landed: 1,
+                combo_current: 1,
+                combo_max: 1,
+                combo_ticks_remaining: PlayerStats::COMBO_WINDOW_TICKS,
+                ..Default::default()
+            },
+            player_stats
+        );
+    }
+
+    #[test]
+    fn hit_land_continues_combo_within_window() {
+        let mut player_stats = PlayerStats::default();
+
+        player_stats.hit_land();
+        player_stats.combo_tick();
+        player_stats.hit_land();
+
+        assert_eq!(2, player_stats.hits_landed);
+        assert_eq!(2, player_stats.combo_current);
+        assert_eq!(2, player_stats.combo_max);
+        assert_eq!(
+            PlayerStats::COMBO_WINDOW_TICKS,
+            player_stats.combo_ticks_remaining
+        );
+    }
+
+    #[test]
+    fn combo_tick_ends_combo_when_window_lapses() {
+        let mut player_stats = PlayerStats::default();
+
+        player_stats.hit_land();
+        player_stats.hit_land();
+        (0..PlayerStats::COMBO_WINDOW_TICKS).for_each(|_| player_stats.combo_tick());
+
+        assert_eq!(0, player_stats.combo_current);
+        assert_eq!(2, player_stats.combo_max);
+
+        player_stats.hit_land();
+
+        assert_eq!(3, player_stats.hits_landed);
+        assert_eq!(1, player_stats.combo_current);
+        assert_eq!(2, player_stats.combo_max);
+    }
+}
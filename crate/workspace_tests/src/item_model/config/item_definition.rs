@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod test {
+    use collision_model::config::Body;
+    use indexmap::IndexMap;
+    use object_model::config::{ObjectDefinition, ObjectFrame, ObjectSequence};
+    use sequence_model::config::{Sequence, SequenceEndTransition, SequenceNameString, Wait};
+    use serde_yaml;
+    use shape_model::Volume;
+    use sprite_model::config::SpriteRef;
+
+    use item_model::config::{ItemDefinition, ItemFrame, ItemSequence, ItemSequenceName};
+
+    const OBJECT_YAML: &str = r#"---
+sequences:
+  on_ground:
+    next: "on_ground"
+    frames:
+      - wait: 5
+        sprite: { sheet: 0, index: 2 }
+        body: [{ box: { x: 10, y: 5, w: 20, h: 8 } }]
+"#;
+
+    #[test]
+    fn deserialize_item_definition() {
+        let item_definition = serde_yaml::from_str::<ItemDefinition>(OBJECT_YAML)
+            .expect("Failed to deserialize `ItemDefinition`.");
+
+        let frames = vec![ItemFrame::new(ObjectFrame {
+            wait: Wait::new(5),
+            sprite: SpriteRef::new(0, 2),
+            body: Body::new(vec![Volume::Box {
+                x: 10,
+                y: 5,
+                z: 0,
+                w: 20,
+                h: 8,
+                d: 26,
+            }]),
+            ..Default::default()
+        })];
+        let sequence = ItemSequence::new(ObjectSequence {
+            sequence: Sequence {
+                next: SequenceEndTransition::SequenceName(SequenceNameString::Name(
+                    ItemSequenceName::OnGround,
+                )),
+                frames,
+            },
+            ..Default::default()
+        });
+        let mut sequences = IndexMap::new();
+        sequences.insert(
+            SequenceNameString::Name(ItemSequenceName::OnGround),
+            sequence,
+        );
+        let object_definition = ObjectDefinition::new(sequences);
+        let expected = ItemDefinition::new(object_definition);
+        assert_eq!(expected, item_definition);
+    }
+}
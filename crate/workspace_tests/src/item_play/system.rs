@@ -0,0 +1,3 @@
+mod item_held_system;
+mod item_pick_up_system;
+mod item_throw_system;
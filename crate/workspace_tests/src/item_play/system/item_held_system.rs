@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use item_model::{
+        config::WeaponPoint,
+        loaded::ItemTransitions,
+        play::{HeldBy, HeldItem},
+    };
+    use kinematic_model::config::Position;
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+
+    use item_play::ItemHeldSystem;
+
+    #[test]
+    fn sticks_item_to_holder_weapon_point() -> Result<(), Error> {
+        run_test(
+            false,
+            HealthPoints::new(100),
+            |world, entity_holder, entity_item| {
+                assert_eq!(
+                    Some(&ChaseModeStick::new(Some(Position::<f32>::new(
+                        10., 20., 3.
+                    )))),
+                    world.read_storage::<ChaseModeStick>().get(entity_item)
+                );
+                assert_eq!(
+                    Some(&HeldItem::new(entity_item)),
+                    world.read_storage::<HeldItem>().get(entity_holder)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn mirrors_weapon_point_when_holder_is_mirrored() -> Result<(), Error> {
+        run_test(
+            true,
+            HealthPoints::new(100),
+            |world, _entity_holder, entity_item| {
+                assert_eq!(
+                    Some(&ChaseModeStick::new(Some(Position::<f32>::new(
+                        -10., 20., 3.
+                    )))),
+                    world.read_storage::<ChaseModeStick>().get(entity_item)
+                );
+                assert_eq!(
+                    Some(&Mirrored::new(true)),
+                    world.read_storage::<Mirrored>().get(entity_item)
+                );
+            },
+        )
+    }
+
+    #[test]
+    fn drops_item_when_holder_has_no_health_points() -> Result<(), Error> {
+        run_test(
+            false,
+            HealthPoints::new(0),
+            |world, entity_holder, entity_item| {
+                assert!(!world.read_storage::<HeldBy>().contains(entity_item));
+                assert!(!world.read_storage::<HeldItem>().contains(entity_holder));
+                assert!(!world.read_storage::<TargetObject>().contains(entity_item));
+                assert!(!world.read_storage::<ChaseModeStick>().contains(entity_item));
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(entity_item)
+                );
+            },
+        )
+    }
+
+    fn run_test(
+        mirrored: bool,
+        health_points: HealthPoints,
+        assertion_fn: fn(&mut World, Entity, Entity),
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemHeldSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let entity_item = world
+                    .create_entity()
+                    .with(ItemTransitions::new(
+                        SequenceId::new(0),
+                        SequenceId::new(1),
+                        SequenceId::new(2),
+                    ))
+                    .with(SequenceId::new(1))
+                    .with(ChaseModeStick::new(None))
+                    .with(Mirrored::new(false))
+                    .build();
+                let entity_holder = world
+                    .create_entity()
+                    .with(HeldItem::new(entity_item))
+                    .with(WeaponPoint::new(Position::<i32>::new(10, 20, 3)))
+                    .with(Mirrored::new(mirrored))
+                    .with(health_points)
+                    .build();
+
+                {
+                    let mut held_bys = world.write_storage::<HeldBy>();
+                    held_bys
+                        .insert(entity_item, HeldBy::new(entity_holder))
+                        .expect("Failed to insert `HeldBy` component.");
+                }
+                {
+                    let mut target_objects = world.write_storage::<TargetObject>();
+                    target_objects
+                        .insert(entity_item, TargetObject::new(entity_holder))
+                        .expect("Failed to insert `TargetObject` component.");
+                }
+
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(move |world| {
+                let (entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+                assertion_fn(world, entity_holder, entity_item);
+            })
+            .run()
+    }
+}
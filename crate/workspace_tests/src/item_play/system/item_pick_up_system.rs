@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use collision_model::{
+        config::{Interaction, InteractionKind, PickUp},
+        play::PickUpEvent,
+    };
+    use item_model::{
+        loaded::ItemTransitions,
+        play::{HeldBy, HeldItem},
+    };
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+
+    use item_play::ItemPickUpSystem;
+
+    #[test]
+    fn holds_item_when_picked_up() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_holder = world.create_entity().build();
+                let entity_item = world
+                    .create_entity()
+                    .with(item_transitions())
+                    .with(SequenceId::new(0))
+                    .build();
+
+                send_event(world, entity_holder, entity_item);
+
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&HeldBy::new(entity_holder)),
+                    world.read_storage::<HeldBy>().get(entity_item)
+                );
+                assert_eq!(
+                    Some(&HeldItem::new(entity_item)),
+                    world.read_storage::<HeldItem>().get(entity_holder)
+                );
+                assert_eq!(
+                    Some(&TargetObject::new(entity_holder)),
+                    world.read_storage::<TargetObject>().get(entity_item)
+                );
+                assert!(world.read_storage::<ChaseModeStick>().contains(entity_item));
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(entity_item)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_hold_non_item() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_holder = world.create_entity().build();
+                let entity_item = world.create_entity().build();
+
+                send_event(world, entity_holder, entity_item);
+
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(!world.read_storage::<HeldBy>().contains(entity_item));
+                assert!(!world.read_storage::<HeldItem>().contains(entity_holder));
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_hold_item_when_holder_already_holds_item() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemPickUpSystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_held = world.create_entity().build();
+                let entity_holder = world
+                    .create_entity()
+                    .with(HeldItem::new(entity_held))
+                    .build();
+                let entity_item = world
+                    .create_entity()
+                    .with(item_transitions())
+                    .with(SequenceId::new(0))
+                    .build();
+
+                send_event(world, entity_holder, entity_item);
+
+                world.insert((entity_held, entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (entity_held, entity_holder, entity_item) =
+                    *world.read_resource::<(Entity, Entity, Entity)>();
+
+                assert!(!world.read_storage::<HeldBy>().contains(entity_item));
+                assert_eq!(
+                    Some(&HeldItem::new(entity_held)),
+                    world.read_storage::<HeldItem>().get(entity_holder)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(entity_item)
+                );
+            })
+            .run()
+    }
+
+    fn item_transitions() -> ItemTransitions {
+        ItemTransitions::new(SequenceId::new(0), SequenceId::new(1), SequenceId::new(2))
+    }
+
+    fn send_event(world: &mut World, entity_holder: Entity, entity_item: Entity) {
+        let interaction = Interaction::new(InteractionKind::PickUp(PickUp::new()), vec![], true);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<PickUpEvent>>();
+        ec.single_write(PickUpEvent::new(
+            entity_holder,
+            entity_item,
+            interaction,
+            body,
+        ));
+    } // kcov-ignore
+}
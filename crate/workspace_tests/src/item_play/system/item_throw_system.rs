@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterGrabTransitions;
+    use chase_model::play::{ChaseModeStick, TargetObject};
+    use item_model::{
+        loaded::ItemTransitions,
+        play::{HeldBy, HeldItem},
+    };
+    use kinematic_model::config::Velocity;
+    use mirrored_model::play::Mirrored;
+    use pretty_assertions::assert_eq;
+    use sequence_model::loaded::SequenceId;
+
+    use item_play::ItemThrowSystem;
+
+    const SEQUENCE_ID_THROW: SequenceId = SequenceId(11);
+
+    #[test]
+    fn throws_item_when_holder_in_throw_sequence() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                let (entity_holder, entity_item) = create_entities(world, SEQUENCE_ID_THROW);
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert!(!world.read_storage::<HeldBy>().contains(entity_item));
+                assert!(!world.read_storage::<HeldItem>().contains(entity_holder));
+                assert!(!world.read_storage::<TargetObject>().contains(entity_item));
+                assert!(!world.read_storage::<ChaseModeStick>().contains(entity_item));
+                assert_eq!(
+                    Some(&SequenceId::new(2)),
+                    world.read_storage::<SequenceId>().get(entity_item)
+                );
+
+                let velocities = world.read_storage::<Velocity<f32>>();
+                let velocity = velocities
+                    .get(entity_item)
+                    .expect("Expected `Velocity<f32>` component to exist.");
+                assert!(velocity.x > 0.);
+                assert!(velocity.y > 0.);
+            })
+            .run()
+    }
+
+    #[test]
+    fn throws_item_in_direction_holder_faces() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                let (entity_holder, entity_item) = create_entities(world, SEQUENCE_ID_THROW);
+                world
+                    .write_storage::<Mirrored>()
+                    .insert(entity_holder, Mirrored::new(true))
+                    .expect("Failed to insert `Mirrored` component.");
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (_entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+
+                let velocities = world.read_storage::<Velocity<f32>>();
+                let velocity = velocities
+                    .get(entity_item)
+                    .expect("Expected `Velocity<f32>` component to exist.");
+                assert!(velocity.x < 0.);
+            })
+            .run()
+    }
+
+    #[test]
+    fn keeps_item_held_when_holder_not_in_throw_sequence() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(ItemThrowSystem::new(), "", &[])
+            .with_effect(|world| {
+                let (entity_holder, entity_item) = create_entities(world, SequenceId::new(0));
+                world.insert((entity_holder, entity_item));
+            })
+            .with_assertion(|world| {
+                let (entity_holder, entity_item) = *world.read_resource::<(Entity, Entity)>();
+
+                assert_eq!(
+                    Some(&HeldBy::new(entity_holder)),
+                    world.read_storage::<HeldBy>().get(entity_item)
+                );
+                assert_eq!(
+                    Some(&HeldItem::new(entity_item)),
+                    world.read_storage::<HeldItem>().get(entity_holder)
+                );
+                assert_eq!(
+                    Some(&SequenceId::new(1)),
+                    world.read_storage::<SequenceId>().get(entity_item)
+                );
+                assert_eq!(
+                    Some(&Velocity::default()),
+                    world.read_storage::<Velocity<f32>>().get(entity_item)
+                );
+            })
+            .run()
+    }
+
+    fn create_entities(world: &mut World, sequence_id_holder: SequenceId) -> (Entity, Entity) {
+        let entity_holder = world
+            .create_entity()
+            .with(character_grab_transitions())
+            .with(sequence_id_holder)
+            .build();
+        let entity_item = world
+            .create_entity()
+            .with(ItemTransitions::new(
+                SequenceId::new(0),
+                SequenceId::new(1),
+                SequenceId::new(2),
+            ))
+            .with(SequenceId::new(1))
+            .with(Velocity::<f32>::default())
+            .with(HeldBy::new(entity_holder))
+            .with(TargetObject::new(entity_holder))
+            .with(ChaseModeStick::new(None))
+            .build();
+        world
+            .write_storage::<HeldItem>()
+            .insert(entity_holder, HeldItem::new(entity_item))
+            .expect("Failed to insert `HeldItem` component.");
+
+        (entity_holder, entity_item)
+    }
+
+    fn character_grab_transitions() -> CharacterGrabTransitions {
+        CharacterGrabTransitions::new(
+            SequenceId::new(10),
+            SEQUENCE_ID_THROW,
+            SequenceId::new(12),
+            SequenceId::new(13),
+            SequenceId::new(0),
+        )
+    }
+}
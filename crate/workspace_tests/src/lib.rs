@@ -9,6 +9,10 @@ extern crate hamcrest;
 #[macro_use]
 extern crate pretty_assertions;
 
+#[cfg(test)]
+mod ai_model;
+#[cfg(test)]
+mod ai_play;
 #[cfg(test)]
 mod application;
 #[cfg(test)]
@@ -54,10 +58,16 @@ mod character_prefab;
 #[cfg(test)]
 mod character_selection;
 #[cfg(test)]
+mod character_selection_model;
+#[cfg(test)]
 mod character_selection_ui_model;
 #[cfg(test)]
 mod charge_play;
 #[cfg(test)]
+mod chase_loading;
+#[cfg(test)]
+mod chase_model;
+#[cfg(test)]
 mod chase_play;
 #[cfg(test)]
 mod collision_audio_loading;
@@ -72,10 +82,16 @@ mod collision_play;
 #[cfg(test)]
 mod debug_util_amethyst;
 #[cfg(test)]
+mod desync_model;
+#[cfg(test)]
+mod desync_play;
+#[cfg(test)]
 mod energy_loading;
 #[cfg(test)]
 mod energy_model;
 #[cfg(test)]
+mod energy_play;
+#[cfg(test)]
 mod energy_prefab;
 #[cfg(test)]
 mod game_input;
@@ -96,14 +112,30 @@ mod game_play;
 #[cfg(test)]
 mod game_play_hud;
 #[cfg(test)]
+mod game_play_stats_stdio;
+#[cfg(test)]
 mod game_play_stdio;
 #[cfg(test)]
+mod game_rules_model;
+#[cfg(test)]
+mod game_rules_selection_model;
+#[cfg(test)]
+mod game_rules_selection_stdio;
+#[cfg(test)]
+mod game_stats_model;
+#[cfg(test)]
 mod input_reaction_loading;
 #[cfg(test)]
 mod input_reaction_model;
 #[cfg(test)]
 mod input_reaction_play;
 #[cfg(test)]
+mod item_loading;
+#[cfg(test)]
+mod item_model;
+#[cfg(test)]
+mod item_play;
+#[cfg(test)]
 mod kinematic_loading;
 #[cfg(test)]
 mod kinematic_model;
@@ -120,12 +152,20 @@ mod map_play;
 #[cfg(test)]
 mod map_selection;
 #[cfg(test)]
+mod map_selection_model;
+#[cfg(test)]
 mod map_selection_ui_model;
 #[cfg(test)]
+mod net_model;
+#[cfg(test)]
 mod network_input_play;
 #[cfg(test)]
 mod network_mode_selection_stdio;
 #[cfg(test)]
+mod network_session_model;
+#[cfg(test)]
+mod network_session_play;
+#[cfg(test)]
 mod object_loading;
 #[cfg(test)]
 mod object_model;
@@ -136,6 +176,14 @@ mod object_status_play;
 #[cfg(test)]
 mod parent_play;
 #[cfg(test)]
+mod replay_model;
+#[cfg(test)]
+mod replay_play;
+#[cfg(test)]
+mod rollback_model;
+#[cfg(test)]
+mod rollback_play;
+#[cfg(test)]
 mod sequence_loading;
 #[cfg(test)]
 mod sequence_play;
@@ -152,6 +200,10 @@ mod session_lobby_play;
 #[cfg(test)]
 mod session_lobby_ui_model;
 #[cfg(test)]
+mod session_reconnect_play;
+#[cfg(test)]
+mod session_server;
+#[cfg(test)]
 mod spawn_loading;
 #[cfg(test)]
 mod spawn_model;
@@ -166,6 +218,10 @@ mod sprite_play;
 #[cfg(test)]
 mod state_play;
 #[cfg(test)]
+mod status_effect_model;
+#[cfg(test)]
+mod status_effect_play;
+#[cfg(test)]
 mod stdio_command_stdio;
 #[cfg(test)]
 mod stdio_input;
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use asset_model::{
+        config::AssetSlug,
+        loaded::{AssetId, AssetIdMappings},
+    };
+    use game_model::play::{GameRng, RngSeed};
+
+    use map_selection_model::MapSelection;
+
+    #[test]
+    fn random_resolve_selects_map_for_random_selection() {
+        let asset_ids = asset_ids();
+        let mut map_selection = MapSelection::Random(None);
+
+        let mut game_rng = GameRng::new(RngSeed::new(123));
+        map_selection.random_resolve(&asset_ids, &mut *game_rng);
+
+        if let MapSelection::Random(Some(asset_id)) = map_selection {
+            assert!(asset_ids.contains(&asset_id));
+        } else {
+            panic!(
+                "Expected map selection to be `Random(Some(..))`, but was: `{:?}`.",
+                map_selection
+            );
+        }
+    }
+
+    #[test]
+    fn random_resolve_is_reproducible_with_same_seed() {
+        let asset_ids = asset_ids();
+        let resolve = || {
+            let mut map_selection = MapSelection::Random(None);
+            let mut game_rng = GameRng::new(RngSeed::new(456));
+            map_selection.random_resolve(&asset_ids, &mut *game_rng);
+            map_selection
+        };
+
+        assert_eq!(resolve(), resolve());
+    }
+
+    #[test]
+    fn random_resolve_does_not_change_id_selection() {
+        let asset_ids = asset_ids();
+        let mut map_selection = MapSelection::Id(asset_ids[0]);
+
+        let mut game_rng = GameRng::new(RngSeed::new(123));
+        map_selection.random_resolve(&asset_ids, &mut *game_rng);
+
+        assert_eq!(MapSelection::Id(asset_ids[0]), map_selection);
+    }
+
+    fn asset_ids() -> Vec<AssetId> {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        ["test/map_0", "test/map_1", "test/map_2"]
+            .iter()
+            .map(|slug| {
+                let asset_slug =
+                    AssetSlug::from_str(slug).expect("Expected asset slug to be valid.");
+                asset_id_mappings.insert(asset_slug)
+            })
+            .collect::<Vec<AssetId>>()
+    }
+}
@@ -0,0 +1,2 @@
+mod game_input_receive_buffer;
+mod game_input_send_buffer;
@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use net_model::play::{GameInputEnvelope, NetData};
+    use network_session_model::play::SessionDeviceId;
+
+    use net_model::play::GameInputReceiveBuffer;
+
+    #[test]
+    fn order_returns_envelope_when_sequence_is_next() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+
+        assert_eq!(
+            vec![envelope(1, 0)],
+            game_input_receive_buffer.order(envelope(1, 0))
+        );
+        assert_eq!(
+            vec![envelope(1, 1)],
+            game_input_receive_buffer.order(envelope(1, 1))
+        );
+    }
+
+    #[test]
+    fn order_holds_envelope_until_earlier_sequences_received() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+
+        assert!(game_input_receive_buffer.order(envelope(1, 2)).is_empty());
+        assert!(game_input_receive_buffer.order(envelope(1, 1)).is_empty());
+        assert_eq!(
+            vec![envelope(1, 0), envelope(1, 1), envelope(1, 2)],
+            game_input_receive_buffer.order(envelope(1, 0))
+        );
+    }
+
+    #[test]
+    fn order_discards_envelope_already_received() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+
+        assert_eq!(
+            vec![envelope(1, 0)],
+            game_input_receive_buffer.order(envelope(1, 0))
+        );
+        assert!(game_input_receive_buffer.order(envelope(1, 0)).is_empty());
+    }
+
+    #[test]
+    fn order_tracks_sequences_per_device() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+
+        assert_eq!(
+            vec![envelope(1, 0)],
+            game_input_receive_buffer.order(envelope(1, 0))
+        );
+        assert_eq!(
+            vec![envelope(2, 0)],
+            game_input_receive_buffer.order(envelope(2, 0))
+        );
+    }
+
+    #[test]
+    fn order_tracks_sequences_per_socket_for_same_device() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+
+        assert_eq!(
+            vec![envelope_from(1234, 0, 0)],
+            game_input_receive_buffer.order(envelope_from(1234, 0, 0))
+        );
+        assert_eq!(
+            vec![envelope_from(1235, 0, 0)],
+            game_input_receive_buffer.order(envelope_from(1235, 0, 0))
+        );
+    }
+
+    #[test]
+    fn order_discards_envelope_beyond_out_of_order_limit() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        let sequence_limit = GameInputReceiveBuffer::OUT_OF_ORDER_LIMIT;
+
+        assert!(game_input_receive_buffer.accepts(&envelope(1, sequence_limit - 1)));
+        assert!(!game_input_receive_buffer.accepts(&envelope(1, sequence_limit)));
+        assert!(game_input_receive_buffer
+            .order(envelope(1, sequence_limit))
+            .is_empty());
+        assert!(game_input_receive_buffer
+            .out_of_order
+            .values()
+            .all(|envelopes| envelopes.is_empty()));
+    }
+
+    #[test]
+    fn accepts_envelope_already_received() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope(1, 0));
+
+        assert!(game_input_receive_buffer.accepts(&envelope(1, 0)));
+    }
+
+    #[test]
+    fn order_restarts_sequence_when_epoch_is_newer() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 0, 0));
+        game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 0, 1));
+        game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 0, 3));
+
+        assert!(game_input_receive_buffer.accepts(&envelope_in_epoch(1234, 1, 1, 0)));
+        assert_eq!(
+            vec![envelope_in_epoch(1234, 1, 1, 0)],
+            game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 1, 0))
+        );
+        assert!(game_input_receive_buffer
+            .out_of_order
+            .values()
+            .all(|envelopes| envelopes.is_empty()));
+    }
+
+    #[test]
+    fn order_discards_envelope_from_earlier_epoch() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 1, 0));
+
+        assert!(game_input_receive_buffer.accepts(&envelope_in_epoch(1234, 1, 0, 1)));
+        assert!(game_input_receive_buffer
+            .order(envelope_in_epoch(1234, 1, 0, 1))
+            .is_empty());
+        assert_eq!(
+            vec![envelope_in_epoch(1234, 1, 1, 1)],
+            game_input_receive_buffer.order(envelope_in_epoch(1234, 1, 1, 1))
+        );
+    }
+
+    #[test]
+    fn remove_socket_resets_expected_sequence() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope(1, 0));
+        game_input_receive_buffer.order(envelope(1, 2));
+
+        game_input_receive_buffer.remove_socket(socket_addr(1234));
+
+        assert_eq!(
+            vec![envelope(1, 0)],
+            game_input_receive_buffer.order(envelope(1, 0))
+        );
+    }
+
+    #[test]
+    fn remove_socket_does_not_reset_same_device_from_other_socket() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope_from(1234, 0, 0));
+        game_input_receive_buffer.order(envelope_from(1235, 0, 0));
+
+        game_input_receive_buffer.remove_socket(socket_addr(1234));
+
+        assert!(game_input_receive_buffer
+            .order(envelope_from(1235, 0, 0))
+            .is_empty());
+        assert_eq!(
+            vec![envelope_from(1235, 0, 1)],
+            game_input_receive_buffer.order(envelope_from(1235, 0, 1))
+        );
+    }
+
+    #[test]
+    fn socket_replace_continues_sequence_from_previous_socket() {
+        let mut game_input_receive_buffer = GameInputReceiveBuffer::default();
+        game_input_receive_buffer.order(envelope_from(1234, 1, 0));
+        game_input_receive_buffer.order(envelope_from(1234, 1, 2));
+
+        game_input_receive_buffer.socket_replace(socket_addr(1234), socket_addr(1235));
+
+        assert!(game_input_receive_buffer
+            .order(envelope_from(1235, 1, 0))
+            .is_empty());
+        assert_eq!(
+            vec![envelope_from(1235, 1, 1), envelope_from(1235, 1, 2)],
+            game_input_receive_buffer.order(envelope_from(1235, 1, 1))
+        );
+    }
+
+    fn envelope(session_device_id: u64, sequence: u64) -> NetData<GameInputEnvelope> {
+        envelope_from(1234, session_device_id, sequence)
+    }
+
+    fn envelope_from(
+        port: u16,
+        session_device_id: u64,
+        sequence: u64,
+    ) -> NetData<GameInputEnvelope> {
+        envelope_in_epoch(port, session_device_id, 0, sequence)
+    }
+
+    fn envelope_in_epoch(
+        port: u16,
+        session_device_id: u64,
+        epoch: u64,
+        sequence: u64,
+    ) -> NetData<GameInputEnvelope> {
+        let game_input_event =
+            GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack));
+        NetData {
+            socket_addr: socket_addr(port),
+            data: GameInputEnvelope::new(
+                SessionDeviceId::new(session_device_id),
+                epoch,
+                sequence,
+                TickedGameInputEvent::new(GameTick(sequence), game_input_event),
+            ),
+        }
+    }
+
+    fn socket_addr(port: u16) -> SocketAddr {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, port))
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use net_model::play::{GameInputAck, GameInputEnvelope};
+    use network_session_model::play::SessionDeviceId;
+
+    use net_model::play::GameInputSendBuffer;
+
+    #[test]
+    fn acknowledge_removes_matching_envelope() {
+        let mut game_input_send_buffer = GameInputSendBuffer::default();
+        game_input_send_buffer.push(socket_addr(1), envelope(0));
+        game_input_send_buffer.push(socket_addr(1), envelope(1));
+
+        game_input_send_buffer.acknowledge(
+            socket_addr(1),
+            GameInputAck::new(SessionDeviceId::new(1), 0, 0),
+        );
+
+        assert_eq!(1, game_input_send_buffer.pending.len());
+        assert_eq!(
+            envelope(1),
+            game_input_send_buffer.pending[0].game_input_envelope
+        );
+    }
+
+    #[test]
+    fn acknowledge_ignores_ack_from_other_socket() {
+        let mut game_input_send_buffer = GameInputSendBuffer::default();
+        game_input_send_buffer.push(socket_addr(1), envelope(0));
+
+        game_input_send_buffer.acknowledge(
+            socket_addr(2),
+            GameInputAck::new(SessionDeviceId::new(1), 0, 0),
+        );
+
+        assert_eq!(1, game_input_send_buffer.pending.len());
+    }
+
+    #[test]
+    fn remove_socket_removes_envelopes_for_socket() {
+        let mut game_input_send_buffer = GameInputSendBuffer::default();
+        game_input_send_buffer.push(socket_addr(1), envelope(0));
+        game_input_send_buffer.push(socket_addr(2), envelope(0));
+
+        game_input_send_buffer.remove_socket(socket_addr(1));
+
+        assert_eq!(1, game_input_send_buffer.pending.len());
+        assert_eq!(
+            socket_addr(2),
+            game_input_send_buffer.pending[0].socket_addr
+        );
+    }
+
+    #[test]
+    fn socket_replace_redirects_envelopes_and_sends_on_next_tick() {
+        let mut game_input_send_buffer = GameInputSendBuffer::default();
+        game_input_send_buffer.push(socket_addr(1), envelope(0));
+        game_input_send_buffer.push(socket_addr(2), envelope(0));
+
+        game_input_send_buffer.socket_replace(socket_addr(1), socket_addr(3));
+        let retransmits = game_input_send_buffer.tick();
+
+        assert_eq!(1, retransmits.len());
+        assert_eq!(socket_addr(3), retransmits[0].socket_addr);
+        assert_eq!(envelope(0), retransmits[0].game_input_envelope);
+        assert_eq!(
+            socket_addr(2),
+            game_input_send_buffer.pending[1].socket_addr
+        );
+    }
+
+    #[test]
+    fn tick_returns_envelopes_due_for_retransmission() {
+        let mut game_input_send_buffer = GameInputSendBuffer::default();
+        game_input_send_buffer.push(socket_addr(1), envelope(0));
+
+        (1..GameInputSendBuffer::RETRANSMIT_DELAY).for_each(|_| {
+            assert!(game_input_send_buffer.tick().is_empty());
+        });
+        let retransmits = game_input_send_buffer.tick();
+
+        assert_eq!(1, retransmits.len());
+        assert_eq!(envelope(0), retransmits[0].game_input_envelope);
+        assert_eq!(0, game_input_send_buffer.pending[0].ticks_since_sent);
+    }
+
+    fn socket_addr(port_offset: u16) -> SocketAddr {
+        SocketAddr::from((Ipv4Addr::LOCALHOST, 1234 + port_offset))
+    }
+
+    fn envelope(sequence: u64) -> GameInputEnvelope {
+        let game_input_event =
+            GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack));
+        GameInputEnvelope::new(
+            SessionDeviceId::new(1),
+            0,
+            sequence,
+            TickedGameInputEvent::new(GameTick(sequence), game_input_event),
+        )
+    }
+}
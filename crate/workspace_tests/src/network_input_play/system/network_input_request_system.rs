@@ -11,10 +11,15 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use game_input_model::{
         config::{ControlAction, ControlBindings, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
         GameInputEvent,
     };
-    use net_model::play::NetMessageEvent;
-    use network_session_model::play::SessionStatus;
+    use net_model::play::{GameInputEnvelope, NetMessageEvent};
+    use network_session_model::play::{SessionDeviceId, SessionStatus};
+    use rollback_model::{
+        config::{RollbackConfig, SessionSyncMode},
+        play::RollbackStatus,
+    };
 
     use network_input_play::NetworkInputRequestSystemDesc;
 
@@ -23,6 +28,8 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::HostEstablished,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                rollback_status: RollbackStatus::None,
                 input_event: None,
             },
             ExpectedParams {
@@ -42,11 +49,18 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::HostEstablished,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                rollback_status: RollbackStatus::None,
                 input_event: Some(input_event),
             },
             ExpectedParams {
                 session_status: SessionStatus::HostEstablished,
-                net_message_event: Some(NetMessageEvent::GameInputEvent(game_input_event)),
+                net_message_event: Some(NetMessageEvent::GameInputEvent(GameInputEnvelope::new(
+                    SessionDeviceId::new(2),
+                    0,
+                    0,
+                    TickedGameInputEvent::new(GameTick(3), game_input_event),
+                ))),
             },
         )
     }
@@ -61,11 +75,78 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::JoinEstablished,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                rollback_status: RollbackStatus::None,
                 input_event: Some(input_event),
             },
             ExpectedParams {
                 session_status: SessionStatus::JoinEstablished,
-                net_message_event: Some(NetMessageEvent::GameInputEvent(game_input_event)),
+                net_message_event: Some(NetMessageEvent::GameInputEvent(GameInputEnvelope::new(
+                    SessionDeviceId::new(2),
+                    0,
+                    0,
+                    TickedGameInputEvent::new(GameTick(3), game_input_event),
+                ))),
+            },
+        )
+    }
+
+    #[test]
+    fn stamps_input_with_input_delay_when_rollback() -> Result<(), Error> {
+        let input_event =
+            InputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack));
+        let game_input_event = GameInputEvent::try_from(input_event.clone())
+            .expect("Failed to convert `InputEvent<ControlBindings>` to `GameInputEvent`.");
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_sync_mode: SessionSyncMode::Rollback,
+                rollback_status: RollbackStatus::None,
+                input_event: Some(input_event),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                net_message_event: Some(NetMessageEvent::GameInputEvent(GameInputEnvelope::new(
+                    SessionDeviceId::new(2),
+                    0,
+                    0,
+                    TickedGameInputEvent::new(
+                        GameTick(3 + RollbackConfig::INPUT_DELAY_DEFAULT),
+                        game_input_event,
+                    ),
+                ))),
+            },
+        )
+    }
+
+    #[test]
+    fn stamps_input_relative_to_present_tick_when_resimulating() -> Result<(), Error> {
+        let input_event =
+            InputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack));
+        let game_input_event = GameInputEvent::try_from(input_event.clone())
+            .expect("Failed to convert `InputEvent<ControlBindings>` to `GameInputEvent`.");
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                session_sync_mode: SessionSyncMode::Rollback,
+                rollback_status: RollbackStatus::Resimulating {
+                    present: GameTick(7),
+                },
+                input_event: Some(input_event),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::JoinEstablished,
+                net_message_event: Some(NetMessageEvent::GameInputEvent(GameInputEnvelope::new(
+                    SessionDeviceId::new(2),
+                    0,
+                    0,
+                    TickedGameInputEvent::new(
+                        GameTick(7 + RollbackConfig::INPUT_DELAY_DEFAULT),
+                        game_input_event,
+                    ),
+                ))),
             },
         )
     }
@@ -75,6 +156,8 @@ mod tests {
         run_test(
             SetupParams {
                 session_status: SessionStatus::None,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                rollback_status: RollbackStatus::None,
                 input_event: Some(InputEvent::ActionPressed(PlayerActionControl::new(
                     0,
                     ControlAction::Attack,
@@ -90,6 +173,8 @@ mod tests {
     fn run_test(
         SetupParams {
             session_status: session_status_setup,
+            session_sync_mode,
+            rollback_status,
             input_event,
         }: SetupParams,
         ExpectedParams {
@@ -102,6 +187,10 @@ mod tests {
             .with_setup(setup_net_message_event_reader)
             .with_system_desc(NetworkInputRequestSystemDesc::default(), "", &[])
             .with_resource(session_status_setup)
+            .with_resource(session_sync_mode)
+            .with_resource(rollback_status)
+            .with_resource(GameTick(3))
+            .with_resource(SessionDeviceId::new(2))
             .with_effect(move |world| {
                 if let Some(input_event) = input_event {
                     world
@@ -139,6 +228,8 @@ mod tests {
 
     struct SetupParams {
         session_status: SessionStatus,
+        session_sync_mode: SessionSyncMode,
+        rollback_status: RollbackStatus,
         input_event: Option<InputEvent<ControlBindings>>,
     }
 
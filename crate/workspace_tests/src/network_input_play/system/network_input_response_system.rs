@@ -10,10 +10,12 @@ mod tests {
     use amethyst_test::AmethystApplication;
     use game_input_model::{
         config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
         GameInputEvent,
     };
-    use net_model::play::{NetData, NetEventChannel};
-    use network_session_model::play::SessionStatus;
+    use net_model::play::{GameInputEnvelope, NetData, NetEventChannel};
+    use network_session_model::play::{SessionDeviceId, SessionStatus};
+    use rollback_model::config::SessionSyncMode;
 
     use network_input_play::NetworkInputResponseSystemDesc;
 
@@ -49,6 +51,72 @@ mod tests {
         )
     }
 
+    #[test]
+    fn sends_ticked_game_input_event_from_other_device_in_rollback_mode() -> Result<(), Error> {
+        run_rollback_test(SessionDeviceId::new(1), true)
+    }
+
+    #[test]
+    fn skips_ticked_game_input_event_echoed_to_local_device_in_rollback_mode() -> Result<(), Error>
+    {
+        run_rollback_test(SessionDeviceId::new(0), false)
+    }
+
+    fn run_rollback_test(
+        session_device_id_origin: SessionDeviceId,
+        sent_expected: bool,
+    ) -> Result<(), Error> {
+        let ticked_game_input_event = TickedGameInputEvent::new(
+            GameTick(3),
+            GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack)),
+        );
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<TickedGameInputEvent>> as SystemData>::setup)
+            .with_setup(|world| {
+                let ticked_game_input_event_rid = world
+                    .write_resource::<EventChannel<TickedGameInputEvent>>()
+                    .register_reader();
+                world.insert(ticked_game_input_event_rid);
+            })
+            .with_system_desc(NetworkInputResponseSystemDesc::default(), "", &[])
+            .with_resource(SessionStatus::JoinEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(SessionDeviceId::new(0))
+            .with_effect(move |world| {
+                let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+                world
+                    .write_resource::<NetEventChannel<GameInputEnvelope>>()
+                    .single_write(NetData {
+                        socket_addr,
+                        data: GameInputEnvelope::new(
+                            session_device_id_origin,
+                            0,
+                            0,
+                            ticked_game_input_event,
+                        ),
+                    });
+            })
+            .with_assertion(move |world| {
+                let (mut ticked_game_input_rid, ticked_game_input_ec) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<TickedGameInputEvent>>,
+                    Read<'_, EventChannel<TickedGameInputEvent>>,
+                )>();
+                let ticked_game_input_events = ticked_game_input_ec
+                    .read(&mut *ticked_game_input_rid)
+                    .copied()
+                    .collect::<Vec<TickedGameInputEvent>>();
+
+                let ticked_game_input_events_expected = if sent_expected {
+                    vec![ticked_game_input_event]
+                } else {
+                    vec![]
+                };
+                assert_eq!(ticked_game_input_events_expected, ticked_game_input_events);
+            })
+            .run()
+    }
+
     fn run_test(
         SetupParams {
             session_status: session_status_setup,
@@ -67,10 +135,15 @@ mod tests {
                 if let Some(game_input_event) = game_input_event {
                     let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
                     world
-                        .write_resource::<NetEventChannel<GameInputEvent>>()
+                        .write_resource::<NetEventChannel<GameInputEnvelope>>()
                         .single_write(NetData {
                             socket_addr,
-                            data: game_input_event,
+                            data: GameInputEnvelope::new(
+                                SessionDeviceId::new(1),
+                                0,
+                                0,
+                                TickedGameInputEvent::new(GameTick(0), game_input_event),
+                            ),
                         });
                 }
             })
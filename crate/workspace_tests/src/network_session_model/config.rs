@@ -0,0 +1 @@
+mod session_server_config;
@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    use network_session_model::play::SessionServerResolveError;
+
+    use network_session_model::config::SessionServerConfig;
+
+    #[test]
+    fn resolves_ipv4_address_with_config_port() {
+        let session_server_config = session_server_config("127.0.0.1");
+
+        assert_eq!(
+            Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 1234)),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn resolves_ipv4_address_with_address_port() {
+        let session_server_config = session_server_config("127.0.0.1:5678");
+
+        assert_eq!(
+            Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 5678)),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn resolves_ipv6_address_with_config_port() {
+        let session_server_config = session_server_config("::1");
+
+        assert_eq!(
+            Ok(SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 1234)),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn resolves_bracketed_ipv6_address_with_config_port() {
+        let session_server_config = session_server_config("[::1]");
+
+        assert_eq!(
+            Ok(SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 1234)),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn resolves_ipv6_address_with_address_port() {
+        let session_server_config = session_server_config("[::1]:5678");
+
+        assert_eq!(
+            Ok(SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 5678)),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn resolves_hostname_with_address_port() {
+        let session_server_config = session_server_config("localhost:5678");
+
+        let socket_addr = session_server_config
+            .socket_addr_resolve()
+            .expect("Expected `localhost` to resolve.");
+
+        assert!(socket_addr.ip().is_loopback());
+        assert_eq!(5678, socket_addr.port());
+    }
+
+    #[test]
+    fn returns_error_when_address_is_empty() {
+        let session_server_config = session_server_config("");
+
+        assert_eq!(
+            Err(SessionServerResolveError::new(
+                String::from(""),
+                String::from("Address is empty.")
+            )),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    #[test]
+    fn returns_error_when_port_is_invalid() {
+        let session_server_config = session_server_config("localhost:abc");
+
+        assert_eq!(
+            Err(SessionServerResolveError::new(
+                String::from("localhost:abc"),
+                String::from("Invalid port: `abc`.")
+            )),
+            session_server_config.socket_addr_resolve()
+        );
+    }
+
+    fn session_server_config(address: &str) -> SessionServerConfig {
+        SessionServerConfig {
+            address: String::from(address),
+            port: 1234,
+        }
+    }
+}
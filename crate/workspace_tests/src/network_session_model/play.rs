@@ -0,0 +1,2 @@
+mod session_code;
+mod session_condition;
@@ -0,0 +1,27 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionCode;
+
+    #[test]
+    fn rng_seed_is_same_for_same_session_code() {
+        let session_code_0 = SessionCode::new(String::from("abcd"));
+        let session_code_1 = SessionCode::new(String::from("abcd"));
+
+        assert_eq!(session_code_0.rng_seed(), session_code_1.rng_seed());
+    }
+
+    #[test]
+    fn rng_seed_is_different_for_different_session_codes() {
+        let session_code_0 = SessionCode::new(String::from("abcd"));
+        let session_code_1 = SessionCode::new(String::from("abce"));
+
+        assert_ne!(session_code_0.rng_seed(), session_code_1.rng_seed());
+    }
+
+    #[test]
+    fn rng_seed_is_stable() {
+        let session_code = SessionCode::new(String::from("a"));
+
+        assert_eq!(0xaf63_dc4c_8601_ec8c, session_code.rng_seed());
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use network_session_model::play::SessionCondition;
+
+    #[test]
+    fn pause_remembers_pending_game_input_tick() {
+        let mut session_condition = SessionCondition::PendingGameInputTick;
+
+        session_condition.pause();
+
+        assert_eq!(
+            SessionCondition::Paused {
+                game_input_tick_pending: true
+            },
+            session_condition
+        );
+        assert!(session_condition.is_paused());
+    }
+
+    #[test]
+    fn pause_when_paused_does_not_change_condition() {
+        let mut session_condition = SessionCondition::Paused {
+            game_input_tick_pending: true,
+        };
+
+        session_condition.pause();
+
+        assert_eq!(
+            SessionCondition::Paused {
+                game_input_tick_pending: true
+            },
+            session_condition
+        );
+    }
+
+    #[test]
+    fn resume_restores_pending_game_input_tick() {
+        let mut session_condition = SessionCondition::PendingGameInputTick;
+
+        session_condition.pause();
+        session_condition.resume();
+
+        assert_eq!(SessionCondition::PendingGameInputTick, session_condition);
+    }
+
+    #[test]
+    fn resume_after_game_input_tick_received_is_ready() {
+        let mut session_condition = SessionCondition::PendingGameInputTick;
+
+        session_condition.pause();
+        session_condition.ready();
+
+        assert!(session_condition.is_paused());
+
+        session_condition.resume();
+
+        assert_eq!(SessionCondition::Ready, session_condition);
+    }
+
+    #[test]
+    fn ready_when_pending_game_input_tick_is_ready() {
+        let mut session_condition = SessionCondition::PendingGameInputTick;
+
+        session_condition.ready();
+
+        assert_eq!(SessionCondition::Ready, session_condition);
+    }
+}
@@ -0,0 +1,2 @@
+mod game_play_pause_request_system;
+mod game_play_restart_request_system;
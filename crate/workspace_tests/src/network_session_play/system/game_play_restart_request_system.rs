@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_play_model::GamePlayEvent;
+    use net_model::play::NetMessageEvent;
+    use network_session_model::{play::SessionStatus, SessionMessageEvent};
+
+    use network_session_play::GamePlayRestartRequestSystemDesc;
+
+    #[test]
+    fn does_nothing_when_no_game_play_event() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                game_play_event: None,
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn does_nothing_when_game_play_event_is_not_restart() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                game_play_event: Some(GamePlayEvent::Pause),
+            },
+            None,
+        )
+    }
+
+    #[test]
+    fn sends_net_message_event_on_restart_when_host_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::HostEstablished,
+                game_play_event: Some(GamePlayEvent::Restart),
+            },
+            Some(NetMessageEvent::SessionMessageEvent(
+                SessionMessageEvent::GamePlayRestartRequest,
+            )),
+        )
+    }
+
+    #[test]
+    fn sends_net_message_event_on_restart_when_join_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::JoinEstablished,
+                game_play_event: Some(GamePlayEvent::Restart),
+            },
+            Some(NetMessageEvent::SessionMessageEvent(
+                SessionMessageEvent::GamePlayRestartRequest,
+            )),
+        )
+    }
+
+    #[test]
+    fn ignores_restart_when_session_not_established() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                game_play_event: Some(GamePlayEvent::Restart),
+            },
+            None,
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_status,
+            game_play_event,
+        }: SetupParams,
+        net_message_event_expected: Option<NetMessageEvent>,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<NetMessageEvent>> as SystemData>::setup)
+            .with_setup(setup_net_message_event_reader)
+            .with_system_desc(GamePlayRestartRequestSystemDesc::default(), "", &[])
+            .with_resource(session_status)
+            .with_effect(move |world| {
+                if let Some(game_play_event) = game_play_event {
+                    world
+                        .write_resource::<EventChannel<GamePlayEvent>>()
+                        .single_write(game_play_event);
+                }
+            })
+            .with_assertion(move |world| {
+                let (mut net_message_event_rid, net_message_ec) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<NetMessageEvent>>,
+                    Read<'_, EventChannel<NetMessageEvent>>,
+                )>();
+                let net_message_event = net_message_ec.read(&mut *net_message_event_rid).next();
+
+                assert_eq!(net_message_event_expected.as_ref(), net_message_event);
+            })
+            .run()
+    }
+
+    fn setup_net_message_event_reader(world: &mut World) {
+        let net_message_event_rid = world
+            .write_resource::<EventChannel<NetMessageEvent>>()
+            .register_reader();
+        world.insert(net_message_event_rid);
+    }
+
+    struct SetupParams {
+        session_status: SessionStatus,
+        game_play_event: Option<GamePlayEvent>,
+    }
+}
@@ -96,6 +96,7 @@ mod test {
                 assert_frame_component_data_count!(sprite_render_sequence_handles);
                 assert_frame_component_data_count!(body_sequence_handles);
                 assert_frame_component_data_count!(interactions_sequence_handles);
+                assert_frame_component_data_count!(chase_sequence_handles);
             })
             .run_winit_loop()
     }
@@ -205,6 +205,7 @@ frames:
                 r: 1,
             }],
             multiple: false,
+            tags: Vec::new(),
         }];
         let frames = vec![ObjectFrame {
             interactions: Interactions::new(interactions),
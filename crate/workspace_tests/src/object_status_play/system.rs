@@ -1 +1,2 @@
+mod defense_points_reduction_system;
 mod stun_points_reduction_system;
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Join, ReadStorage, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use object_status_model::config::DefensePoints;
+
+    use object_status_play::DefensePointsReductionSystem;
+
+    #[test]
+    fn reduces_defense_points_each_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(DefensePointsReductionSystem::new(), "", &[])
+            .with_effect(|world| create_entity_with_defense_points(world, 3))
+            .with_assertion(|world| assert_entity_with_defense_points(world, 2))
+            .with_assertion(|world| assert_entity_with_defense_points(world, 1))
+            .with_assertion(|world| assert_entity_with_defense_points(world, 0))
+            .with_assertion(|world| assert_entity_with_defense_points(world, 0))
+            .run()
+    }
+
+    fn create_entity_with_defense_points(world: &mut World, points: u32) {
+        world
+            .create_entity()
+            .with(DefensePoints::new(points))
+            .build();
+    }
+
+    fn assert_entity_with_defense_points(world: &mut World, points: u32) {
+        let defense_points = world
+            .system_data::<ReadStorage<'_, DefensePoints>>()
+            .join()
+            .next()
+            .cloned()
+            .expect("Expected entity with `DefensePoints` to exist.");
+
+        assert_eq!(DefensePoints::new(points), defense_points);
+    }
+}
@@ -5,7 +5,7 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
-    use object_status_model::config::StunPoints;
+    use object_status_model::config::{StunPoints, StunRecovery};
 
     use object_status_play::StunPointsReductionSystem;
 
@@ -21,6 +21,23 @@ mod tests {
             .run()
     }
 
+    #[test]
+    fn reduces_stun_points_by_stun_recovery() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StunPointsReductionSystem::new(), "", &[])
+            .with_effect(|world| {
+                world
+                    .create_entity()
+                    .with(StunPoints::new(5))
+                    .with(StunRecovery::new(2))
+                    .build();
+            })
+            .with_assertion(|world| assert_entity_with_stun_points(world, 3))
+            .with_assertion(|world| assert_entity_with_stun_points(world, 1))
+            .with_assertion(|world| assert_entity_with_stun_points(world, 0))
+            .run()
+    }
+
     fn create_entity_with_stun_points(world: &mut World, points: u32) {
         world.create_entity().with(StunPoints::new(points)).build();
     }
@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use ai_model::config::AiDifficulty;
+    use asset_model::config::AssetSlug;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use game_rules_model::config::GameRules;
+    use team_model::play::TeamCounter;
+
+    use replay_model::config::{Replay, ReplayCharacterSelection};
+
+    const REPLAY_YAML: &str = "---
+rng_seed: 123
+character_selections:
+  - controller_id: 0
+    asset_slug: test/char_0
+map_selection: test/map_0
+game_input_events:
+  - tick: 3
+    game_input_event:
+      action_pressed:
+        player: 0
+        action: attack
+";
+
+    #[test]
+    fn round_trips_replay_through_yaml() {
+        let replay_yaml = serde_yaml::to_string(&replay()).expect("Failed to serialize `Replay`.");
+        let replay_deserialized =
+            serde_yaml::from_str::<Replay>(&replay_yaml).expect("Failed to deserialize `Replay`.");
+
+        assert_eq!(replay(), replay_deserialized);
+    }
+
+    #[test]
+    fn deserializes_replay() {
+        let replay =
+            serde_yaml::from_str::<Replay>(REPLAY_YAML).expect("Failed to deserialize `Replay`.");
+
+        assert_eq!(replay(), replay);
+    }
+
+    #[test]
+    fn deserializes_replay_game_rules() {
+        let replay_yaml = format!("{}game_rules:\n  stocks: 3\n", REPLAY_YAML);
+        let replay_deserialized =
+            serde_yaml::from_str::<Replay>(&replay_yaml).expect("Failed to deserialize `Replay`.");
+
+        let mut expected = replay();
+        expected.game_rules = GameRules {
+            stocks: 3,
+            ..Default::default()
+        };
+        assert_eq!(expected, replay_deserialized);
+    }
+
+    #[test]
+    fn deserializes_character_selection_ai_difficulty() {
+        let replay_character_selection = serde_yaml::from_str::<ReplayCharacterSelection>(
+            "{ controller_id: 1, asset_slug: test/char_0, ai_difficulty: hard }",
+        )
+        .expect("Failed to deserialize `ReplayCharacterSelection`.");
+
+        let mut expected = ReplayCharacterSelection::new(
+            1,
+            AssetSlug::from_str("test/char_0").expect("Expected slug to be valid."),
+        );
+        expected.ai_difficulty = Some(AiDifficulty::Hard);
+
+        assert_eq!(expected, replay_character_selection);
+    }
+
+    #[test]
+    fn deserializes_character_selection_team_counter() {
+        let replay_character_selection = serde_yaml::from_str::<ReplayCharacterSelection>(
+            "{ controller_id: 1, asset_slug: test/char_0, team_counter: 2 }",
+        )
+        .expect("Failed to deserialize `ReplayCharacterSelection`.");
+
+        let mut expected = ReplayCharacterSelection::new(
+            1,
+            AssetSlug::from_str("test/char_0").expect("Expected slug to be valid."),
+        );
+        expected.team_counter = Some(TeamCounter::new(2));
+
+        assert_eq!(expected, replay_character_selection);
+    }
+
+    fn replay() -> Replay {
+        Replay::new(
+            123,
+            vec![ReplayCharacterSelection::new(
+                0,
+                AssetSlug::from_str("test/char_0").expect("Expected slug to be valid."),
+            )],
+            AssetSlug::from_str("test/map_0").expect("Expected slug to be valid."),
+            vec![TickedGameInputEvent::new(
+                GameTick(3),
+                GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack)),
+            )],
+        )
+    }
+}
@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+
+    use replay_model::play::ReplayPlayback;
+
+    #[test]
+    fn groups_events_by_tick_in_recorded_order() {
+        let replay_playback = ReplayPlayback::from(vec![
+            ticked_event(1, 0),
+            ticked_event(2, 0),
+            ticked_event(1, 1),
+        ]);
+
+        assert_eq!(&[event(0), event(1)], replay_playback.events(GameTick(1)));
+        assert_eq!(&[event(0)], replay_playback.events(GameTick(2)));
+    }
+
+    #[test]
+    fn events_returns_empty_slice_when_no_events_for_tick() {
+        let replay_playback = ReplayPlayback::from(vec![ticked_event(1, 0)]);
+
+        assert!(replay_playback.events(GameTick(0)).is_empty());
+    }
+
+    fn ticked_event(tick: u64, controller_id: usize) -> TickedGameInputEvent {
+        TickedGameInputEvent::new(GameTick(tick), event(controller_id))
+    }
+
+    fn event(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
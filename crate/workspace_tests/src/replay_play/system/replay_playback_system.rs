@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use replay_model::play::{ReplayMode, ReplayPlayback};
+    use state_registry::StateId;
+
+    use replay_play::ReplayPlaybackSystem;
+
+    #[test]
+    fn sends_recorded_events_for_current_tick_during_playback() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                replay_mode: ReplayMode::Playback,
+                game_tick: GameTick(2),
+            },
+            vec![event(0), event(1)],
+        )
+    }
+
+    #[test]
+    fn does_not_send_events_when_not_playing_back() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                replay_mode: ReplayMode::None,
+                game_tick: GameTick(2),
+            },
+            vec![],
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            replay_mode,
+            game_tick,
+        }: SetupParams,
+        game_input_events_expected: Vec<GameInputEvent>,
+    ) -> Result<(), Error> {
+        let replay_playback = ReplayPlayback::from(vec![
+            TickedGameInputEvent::new(GameTick(1), event(2)),
+            TickedGameInputEvent::new(GameTick(2), event(0)),
+            TickedGameInputEvent::new(GameTick(2), event(1)),
+        ]);
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_system(ReplayPlaybackSystem::new(), "", &[])
+            .with_resource(replay_mode)
+            .with_resource(replay_playback)
+            .with_resource(StateId::GamePlay)
+            .with_resource(game_tick)
+            .with_assertion(move |world| {
+                let (mut game_input_event_rid, game_input_ec) = world.system_data::<(
+                    WriteExpect<'_, ReaderId<GameInputEvent>>,
+                    Read<'_, EventChannel<GameInputEvent>>,
+                )>();
+                let game_input_events = game_input_ec
+                    .read(&mut *game_input_event_rid)
+                    .copied()
+                    .collect::<Vec<GameInputEvent>>();
+
+                assert_eq!(game_input_events_expected, game_input_events);
+            })
+            .run()
+    }
+
+    fn setup_game_input_event_reader(world: &mut World) {
+        let game_input_event_rid = world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .register_reader();
+        world.insert(game_input_event_rid);
+    }
+
+    fn event(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+
+    struct SetupParams {
+        replay_mode: ReplayMode,
+        game_tick: GameTick,
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use std::{fs, str::FromStr};
+
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use asset_model::{config::AssetSlug, loaded::AssetIdMappings};
+    use character_selection_model::CharacterSelections;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use game_play_model::GamePlayStatus;
+    use map_selection_model::MapSelection;
+    use replay_model::{
+        config::Replay,
+        play::{ReplayMode, ReplayRecording},
+    };
+    use state_registry::StateId;
+    use tempfile::tempdir;
+
+    use replay_play::ReplayRecordSystemDesc;
+
+    #[test]
+    fn keeps_recording_while_paused_and_writes_full_replay_when_match_ends() -> Result<(), Error> {
+        let replay_dir = tempdir()?;
+        let replay_path = replay_dir.path().join("replay.yaml");
+        let replay_path_assert = replay_path.clone();
+
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_system_desc(ReplayRecordSystemDesc::default(), "", &[])
+            .with_resource(ReplayMode::Record { path: replay_path })
+            .with_resource(StateId::GamePlay)
+            .with_resource(GamePlayStatus::Playing)
+            .with_resource(GameTick(0))
+            .with_effect(setup_selections)
+            .with_effect(|world| send_event(world, event(0)))
+            .with_effect(|world| {
+                world.insert(StateId::GamePlayPause);
+                world.insert(GamePlayStatus::Paused);
+                send_event(world, event(1));
+            })
+            .with_assertion(|world| {
+                let replay_recording = world.read_resource::<ReplayRecording>();
+                let game_input_events = replay_recording
+                    .0
+                    .as_ref()
+                    .map(|replay| replay.game_input_events.clone());
+
+                assert_eq!(
+                    Some(vec![TickedGameInputEvent::new(GameTick(0), event(0))]),
+                    game_input_events
+                );
+            })
+            .with_effect(|world| {
+                world.insert(StateId::GamePlay);
+                world.insert(GamePlayStatus::Playing);
+                world.insert(GameTick(1));
+                send_event(world, event(2));
+            })
+            .with_effect(|world| world.insert(GamePlayStatus::Ended))
+            .with_assertion(move |world| {
+                assert!(world.read_resource::<ReplayRecording>().is_none());
+
+                let replay_yaml = fs::read_to_string(&replay_path_assert)
+                    .expect("Expected replay to be written.");
+                let replay = serde_yaml::from_str::<Replay>(&replay_yaml)
+                    .expect("Failed to deserialize replay.");
+
+                assert_eq!(
+                    vec![
+                        TickedGameInputEvent::new(GameTick(0), event(0)),
+                        TickedGameInputEvent::new(GameTick(1), event(2)),
+                    ],
+                    replay.game_input_events
+                );
+            })
+            .run()
+    }
+
+    fn setup_selections(world: &mut World) {
+        let mut asset_id_mappings = AssetIdMappings::new();
+        let character_asset_id = asset_id_mappings
+            .insert(AssetSlug::from_str("test/character").expect("Expected slug to be valid."));
+        let map_asset_id = asset_id_mappings
+            .insert(AssetSlug::from_str("test/map").expect("Expected slug to be valid."));
+
+        let mut character_selections = CharacterSelections::default();
+        character_selections
+            .selections
+            .insert(0, character_asset_id);
+
+        world.insert(asset_id_mappings);
+        world.insert(character_selections);
+        world.insert(MapSelection::Id(map_asset_id));
+    }
+
+    fn send_event(world: &mut World, game_input_event: GameInputEvent) {
+        world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .single_write(game_input_event);
+    }
+
+    fn event(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
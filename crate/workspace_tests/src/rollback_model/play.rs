@@ -0,0 +1,3 @@
+mod component_snapshots;
+mod input_history;
+mod resource_snapshots;
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::ecs::{Builder, Entity, World, WorldExt};
+    use game_input_model::play::GameTick;
+
+    use rollback_model::play::ComponentSnapshots;
+
+    #[test]
+    fn get_returns_recorded_snapshot() {
+        let entity = entity();
+        let mut component_snapshots = ComponentSnapshots::<u32>::default();
+
+        component_snapshots.record(GameTick(1), vec![(entity, 10)], 3);
+        component_snapshots.record(GameTick(2), vec![(entity, 20)], 3);
+
+        assert_eq!(
+            Some([(entity, 10)].as_ref()),
+            component_snapshots.get(GameTick(1))
+        );
+        assert_eq!(
+            Some([(entity, 20)].as_ref()),
+            component_snapshots.get(GameTick(2))
+        );
+        assert_eq!(None, component_snapshots.get(GameTick(3)));
+    }
+
+    #[test]
+    fn record_discards_snapshots_beyond_capacity() {
+        let entity = entity();
+        let mut component_snapshots = ComponentSnapshots::<u32>::default();
+
+        component_snapshots.record(GameTick(1), vec![(entity, 10)], 2);
+        component_snapshots.record(GameTick(2), vec![(entity, 20)], 2);
+        component_snapshots.record(GameTick(3), vec![(entity, 30)], 2);
+
+        assert_eq!(None, component_snapshots.get(GameTick(1)));
+        assert_eq!(2, component_snapshots.snapshots.len());
+    }
+
+    #[test]
+    fn record_replaces_snapshots_for_same_and_later_ticks() {
+        let entity = entity();
+        let mut component_snapshots = ComponentSnapshots::<u32>::default();
+
+        component_snapshots.record(GameTick(1), vec![(entity, 10)], 4);
+        component_snapshots.record(GameTick(2), vec![(entity, 20)], 4);
+        component_snapshots.record(GameTick(3), vec![(entity, 30)], 4);
+        component_snapshots.record(GameTick(2), vec![(entity, 21)], 4);
+
+        assert_eq!(
+            Some([(entity, 21)].as_ref()),
+            component_snapshots.get(GameTick(2))
+        );
+        assert_eq!(None, component_snapshots.get(GameTick(3)));
+    }
+
+    fn entity() -> Entity {
+        let mut world = World::new();
+        world.create_entity().build()
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+
+    use rollback_model::play::InputHistory;
+
+    #[test]
+    fn insert_records_events_for_tick() {
+        let mut input_history = InputHistory::default();
+
+        input_history.insert(ticked_event(1, 0));
+        input_history.insert(ticked_event(1, 1));
+        assert_eq!(&[event(0), event(1)], input_history.events(GameTick(1)));
+    }
+
+    #[test]
+    fn insert_records_repeated_events_for_tick() {
+        let mut input_history = InputHistory::default();
+
+        input_history.insert(ticked_event(1, 0));
+        input_history.insert(ticked_event(1, 0));
+        assert_eq!(&[event(0), event(0)], input_history.events(GameTick(1)));
+    }
+
+    #[test]
+    fn events_returns_empty_slice_when_no_events_for_tick() {
+        let input_history = InputHistory::default();
+
+        assert!(input_history.events(GameTick(1)).is_empty());
+    }
+
+    #[test]
+    fn prune_before_removes_earlier_ticks() {
+        let mut input_history = InputHistory::default();
+        input_history.insert(ticked_event(1, 0));
+        input_history.insert(ticked_event(2, 0));
+        input_history.insert(ticked_event(3, 0));
+
+        input_history.prune_before(GameTick(2));
+
+        assert!(input_history.events(GameTick(1)).is_empty());
+        assert_eq!(&[event(0)], input_history.events(GameTick(2)));
+        assert_eq!(&[event(0)], input_history.events(GameTick(3)));
+    }
+
+    fn ticked_event(tick: u64, controller_id: usize) -> TickedGameInputEvent {
+        TickedGameInputEvent::new(GameTick(tick), event(controller_id))
+    }
+
+    fn event(controller_id: usize) -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(
+            controller_id,
+            ControlAction::Attack,
+        ))
+    }
+}
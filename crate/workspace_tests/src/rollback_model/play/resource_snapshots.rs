@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use game_input_model::play::GameTick;
+
+    use rollback_model::play::ResourceSnapshots;
+
+    #[test]
+    fn get_returns_recorded_snapshot() {
+        let mut resource_snapshots = ResourceSnapshots::<u32>::default();
+
+        resource_snapshots.record(GameTick(1), 10, 3);
+        resource_snapshots.record(GameTick(2), 20, 3);
+
+        assert_eq!(Some(&10), resource_snapshots.get(GameTick(1)));
+        assert_eq!(Some(&20), resource_snapshots.get(GameTick(2)));
+        assert_eq!(None, resource_snapshots.get(GameTick(3)));
+    }
+
+    #[test]
+    fn record_discards_snapshots_beyond_capacity() {
+        let mut resource_snapshots = ResourceSnapshots::<u32>::default();
+
+        resource_snapshots.record(GameTick(1), 10, 2);
+        resource_snapshots.record(GameTick(2), 20, 2);
+        resource_snapshots.record(GameTick(3), 30, 2);
+
+        assert_eq!(None, resource_snapshots.get(GameTick(1)));
+        assert_eq!(2, resource_snapshots.snapshots.len());
+    }
+
+    #[test]
+    fn record_replaces_snapshots_for_same_and_later_ticks() {
+        let mut resource_snapshots = ResourceSnapshots::<u32>::default();
+
+        resource_snapshots.record(GameTick(1), 10, 4);
+        resource_snapshots.record(GameTick(2), 20, 4);
+        resource_snapshots.record(GameTick(3), 30, 4);
+        resource_snapshots.record(GameTick(2), 21, 4);
+
+        assert_eq!(Some(&21), resource_snapshots.get(GameTick(2)));
+        assert_eq!(None, resource_snapshots.get(GameTick(3)));
+    }
+}
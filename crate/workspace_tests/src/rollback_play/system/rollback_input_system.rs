@@ -0,0 +1,219 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Read, SystemData, World, WorldExt, WriteExpect},
+        shrev::{EventChannel, ReaderId},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use network_session_model::play::SessionStatus;
+    use rollback_model::{
+        config::SessionSyncMode,
+        play::{InputHistory, RollbackStatus},
+    };
+    use state_registry::StateId;
+
+    use rollback_play::RollbackInputSystemDesc;
+
+    #[test]
+    fn sends_recorded_game_input_events_for_current_tick() -> Result<(), Error> {
+        let mut input_history = InputHistory::default();
+        input_history.insert(TickedGameInputEvent::new(GameTick(5), event()));
+
+        run_test(
+            SetupParams {
+                game_tick: GameTick(5),
+                input_history,
+                ticked_game_input_event: None,
+            },
+            ExpectedParams {
+                game_tick: GameTick(5),
+                rollback_status: RollbackStatus::None,
+                game_input_events: vec![event()],
+            },
+        )
+    }
+
+    #[test]
+    fn does_not_send_game_input_event_when_tick_is_future() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_tick: GameTick(5),
+                input_history: InputHistory::default(),
+                ticked_game_input_event: Some(TickedGameInputEvent::new(GameTick(7), event())),
+            },
+            ExpectedParams {
+                game_tick: GameTick(5),
+                rollback_status: RollbackStatus::None,
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn rolls_back_to_tick_when_tick_is_past() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_tick: GameTick(5),
+                input_history: InputHistory::default(),
+                ticked_game_input_event: Some(TickedGameInputEvent::new(GameTick(3), event())),
+            },
+            ExpectedParams {
+                game_tick: GameTick(3),
+                rollback_status: RollbackStatus::Restoring {
+                    tick: GameTick(3),
+                    present: GameTick(5),
+                },
+                game_input_events: vec![event()],
+            },
+        )
+    }
+
+    #[test]
+    fn discards_event_when_tick_is_outside_rollback_window() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                game_tick: GameTick(20),
+                input_history: InputHistory::default(),
+                ticked_game_input_event: Some(TickedGameInputEvent::new(GameTick(3), event())),
+            },
+            ExpectedParams {
+                game_tick: GameTick(20),
+                rollback_status: RollbackStatus::None,
+                game_input_events: vec![],
+            },
+        )
+    }
+
+    #[test]
+    fn rolls_back_to_tick_received_while_paused_when_resumed() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_system_desc(RollbackInputSystemDesc::default(), "", &[])
+            .with_resource(SessionStatus::HostEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(StateId::GamePlayPause)
+            .with_resource(GameTick(5))
+            .with_effect(|world| {
+                world
+                    .write_resource::<EventChannel<TickedGameInputEvent>>()
+                    .single_write(TickedGameInputEvent::new(GameTick(3), event()));
+            })
+            .with_assertion(|world| {
+                assert_state(world, GameTick(5), RollbackStatus::None, vec![]);
+                assert_eq!(
+                    &[event()],
+                    world.read_resource::<InputHistory>().events(GameTick(3))
+                );
+            })
+            .with_effect(|world| world.insert(StateId::GamePlay))
+            .with_assertion(|world| {
+                assert_state(
+                    world,
+                    GameTick(3),
+                    RollbackStatus::Restoring {
+                        tick: GameTick(3),
+                        present: GameTick(5),
+                    },
+                    vec![event()],
+                )
+            })
+            .run()
+    }
+
+    fn run_test(
+        SetupParams {
+            game_tick: game_tick_setup,
+            input_history,
+            ticked_game_input_event,
+        }: SetupParams,
+        ExpectedParams {
+            game_tick: game_tick_expected,
+            rollback_status: rollback_status_expected,
+            game_input_events: game_input_events_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_setup(<Read<'_, EventChannel<GameInputEvent>> as SystemData>::setup)
+            .with_setup(setup_game_input_event_reader)
+            .with_system_desc(RollbackInputSystemDesc::default(), "", &[])
+            .with_resource(SessionStatus::HostEstablished)
+            .with_resource(SessionSyncMode::Rollback)
+            .with_resource(StateId::GamePlay)
+            .with_resource(game_tick_setup)
+            .with_resource(input_history)
+            .with_effect(move |world| {
+                if let Some(ticked_game_input_event) = ticked_game_input_event {
+                    world
+                        .write_resource::<EventChannel<TickedGameInputEvent>>()
+                        .single_write(ticked_game_input_event);
+                }
+            })
+            .with_assertion(move |world| {
+                assert_state(
+                    world,
+                    game_tick_expected,
+                    rollback_status_expected,
+                    game_input_events_expected,
+                )
+            })
+            .run()
+    }
+
+    fn assert_state(
+        world: &mut World,
+        game_tick_expected: GameTick,
+        rollback_status_expected: RollbackStatus,
+        game_input_events_expected: Vec<GameInputEvent>,
+    ) {
+        let (game_tick, rollback_status, mut game_input_event_rid, game_input_ec) = world
+            .system_data::<(
+                Read<'_, GameTick>,
+                Read<'_, RollbackStatus>,
+                WriteExpect<'_, ReaderId<GameInputEvent>>,
+                Read<'_, EventChannel<GameInputEvent>>,
+            )>();
+        let game_input_events = game_input_ec
+            .read(&mut *game_input_event_rid)
+            .copied()
+            .collect::<Vec<GameInputEvent>>();
+
+        assert_eq!(
+            (
+                game_tick_expected,
+                rollback_status_expected,
+                game_input_events_expected
+            ),
+            (*game_tick, *rollback_status, game_input_events)
+        );
+    }
+
+    fn setup_game_input_event_reader(world: &mut World) {
+        let game_input_event_rid = world
+            .write_resource::<EventChannel<GameInputEvent>>()
+            .register_reader();
+        world.insert(game_input_event_rid);
+    }
+
+    fn event() -> GameInputEvent {
+        GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack))
+    }
+
+    struct SetupParams {
+        game_tick: GameTick,
+        input_history: InputHistory,
+        ticked_game_input_event: Option<TickedGameInputEvent>,
+    }
+
+    struct ExpectedParams {
+        game_tick: GameTick,
+        rollback_status: RollbackStatus,
+        game_input_events: Vec<GameInputEvent>,
+    }
+}
@@ -6,13 +6,19 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
+    use asset_model::loaded::AssetFingerprint;
     use game_input_model::loaded::{PlayerController, PlayerControllers};
     use net_model::play::NetMessageEvent;
-    use network_session_model::play::{SessionDeviceName, SessionStatus};
+    use network_session_model::play::{
+        ProtocolVersion, SessionDeviceName, SessionHandshake, SessionStatus,
+    };
+    use rollback_model::config::SessionSyncMode;
     use session_host_model::{play::SessionHostRequestParams, SessionHostEvent};
 
     use session_host_play::SessionHostRequestSystemDesc;
 
+    const ASSET_FINGERPRINT: AssetFingerprint = AssetFingerprint(123);
+
     #[test]
     fn does_nothing_when_no_session_host_event() -> Result<(), Error> {
         run_test(
@@ -28,23 +34,35 @@ mod tests {
     }
 
     #[test]
-    fn inserts_resources_on_session_accepted() -> Result<(), Error> {
-        let session_host_event = SessionHostEvent::SessionHostRequest(SessionHostRequestParams {
+    fn inserts_resources_and_sends_handshake_on_session_host_request() -> Result<(), Error> {
+        let session_host_request_params = SessionHostRequestParams {
             session_device_name: SessionDeviceName::new(String::from("azriel")),
             player_controllers: PlayerControllers::new(vec![PlayerController::new(
                 0,
                 String::from("p0"),
             )]),
-        });
+            session_handshake: SessionHandshake::default(),
+        };
+        let session_host_event =
+            SessionHostEvent::SessionHostRequest(session_host_request_params.clone());
+        let session_host_event_sent =
+            SessionHostEvent::SessionHostRequest(SessionHostRequestParams {
+                session_handshake: SessionHandshake::new(
+                    ProtocolVersion::CURRENT,
+                    ASSET_FINGERPRINT,
+                    SessionSyncMode::default(),
+                ),
+                ..session_host_request_params
+            });
 
         run_test(
             SetupParams {
                 session_status: SessionStatus::None,
-                session_host_event: Some(session_host_event.clone()),
+                session_host_event: Some(session_host_event),
             },
             ExpectedParams {
                 session_status: SessionStatus::HostRequested,
-                net_message_event: Some(NetMessageEvent::SessionHostEvent(session_host_event)),
+                net_message_event: Some(NetMessageEvent::SessionHostEvent(session_host_event_sent)),
             },
         )
     }
@@ -61,6 +79,7 @@ mod tests {
                             0,
                             String::from("p0"),
                         )]),
+                        session_handshake: SessionHandshake::default(),
                     },
                 )),
             },
@@ -86,6 +105,7 @@ mod tests {
             .with_setup(setup_net_message_event_reader)
             .with_system_desc(SessionHostRequestSystemDesc::default(), "", &[])
             .with_resource(session_status_setup)
+            .with_resource(ASSET_FINGERPRINT)
             .with_effect(move |world| {
                 if let Some(session_host_event) = session_host_event {
                     world
@@ -11,7 +11,7 @@ mod tests {
     use net_model::play::{NetData, NetEventChannel};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
-        SessionStatus,
+        SessionReconnectToken, SessionStatus,
     };
     use session_host_model::{play::SessionAcceptResponse, SessionHostEvent};
 
@@ -30,6 +30,7 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("abcd")),
                 session_device_id: SessionDeviceId::new(123),
+                session_reconnect_token: SessionReconnectToken::default(),
                 session_devices: SessionDevices::new(vec![]),
                 session_status: SessionStatus::None,
                 player_controllers: PlayerControllers::default(),
@@ -53,6 +54,7 @@ mod tests {
                 session_status: SessionStatus::HostRequested,
                 session_host_event: Some(SessionHostEvent::SessionAccept(SessionAcceptResponse {
                     session_device_id: SessionDeviceId::new(234),
+                    session_reconnect_token: SessionReconnectToken::new(1234),
                     session: Session {
                         session_code: SessionCode::new(String::from("defg")),
                         session_devices: SessionDevices::new(vec![SessionDevice::new(
@@ -70,6 +72,7 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("defg")),
                 session_device_id: SessionDeviceId::new(234),
+                session_reconnect_token: SessionReconnectToken::new(1234),
                 session_devices: SessionDevices::new(vec![SessionDevice::new(
                     SessionDeviceId::new(234),
                     SessionDeviceName::new(String::from("azriel")),
@@ -91,6 +94,7 @@ mod tests {
                 session_status: SessionStatus::None,
                 session_host_event: Some(SessionHostEvent::SessionAccept(SessionAcceptResponse {
                     session_device_id: SessionDeviceId::new(234),
+                    session_reconnect_token: SessionReconnectToken::new(1234),
                     session: Session {
                         session_code: SessionCode::new(String::from("defg")),
                         session_devices: SessionDevices::new(vec![SessionDevice::new(
@@ -112,6 +116,7 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("abcd")),
                 session_device_id: SessionDeviceId::new(123),
+                session_reconnect_token: SessionReconnectToken::default(),
                 session_devices: SessionDevices::new(vec![]),
                 session_status: SessionStatus::None,
                 player_controllers: PlayerControllers::default(),
@@ -130,6 +135,7 @@ mod tests {
         ExpectedParams {
             session_code: session_code_expected,
             session_device_id: session_device_id_expected,
+            session_reconnect_token: session_reconnect_token_expected,
             session_devices: session_devices_expected,
             session_status: session_status_expected,
             player_controllers: player_controllers_expected,
@@ -158,12 +164,14 @@ mod tests {
                 let (
                     session_code,
                     session_device_id,
+                    session_reconnect_token,
                     session_devices,
                     session_status,
                     player_controllers,
                 ) = world.system_data::<(
                     Read<'_, SessionCode>,
                     Read<'_, SessionDeviceId>,
+                    Read<'_, SessionReconnectToken>,
                     Read<'_, SessionDevices>,
                     Read<'_, SessionStatus>,
                     Read<'_, PlayerControllers>,
@@ -172,12 +180,14 @@ mod tests {
                 let (
                     session_code,
                     session_device_id,
+                    session_reconnect_token,
                     session_devices,
                     session_status,
                     player_controllers,
                 ) = (
                     &*session_code,
                     &*session_device_id,
+                    &*session_reconnect_token,
                     &*session_devices,
                     &*session_status,
                     &*player_controllers,
@@ -187,6 +197,7 @@ mod tests {
                     (
                         &session_code_expected,
                         &session_device_id_expected,
+                        &session_reconnect_token_expected,
                         &session_devices_expected,
                         &session_status_expected,
                         &player_controllers_expected,
@@ -194,6 +205,7 @@ mod tests {
                     (
                         session_code,
                         session_device_id,
+                        session_reconnect_token,
                         session_devices,
                         session_status,
                         player_controllers,
@@ -214,6 +226,7 @@ mod tests {
     struct ExpectedParams {
         session_code: SessionCode,
         session_device_id: SessionDeviceId,
+        session_reconnect_token: SessionReconnectToken,
         session_devices: SessionDevices,
         session_status: SessionStatus,
         player_controllers: PlayerControllers,
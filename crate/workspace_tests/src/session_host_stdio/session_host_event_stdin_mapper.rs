@@ -3,6 +3,7 @@ mod tests {
     use game_input_model::loaded::{PlayerController, PlayerControllers};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        SessionHandshake, SessionReconnectToken,
     };
     use session_host_model::{
         play::{SessionAcceptResponse, SessionHostRequestParams},
@@ -20,6 +21,7 @@ mod tests {
         let args = SessionHostEvent::SessionHostRequest(SessionHostRequestParams {
             session_device_name,
             player_controllers,
+            session_handshake: SessionHandshake::default(),
         });
 
         let result = SessionHostEventStdinMapper::map(&(), args.clone());
@@ -75,6 +77,7 @@ mod tests {
         ]);
         let args = SessionHostEvent::SessionAccept(SessionAcceptResponse {
             session_device_id,
+            session_reconnect_token: SessionReconnectToken::new(1234),
             session: Session {
                 session_code,
                 session_devices,
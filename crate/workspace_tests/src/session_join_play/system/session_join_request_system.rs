@@ -6,18 +6,29 @@ mod tests {
         Error,
     };
     use amethyst_test::AmethystApplication;
+    use asset_model::loaded::AssetFingerprint;
     use game_input_model::loaded::{PlayerController, PlayerControllers};
     use net_model::play::NetMessageEvent;
-    use network_session_model::play::{SessionCode, SessionDeviceName, SessionStatus};
+    use network_session_model::{
+        config::SessionServerConfig,
+        play::{
+            ProtocolVersion, SessionCode, SessionDeviceName, SessionDeviceType, SessionHandshake,
+            SessionStatus,
+        },
+    };
+    use rollback_model::config::SessionSyncMode;
     use session_join_model::{play::SessionJoinRequestParams, SessionJoinEvent};
 
     use session_join_play::SessionJoinRequestSystemDesc;
 
+    const ASSET_FINGERPRINT: AssetFingerprint = AssetFingerprint(123);
+
     #[test]
     fn does_nothing_when_no_session_join_event() -> Result<(), Error> {
         run_test(
             SetupParams {
                 session_status: SessionStatus::None,
+                session_server_config: SessionServerConfig::default(),
                 session_join_event: None,
             },
             ExpectedParams {
@@ -28,26 +39,40 @@ mod tests {
     }
 
     #[test]
-    fn inserts_resources_on_session_accepted() -> Result<(), Error> {
-        let session_join_event = SessionJoinEvent::SessionJoinRequest(SessionJoinRequestParams {
+    fn inserts_resources_and_sends_handshake_on_session_join_request() -> Result<(), Error> {
+        let session_join_request_params = SessionJoinRequestParams {
             session_code: SessionCode::new(String::from("abcd")),
             session_device_name: SessionDeviceName::new(String::from("azriel")),
             player_controllers: PlayerControllers::new(vec![PlayerController::new(
                 0,
                 String::from("p0"),
             )]),
-        });
+            session_device_type: SessionDeviceType::Player,
+            session_handshake: SessionHandshake::default(),
+        };
+        let session_join_event =
+            SessionJoinEvent::SessionJoinRequest(session_join_request_params.clone());
+        let session_join_event_sent =
+            SessionJoinEvent::SessionJoinRequest(SessionJoinRequestParams {
+                session_handshake: SessionHandshake::new(
+                    ProtocolVersion::CURRENT,
+                    ASSET_FINGERPRINT,
+                    SessionSyncMode::default(),
+                ),
+                ..session_join_request_params
+            });
 
         run_test(
             SetupParams {
                 session_status: SessionStatus::None,
-                session_join_event: Some(session_join_event.clone()),
+                session_server_config: SessionServerConfig::default(),
+                session_join_event: Some(session_join_event),
             },
             ExpectedParams {
                 session_status: SessionStatus::JoinRequested {
                     session_code: SessionCode::new(String::from("abcd")),
                 },
-                net_message_event: Some(NetMessageEvent::SessionJoinEvent(session_join_event)),
+                net_message_event: Some(NetMessageEvent::SessionJoinEvent(session_join_event_sent)),
             },
         )
     }
@@ -59,6 +84,7 @@ mod tests {
                 session_status: SessionStatus::JoinRequested {
                     session_code: SessionCode::new(String::from("abcd")),
                 },
+                session_server_config: SessionServerConfig::default(),
                 session_join_event: Some(SessionJoinEvent::SessionJoinRequest(
                     SessionJoinRequestParams {
                         session_code: SessionCode::new(String::from("abcd")),
@@ -67,6 +93,8 @@ mod tests {
                             0,
                             String::from("p0"),
                         )]),
+                        session_device_type: SessionDeviceType::Player,
+                        session_handshake: SessionHandshake::default(),
                     },
                 )),
             },
@@ -79,9 +107,39 @@ mod tests {
         )
     }
 
+    #[test]
+    fn does_not_send_request_when_session_server_address_fails_to_resolve() -> Result<(), Error> {
+        let session_join_event = SessionJoinEvent::SessionJoinRequest(SessionJoinRequestParams {
+            session_code: SessionCode::new(String::from("abcd")),
+            session_device_name: SessionDeviceName::new(String::from("azriel")),
+            player_controllers: PlayerControllers::new(vec![PlayerController::new(
+                0,
+                String::from("p0"),
+            )]),
+            session_device_type: SessionDeviceType::Player,
+            session_handshake: SessionHandshake::default(),
+        });
+
+        run_test(
+            SetupParams {
+                session_status: SessionStatus::None,
+                session_server_config: SessionServerConfig {
+                    address: String::from("localhost:abc"),
+                    port: 1234,
+                },
+                session_join_event: Some(session_join_event),
+            },
+            ExpectedParams {
+                session_status: SessionStatus::None,
+                net_message_event: None,
+            },
+        )
+    }
+
     fn run_test(
         SetupParams {
             session_status: session_status_setup,
+            session_server_config,
             session_join_event,
         }: SetupParams,
         ExpectedParams {
@@ -94,6 +152,8 @@ mod tests {
             .with_setup(setup_net_message_event_reader)
             .with_system_desc(SessionJoinRequestSystemDesc::default(), "", &[])
             .with_resource(session_status_setup)
+            .with_resource(session_server_config)
+            .with_resource(ASSET_FINGERPRINT)
             .with_effect(move |world| {
                 if let Some(session_join_event) = session_join_event {
                     world
@@ -131,6 +191,7 @@ mod tests {
 
     struct SetupParams {
         session_status: SessionStatus,
+        session_server_config: SessionServerConfig,
         session_join_event: Option<SessionJoinEvent>,
     }
 
@@ -14,9 +14,12 @@ mod tests {
     use net_model::play::{NetData, NetEventChannel};
     use network_session_model::play::{
         Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
-        SessionStatus,
+        SessionReconnectToken, SessionStatus,
+    };
+    use session_join_model::{
+        play::{SessionAcceptResponse, SessionJoinError, SessionRejectResponse},
+        SessionJoinEvent,
     };
-    use session_join_model::{play::SessionAcceptResponse, SessionJoinEvent};
 
     use session_join_play::SessionJoinResponseSystemDesc;
 
@@ -33,6 +36,7 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("abcd")),
                 session_device_id: SessionDeviceId::new(123),
+                session_reconnect_token: SessionReconnectToken::default(),
                 session_devices: SessionDevices::new(vec![]),
                 session_status: SessionStatus::None,
                 player_controllers: PlayerControllers::default(),
@@ -59,6 +63,7 @@ mod tests {
                 },
                 session_join_event: Some(SessionJoinEvent::SessionAccept(SessionAcceptResponse {
                     session_device_id: SessionDeviceId::new(234),
+                    session_reconnect_token: SessionReconnectToken::new(1234),
                     session: Session {
                         session_code: SessionCode::new(String::from("defg")),
                         session_devices: SessionDevices::new(vec![SessionDevice::new(
@@ -77,6 +82,7 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("defg")),
                 session_device_id: SessionDeviceId::new(234),
+                session_reconnect_token: SessionReconnectToken::new(1234),
                 session_devices: SessionDevices::new(vec![SessionDevice::new(
                     SessionDeviceId::new(234),
                     SessionDeviceName::new(String::from("azriel")),
@@ -105,6 +111,7 @@ mod tests {
                 session_status: SessionStatus::None,
                 session_join_event: Some(SessionJoinEvent::SessionAccept(SessionAcceptResponse {
                     session_device_id: SessionDeviceId::new(234),
+                    session_reconnect_token: SessionReconnectToken::new(1234),
                     session: Session {
                         session_code: SessionCode::new(String::from("defg")),
                         session_devices: SessionDevices::new(vec![SessionDevice::new(
@@ -123,6 +130,36 @@ mod tests {
             ExpectedParams {
                 session_code: SessionCode::new(String::from("abcd")),
                 session_device_id: SessionDeviceId::new(123),
+                session_reconnect_token: SessionReconnectToken::default(),
+                session_devices: SessionDevices::new(vec![]),
+                session_status: SessionStatus::None,
+                player_controllers: PlayerControllers::default(),
+                controller_id_offset: ControllerIdOffset::default(),
+            },
+        )
+    }
+
+    #[test]
+    fn resets_session_status_on_session_rejected() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(123),
+                session_devices: SessionDevices::new(vec![]),
+                session_status: SessionStatus::JoinRequested {
+                    session_code: SessionCode::new(String::from("defg")),
+                },
+                session_join_event: Some(SessionJoinEvent::SessionReject(
+                    SessionRejectResponse::new(
+                        SessionCode::new(String::from("defg")),
+                        SessionJoinError::SessionFull,
+                    ),
+                )),
+            },
+            ExpectedParams {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(123),
+                session_reconnect_token: SessionReconnectToken::default(),
                 session_devices: SessionDevices::new(vec![]),
                 session_status: SessionStatus::None,
                 player_controllers: PlayerControllers::default(),
@@ -142,6 +179,7 @@ mod tests {
         ExpectedParams {
             session_code: session_code_expected,
             session_device_id: session_device_id_expected,
+            session_reconnect_token: session_reconnect_token_expected,
             session_devices: session_devices_expected,
             session_status: session_status_expected,
             player_controllers: player_controllers_expected,
@@ -171,6 +209,7 @@ mod tests {
                 let (
                     session_code,
                     session_device_id,
+                    session_reconnect_token,
                     session_devices,
                     session_status,
                     player_controllers,
@@ -178,6 +217,7 @@ mod tests {
                 ) = world.system_data::<(
                     Read<'_, SessionCode>,
                     Read<'_, SessionDeviceId>,
+                    Read<'_, SessionReconnectToken>,
                     Read<'_, SessionDevices>,
                     Read<'_, SessionStatus>,
                     Read<'_, PlayerControllers>,
@@ -187,6 +227,7 @@ mod tests {
                 let (
                     session_code,
                     session_device_id,
+                    session_reconnect_token,
                     session_devices,
                     session_status,
                     player_controllers,
@@ -194,6 +235,7 @@ mod tests {
                 ) = (
                     &*session_code,
                     &*session_device_id,
+                    &*session_reconnect_token,
                     &*session_devices,
                     &*session_status,
                     &*player_controllers,
@@ -204,6 +246,7 @@ mod tests {
                     (
                         &session_code_expected,
                         &session_device_id_expected,
+                        &session_reconnect_token_expected,
                         &session_devices_expected,
                         &session_status_expected,
                         &player_controllers_expected,
@@ -212,6 +255,7 @@ mod tests {
                     (
                         session_code,
                         session_device_id,
+                        session_reconnect_token,
                         session_devices,
                         session_status,
                         player_controllers,
@@ -233,6 +277,7 @@ mod tests {
     struct ExpectedParams {
         session_code: SessionCode,
         session_device_id: SessionDeviceId,
+        session_reconnect_token: SessionReconnectToken,
         session_devices: SessionDevices,
         session_status: SessionStatus,
         player_controllers: PlayerControllers,
@@ -5,7 +5,8 @@ mod tests {
         play::ControllerIdOffset,
     };
     use network_session_model::play::{
-        Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDeviceType,
+        SessionDevices, SessionHandshake, SessionReconnectToken,
     };
     use session_join_model::{
         play::{SessionAcceptResponse, SessionJoinRequestParams},
@@ -25,6 +26,26 @@ mod tests {
             session_device_name,
             session_code,
             player_controllers,
+            session_device_type: SessionDeviceType::Player,
+            session_handshake: SessionHandshake::default(),
+        });
+
+        let result = SessionJoinEventStdinMapper::map(&(), args.clone());
+
+        assert!(result.is_ok());
+        assert_eq!(args, result.unwrap())
+    }
+
+    #[test]
+    fn maps_session_join_request_event_for_spectator() {
+        let session_device_name = SessionDeviceName::from(String::from("エイズリエル"));
+        let session_code = SessionCode::from(String::from("abcd"));
+        let args = SessionJoinEvent::SessionJoinRequest(SessionJoinRequestParams {
+            session_device_name,
+            session_code,
+            player_controllers: PlayerControllers::default(),
+            session_device_type: SessionDeviceType::Spectator,
+            session_handshake: SessionHandshake::default(),
         });
 
         let result = SessionJoinEventStdinMapper::map(&(), args.clone());
@@ -81,6 +102,7 @@ mod tests {
         let controller_id_offset = ControllerIdOffset::new(0);
         let args = SessionJoinEvent::SessionAccept(SessionAcceptResponse {
             session_device_id,
+            session_reconnect_token: SessionReconnectToken::new(1234),
             session: Session {
                 session_code,
                 session_devices,
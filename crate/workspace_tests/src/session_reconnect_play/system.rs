@@ -0,0 +1 @@
+mod session_reconnect_response_system;
@@ -0,0 +1,213 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use amethyst::{
+        ecs::{Read, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        loaded::{PlayerController, PlayerControllers},
+        play::GameTick,
+    };
+    use net_model::play::{NetData, NetEventChannel};
+    use network_session_model::play::{
+        Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName, SessionDevices,
+        SessionStatus,
+    };
+    use rollback_model::{config::SessionSyncMode, play::RollbackStatus};
+    use session_reconnect_model::{
+        play::{
+            SessionReconnectAcceptResponse, SessionReconnectError, SessionReconnectRejectResponse,
+            SessionReconnectStatus,
+        },
+        SessionReconnectEvent,
+    };
+    use state_registry::StateId;
+
+    use session_reconnect_play::SessionReconnectResponseSystemDesc;
+
+    #[test]
+    fn does_not_change_resources_when_not_reconnecting() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_reconnect_status: SessionReconnectStatus::None,
+                session_sync_mode: SessionSyncMode::Rollback,
+                game_tick: GameTick::new(10),
+                session_reconnect_event: Some(SessionReconnectEvent::SessionReconnectAccept(
+                    accept_response(GameTick::new(20)),
+                )),
+            },
+            ExpectedParams {
+                session_reconnect_status: SessionReconnectStatus::None,
+                session_status: SessionStatus::JoinEstablished,
+                session_devices: SessionDevices::default(),
+                rollback_status: RollbackStatus::None,
+            },
+        )
+    }
+
+    #[test]
+    fn restores_session_resources_when_accepted() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_reconnect_status: SessionReconnectStatus::Reconnecting,
+                session_sync_mode: SessionSyncMode::Lockstep,
+                game_tick: GameTick::new(10),
+                session_reconnect_event: Some(SessionReconnectEvent::SessionReconnectAccept(
+                    accept_response(GameTick::new(20)),
+                )),
+            },
+            ExpectedParams {
+                session_reconnect_status: SessionReconnectStatus::None,
+                session_status: SessionStatus::JoinEstablished,
+                session_devices: session_devices(),
+                rollback_status: RollbackStatus::None,
+            },
+        )
+    }
+
+    #[test]
+    fn resimulates_to_session_tick_in_rollback_mode_when_accepted() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_reconnect_status: SessionReconnectStatus::Reconnecting,
+                session_sync_mode: SessionSyncMode::Rollback,
+                game_tick: GameTick::new(10),
+                session_reconnect_event: Some(SessionReconnectEvent::SessionReconnectAccept(
+                    accept_response(GameTick::new(20)),
+                )),
+            },
+            ExpectedParams {
+                session_reconnect_status: SessionReconnectStatus::None,
+                session_status: SessionStatus::JoinEstablished,
+                session_devices: session_devices(),
+                rollback_status: RollbackStatus::Resimulating {
+                    present: GameTick::new(20),
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn leaves_session_when_rejected() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_reconnect_status: SessionReconnectStatus::Reconnecting,
+                session_sync_mode: SessionSyncMode::Rollback,
+                game_tick: GameTick::new(10),
+                session_reconnect_event: Some(SessionReconnectEvent::SessionReconnectReject(
+                    SessionReconnectRejectResponse::new(
+                        session_code(),
+                        SessionReconnectError::SessionReconnectTokenInvalid,
+                    ),
+                )),
+            },
+            ExpectedParams {
+                session_reconnect_status: SessionReconnectStatus::None,
+                session_status: SessionStatus::None,
+                session_devices: SessionDevices::default(),
+                rollback_status: RollbackStatus::None,
+            },
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_reconnect_status: session_reconnect_status_setup,
+            session_sync_mode,
+            game_tick,
+            session_reconnect_event,
+        }: SetupParams,
+        ExpectedParams {
+            session_reconnect_status: session_reconnect_status_expected,
+            session_status: session_status_expected,
+            session_devices: session_devices_expected,
+            rollback_status: rollback_status_expected,
+        }: ExpectedParams,
+    ) -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system_desc(SessionReconnectResponseSystemDesc::default(), "", &[])
+            .with_setup(move |world| {
+                world.insert(session_reconnect_status_setup);
+                world.insert(session_sync_mode);
+                world.insert(game_tick);
+                world.insert(StateId::GamePlay);
+                world.insert(session_code());
+                world.insert(SessionDeviceId::new(234));
+                world.insert(SessionStatus::JoinEstablished);
+            })
+            .with_effect(move |world| {
+                if let Some(session_reconnect_event) = session_reconnect_event {
+                    let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+                    world
+                        .write_resource::<NetEventChannel<SessionReconnectEvent>>()
+                        .single_write(NetData {
+                            socket_addr,
+                            data: session_reconnect_event,
+                        });
+                }
+            })
+            .with_assertion(move |world| {
+                let (session_reconnect_status, session_status, session_devices, rollback_status) =
+                    world.system_data::<(
+                        Read<'_, SessionReconnectStatus>,
+                        Read<'_, SessionStatus>,
+                        Read<'_, SessionDevices>,
+                        Read<'_, RollbackStatus>,
+                    )>();
+
+                assert_eq!(
+                    (
+                        session_reconnect_status_expected,
+                        &session_status_expected,
+                        &session_devices_expected,
+                        rollback_status_expected,
+                    ),
+                    (
+                        *session_reconnect_status,
+                        &*session_status,
+                        &*session_devices,
+                        *rollback_status,
+                    )
+                );
+            })
+            .run()
+    }
+
+    fn session_code() -> SessionCode {
+        SessionCode::new(String::from("abcd"))
+    }
+
+    fn session_devices() -> SessionDevices {
+        SessionDevices::new(vec![SessionDevice::new(
+            SessionDeviceId::new(234),
+            SessionDeviceName::new(String::from("azriel")),
+            PlayerControllers::new(vec![PlayerController::new(0, String::from("p0"))]),
+        )])
+    }
+
+    fn accept_response(game_tick: GameTick) -> SessionReconnectAcceptResponse {
+        SessionReconnectAcceptResponse::new(
+            Session::new(session_code(), session_devices()),
+            SessionDeviceId::new(234),
+            PlayerControllers::new(vec![PlayerController::new(0, String::from("p0"))]),
+            game_tick,
+        )
+    }
+
+    struct SetupParams {
+        session_reconnect_status: SessionReconnectStatus,
+        session_sync_mode: SessionSyncMode,
+        game_tick: GameTick,
+        session_reconnect_event: Option<SessionReconnectEvent>,
+    }
+
+    struct ExpectedParams {
+        session_reconnect_status: SessionReconnectStatus,
+        session_status: SessionStatus,
+        session_devices: SessionDevices,
+        rollback_status: RollbackStatus,
+    }
+}
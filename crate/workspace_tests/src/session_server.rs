@@ -0,0 +1,2 @@
+mod model;
+mod system;
@@ -0,0 +1,2 @@
+mod admin_command;
+mod ip_request_trackers;
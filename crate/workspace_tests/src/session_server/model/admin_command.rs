@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use network_session_model::play::{SessionCode, SessionDeviceId};
+
+    use session_server::model::AdminCommand;
+
+    #[test]
+    fn parses_commands_without_arguments() {
+        assert_eq!(Ok(AdminCommand::Help), AdminCommand::from_str("help"));
+        assert_eq!(
+            Ok(AdminCommand::Sessions),
+            AdminCommand::from_str("sessions")
+        );
+        assert_eq!(Ok(AdminCommand::Metrics), AdminCommand::from_str("metrics"));
+        assert_eq!(Ok(AdminCommand::Quit), AdminCommand::from_str("quit"));
+    }
+
+    #[test]
+    fn parses_kick_command() {
+        assert_eq!(
+            Ok(AdminCommand::Kick {
+                session_code: SessionCode::new(String::from("abcd")),
+                session_device_id: SessionDeviceId::new(1),
+            }),
+            AdminCommand::from_str("kick abcd 1")
+        );
+    }
+
+    #[test]
+    fn parses_close_command() {
+        assert_eq!(
+            Ok(AdminCommand::Close {
+                session_code: SessionCode::new(String::from("abcd")),
+            }),
+            AdminCommand::from_str("close abcd")
+        );
+    }
+
+    #[test]
+    fn ignores_surrounding_whitespace() {
+        assert_eq!(
+            Ok(AdminCommand::Close {
+                session_code: SessionCode::new(String::from("abcd")),
+            }),
+            AdminCommand::from_str("  close   abcd \r\n")
+        );
+    }
+
+    #[test]
+    fn round_trips_through_display() {
+        let admin_command = AdminCommand::Kick {
+            session_code: SessionCode::new(String::from("abcd")),
+            session_device_id: SessionDeviceId::new(1),
+        };
+
+        assert_eq!(
+            Ok(admin_command.clone()),
+            AdminCommand::from_str(&admin_command.to_string())
+        );
+    }
+
+    #[test]
+    fn returns_err_for_unknown_command() {
+        assert_eq!(
+            Err(String::from(
+                "Unknown command: `ban`. Enter `help` for a list of commands."
+            )),
+            AdminCommand::from_str("ban abcd")
+        );
+    }
+
+    #[test]
+    fn returns_err_for_empty_command() {
+        assert_eq!(
+            Err(String::from(
+                "Unknown command: ``. Enter `help` for a list of commands."
+            )),
+            AdminCommand::from_str("")
+        );
+    }
+
+    #[test]
+    fn returns_err_when_kick_arguments_missing() {
+        let usage = Err(String::from("Usage: kick <session_code> <id>"));
+
+        assert_eq!(usage, AdminCommand::from_str("kick"));
+        assert_eq!(usage, AdminCommand::from_str("kick abcd"));
+    }
+
+    #[test]
+    fn returns_err_when_kick_device_id_invalid() {
+        assert_eq!(
+            Err(String::from("Usage: kick <session_code> <id>")),
+            AdminCommand::from_str("kick abcd one")
+        );
+    }
+
+    #[test]
+    fn returns_err_when_close_session_code_missing() {
+        assert_eq!(
+            Err(String::from("Usage: close <session_code>")),
+            AdminCommand::from_str("close")
+        );
+    }
+
+    #[test]
+    fn returns_err_when_too_many_arguments() {
+        assert_eq!(
+            Err(String::from("Too many arguments for `quit`.")),
+            AdminCommand::from_str("quit now")
+        );
+        assert_eq!(
+            Err(String::from("Too many arguments for `kick`.")),
+            AdminCommand::from_str("kick abcd 1 2")
+        );
+    }
+}
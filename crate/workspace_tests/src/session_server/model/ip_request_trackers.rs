@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{IpAddr, Ipv4Addr},
+        time::{Duration, Instant},
+    };
+
+    use session_server::model::{IpRequestTrackers, SessionLimits};
+
+    const IP_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn allows_requests_up_to_request_limit() {
+        let mut ip_request_trackers = IpRequestTrackers::default();
+        let session_limits = session_limits();
+        let now = Instant::now();
+
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now));
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now));
+        assert!(!ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now));
+    }
+
+    #[test]
+    fn resets_request_count_when_request_window_elapses() {
+        let mut ip_request_trackers = IpRequestTrackers::default();
+        let session_limits = session_limits();
+        let now = Instant::now();
+
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now));
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now));
+
+        let window_end = now + session_limits.request_window;
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, window_end));
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, window_end));
+        assert!(!ip_request_trackers.request_allowed(IP_ADDR, &session_limits, window_end));
+    }
+
+    #[test]
+    fn rejects_requests_until_ban_duration_elapses() {
+        let mut ip_request_trackers = IpRequestTrackers::default();
+        let session_limits = session_limits();
+        let now = Instant::now();
+        (0..3).for_each(|_| {
+            ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now);
+        });
+
+        // The request window has elapsed, but the ban has not.
+        let window_end = now + session_limits.request_window;
+        assert!(!ip_request_trackers.request_allowed(IP_ADDR, &session_limits, window_end));
+
+        let ban_end = now + session_limits.ban_duration;
+        assert!(ip_request_trackers.request_allowed(IP_ADDR, &session_limits, ban_end));
+    }
+
+    #[test]
+    fn prune_removes_trackers_that_are_not_banned_after_request_window() {
+        let mut ip_request_trackers = IpRequestTrackers::default();
+        let session_limits = session_limits();
+        let now = Instant::now();
+        let ip_addr_banned = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 2));
+        ip_request_trackers.request_allowed(IP_ADDR, &session_limits, now);
+        (0..3).for_each(|_| {
+            ip_request_trackers.request_allowed(ip_addr_banned, &session_limits, now);
+        });
+
+        ip_request_trackers.prune(&session_limits, now + session_limits.request_window);
+
+        assert!(!ip_request_trackers.contains_key(&IP_ADDR));
+        assert!(ip_request_trackers.contains_key(&ip_addr_banned));
+    }
+
+    fn session_limits() -> SessionLimits {
+        SessionLimits {
+            request_limit: 2,
+            request_window: Duration::from_secs(10),
+            ban_duration: Duration::from_secs(60),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod network_input_responder_system;
+mod session_join_responder_system;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, SocketAddr};
+
+    use amethyst::{ecs::WorldExt, network::simulation::TransportResource, Error};
+    use amethyst_test::AmethystApplication;
+    use game_input_model::{
+        config::{ControlAction, PlayerActionControl},
+        loaded::PlayerControllers,
+        play::{GameTick, TickedGameInputEvent},
+        GameInputEvent,
+    };
+    use net_model::play::{
+        GameInputEnvelope, NetData, NetEventChannel, NetMessageEvent, NetSessionDevice,
+        NetSessionDevices,
+    };
+    use network_session_model::{
+        play::{SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName},
+        SessionMessageEvent,
+    };
+
+    use session_server::{
+        model::{SessionCodeToId, SessionDeviceMappings, SessionIdToDeviceMappings},
+        system::NetworkInputResponderSystemDesc,
+    };
+
+    #[test]
+    fn sends_game_input_event_to_devices_in_session() -> Result<(), Error> {
+        run_test(
+            true,
+            NetMessageEvent::GameInputEvent(game_input_envelope(SessionDeviceId::new(0))),
+        )
+    }
+
+    #[test]
+    fn sends_session_not_found_when_socket_is_not_in_session() -> Result<(), Error> {
+        run_test(
+            false,
+            NetMessageEvent::SessionMessageEvent(SessionMessageEvent::SessionNotFound),
+        )
+    }
+
+    fn run_test(
+        session_exists: bool,
+        net_message_event_expected: NetMessageEvent,
+    ) -> Result<(), Error> {
+        let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+
+        let mut session_code_to_id = SessionCodeToId::default();
+        let mut session_id_to_device_mappings = SessionIdToDeviceMappings::default();
+        if session_exists {
+            let session_code = SessionCode::new(String::from("abcd"));
+            let session_device = SessionDevice::new(
+                SessionDeviceId::new(0),
+                SessionDeviceName::new(String::from("host")),
+                PlayerControllers::default(),
+            );
+            SessionDeviceMappings::new(&mut session_code_to_id, &mut session_id_to_device_mappings)
+                .insert(
+                    &session_code,
+                    NetSessionDevices::new(vec![NetSessionDevice::new(
+                        socket_addr,
+                        session_device,
+                    )]),
+                );
+        }
+
+        AmethystApplication::blank()
+            .with_resource(session_code_to_id)
+            .with_resource(session_id_to_device_mappings)
+            .with_system_desc(NetworkInputResponderSystemDesc::default(), "", &[])
+            .with_effect(move |world| {
+                // The server overwrites the sender's `SessionDeviceId` with its own record.
+                world
+                    .write_resource::<NetEventChannel<GameInputEnvelope>>()
+                    .single_write(NetData {
+                        socket_addr,
+                        data: game_input_envelope(SessionDeviceId::new(5)),
+                    });
+            })
+            .with_assertion(move |world| {
+                let transport_resource = world.read_resource::<TransportResource>();
+                let net_message_events = transport_resource
+                    .get_messages()
+                    .iter()
+                    .filter(|message| message.destination == socket_addr)
+                    .map(|message| {
+                        bincode::deserialize::<NetMessageEvent>(&message.payload)
+                            .expect("Failed to deserialize `NetMessageEvent`.")
+                    })
+                    .collect::<Vec<NetMessageEvent>>();
+
+                assert_eq!(vec![net_message_event_expected.clone()], net_message_events);
+            })
+            .run()
+    }
+
+    fn game_input_envelope(session_device_id: SessionDeviceId) -> GameInputEnvelope {
+        GameInputEnvelope::new(
+            session_device_id,
+            0,
+            0,
+            TickedGameInputEvent::new(
+                GameTick(3),
+                GameInputEvent::ActionPressed(PlayerActionControl::new(0, ControlAction::Attack)),
+            ),
+        )
+    }
+}
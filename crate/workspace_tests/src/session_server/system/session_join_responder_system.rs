@@ -0,0 +1,235 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::{Ipv4Addr, SocketAddr},
+        time::Instant,
+    };
+
+    use amethyst::{
+        ecs::WorldExt, network::simulation::TransportResource, shrev::EventChannel, Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use game_input_model::loaded::PlayerControllers;
+    use net_model::play::{NetData, NetMessageEvent, NetSessionDevice, NetSessionDevices};
+    use network_session_model::play::{
+        ProtocolVersion, Session, SessionCode, SessionDevice, SessionDeviceId, SessionDeviceName,
+        SessionDevices, SessionHandshake, Sessions,
+    };
+    use rollback_model::config::SessionSyncMode;
+    use session_join_model::{
+        play::{SessionJoinError, SessionJoinRequestParams, SessionRejectResponse},
+        SessionJoinEvent,
+    };
+
+    use session_server::{
+        model::{
+            SessionCodeToId, SessionCodesExpired, SessionDeviceMappings, SessionHandshakes,
+            SessionIdToDeviceMappings, SessionLimits,
+        },
+        system::SessionJoinResponderSystemDesc,
+    };
+
+    const SESSION_CODE: &str = "abcd";
+
+    #[test]
+    fn rejects_join_request_when_session_full() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: true,
+                session_code_expired: false,
+                session_limits: SessionLimits {
+                    session_devices_max: 1,
+                    ..Default::default()
+                },
+                protocol_version: ProtocolVersion::CURRENT,
+                session_sync_mode: SessionSyncMode::Lockstep,
+            },
+            SessionJoinError::SessionFull,
+        )
+    }
+
+    #[test]
+    fn rejects_join_request_when_session_code_expired() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: false,
+                session_code_expired: true,
+                session_limits: SessionLimits::default(),
+                protocol_version: ProtocolVersion::CURRENT,
+                session_sync_mode: SessionSyncMode::Lockstep,
+            },
+            SessionJoinError::SessionCodeExpired,
+        )
+    }
+
+    #[test]
+    fn rejects_join_request_when_session_code_not_found() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: false,
+                session_code_expired: false,
+                session_limits: SessionLimits::default(),
+                protocol_version: ProtocolVersion::CURRENT,
+                session_sync_mode: SessionSyncMode::Lockstep,
+            },
+            SessionJoinError::SessionCodeNotFound,
+        )
+    }
+
+    #[test]
+    fn rejects_join_request_when_banned() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: true,
+                session_code_expired: false,
+                session_limits: SessionLimits {
+                    request_limit: 0,
+                    ..Default::default()
+                },
+                protocol_version: ProtocolVersion::CURRENT,
+                session_sync_mode: SessionSyncMode::Lockstep,
+            },
+            SessionJoinError::Banned,
+        )
+    }
+
+    #[test]
+    fn rejects_join_request_when_version_mismatch() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: true,
+                session_code_expired: false,
+                session_limits: SessionLimits::default(),
+                protocol_version: ProtocolVersion::new(ProtocolVersion::CURRENT.0 + 1),
+                session_sync_mode: SessionSyncMode::Lockstep,
+            },
+            SessionJoinError::VersionMismatch,
+        )
+    }
+
+    #[test]
+    fn rejects_join_request_when_sync_mode_mismatch() -> Result<(), Error> {
+        run_test(
+            SetupParams {
+                session_exists: true,
+                session_code_expired: false,
+                session_limits: SessionLimits::default(),
+                protocol_version: ProtocolVersion::CURRENT,
+                session_sync_mode: SessionSyncMode::Rollback,
+            },
+            SessionJoinError::SyncModeMismatch,
+        )
+    }
+
+    fn run_test(
+        SetupParams {
+            session_exists,
+            session_code_expired,
+            session_limits,
+            protocol_version,
+            session_sync_mode,
+        }: SetupParams,
+        session_join_error_expected: SessionJoinError,
+    ) -> Result<(), Error> {
+        let session_code = SessionCode::new(String::from(SESSION_CODE));
+        let socket_addr_host = SocketAddr::from((Ipv4Addr::LOCALHOST, 1234));
+        let socket_addr = SocketAddr::from((Ipv4Addr::LOCALHOST, 1235));
+
+        let mut sessions = Sessions::default();
+        let mut session_code_to_id = SessionCodeToId::default();
+        let mut session_id_to_device_mappings = SessionIdToDeviceMappings::default();
+        let mut session_handshakes = SessionHandshakes::default();
+        if session_exists {
+            let session_device = SessionDevice::new(
+                SessionDeviceId::new(0),
+                SessionDeviceName::new(String::from("host")),
+                PlayerControllers::default(),
+            );
+            sessions.insert(
+                session_code.clone(),
+                Session::new(
+                    session_code.clone(),
+                    SessionDevices::new(vec![session_device.clone()]),
+                ),
+            );
+            SessionDeviceMappings::new(&mut session_code_to_id, &mut session_id_to_device_mappings)
+                .insert(
+                    &session_code,
+                    NetSessionDevices::new(vec![NetSessionDevice::new(
+                        socket_addr_host,
+                        session_device,
+                    )]),
+                );
+
+            // The session host uses lockstep mode.
+            let session_code_id = session_code_to_id
+                .id(&session_code)
+                .expect("Expected session code ID to exist.");
+            session_handshakes.insert(session_code_id, SessionHandshake::default());
+        }
+
+        let mut session_codes_expired = SessionCodesExpired::default();
+        if session_code_expired {
+            session_codes_expired.insert(session_code.clone(), Instant::now());
+        }
+
+        let mut session_join_request_params = SessionJoinRequestParams::new(
+            session_code.clone(),
+            SessionDeviceName::new(String::from("joiner")),
+            PlayerControllers::default(),
+            Default::default(),
+        );
+        session_join_request_params.session_handshake =
+            SessionHandshake::new(protocol_version, Default::default(), session_sync_mode);
+
+        AmethystApplication::blank()
+            .with_resource(sessions)
+            .with_resource(session_code_to_id)
+            .with_resource(session_id_to_device_mappings)
+            .with_resource(session_handshakes)
+            .with_resource(session_codes_expired)
+            .with_resource(session_limits)
+            .with_system_desc(SessionJoinResponderSystemDesc::default(), "", &[])
+            .with_effect(move |world| {
+                world
+                    .write_resource::<EventChannel<NetData<SessionJoinEvent>>>()
+                    .single_write(NetData {
+                        socket_addr,
+                        data: SessionJoinEvent::SessionJoinRequest(
+                            session_join_request_params.clone(),
+                        ),
+                    });
+            })
+            .with_assertion(move |world| {
+                let transport_resource = world.read_resource::<TransportResource>();
+                let net_message_events = transport_resource
+                    .get_messages()
+                    .iter()
+                    .filter(|message| message.destination == socket_addr)
+                    .map(|message| {
+                        bincode::deserialize::<NetMessageEvent>(&message.payload)
+                            .expect("Failed to deserialize `NetMessageEvent`.")
+                    })
+                    .collect::<Vec<NetMessageEvent>>();
+
+                assert_eq!(
+                    vec![NetMessageEvent::SessionJoinEvent(
+                        SessionJoinEvent::SessionReject(SessionRejectResponse::new(
+                            session_code.clone(),
+                            session_join_error_expected,
+                        ))
+                    )],
+                    net_message_events
+                );
+            })
+            .run()
+    }
+
+    struct SetupParams {
+        session_exists: bool,
+        session_code_expired: bool,
+        session_limits: SessionLimits,
+        protocol_version: ProtocolVersion,
+        session_sync_mode: SessionSyncMode,
+    }
+}
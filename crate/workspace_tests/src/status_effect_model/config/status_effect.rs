@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod tests {
+    use serde_yaml;
+
+    use status_effect_model::config::{StatusEffect, StatusEffectKind};
+
+    const STATUS_EFFECT_YAML: &str = "{ kind: 'poison', duration: 30, hp_damage: 2, interval: 5 }";
+
+    #[test]
+    fn deserialize_status_effect() {
+        let status_effect = serde_yaml::from_str::<StatusEffect>(STATUS_EFFECT_YAML)
+            .expect("Failed to deserialize `StatusEffect`.");
+
+        assert_eq!(
+            StatusEffect::new(StatusEffectKind::Poison, 30, 2, 5),
+            status_effect
+        );
+    }
+
+    #[test]
+    fn deserialize_status_effect_with_defaults() {
+        let status_effect = serde_yaml::from_str::<StatusEffect>("{ kind: 'freeze' }")
+            .expect("Failed to deserialize `StatusEffect`.");
+
+        assert_eq!(
+            StatusEffect::new(StatusEffectKind::Freeze, 60, 0, 10),
+            status_effect
+        );
+    }
+}
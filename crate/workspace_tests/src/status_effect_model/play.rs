@@ -0,0 +1 @@
+mod active_status_effects;
@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod tests {
+    use logic_clock::LogicClock;
+
+    use status_effect_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::{ActiveStatusEffect, ActiveStatusEffects},
+    };
+
+    #[test]
+    fn apply_adds_status_effect_of_new_kind() {
+        let mut active_status_effects = ActiveStatusEffects::default();
+
+        active_status_effects.apply(StatusEffect::new(StatusEffectKind::Burn, 10, 1, 2));
+        active_status_effects.apply(StatusEffect::new(StatusEffectKind::Slow, 20, 0, 2));
+
+        assert!(active_status_effects.contains(StatusEffectKind::Burn));
+        assert!(active_status_effects.contains(StatusEffectKind::Slow));
+        assert!(!active_status_effects.contains(StatusEffectKind::Freeze));
+        assert_eq!(2, active_status_effects.len());
+    }
+
+    #[test]
+    fn apply_replaces_status_effect_of_same_kind() {
+        let mut active_status_effects = ActiveStatusEffects::default();
+        active_status_effects.apply(StatusEffect::new(StatusEffectKind::Burn, 10, 1, 2));
+        active_status_effects[0].duration_clock.tick();
+
+        let status_effect = StatusEffect::new(StatusEffectKind::Burn, 30, 3, 4);
+        active_status_effects.apply(status_effect);
+
+        assert_eq!(
+            ActiveStatusEffects::new(vec![ActiveStatusEffect::new(
+                status_effect,
+                LogicClock::new(30),
+                LogicClock::new(4),
+            )]),
+            active_status_effects
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod status_effect_apply_system;
+mod status_effect_tick_system;
+mod status_effect_tint_system;
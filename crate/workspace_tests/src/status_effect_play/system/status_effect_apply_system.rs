@@ -0,0 +1,151 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        shrev::EventChannel,
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use character_model::loaded::CharacterDefendTransitions;
+    use collision_model::{
+        config::{Hit, Interaction, InteractionKind},
+        play::HitEvent,
+    };
+    use mirrored_model::play::Mirrored;
+    use object_model::play::HealthPoints;
+    use sequence_model::loaded::SequenceId;
+    use shape_model::Volume;
+    use status_effect_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::ActiveStatusEffects,
+    };
+
+    use status_effect_play::StatusEffectApplySystem;
+
+    #[test]
+    fn applies_status_effect_to_hit_object() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectApplySystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().with(HealthPoints::new(100)).build();
+
+                send_event(world, entity_from, entity_to, Some(status_effect()));
+
+                world.insert(entity_to);
+            })
+            .with_assertion(|world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                let active_status_effectses = world.read_storage::<ActiveStatusEffects>();
+                let active_status_effects = active_status_effectses
+                    .get(entity_to)
+                    .expect("Expected `ActiveStatusEffects` component to exist.");
+                assert!(active_status_effects.contains(StatusEffectKind::Burn));
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_apply_status_effect_when_hit_has_none() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectApplySystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().with(HealthPoints::new(100)).build();
+
+                send_event(world, entity_from, entity_to, None);
+
+                world.insert(entity_to);
+            })
+            .with_assertion(|world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                assert!(!world
+                    .read_storage::<ActiveStatusEffects>()
+                    .contains(entity_to));
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_apply_status_effect_to_object_without_health_points() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectApplySystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world.create_entity().build();
+                let entity_to = world.create_entity().build();
+
+                send_event(world, entity_from, entity_to, Some(status_effect()));
+
+                world.insert(entity_to);
+            })
+            .with_assertion(|world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                assert!(!world
+                    .read_storage::<ActiveStatusEffects>()
+                    .contains(entity_to));
+            })
+            .run()
+    }
+
+    #[test]
+    fn does_not_apply_status_effect_when_hit_is_blocked() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectApplySystem::new(), "", &[])
+            .with_effect(|world| {
+                let entity_from = world.create_entity().with(Mirrored::new(false)).build();
+                let entity_to = world
+                    .create_entity()
+                    .with(HealthPoints::new(100))
+                    .with(Mirrored::new(true))
+                    .with(SequenceId::new(1))
+                    .with(CharacterDefendTransitions::new(
+                        SequenceId::new(1),
+                        SequenceId::new(2),
+                        SequenceId::new(3),
+                    ))
+                    .build();
+
+                send_event(world, entity_from, entity_to, Some(status_effect()));
+
+                world.insert(entity_to);
+            })
+            .with_assertion(|world| {
+                let entity_to = *world.read_resource::<Entity>();
+
+                assert!(!world
+                    .read_storage::<ActiveStatusEffects>()
+                    .contains(entity_to));
+            })
+            .run()
+    }
+
+    fn status_effect() -> StatusEffect {
+        StatusEffect::new(StatusEffectKind::Burn, 10, 1, 2)
+    }
+
+    fn send_event(
+        world: &mut World,
+        entity_from: Entity,
+        entity_to: Entity,
+        status_effect: Option<StatusEffect>,
+    ) {
+        let hit = Hit {
+            status_effect,
+            ..Default::default()
+        };
+        let interaction = Interaction::new(InteractionKind::Hit(hit), vec![], true);
+        let body = Volume::Box {
+            x: 0,
+            y: 0,
+            z: 0,
+            w: 1,
+            h: 1,
+            d: 1,
+        };
+        let mut ec = world.write_resource::<EventChannel<HitEvent>>();
+        ec.single_write(HitEvent::new(entity_from, entity_to, interaction, body));
+    } // kcov-ignore
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        ecs::{Builder, Entity, World, WorldExt},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use kinematic_model::config::Velocity;
+    use object_model::play::HealthPoints;
+    use sequence_model::loaded::SequenceId;
+    use status_effect_model::{
+        config::{StatusEffect, StatusEffectKind},
+        loaded::StatusEffectTransitions,
+        play::ActiveStatusEffects,
+    };
+
+    use status_effect_play::StatusEffectTickSystem;
+
+    #[test]
+    fn subtracts_health_points_every_interval() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Burn, 10, 5, 2));
+            })
+            .with_assertion(|world| assert_health_points(world, 100))
+            .with_assertion(|world| assert_health_points(world, 95))
+            .with_assertion(|world| assert_health_points(world, 95))
+            .with_assertion(|world| assert_health_points(world, 90))
+            .run()
+    }
+
+    #[test]
+    fn removes_status_effects_when_duration_ends() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Poison, 2, 0, 2));
+            })
+            .with_assertion(|world| assert_status_effects_active(world, true))
+            .with_assertion(|world| assert_status_effects_active(world, false))
+            .run()
+    }
+
+    #[test]
+    fn freeze_stops_movement_and_holds_frozen_sequence() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Freeze, 10, 0, 2));
+            })
+            .with_assertion(|world| {
+                assert_velocity(world, Velocity::<f32>::new(0., 3., 0.));
+
+                let entity = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&SequenceId::new(5)),
+                    world.read_storage::<SequenceId>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn freeze_does_not_hold_frozen_sequence_when_health_points_is_zero() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Freeze, 10, 0, 2));
+
+                let entity = *world.read_resource::<Entity>();
+                world
+                    .write_storage::<HealthPoints>()
+                    .insert(entity, HealthPoints::new(0))
+                    .expect("Failed to insert `HealthPoints` component.");
+            })
+            .with_assertion(|world| {
+                let entity = *world.read_resource::<Entity>();
+                assert_eq!(
+                    Some(&SequenceId::new(0)),
+                    world.read_storage::<SequenceId>().get(entity)
+                );
+            })
+            .run()
+    }
+
+    #[test]
+    fn slow_reduces_horizontal_velocity() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Slow, 10, 0, 2));
+            })
+            .with_assertion(|world| assert_velocity(world, Velocity::<f32>::new(2., 3., 1.)))
+            .run()
+    }
+
+    #[test]
+    fn slow_reduces_horizontal_velocity_every_tick() -> Result<(), Error> {
+        AmethystApplication::blank()
+            .with_system(StatusEffectTickSystem::new(), "", &[])
+            .with_effect(|world| {
+                create_entity(world, StatusEffect::new(StatusEffectKind::Slow, 10, 0, 2));
+            })
+            .with_assertion(|world| assert_velocity(world, Velocity::<f32>::new(2., 3., 1.)))
+            .with_assertion(|world| assert_velocity(world, Velocity::<f32>::new(1., 3., 0.5)))
+            .with_assertion(|world| assert_velocity(world, Velocity::<f32>::new(0.5, 3., 0.25)))
+            .run()
+    }
+
+    fn create_entity(world: &mut World, status_effect: StatusEffect) {
+        let mut active_status_effects = ActiveStatusEffects::default();
+        active_status_effects.apply(status_effect);
+
+        let entity = world
+            .create_entity()
+            .with(active_status_effects)
+            .with(HealthPoints::new(100))
+            .with(Velocity::<f32>::new(4., 3., 2.))
+            .with(SequenceId::new(0))
+            .with(StatusEffectTransitions::new(SequenceId::new(5)))
+            .build();
+
+        world.insert(entity);
+    }
+
+    fn assert_health_points(world: &mut World, health_points: u32) {
+        let entity = *world.read_resource::<Entity>();
+        assert_eq!(
+            Some(&HealthPoints::new(health_points)),
+            world.read_storage::<HealthPoints>().get(entity)
+        );
+    }
+
+    fn assert_status_effects_active(world: &mut World, active: bool) {
+        let entity = *world.read_resource::<Entity>();
+        assert_eq!(
+            active,
+            world.read_storage::<ActiveStatusEffects>().contains(entity)
+        );
+    }
+
+    fn assert_velocity(world: &mut World, velocity: Velocity<f32>) {
+        let entity = *world.read_resource::<Entity>();
+        assert_eq!(
+            Some(&velocity),
+            world.read_storage::<Velocity<f32>>().get(entity)
+        );
+    }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use amethyst::{
+        assets::{AssetStorage, Loader},
+        ecs::{Builder, Entity, Read, ReadExpect, WorldExt},
+        renderer::{palette::Srgba, resources::Tint},
+        Error,
+    };
+    use amethyst_test::AmethystApplication;
+    use application_test_support::AutexousiousApplication;
+    use sequence_model::play::FrameIndexClock;
+    use sprite_model::loaded::TintSequence;
+    use status_effect_model::{
+        config::{StatusEffect, StatusEffectKind},
+        play::ActiveStatusEffects,
+    };
+
+    use status_effect_play::StatusEffectTintSystem;
+
+    #[test]
+    fn tints_object_while_status_effect_is_active_and_resets_afterwards() -> Result<(), Error> {
+        let tint_white = Tint(Srgba::new(1., 1., 1., 1.));
+
+        AmethystApplication::blank()
+            .with_system(StatusEffectTintSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let mut active_status_effects = ActiveStatusEffects::default();
+                active_status_effects.apply(StatusEffect::new(StatusEffectKind::Freeze, 10, 0, 2));
+
+                let entity = world
+                    .create_entity()
+                    .with(active_status_effects)
+                    .with(tint_white)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+                let tints = world.read_storage::<Tint>();
+                let tint = tints.get(entity).expect("Expected `Tint` to exist.");
+
+                assert_ne!(&tint_white, tint);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world.write_storage::<ActiveStatusEffects>().remove(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(Some(&tint_white), world.read_storage::<Tint>().get(entity));
+            })
+            .run()
+    }
+
+    #[test]
+    fn blends_status_effect_tint_with_sequence_tint_and_restores_sequence_tint_afterwards(
+    ) -> Result<(), Error> {
+        let tint_sequence_frame = Tint(Srgba::new(1., 0.5, 0.5, 1.));
+
+        AutexousiousApplication::config_base()
+            .with_system(StatusEffectTintSystem::new(), "", &[])
+            .with_effect(move |world| {
+                let tint_sequence_handle = {
+                    let (loader, tint_sequence_assets) = world.system_data::<(
+                        ReadExpect<'_, Loader>,
+                        Read<'_, AssetStorage<TintSequence>>,
+                    )>();
+
+                    let tint_sequence = TintSequence::new(vec![
+                        Tint(Srgba::new(1., 1., 1., 1.)),
+                        tint_sequence_frame,
+                    ]);
+                    loader.load_from_data(tint_sequence, (), &tint_sequence_assets)
+                };
+
+                let entity = world
+                    .create_entity()
+                    .with(tint_sequence_handle)
+                    .with(FrameIndexClock::new_with_value(2, 1))
+                    .with(tint_sequence_frame)
+                    .build();
+
+                world.insert(entity);
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                let mut active_status_effects = ActiveStatusEffects::default();
+                active_status_effects.apply(StatusEffect::new(StatusEffectKind::Freeze, 10, 0, 2));
+
+                world
+                    .write_storage::<ActiveStatusEffects>()
+                    .insert(entity, active_status_effects)
+                    .expect("Failed to insert `ActiveStatusEffects` component.");
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&Tint(Srgba::new(0.55, 0.375, 0.5, 1.))),
+                    world.read_storage::<Tint>().get(entity)
+                );
+            })
+            .with_effect(|world| {
+                let entity = *world.read_resource::<Entity>();
+                world.write_storage::<ActiveStatusEffects>().remove(entity);
+            })
+            .with_assertion(move |world| {
+                let entity = *world.read_resource::<Entity>();
+
+                assert_eq!(
+                    Some(&tint_sequence_frame),
+                    world.read_storage::<Tint>().get(entity)
+                );
+            })
+            .run_winit_loop()
+    }
+}
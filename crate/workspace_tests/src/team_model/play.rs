@@ -1 +1,4 @@
 mod independent_counter;
+mod team;
+mod team_colour;
+mod team_counter;
@@ -0,0 +1,17 @@
+#[cfg(test)]
+mod tests {
+    use team_model::play::{IndependentCounter, Team, TeamColour, TeamCounter};
+
+    #[test]
+    fn colour_is_none_for_independent_team() {
+        assert_eq!(None, Team::Independent(IndependentCounter::new(2)).colour());
+    }
+
+    #[test]
+    fn colour_is_team_colour_for_numbered_team() {
+        assert_eq!(
+            Some(TeamColour::Yellow),
+            Team::Number(TeamCounter::new(3)).colour()
+        );
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use team_model::play::{TeamColour, TeamCounter};
+
+    #[test]
+    fn from_team_counter_maps_to_colour() {
+        assert_eq!(TeamColour::Red, TeamColour::from(TeamCounter::new(0)));
+        assert_eq!(TeamColour::Blue, TeamColour::from(TeamCounter::new(1)));
+        assert_eq!(TeamColour::Green, TeamColour::from(TeamCounter::new(2)));
+        assert_eq!(TeamColour::Yellow, TeamColour::from(TeamCounter::new(3)));
+    }
+
+    #[test]
+    fn from_team_counter_wraps_around_colour_count() {
+        assert_eq!(
+            TeamColour::Blue,
+            TeamColour::from(TeamCounter::new(TeamColour::COUNT + 1))
+        );
+    }
+
+    #[test]
+    fn display_is_colour_name() {
+        assert_eq!("Green", TeamColour::Green.to_string());
+    }
+}
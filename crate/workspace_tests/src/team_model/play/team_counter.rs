@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod tests {
+    use team_model::play::{TeamColour, TeamCounter};
+
+    #[test]
+    fn next_switches_independent_to_first_team() {
+        assert_eq!(Some(TeamCounter::new(0)), TeamCounter::next(None));
+    }
+
+    #[test]
+    fn next_switches_to_subsequent_team() {
+        assert_eq!(
+            Some(TeamCounter::new(1)),
+            TeamCounter::next(Some(TeamCounter::new(0)))
+        );
+    }
+
+    #[test]
+    fn next_switches_last_team_to_independent() {
+        assert_eq!(
+            None,
+            TeamCounter::next(Some(TeamCounter::new(TeamColour::COUNT - 1)))
+        );
+    }
+}